@@ -0,0 +1,281 @@
+// Copyright 2026, The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+//! Base32 encoding and decoding with the [RFC 4648](https://datatracker.ietf.org/doc/html/rfc4648#section-6) alphabet,
+//! and with the human-oriented [z-base-32](https://philzimmermann.com/docs/human-oriented-base-32-encoding.txt)
+//! alphabet.
+//!
+//! Encoding produces lower case output without padding; use [encode_padded] for `=` padding. Decoding accepts lower
+//! case, with or without padding, and [DecodeMode::Lenient] decoding also accepts upper case and whitespace. z-base-32
+//! is never padded, and its alphabet avoids characters that are easily confused when read aloud or written down, which
+//! makes it suited to recovery data that people transcribe.
+//!
+//! ```edition2018
+//! # use tari_utilities::base32;
+//! assert_eq!(base32::encode(b"foobar"), "mzxw6ytboi");
+//! assert_eq!(base32::encode_padded(b"foobar"), "mzxw6ytboi======");
+//! assert_eq!(base32::decode("mzxw6ytboi======").unwrap(), b"foobar");
+//! assert_eq!(base32::encode_z(b"hello"), "pb1sa5dx");
+//! assert_eq!(base32::decode_z("pb1sa5dx").unwrap(), b"hello");
+//! ```
+
+use alloc::{string::String, vec::Vec};
+
+use snafu::prelude::*;
+
+use crate::encoding::DecodeMode;
+
+/// The RFC 4648 Base32 alphabet, in lower case.
+pub const ALPHABET: &[u8; 32] = b"abcdefghijklmnopqrstuvwxyz234567";
+
+/// The z-base-32 alphabet.
+pub const Z_ALPHABET: &[u8; 32] = b"ybndrfg8ejkmcpqxot1uwisza345h769";
+
+/// Errors for Base32 decoding.
+#[derive(Debug, Snafu, PartialEq, Eq)]
+pub enum Base32Error {
+    /// The input contains a character that is not in the alphabet.
+    #[snafu(display("Invalid Base32 character `{character}' at position {index}"))]
+    InvalidCharacter {
+        /// The offending character
+        character: char,
+        /// The position of the character in the input
+        index: usize,
+    },
+    /// The input length, excluding padding, cannot be produced by encoding whole bytes.
+    #[snafu(display("Invalid Base32 length {len}"))]
+    InvalidLength {
+        /// The number of characters, excluding padding
+        len: usize,
+    },
+    /// The unused bits of the last character are not zero.
+    #[snafu(display("Base32 input has non-zero trailing bits"))]
+    InvalidTrailingBits {},
+    /// The input has padding of the wrong length.
+    #[snafu(display("Invalid Base32 padding at position {index}"))]
+    InvalidPadding {
+        /// The position of the first padding character
+        index: usize,
+    },
+}
+
+/// Encode bytes as unpadded lower case Base32.
+pub fn encode(data: &[u8]) -> String {
+    encode_with(ALPHABET, data)
+}
+
+/// Encode bytes as lower case Base32, padded with `=` to a multiple of 8 characters.
+pub fn encode_padded(data: &[u8]) -> String {
+    let mut s = encode(data);
+    while !s.len().is_multiple_of(8) {
+        s.push('=');
+    }
+    s
+}
+
+/// Decode Base32 in [DecodeMode::Strict] mode.
+pub fn decode(s: &str) -> Result<Vec<u8>, Base32Error> {
+    decode_with_mode(s, DecodeMode::Strict)
+}
+
+/// Decode Base32.
+///
+/// In [DecodeMode::Strict] mode only the output of [encode] or [encode_padded] is accepted: lower case, and either
+/// unpadded or padded to a multiple of 8 characters. In [DecodeMode::Lenient] mode, ASCII whitespace is ignored, either
+/// case is accepted and any trailing `=` padding is removed.
+pub fn decode_with_mode(s: &str, mode: DecodeMode) -> Result<Vec<u8>, Base32Error> {
+    match mode {
+        DecodeMode::Strict => {
+            let unpadded = s.trim_end_matches('=');
+            if unpadded.len() < s.len() {
+                let padded_len = unpadded.len().div_ceil(8) * 8;
+                ensure!(s.len() == padded_len, InvalidPaddingSnafu { index: unpadded.len() });
+            }
+            decode_with(ALPHABET, unpadded, false)
+        },
+        DecodeMode::Lenient => decode_with(ALPHABET, without_whitespace(s).trim_end_matches('='), true),
+    }
+}
+
+/// Encode bytes as z-base-32.
+pub fn encode_z(data: &[u8]) -> String {
+    encode_with(Z_ALPHABET, data)
+}
+
+/// Decode z-base-32 in [DecodeMode::Strict] mode.
+pub fn decode_z(s: &str) -> Result<Vec<u8>, Base32Error> {
+    decode_z_with_mode(s, DecodeMode::Strict)
+}
+
+/// Decode z-base-32. [DecodeMode::Strict] mode accepts only lower case; [DecodeMode::Lenient] mode ignores ASCII
+/// whitespace and accepts either case.
+pub fn decode_z_with_mode(s: &str, mode: DecodeMode) -> Result<Vec<u8>, Base32Error> {
+    match mode {
+        DecodeMode::Strict => decode_with(Z_ALPHABET, s, false),
+        DecodeMode::Lenient => decode_with(Z_ALPHABET, &without_whitespace(s), true),
+    }
+}
+
+fn without_whitespace(s: &str) -> String {
+    s.split_ascii_whitespace().collect()
+}
+
+/// Encode `data` with the given 32-character alphabet, without padding.
+pub(crate) fn encode_with(alphabet: &[u8; 32], data: &[u8]) -> String {
+    let mut s = String::with_capacity((data.len() * 8).div_ceil(5));
+    let mut acc = 0u16;
+    let mut bits = 0;
+    for byte in data {
+        acc = (acc << 8) | u16::from(*byte);
+        bits += 8;
+        while bits >= 5 {
+            bits -= 5;
+            s.push(char::from(alphabet[usize::from((acc >> bits) & 31)]));
+        }
+    }
+    if bits > 0 {
+        s.push(char::from(alphabet[usize::from((acc << (5 - bits)) & 31)]));
+    }
+    s
+}
+
+/// Decode unpadded input with the given 32-character alphabet, optionally ignoring ASCII case.
+pub(crate) fn decode_with(alphabet: &[u8; 32], s: &str, ignore_case: bool) -> Result<Vec<u8>, Base32Error> {
+    let len = s.chars().count();
+    ensure!(!matches!(len % 8, 1 | 3 | 6), InvalidLengthSnafu { len });
+    let mut out = Vec::with_capacity(len * 5 / 8);
+    let mut acc = 0u16;
+    let mut bits = 0;
+    for (index, character) in s.chars().enumerate() {
+        let c = if ignore_case {
+            character.to_ascii_lowercase()
+        } else {
+            character
+        };
+        let value = alphabet
+            .iter()
+            .position(|a| char::from(*a) == c)
+            .context(InvalidCharacterSnafu { character, index })?;
+        acc = (acc << 5) | value as u16;
+        bits += 5;
+        if bits >= 8 {
+            bits -= 8;
+            out.push((acc >> bits) as u8);
+        }
+    }
+    ensure!(acc & ((1 << bits) - 1) == 0, InvalidTrailingBitsSnafu);
+    Ok(out)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn rfc4648_vectors() {
+        let vectors: [(&[u8], &str); 7] = [
+            (b"", ""),
+            (b"f", "my======"),
+            (b"fo", "mzxq===="),
+            (b"foo", "mzxw6==="),
+            (b"foob", "mzxw6yq="),
+            (b"fooba", "mzxw6ytb"),
+            (b"foobar", "mzxw6ytboi======"),
+        ];
+        for (data, encoded) in vectors {
+            assert_eq!(encode_padded(data), encoded);
+            assert_eq!(encode(data), encoded.trim_end_matches('='));
+            assert_eq!(decode(encoded).unwrap(), data);
+            assert_eq!(
+                decode_with_mode(&encoded.to_ascii_uppercase(), DecodeMode::Lenient).unwrap(),
+                data
+            );
+            assert_eq!(decode(encoded.trim_end_matches('=')).unwrap(), data);
+        }
+    }
+
+    #[test]
+    fn z_base_32() {
+        let vectors: [(&[u8], &str); 4] = [
+            (b"", ""),
+            (&[0], "yy"),
+            (b"hello", "pb1sa5dx"),
+            (b"yes mani !", "xf1zgedpcfzg1ebb"),
+        ];
+        for (data, encoded) in vectors {
+            assert_eq!(encode_z(data), encoded);
+            assert_eq!(decode_z(encoded).unwrap(), data);
+            assert_eq!(
+                decode_z_with_mode(&encoded.to_ascii_uppercase(), DecodeMode::Lenient).unwrap(),
+                data
+            );
+        }
+        assert_eq!(
+            decode_z("pb1sa5dl"),
+            Err(Base32Error::InvalidCharacter {
+                character: 'l',
+                index: 7
+            })
+        );
+        assert_eq!(
+            decode_z("pb1sa5dx=="),
+            Err(Base32Error::InvalidCharacter {
+                character: '=',
+                index: 8
+            })
+        );
+    }
+
+    #[test]
+    fn decode_modes() {
+        assert_eq!(decode_with_mode("mzxw6", DecodeMode::Strict).unwrap(), b"foo");
+        assert_eq!(decode_with_mode("mzxw6===", DecodeMode::Strict).unwrap(), b"foo");
+        for malformed in ["MZXW6", "mzxw6=", "mzxw6====", " mzxw6", "mzx w6"] {
+            assert!(
+                decode_with_mode(malformed, DecodeMode::Strict).is_err(),
+                "{}",
+                malformed
+            );
+            assert_eq!(decode_with_mode(malformed, DecodeMode::Lenient).unwrap(), b"foo");
+        }
+        assert_eq!(
+            decode_with_mode("mzxw6=", DecodeMode::Strict),
+            Err(Base32Error::InvalidPadding { index: 5 })
+        );
+        assert_eq!(decode_z_with_mode("pb1sa5dx", DecodeMode::Strict).unwrap(), b"hello");
+        assert!(decode_z_with_mode("PB1SA5DX", DecodeMode::Strict).is_err());
+        assert_eq!(decode_z_with_mode("PB1S A5DX", DecodeMode::Lenient).unwrap(), b"hello");
+    }
+
+    #[test]
+    fn invalid_input() {
+        assert_eq!(
+            decode("mzx1"),
+            Err(Base32Error::InvalidCharacter {
+                character: '1',
+                index: 3
+            })
+        );
+        assert_eq!(decode("mzx"), Err(Base32Error::InvalidLength { len: 3 }));
+        assert_eq!(decode("mz"), Err(Base32Error::InvalidTrailingBits {}));
+    }
+}
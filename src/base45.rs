@@ -0,0 +1,166 @@
+// Copyright 2026, The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+//! [Base45](https://datatracker.ietf.org/doc/html/rfc9285) encoding and decoding.
+//!
+//! Base45 uses exactly the 45 characters of the QR code alphanumeric mode, which stores 11 bits for every 2
+//! characters. Encoding 2 bytes as 3 characters therefore gives a QR code about 20% denser than the same data in
+//! Base64, which QR codes must store in byte mode.
+//!
+//! ```edition2018
+//! # use tari_utilities::base45;
+//! assert_eq!(base45::encode(b"Hello!!"), "%69 VD92EX0");
+//! assert_eq!(base45::decode("%69 VD92EX0").unwrap(), b"Hello!!");
+//! ```
+
+use alloc::{string::String, vec::Vec};
+use core::convert::TryFrom;
+
+use snafu::prelude::*;
+
+/// The Base45 alphabet, which is the QR code alphanumeric character set.
+pub const ALPHABET: &[u8; 45] = b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZ $%*+-./:";
+
+/// Errors for Base45 decoding.
+#[derive(Debug, Snafu, PartialEq, Eq)]
+pub enum Base45Error {
+    /// The input contains a character that is not in the alphabet.
+    #[snafu(display("Invalid Base45 character `{character}' at position {index}"))]
+    InvalidCharacter {
+        /// The offending character
+        character: char,
+        /// The position of the character in the input
+        index: usize,
+    },
+    /// The input length leaves a single character in the last group.
+    #[snafu(display("Invalid Base45 length {len}"))]
+    InvalidLength {
+        /// The number of characters in the input
+        len: usize,
+    },
+    /// A group of characters encodes a value that is too large for its bytes.
+    #[snafu(display("Base45 group at position {index} is out of range"))]
+    Overflow {
+        /// The position of the first character of the group
+        index: usize,
+    },
+}
+
+/// Returns true if every character of `s` is in the QR code alphanumeric character set.
+pub fn is_qr_alphanumeric(s: &str) -> bool {
+    s.bytes().all(|b| ALPHABET.contains(&b))
+}
+
+/// Encode bytes as Base45.
+pub fn encode(data: &[u8]) -> String {
+    let mut s = String::with_capacity(data.len().div_ceil(2) * 3);
+    for chunk in data.chunks(2) {
+        let (mut value, digits) = match chunk {
+            [a, b] => (usize::from(*a) * 256 + usize::from(*b), 3),
+            _ => (usize::from(chunk[0]), 2),
+        };
+        for _ in 0..digits {
+            s.push(char::from(ALPHABET[value % 45]));
+            value /= 45;
+        }
+    }
+    s
+}
+
+/// Decode Base45.
+pub fn decode(s: &str) -> Result<Vec<u8>, Base45Error> {
+    let digits = s
+        .chars()
+        .enumerate()
+        .map(|(index, character)| {
+            ALPHABET
+                .iter()
+                .position(|a| char::from(*a) == character)
+                .context(InvalidCharacterSnafu { character, index })
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    let len = digits.len();
+    ensure!(len % 3 != 1, InvalidLengthSnafu { len });
+    let mut out = Vec::with_capacity(len / 3 * 2 + 1);
+    for (group, chunk) in digits.chunks(3).enumerate() {
+        let index = group * 3;
+        let value = chunk.iter().rev().fold(0, |value, digit| value * 45 + digit);
+        if chunk.len() == 3 {
+            let value = u16::try_from(value).ok().context(OverflowSnafu { index })?;
+            out.extend_from_slice(&value.to_be_bytes());
+        } else {
+            out.push(u8::try_from(value).ok().context(OverflowSnafu { index })?);
+        }
+    }
+    Ok(out)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn rfc9285_vectors() {
+        let vectors: [(&[u8], &str); 5] = [
+            (b"AB", "BB8"),
+            (b"Hello!!", "%69 VD92EX0"),
+            (b"base-45", "UJCLQE7W581"),
+            (b"ietf!", "QED8WEX0"),
+            (b"", ""),
+        ];
+        for (data, encoded) in vectors {
+            assert_eq!(encode(data), encoded);
+            assert_eq!(decode(encoded).unwrap(), data);
+            assert!(is_qr_alphanumeric(encoded));
+        }
+    }
+
+    #[test]
+    fn round_trip() {
+        let data = (0..=255u8).collect::<Vec<_>>();
+        assert_eq!(decode(&encode(&data)).unwrap(), data);
+        assert_eq!(encode(&[0xff, 0xff]), "FGW");
+        assert_eq!(encode(&[0xff]), "U5");
+    }
+
+    #[test]
+    fn invalid_input() {
+        assert_eq!(decode("BB8A"), Err(Base45Error::InvalidLength { len: 4 }));
+        assert_eq!(
+            decode("BB8a0"),
+            Err(Base45Error::InvalidCharacter {
+                character: 'a',
+                index: 3
+            })
+        );
+        assert_eq!(
+            decode("B€8"),
+            Err(Base45Error::InvalidCharacter {
+                character: '€',
+                index: 1
+            })
+        );
+        assert_eq!(decode("GGW"), Err(Base45Error::Overflow { index: 0 }));
+        assert_eq!(decode("BB8V5"), Err(Base45Error::Overflow { index: 3 }));
+        assert!(!is_qr_alphanumeric("abc"));
+    }
+}
@@ -0,0 +1,244 @@
+// Copyright 2026, The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+//! Bitcoin-style Base58 and Base58Check encoding and decoding.
+//!
+//! This is the encoding used for addresses and keys in Bitcoin and many other projects. Note that it differs from the
+//! Monero block-based variant used by the [Base58](crate::encoding::Base58) trait.
+//!
+//! ```edition2018
+//! # use tari_utilities::base58;
+//! assert_eq!(base58::encode(b"hello"), "Cn8eVZg");
+//! assert_eq!(base58::decode("Cn8eVZg").unwrap(), b"hello");
+//!
+//! let address = base58::encode_check(&[0u8; 21]);
+//! assert_eq!(address, "1111111111111111111114oLvT2");
+//! assert_eq!(base58::decode_check(&address).unwrap(), vec![0u8; 21]);
+//! ```
+
+use alloc::{string::String, vec::Vec};
+use core::convert::TryFrom;
+
+use sha2::{Digest, Sha256};
+use snafu::prelude::*;
+
+use crate::encoding::DecodeMode;
+
+/// The Bitcoin Base58 alphabet, which omits `0`, `O`, `I` and `l` to avoid visual ambiguity.
+pub const ALPHABET: &[u8; 58] = b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+
+/// The number of checksum bytes appended by Base58Check.
+pub const CHECKSUM_LENGTH: usize = 4;
+
+/// Errors for Base58 and Base58Check decoding.
+#[derive(Debug, Snafu, PartialEq, Eq)]
+pub enum Base58DecodeError {
+    /// The input contains a character that is not in the Base58 alphabet.
+    #[snafu(display("Invalid Base58 character `{character}' at position {index}"))]
+    InvalidCharacter {
+        /// The offending character
+        character: char,
+        /// The position of the character in the input
+        index: usize,
+    },
+    /// The decoded data is too short to contain a checksum.
+    #[snafu(display("Base58Check data of {len} bytes is too short to contain a checksum"))]
+    TooShort {
+        /// The number of decoded bytes
+        len: usize,
+    },
+    /// The checksum does not match the payload.
+    #[snafu(display("Base58Check checksum does not match"))]
+    InvalidChecksum {},
+}
+
+/// Encode bytes as a Base58 string. Each leading zero byte is encoded as a leading `1`.
+pub fn encode(bytes: &[u8]) -> String {
+    let zeros = bytes.iter().take_while(|b| **b == 0).count();
+    // Base58 digits, least significant first. Each byte needs at most log(256)/log(58) ~ 1.37 digits.
+    let mut digits: Vec<u8> = Vec::with_capacity((bytes.len() - zeros) * 138 / 100 + 1);
+    for byte in &bytes[zeros..] {
+        let mut carry = u32::from(*byte);
+        for digit in &mut digits {
+            carry += u32::from(*digit) << 8;
+            *digit = (carry % 58) as u8;
+            carry /= 58;
+        }
+        while carry > 0 {
+            digits.push((carry % 58) as u8);
+            carry /= 58;
+        }
+    }
+    let mut s = String::with_capacity(zeros + digits.len());
+    s.extend(core::iter::repeat_n('1', zeros));
+    s.extend(
+        digits
+            .iter()
+            .rev()
+            .map(|digit| char::from(ALPHABET[usize::from(*digit)])),
+    );
+    s
+}
+
+/// Decode a Base58 string in [DecodeMode::Strict] mode. Each leading `1` is decoded as a leading zero byte.
+pub fn decode(s: &str) -> Result<Vec<u8>, Base58DecodeError> {
+    decode_with_mode(s, DecodeMode::Strict)
+}
+
+/// Decode a Base58 string. [DecodeMode::Lenient] mode ignores ASCII whitespace; Base58 is case sensitive and unpadded
+/// in both modes.
+pub fn decode_with_mode(s: &str, mode: DecodeMode) -> Result<Vec<u8>, Base58DecodeError> {
+    match mode {
+        DecodeMode::Strict => decode_strict(s),
+        DecodeMode::Lenient => decode_strict(&s.split_ascii_whitespace().collect::<String>()),
+    }
+}
+
+fn decode_strict(s: &str) -> Result<Vec<u8>, Base58DecodeError> {
+    let zeros = s.bytes().take_while(|c| *c == b'1').count();
+    // Bytes, least significant first
+    let mut bytes: Vec<u8> = Vec::with_capacity(s.len());
+    for (index, character) in s.chars().enumerate().skip(zeros) {
+        let mut carry = digit_value(character).context(InvalidCharacterSnafu { character, index })?;
+        for byte in &mut bytes {
+            carry += u32::from(*byte) * 58;
+            *byte = (carry & 0xff) as u8;
+            carry >>= 8;
+        }
+        while carry > 0 {
+            bytes.push((carry & 0xff) as u8);
+            carry >>= 8;
+        }
+    }
+    bytes.extend(core::iter::repeat_n(0, zeros));
+    bytes.reverse();
+    Ok(bytes)
+}
+
+/// Encode a payload as Base58Check: the payload followed by the first four bytes of its double SHA-256 hash.
+pub fn encode_check(payload: &[u8]) -> String {
+    let mut data = Vec::with_capacity(payload.len() + CHECKSUM_LENGTH);
+    data.extend_from_slice(payload);
+    data.extend_from_slice(&checksum(payload));
+    encode(&data)
+}
+
+/// Decode a Base58Check string in [DecodeMode::Strict] mode, verifying and removing the checksum.
+pub fn decode_check(s: &str) -> Result<Vec<u8>, Base58DecodeError> {
+    decode_check_with_mode(s, DecodeMode::Strict)
+}
+
+/// Decode a Base58Check string, verifying and removing the checksum. See [decode_with_mode] for the modes.
+pub fn decode_check_with_mode(s: &str, mode: DecodeMode) -> Result<Vec<u8>, Base58DecodeError> {
+    let mut data = decode_with_mode(s, mode)?;
+    ensure!(data.len() >= CHECKSUM_LENGTH, TooShortSnafu { len: data.len() });
+    let payload_len = data.len() - CHECKSUM_LENGTH;
+    ensure!(
+        checksum(&data[..payload_len]) == data[payload_len..],
+        InvalidChecksumSnafu
+    );
+    data.truncate(payload_len);
+    Ok(data)
+}
+
+fn checksum(payload: &[u8]) -> [u8; CHECKSUM_LENGTH] {
+    let hash = Sha256::digest(Sha256::digest(payload));
+    let mut checksum = [0u8; CHECKSUM_LENGTH];
+    checksum.copy_from_slice(&hash[..CHECKSUM_LENGTH]);
+    checksum
+}
+
+fn digit_value(character: char) -> Option<u32> {
+    let c = u8::try_from(character).ok()?;
+    ALPHABET.iter().position(|a| *a == c).map(|value| value as u32)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn known_vectors() {
+        let vectors: [(&[u8], &str); 6] = [
+            (b"", ""),
+            (&[0], "1"),
+            (&[0, 0, 0x28, 0x7f, 0xb4, 0xcd], "11233QC4"),
+            (b"Hello World!", "2NEpo7TZRRrLZSi2U"),
+            (
+                b"The quick brown fox jumps over the lazy dog.",
+                "USm3fpXnKG5EUBx2ndxBDMPVciP5hGey2Jh4NDv6gmeo1LkMeiKrLJUUBk6Z",
+            ),
+            (&[0xff; 4], "7YXq9G"),
+        ];
+        for (bytes, encoded) in vectors {
+            assert_eq!(encode(bytes), encoded);
+            assert_eq!(decode(encoded).unwrap(), bytes);
+        }
+    }
+
+    #[test]
+    fn invalid_characters() {
+        assert_eq!(
+            decode("abc0"),
+            Err(Base58DecodeError::InvalidCharacter {
+                character: '0',
+                index: 3
+            })
+        );
+        assert!(decode("Il").is_err());
+        assert!(decode("🖖").is_err());
+    }
+
+    #[test]
+    fn decode_modes() {
+        assert!(decode_with_mode(" 2NEpo7TZRRrLZSi2U ", DecodeMode::Strict).is_err());
+        assert_eq!(
+            decode_with_mode(" 2NEpo7TZRR\nrLZSi2U ", DecodeMode::Lenient).unwrap(),
+            b"Hello World!"
+        );
+        let encoded = encode_check(b"payload");
+        assert!(decode_check_with_mode(&format!("{}\n", encoded), DecodeMode::Strict).is_err());
+        assert_eq!(
+            decode_check_with_mode(&format!("{}\n", encoded), DecodeMode::Lenient).unwrap(),
+            b"payload"
+        );
+    }
+
+    #[test]
+    fn check_encoding() {
+        // Bitcoin genesis block coinbase address
+        let payload = [
+            0x00, 0x62, 0xe9, 0x07, 0xb1, 0x5c, 0xbf, 0x27, 0xd5, 0x42, 0x53, 0x99, 0xeb, 0xf6, 0xf0, 0xfb, 0x50, 0xeb,
+            0xb8, 0x8f, 0x18,
+        ];
+        let address = encode_check(&payload);
+        assert_eq!(address, "1A1zP1eP5QGefi2DMPTfTL5SLmv7DivfNa");
+        assert_eq!(decode_check(&address).unwrap(), payload);
+
+        assert_eq!(
+            decode_check("1A1zP1eP5QGefi2DMPTfTL5SLmv7DivfNb"),
+            Err(Base58DecodeError::InvalidChecksum {})
+        );
+        assert_eq!(decode_check("111"), Err(Base58DecodeError::TooShort { len: 3 }));
+        assert_eq!(decode_check(&encode_check(&[])).unwrap(), Vec::<u8>::new());
+    }
+}
@@ -0,0 +1,757 @@
+// Copyright 2026, The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+//! Base64 encoding and decoding, including streaming encoders and decoders over `std::io`.
+//!
+//! Unlike [MessageFormat](crate::message_format::MessageFormat), which encodes a whole serialized value at once, the
+//! [EncoderWriter] and [DecoderReader] types process data as it passes through, so large payloads need not be held in
+//! memory. Both the standard and the URL-safe alphabets are supported, with or without `=` padding; see [Config].
+//!
+//! ```edition2018
+//! # use tari_utilities::base64::{self, Config};
+//! assert_eq!(base64::encode(b"foobar!", Config::STANDARD), "Zm9vYmFyIQ==");
+//! assert_eq!(
+//!     base64::encode(&[0xfb, 0xff], Config::URL_SAFE_NO_PAD),
+//!     "-_8"
+//! );
+//! assert_eq!(
+//!     base64::decode("Zm9vYmFyIQ==", Config::STANDARD).unwrap(),
+//!     b"foobar!"
+//! );
+//! ```
+//!
+//! Streaming:
+//!
+//! ```edition2018
+//! # use std::io::{Read, Write};
+//! # use tari_utilities::base64::{Config, DecoderReader, EncoderWriter};
+//! let mut encoder = EncoderWriter::new(Vec::new(), Config::STANDARD);
+//! encoder.write_all(b"foo").unwrap();
+//! encoder.write_all(b"bar!").unwrap();
+//! let encoded = encoder.finish().unwrap();
+//! assert_eq!(encoded, b"Zm9vYmFyIQ==");
+//!
+//! let mut decoded = Vec::new();
+//! DecoderReader::new(&encoded[..], Config::STANDARD)
+//!     .read_to_end(&mut decoded)
+//!     .unwrap();
+//! assert_eq!(decoded, b"foobar!");
+//! ```
+
+#[cfg(feature = "std")]
+use alloc::string::ToString;
+use alloc::{string::String, vec::Vec};
+#[cfg(feature = "std")]
+use std::io::{self, Read, Write};
+
+use snafu::prelude::*;
+
+use crate::encoding::DecodeMode;
+
+/// The standard Base64 alphabet, from RFC 4648 section 4.
+pub const STANDARD_ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+/// The URL and filename safe Base64 alphabet, from RFC 4648 section 5.
+pub const URL_SAFE_ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_";
+
+/// The Base64 alphabet to use
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum Alphabet {
+    /// The standard alphabet, using `+` and `/`
+    #[default]
+    Standard,
+    /// The URL-safe alphabet, using `-` and `_`
+    UrlSafe,
+}
+
+impl Alphabet {
+    /// Returns the 64 characters of the alphabet.
+    pub fn characters(self) -> &'static [u8; 64] {
+        match self {
+            Alphabet::Standard => STANDARD_ALPHABET,
+            Alphabet::UrlSafe => URL_SAFE_ALPHABET,
+        }
+    }
+
+    fn value(self, c: u8) -> Option<u8> {
+        match c {
+            b'A'..=b'Z' => Some(c - b'A'),
+            b'a'..=b'z' => Some(c - b'a' + 26),
+            b'0'..=b'9' => Some(c - b'0' + 52),
+            b'+' if self == Alphabet::Standard => Some(62),
+            b'/' if self == Alphabet::Standard => Some(63),
+            b'-' if self == Alphabet::UrlSafe => Some(62),
+            b'_' if self == Alphabet::UrlSafe => Some(63),
+            _ => None,
+        }
+    }
+}
+
+/// Encoding and decoding options.
+///
+/// When `padding` is set, encoded output is padded with `=` to a multiple of 4 characters and decoding requires the
+/// padding. Otherwise no padding is written and a `=` in the input is rejected.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Config {
+    /// The alphabet to use
+    pub alphabet: Alphabet,
+    /// Whether the encoding is padded with `=`
+    pub padding: bool,
+}
+
+impl Config {
+    /// The standard alphabet, with padding
+    pub const STANDARD: Config = Config::new(Alphabet::Standard, true);
+    /// The standard alphabet, without padding
+    pub const STANDARD_NO_PAD: Config = Config::new(Alphabet::Standard, false);
+    /// The URL-safe alphabet, with padding
+    pub const URL_SAFE: Config = Config::new(Alphabet::UrlSafe, true);
+    /// The URL-safe alphabet, without padding
+    pub const URL_SAFE_NO_PAD: Config = Config::new(Alphabet::UrlSafe, false);
+
+    /// Create a new configuration.
+    pub const fn new(alphabet: Alphabet, padding: bool) -> Self {
+        Self { alphabet, padding }
+    }
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config::STANDARD
+    }
+}
+
+/// Errors for Base64 decoding.
+#[derive(Debug, Snafu, PartialEq, Eq)]
+pub enum Base64Error {
+    /// The input contains a character that is not in the alphabet.
+    #[snafu(display("Invalid Base64 character `{character}' at position {index}"))]
+    InvalidCharacter {
+        /// The offending character
+        character: char,
+        /// The position of the character in the input
+        index: usize,
+    },
+    /// The input ends with a single character in its last group, which cannot encode a whole byte.
+    #[snafu(display("Invalid Base64 length"))]
+    InvalidLength {},
+    /// Padding is missing, misplaced or of the wrong length.
+    #[snafu(display("Invalid Base64 padding at position {index}"))]
+    InvalidPadding {
+        /// The position in the input at which the padding problem was detected
+        index: usize,
+    },
+    /// The unused bits of the last character are not zero.
+    #[snafu(display("Base64 input has non-zero trailing bits"))]
+    InvalidTrailingBits {},
+    /// The output buffer is too small for the decoded data.
+    #[snafu(display("Base64 output needs {required} bytes but the buffer has {available}"))]
+    OutputTooSmall {
+        /// The number of bytes needed
+        required: usize,
+        /// The size of the output buffer
+        available: usize,
+    },
+}
+
+/// Returns the length of the encoding of `len` bytes.
+pub fn encoded_len(len: usize, config: Config) -> usize {
+    if config.padding {
+        len.div_ceil(3) * 4
+    } else {
+        (len * 4).div_ceil(3)
+    }
+}
+
+/// Encode bytes as Base64.
+pub fn encode(data: &[u8], config: Config) -> String {
+    let mut out = String::with_capacity(encoded_len(data.len(), config));
+    encode_into(data, config, &mut out);
+    out
+}
+
+/// Encode bytes as Base64, appending to `out`. Reusing `out` across calls avoids allocating for each encoding.
+pub fn encode_into(data: &[u8], config: Config, out: &mut String) {
+    out.reserve(encoded_len(data.len(), config));
+    let chunks = data.chunks_exact(3);
+    let tail = chunks.remainder();
+    for chunk in chunks {
+        out.extend(encode_chunk(chunk, config).map(char::from));
+    }
+    out.extend(encode_chunk(tail, config).map(char::from));
+}
+
+/// Decode Base64 in [DecodeMode::Strict] mode.
+pub fn decode(s: &str, config: Config) -> Result<Vec<u8>, Base64Error> {
+    decode_with_mode(s, config, DecodeMode::Strict)
+}
+
+/// Decode Base64.
+///
+/// In [DecodeMode::Strict] mode the input must be padded exactly as `config` specifies and must not contain
+/// whitespace. In [DecodeMode::Lenient] mode, ASCII whitespace is ignored and padding is optional whatever the
+/// configuration. The alphabet is always that of `config`.
+pub fn decode_with_mode(s: &str, config: Config, mode: DecodeMode) -> Result<Vec<u8>, Base64Error> {
+    let mut out = Vec::with_capacity(s.len() * 3 / 4);
+    let mut decoder = Decoder::new(config, mode);
+    for c in s.bytes() {
+        decoder.push(c, &mut out)?;
+    }
+    decoder.finish(&mut out)?;
+    Ok(out)
+}
+
+/// Decode Base64 into `out` in [DecodeMode::Strict] mode, returning the number of bytes written.
+pub fn decode_into(s: &str, config: Config, out: &mut [u8]) -> Result<usize, Base64Error> {
+    decode_into_with_mode(s, config, out, DecodeMode::Strict)
+}
+
+/// Decode Base64 into `out`, returning the number of bytes written. The modes are as for [decode_with_mode]. This does
+/// not allocate, so it suits hot loops that decode into fixed-size arrays.
+pub fn decode_into_with_mode(s: &str, config: Config, out: &mut [u8], mode: DecodeMode) -> Result<usize, Base64Error> {
+    let lenient = mode == DecodeMode::Lenient;
+    let chars = s
+        .bytes()
+        .filter(|c| *c != b'=' && !(lenient && c.is_ascii_whitespace()))
+        .count();
+    let required = chars * 3 / 4;
+    ensure!(out.len() >= required, OutputTooSmallSnafu {
+        required,
+        available: out.len()
+    });
+    let mut sink = SliceSink { buf: out, len: 0 };
+    let mut decoder = Decoder::new(config, mode);
+    for c in s.bytes() {
+        decoder.push(c, &mut sink)?;
+    }
+    decoder.finish(&mut sink)?;
+    Ok(sink.len)
+}
+
+/// Encode up to 3 bytes, appending the characters to `out`. Fewer than 3 bytes are padded according to `config`.
+fn encode_chunk(chunk: &[u8], config: Config) -> impl Iterator<Item = u8> {
+    let alphabet = config.alphabet.characters();
+    let mut block = [0u8; 3];
+    block[..chunk.len()].copy_from_slice(chunk);
+    let n = (u32::from(block[0]) << 16) | (u32::from(block[1]) << 8) | u32::from(block[2]);
+    let chars = if chunk.is_empty() { 0 } else { chunk.len() + 1 };
+    let padding = if config.padding && chars > 0 { 4 - chars } else { 0 };
+    (0..chars)
+        .map(move |i| alphabet[((n >> (18 - 6 * i)) & 0x3f) as usize])
+        .chain(core::iter::repeat_n(b'=', padding))
+}
+
+/// A destination for decoded bytes.
+trait Sink {
+    fn write(&mut self, bytes: &[u8]) -> Result<(), Base64Error>;
+}
+
+impl Sink for Vec<u8> {
+    fn write(&mut self, bytes: &[u8]) -> Result<(), Base64Error> {
+        self.extend_from_slice(bytes);
+        Ok(())
+    }
+}
+
+/// A byte slice that is filled from the start.
+struct SliceSink<'a> {
+    buf: &'a mut [u8],
+    len: usize,
+}
+
+impl Sink for SliceSink<'_> {
+    fn write(&mut self, bytes: &[u8]) -> Result<(), Base64Error> {
+        let end = self.len + bytes.len();
+        let available = self.buf.len();
+        self.buf
+            .get_mut(self.len..end)
+            .context(OutputTooSmallSnafu {
+                required: end,
+                available,
+            })?
+            .copy_from_slice(bytes);
+        self.len = end;
+        Ok(())
+    }
+}
+
+/// Incremental Base64 decoder, fed one input character at a time.
+#[derive(Debug)]
+struct Decoder {
+    config: Config,
+    mode: DecodeMode,
+    acc: u32,
+    sextets: usize,
+    padding: usize,
+    index: usize,
+}
+
+impl Decoder {
+    fn new(config: Config, mode: DecodeMode) -> Self {
+        Self {
+            config,
+            mode,
+            acc: 0,
+            sextets: 0,
+            padding: 0,
+            index: 0,
+        }
+    }
+
+    fn push(&mut self, c: u8, out: &mut impl Sink) -> Result<(), Base64Error> {
+        let index = self.index;
+        self.index += 1;
+        let lenient = self.mode == DecodeMode::Lenient;
+        if c.is_ascii_whitespace() && lenient {
+            return Ok(());
+        }
+        if c == b'=' && (self.config.padding || lenient) {
+            ensure!(
+                self.sextets >= 2 && self.sextets + self.padding < 4,
+                InvalidPaddingSnafu { index }
+            );
+            self.padding += 1;
+            if self.sextets + self.padding == 4 {
+                self.flush_partial(out)?;
+            }
+            return Ok(());
+        }
+        ensure!(self.padding == 0, InvalidPaddingSnafu { index });
+        let value = self.config.alphabet.value(c).context(InvalidCharacterSnafu {
+            character: char::from(c),
+            index,
+        })?;
+        self.acc = (self.acc << 6) | u32::from(value);
+        self.sextets += 1;
+        if self.sextets == 4 {
+            out.write(&self.acc.to_be_bytes()[1..])?;
+            self.acc = 0;
+            self.sextets = 0;
+        }
+        Ok(())
+    }
+
+    fn finish(&mut self, out: &mut impl Sink) -> Result<(), Base64Error> {
+        let index = self.index;
+        if self.padding > 0 {
+            // A complete padded group has already been flushed
+            ensure!(self.sextets == 0, InvalidPaddingSnafu { index });
+            return Ok(());
+        }
+        if self.sextets == 0 {
+            return Ok(());
+        }
+        ensure!(self.sextets > 1, InvalidLengthSnafu);
+        ensure!(
+            !self.config.padding || self.mode == DecodeMode::Lenient,
+            InvalidPaddingSnafu { index }
+        );
+        self.flush_partial(out)
+    }
+
+    /// Write out the bytes of a final group of 2 or 3 characters.
+    fn flush_partial(&mut self, out: &mut impl Sink) -> Result<(), Base64Error> {
+        let unused = (self.sextets * 6) % 8;
+        ensure!(self.acc & ((1 << unused) - 1) == 0, InvalidTrailingBitsSnafu);
+        let bytes = (self.acc >> unused).to_be_bytes();
+        out.write(&bytes[4 - (self.sextets - 1)..])?;
+        self.acc = 0;
+        self.sextets = 0;
+        Ok(())
+    }
+}
+
+/// A writer that Base64 encodes everything written to it and writes the encoding to an inner writer.
+///
+/// Up to 2 bytes are held back until a complete 3 byte group is available. Call [EncoderWriter::finish] to write the
+/// final group and any padding; if the encoder is dropped without finishing, this is attempted on drop and errors are
+/// ignored.
+#[cfg(feature = "std")]
+#[derive(Debug)]
+pub struct EncoderWriter<W: Write> {
+    inner: Option<W>,
+    config: Config,
+    pending: [u8; 3],
+    pending_len: usize,
+    buffer: Vec<u8>,
+}
+
+/// The inner writer is only taken by [EncoderWriter::finish], which consumes the encoder, so this is not expected in
+/// practice.
+#[cfg(feature = "std")]
+fn finished() -> io::Error {
+    io::Error::other("the encoder has already been finished")
+}
+
+#[cfg(feature = "std")]
+impl<W: Write> EncoderWriter<W> {
+    /// Create a new encoder writing to `inner`.
+    pub fn new(inner: W, config: Config) -> Self {
+        Self {
+            inner: Some(inner),
+            config,
+            pending: [0; 3],
+            pending_len: 0,
+            buffer: Vec::new(),
+        }
+    }
+
+    /// Write the final group and padding, flush, and return the inner writer.
+    pub fn finish(mut self) -> io::Result<W> {
+        self.write_final()?;
+        let mut inner = self.inner.take().ok_or_else(finished)?;
+        inner.flush()?;
+        Ok(inner)
+    }
+
+    fn inner_mut(&mut self) -> io::Result<&mut W> {
+        self.inner.as_mut().ok_or_else(finished)
+    }
+
+    fn write_final(&mut self) -> io::Result<()> {
+        let out = encode_chunk(&self.pending[..self.pending_len], self.config).collect::<Vec<_>>();
+        self.pending_len = 0;
+        self.inner_mut()?.write_all(&out)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<W: Write> Write for EncoderWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let mut data = buf;
+        self.buffer.clear();
+        if self.pending_len > 0 {
+            let take = (3 - self.pending_len).min(data.len());
+            self.pending[self.pending_len..self.pending_len + take].copy_from_slice(&data[..take]);
+            self.pending_len += take;
+            data = &data[take..];
+            if self.pending_len < 3 {
+                return Ok(buf.len());
+            }
+            self.buffer.extend(encode_chunk(&self.pending, self.config));
+            self.pending_len = 0;
+        }
+        let chunks = data.chunks_exact(3);
+        let tail = chunks.remainder();
+        for chunk in chunks {
+            self.buffer.extend(encode_chunk(chunk, self.config));
+        }
+        self.pending[..tail.len()].copy_from_slice(tail);
+        self.pending_len = tail.len();
+        let encoded = core::mem::take(&mut self.buffer);
+        let result = self.inner_mut().and_then(|inner| inner.write_all(&encoded));
+        self.buffer = encoded;
+        result.map(|_| buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner_mut()?.flush()
+    }
+}
+
+#[cfg(feature = "std")]
+impl<W: Write> Drop for EncoderWriter<W> {
+    fn drop(&mut self) {
+        if self.inner.is_some() {
+            let _result = self.write_final();
+        }
+    }
+}
+
+/// The number of bytes a [DecoderReader] reads from its inner reader at a time
+#[cfg(feature = "std")]
+const READ_SIZE: usize = 1024;
+
+/// A reader that decodes Base64 read from an inner reader.
+///
+/// The input is decoded in [DecodeMode::Strict] mode unless created with [DecoderReader::with_mode]; use
+/// [DecodeMode::Lenient] to read line-wrapped input. Invalid input is reported as an [io::Error] of kind
+/// [io::ErrorKind::InvalidData] with the [Base64Error] message.
+#[cfg(feature = "std")]
+#[derive(Debug)]
+pub struct DecoderReader<R: Read> {
+    inner: R,
+    decoder: Decoder,
+    decoded: Vec<u8>,
+    position: usize,
+    finished: bool,
+}
+
+#[cfg(feature = "std")]
+impl<R: Read> DecoderReader<R> {
+    /// Create a new decoder reading from `inner`, in [DecodeMode::Strict] mode.
+    pub fn new(inner: R, config: Config) -> Self {
+        Self::with_mode(inner, config, DecodeMode::Strict)
+    }
+
+    /// Create a new decoder reading from `inner`, in the given mode. See [decode_with_mode] for the modes.
+    pub fn with_mode(inner: R, config: Config, mode: DecodeMode) -> Self {
+        Self {
+            inner,
+            decoder: Decoder::new(config, mode),
+            decoded: Vec::new(),
+            position: 0,
+            finished: false,
+        }
+    }
+
+    /// Return the inner reader. Any input that has been read but not yet returned is lost.
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+
+    fn fill(&mut self) -> io::Result<()> {
+        let mut input = [0u8; READ_SIZE];
+        self.decoded.clear();
+        self.position = 0;
+        while self.decoded.is_empty() && !self.finished {
+            let n = self.inner.read(&mut input)?;
+            let result = if n == 0 {
+                self.finished = true;
+                self.decoder.finish(&mut self.decoded)
+            } else {
+                input[..n]
+                    .iter()
+                    .try_for_each(|c| self.decoder.push(*c, &mut self.decoded))
+            };
+            result.map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e.to_string()))?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "std")]
+impl<R: Read> Read for DecoderReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        if self.position == self.decoded.len() {
+            self.fill()?;
+        }
+        let available = &self.decoded[self.position..];
+        let n = available.len().min(buf.len());
+        buf[..n].copy_from_slice(&available[..n]);
+        self.position += n;
+        Ok(n)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const VECTORS: [(&[u8], &str); 7] = [
+        (b"", ""),
+        (b"f", "Zg=="),
+        (b"fo", "Zm8="),
+        (b"foo", "Zm9v"),
+        (b"foob", "Zm9vYg=="),
+        (b"fooba", "Zm9vYmE="),
+        (b"foobar", "Zm9vYmFy"),
+    ];
+
+    #[test]
+    fn rfc4648_vectors() {
+        for (data, encoded) in VECTORS {
+            assert_eq!(encode(data, Config::STANDARD), encoded);
+            assert_eq!(encoded_len(data.len(), Config::STANDARD), encoded.len());
+            assert_eq!(decode(encoded, Config::STANDARD).unwrap(), data);
+            let unpadded = encoded.trim_end_matches('=');
+            assert_eq!(encode(data, Config::STANDARD_NO_PAD), unpadded);
+            assert_eq!(encoded_len(data.len(), Config::STANDARD_NO_PAD), unpadded.len());
+            assert_eq!(decode(unpadded, Config::STANDARD_NO_PAD).unwrap(), data);
+        }
+    }
+
+    #[test]
+    fn encode_into_appends() {
+        let mut out = String::from("data:");
+        encode_into(b"foob", Config::STANDARD, &mut out);
+        assert_eq!(out, "data:Zm9vYg==");
+        out.clear();
+        let capacity = out.capacity();
+        encode_into(&[0x5a; 6], Config::URL_SAFE_NO_PAD, &mut out);
+        assert_eq!(out, "WlpaWlpa");
+        assert_eq!(out.capacity(), capacity);
+    }
+
+    #[test]
+    fn decode_into_slice() {
+        let mut out = [0u8; 6];
+        for (data, encoded) in VECTORS {
+            let len = decode_into(encoded, Config::STANDARD, &mut out).unwrap();
+            assert_eq!(&out[..len], data);
+        }
+        assert_eq!(
+            decode_into_with_mode("Zm9v\nYmFy", Config::STANDARD, &mut out, DecodeMode::Lenient),
+            Ok(6)
+        );
+        assert_eq!(
+            decode_into("Zm9vYmFyYg==", Config::STANDARD, &mut out),
+            Err(Base64Error::OutputTooSmall {
+                required: 7,
+                available: 6
+            })
+        );
+        assert_eq!(
+            decode_into("Zm9v!", Config::STANDARD, &mut out),
+            Err(Base64Error::InvalidCharacter {
+                character: '!',
+                index: 4
+            })
+        );
+    }
+
+    #[test]
+    fn alphabets() {
+        let data = [0xfb, 0xff, 0xfe];
+        assert_eq!(encode(&data, Config::STANDARD), "+//+");
+        assert_eq!(encode(&data, Config::URL_SAFE), "-__-");
+        assert_eq!(decode("-__-", Config::URL_SAFE_NO_PAD).unwrap(), data);
+        assert_eq!(
+            decode("+//+", Config::URL_SAFE),
+            Err(Base64Error::InvalidCharacter {
+                character: '+',
+                index: 0
+            })
+        );
+    }
+
+    #[test]
+    fn decode_modes() {
+        assert!(decode("Zm9v\r\nYmFy\n", Config::STANDARD).is_err());
+        assert_eq!(
+            decode_with_mode("Zm9v\r\nYmFy\n", Config::STANDARD, DecodeMode::Lenient).unwrap(),
+            b"foobar"
+        );
+        assert!(decode("Zm8", Config::STANDARD).is_err());
+        assert!(decode("Zm8=", Config::STANDARD_NO_PAD).is_err());
+        for config in [Config::STANDARD, Config::STANDARD_NO_PAD] {
+            assert_eq!(decode_with_mode("Zm8", config, DecodeMode::Lenient).unwrap(), b"fo");
+            assert_eq!(decode_with_mode("Zm8=", config, DecodeMode::Lenient).unwrap(), b"fo");
+            assert!(decode_with_mode("Zm8==", config, DecodeMode::Lenient).is_err());
+        }
+    }
+
+    #[test]
+    fn invalid_input() {
+        assert_eq!(
+            decode("Zg", Config::STANDARD),
+            Err(Base64Error::InvalidPadding { index: 2 })
+        );
+        assert_eq!(
+            decode("Zg==", Config::STANDARD_NO_PAD),
+            Err(Base64Error::InvalidCharacter {
+                character: '=',
+                index: 2
+            })
+        );
+        assert_eq!(
+            decode("Z===", Config::STANDARD),
+            Err(Base64Error::InvalidPadding { index: 1 })
+        );
+        assert_eq!(
+            decode("Zg=", Config::STANDARD),
+            Err(Base64Error::InvalidPadding { index: 3 })
+        );
+        assert_eq!(
+            decode("Zg==Zg==", Config::STANDARD),
+            Err(Base64Error::InvalidPadding { index: 4 })
+        );
+        assert_eq!(
+            decode("Zm9vY", Config::STANDARD_NO_PAD),
+            Err(Base64Error::InvalidLength {})
+        );
+        assert_eq!(
+            decode("Zh==", Config::STANDARD),
+            Err(Base64Error::InvalidTrailingBits {})
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn streaming_round_trip() {
+        let data = (0..5000u32).map(|i| (i * 7) as u8).collect::<Vec<_>>();
+        for config in [
+            Config::STANDARD,
+            Config::STANDARD_NO_PAD,
+            Config::URL_SAFE,
+            Config::URL_SAFE_NO_PAD,
+        ] {
+            let mut encoder = EncoderWriter::new(Vec::new(), config);
+            for chunk in data.chunks(7) {
+                encoder.write_all(chunk).unwrap();
+            }
+            let encoded = encoder.finish().unwrap();
+            assert_eq!(encoded, encode(&data, config).into_bytes());
+
+            let mut reader = DecoderReader::new(&encoded[..], config);
+            let mut decoded = Vec::new();
+            let mut buf = [0u8; 13];
+            loop {
+                let n = reader.read(&mut buf).unwrap();
+                if n == 0 {
+                    break;
+                }
+                decoded.extend_from_slice(&buf[..n]);
+            }
+            assert_eq!(decoded, data);
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn encoder_finishes_on_drop() {
+        let mut out = Vec::new();
+        {
+            let mut encoder = EncoderWriter::new(&mut out, Config::STANDARD);
+            encoder.write_all(b"fo").unwrap();
+        }
+        assert_eq!(out, b"Zm8=");
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn decoder_reports_invalid_data() {
+        let mut decoded = Vec::new();
+        let err = DecoderReader::new(&b"Zm9v$"[..], Config::STANDARD)
+            .read_to_end(&mut decoded)
+            .unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+        let err = DecoderReader::new(&b"Zm9vY"[..], Config::STANDARD_NO_PAD)
+            .read_to_end(&mut decoded)
+            .unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn decoder_modes() {
+        let wrapped = b"Zm9v\nYmFy\n";
+        let mut decoded = Vec::new();
+        assert!(DecoderReader::new(&wrapped[..], Config::STANDARD)
+            .read_to_end(&mut decoded)
+            .is_err());
+        decoded.clear();
+        DecoderReader::with_mode(&wrapped[..], Config::STANDARD, DecodeMode::Lenient)
+            .read_to_end(&mut decoded)
+            .unwrap();
+        assert_eq!(decoded, b"foobar");
+    }
+}
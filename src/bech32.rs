@@ -0,0 +1,381 @@
+// Copyright 2026, The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+//! Bech32 ([BIP-173](https://github.com/bitcoin/bips/blob/master/bip-0173.mediawiki)) and Bech32m
+//! ([BIP-350](https://github.com/bitcoin/bips/blob/master/bip-0350.mediawiki)) encoding and decoding.
+//!
+//! A Bech32 string consists of a human-readable part (HRP), the separator `1`, and data encoded as 5-bit values
+//! followed by a six character checksum. [encode] and [decode] convert byte arrays to and from 5-bit values; use
+//! [encode_u5] and [decode_u5] when the data is not a plain byte array, such as a segwit witness version followed by a
+//! program.
+//!
+//! ```edition2018
+//! # use tari_utilities::bech32::{self, Variant};
+//! let encoded = bech32::encode("tari", &[0xde, 0xad, 0xbe, 0xef], Variant::Bech32m).unwrap();
+//! let (hrp, data, variant) = bech32::decode(&encoded).unwrap();
+//! assert_eq!(hrp, "tari");
+//! assert_eq!(data, vec![0xde, 0xad, 0xbe, 0xef]);
+//! assert_eq!(variant, Variant::Bech32m);
+//! ```
+
+use alloc::{string::String, vec::Vec};
+
+use snafu::prelude::*;
+
+/// The characters used to encode 5-bit values.
+pub const CHARSET: &[u8; 32] = b"qpzry9x8gf2tvdw0s3jn54khce6mua7l";
+
+/// The maximum length of an encoded string.
+pub const MAX_LENGTH: usize = 90;
+
+const CHECKSUM_LENGTH: usize = 6;
+const GENERATOR: [u32; 5] = [0x3b6a_57b2, 0x2650_8e6d, 0x1ea1_19fa, 0x3d42_33dd, 0x2a14_62b3];
+
+/// The checksum variant of a Bech32 string.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Variant {
+    /// The original checksum from BIP-173.
+    Bech32,
+    /// The improved checksum from BIP-350.
+    Bech32m,
+}
+
+impl Variant {
+    fn constant(self) -> u32 {
+        match self {
+            Variant::Bech32 => 1,
+            Variant::Bech32m => 0x2bc8_30a3,
+        }
+    }
+
+    fn from_residue(residue: u32) -> Option<Variant> {
+        [Variant::Bech32, Variant::Bech32m]
+            .iter()
+            .copied()
+            .find(|variant| variant.constant() == residue)
+    }
+}
+
+/// Errors for Bech32 encoding and decoding.
+#[derive(Debug, Snafu, PartialEq, Eq)]
+pub enum Bech32Error {
+    /// The human-readable part is invalid.
+    #[snafu(display("Invalid human-readable part: `{reason}'"))]
+    InvalidHrp {
+        /// Why the human-readable part is invalid
+        reason: String,
+    },
+    /// The string mixes upper and lower case characters.
+    #[snafu(display("Bech32 strings cannot mix upper and lower case"))]
+    MixedCase {},
+    /// The string has no `1` separator.
+    #[snafu(display("Missing `1' separator"))]
+    MissingSeparator {},
+    /// The data part contains a character that is not in the Bech32 character set.
+    #[snafu(display("Invalid Bech32 character `{character}' at position {index}"))]
+    InvalidCharacter {
+        /// The offending character
+        character: char,
+        /// The position of the character in the string
+        index: usize,
+    },
+    /// The string is too long, or the data part is too short to contain a checksum.
+    #[snafu(display("Invalid Bech32 length {len}"))]
+    InvalidLength {
+        /// The length of the string
+        len: usize,
+    },
+    /// The checksum does not match either variant.
+    #[snafu(display("Bech32 checksum does not match"))]
+    InvalidChecksum {},
+    /// A value given as 5-bit data does not fit in 5 bits.
+    #[snafu(display("Value {value} does not fit in 5 bits"))]
+    InvalidValue {
+        /// The offending value
+        value: u8,
+    },
+    /// Converting between bit widths left non-zero padding bits or a whole padding group.
+    #[snafu(display("Invalid padding in Bech32 data"))]
+    InvalidPadding {},
+    /// A bit width given to [convert_bits] is not between 1 and 8.
+    #[snafu(display("Cannot convert from {from}-bit to {to}-bit values"))]
+    InvalidBitWidth {
+        /// The width of the input values
+        from: u32,
+        /// The width of the output values
+        to: u32,
+    },
+}
+
+/// Encode bytes under the human-readable part `hrp`. The HRP is converted to lower case.
+pub fn encode(hrp: &str, data: &[u8], variant: Variant) -> Result<String, Bech32Error> {
+    encode_u5(hrp, &bytes_to_u5(data), variant)
+}
+
+/// Encode 5-bit values under the human-readable part `hrp`. The HRP is converted to lower case.
+pub fn encode_u5(hrp: &str, data: &[u8], variant: Variant) -> Result<String, Bech32Error> {
+    let hrp = validate_hrp(hrp)?;
+    if let Some(value) = data.iter().find(|value| **value >= 32) {
+        return InvalidValueSnafu { value: *value }.fail();
+    }
+    let len = hrp.len() + 1 + data.len() + CHECKSUM_LENGTH;
+    ensure!(len <= MAX_LENGTH, InvalidLengthSnafu { len });
+
+    let mut values = hrp_expand(&hrp);
+    values.extend_from_slice(data);
+    values.extend_from_slice(&[0; CHECKSUM_LENGTH]);
+    let residue = polymod(&values) ^ variant.constant();
+
+    let mut s = String::with_capacity(len);
+    s.push_str(&hrp);
+    s.push('1');
+    s.extend(data.iter().map(|value| char::from(CHARSET[usize::from(*value)])));
+    s.extend((0..CHECKSUM_LENGTH).map(|i| char::from(CHARSET[((residue >> (5 * (5 - i))) & 31) as usize])));
+    Ok(s)
+}
+
+/// Decode a Bech32 or Bech32m string into its lower case human-readable part, its data as bytes, and the checksum
+/// variant.
+pub fn decode(s: &str) -> Result<(String, Vec<u8>, Variant), Bech32Error> {
+    let (hrp, data, variant) = decode_u5(s)?;
+    Ok((hrp, u5_to_bytes(&data)?, variant))
+}
+
+/// Decode a Bech32 or Bech32m string into its lower case human-readable part, its data as 5-bit values, and the
+/// checksum variant.
+pub fn decode_u5(s: &str) -> Result<(String, Vec<u8>, Variant), Bech32Error> {
+    ensure!(s.len() <= MAX_LENGTH, InvalidLengthSnafu { len: s.len() });
+    let has_lower = s.bytes().any(|c| c.is_ascii_lowercase());
+    let has_upper = s.bytes().any(|c| c.is_ascii_uppercase());
+    ensure!(!(has_lower && has_upper), MixedCaseSnafu);
+
+    let separator = s.rfind('1').context(MissingSeparatorSnafu)?;
+    let hrp = validate_hrp(&s[..separator])?;
+    let data_part = &s[separator + 1..];
+    ensure!(data_part.len() >= CHECKSUM_LENGTH, InvalidLengthSnafu { len: s.len() });
+
+    let mut data = Vec::with_capacity(data_part.len());
+    for (offset, character) in data_part.chars().enumerate() {
+        let value = CHARSET
+            .iter()
+            .position(|c| char::from(*c) == character.to_ascii_lowercase())
+            .context(InvalidCharacterSnafu {
+                character,
+                index: separator + 1 + offset,
+            })?;
+        data.push(value as u8);
+    }
+
+    let mut values = hrp_expand(&hrp);
+    values.extend_from_slice(&data);
+    let variant = Variant::from_residue(polymod(&values)).context(InvalidChecksumSnafu)?;
+    data.truncate(data.len() - CHECKSUM_LENGTH);
+    Ok((hrp, data, variant))
+}
+
+/// Regroup the bits of `data` from `from`-bit values into `to`-bit values. With `pad`, the final group is padded with
+/// zero bits; without it, any leftover bits must be zero padding of less than `from` bits. Both widths must be between
+/// 1 and 8.
+pub fn convert_bits(data: &[u8], from: u32, to: u32, pad: bool) -> Result<Vec<u8>, Bech32Error> {
+    ensure!((1..=8).contains(&from) && (1..=8).contains(&to), InvalidBitWidthSnafu {
+        from,
+        to
+    });
+    let mut acc = 0u32;
+    let mut bits = 0u32;
+    let max = (1u32 << to) - 1;
+    let mut out = Vec::with_capacity(data.len() * from as usize / to as usize + 1);
+    for value in data {
+        ensure!(u32::from(*value) >> from == 0, InvalidValueSnafu { value: *value });
+        acc = (acc << from) | u32::from(*value);
+        bits += from;
+        while bits >= to {
+            bits -= to;
+            out.push(((acc >> bits) & max) as u8);
+        }
+    }
+    if pad {
+        if bits > 0 {
+            out.push(((acc << (to - bits)) & max) as u8);
+        }
+    } else {
+        ensure!(bits < from && (acc << (to - bits)) & max == 0, InvalidPaddingSnafu);
+    }
+    Ok(out)
+}
+
+/// Convert bytes to 5-bit values, padding the last value with zero bits.
+pub fn bytes_to_u5(bytes: &[u8]) -> Vec<u8> {
+    // Both widths are valid, every byte fits in 8 bits and padding is allowed, so no error case can occur
+    convert_bits(bytes, 8, 5, true).unwrap_or_else(|_| unreachable!("bytes always fit in 8 bits"))
+}
+
+/// Convert 5-bit values to bytes. Fails if a value does not fit in 5 bits or the padding is invalid.
+pub fn u5_to_bytes(data: &[u8]) -> Result<Vec<u8>, Bech32Error> {
+    convert_bits(data, 5, 8, false)
+}
+
+fn validate_hrp(hrp: &str) -> Result<String, Bech32Error> {
+    ensure!(!hrp.is_empty() && hrp.len() <= 83, InvalidHrpSnafu {
+        reason: "must be between 1 and 83 characters"
+    });
+    ensure!(hrp.bytes().all(|c| (33..=126).contains(&c)), InvalidHrpSnafu {
+        reason: "must only contain printable ASCII characters"
+    });
+    ensure!(
+        !(hrp.bytes().any(|c| c.is_ascii_lowercase()) && hrp.bytes().any(|c| c.is_ascii_uppercase())),
+        MixedCaseSnafu
+    );
+    Ok(hrp.to_ascii_lowercase())
+}
+
+fn hrp_expand(hrp: &str) -> Vec<u8> {
+    let mut values = Vec::with_capacity(hrp.len() * 2 + 1);
+    values.extend(hrp.bytes().map(|c| c >> 5));
+    values.push(0);
+    values.extend(hrp.bytes().map(|c| c & 31));
+    values
+}
+
+fn polymod(values: &[u8]) -> u32 {
+    let mut chk = 1u32;
+    for value in values {
+        let top = chk >> 25;
+        chk = ((chk & 0x01ff_ffff) << 5) ^ u32::from(*value);
+        for (i, generator) in GENERATOR.iter().enumerate() {
+            if (top >> i) & 1 == 1 {
+                chk ^= generator;
+            }
+        }
+    }
+    chk
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::hex::from_hex;
+
+    #[test]
+    fn valid_checksums() {
+        let bech32 = [
+            "A12UEL5L",
+            "a12uel5l",
+            "an83characterlonghumanreadablepartthatcontainsthenumber1andtheexcludedcharactersbio1tt5tgs",
+            "abcdef1qpzry9x8gf2tvdw0s3jn54khce6mua7lmqqqxw",
+            "split1checkupstagehandshakeupstreamerranterredcaperred2y9e3w",
+        ];
+        for s in bech32 {
+            assert_eq!(decode_u5(s).unwrap().2, Variant::Bech32, "{}", s);
+        }
+        let bech32m = [
+            "A1LQFN3A",
+            "a1lqfn3a",
+            "abcdef1l7aum6echk45nj3s0wdvt2fg8x9yrzpqzd3ryx",
+            "split1checkupstagehandshakeupstreamerranterredcaperredlc445v",
+        ];
+        for s in bech32m {
+            assert_eq!(decode_u5(s).unwrap().2, Variant::Bech32m, "{}", s);
+        }
+    }
+
+    #[test]
+    fn invalid_strings() {
+        assert_eq!(decode_u5("pzry9x0s0muk"), Err(Bech32Error::MissingSeparator {}));
+        assert!(matches!(
+            decode_u5("1pzry9x0s0muk"),
+            Err(Bech32Error::InvalidHrp { .. })
+        ));
+        assert!(matches!(
+            decode_u5("x1b4n0q5v"),
+            Err(Bech32Error::InvalidCharacter { character: 'b', .. })
+        ));
+        assert!(matches!(decode_u5("li1dgmt3"), Err(Bech32Error::InvalidLength { .. })));
+        assert!(matches!(decode_u5("A1G7SGD8"), Err(Bech32Error::InvalidChecksum {})));
+        assert_eq!(decode_u5("A12uEL5L"), Err(Bech32Error::MixedCase {}));
+        assert!(matches!(
+            decode_u5("\u{7f}1axkwrx"),
+            Err(Bech32Error::InvalidHrp { .. })
+        ));
+        let long = "an84characterslonghumanreadablepartthatcontainsthenumber1andtheexcludedcharactersbio1569pvx";
+        assert!(matches!(decode_u5(long), Err(Bech32Error::InvalidLength { .. })));
+    }
+
+    #[test]
+    fn segwit_address() {
+        let (hrp, data, variant) = decode_u5("BC1QW508D6QEJXTDG4Y5R3ZARVARY0C5XW7KV8F3T4").unwrap();
+        assert_eq!(hrp, "bc");
+        assert_eq!(variant, Variant::Bech32);
+        assert_eq!(data[0], 0);
+        let program = u5_to_bytes(&data[1..]).unwrap();
+        assert_eq!(program, from_hex("751e76e8199196d454941c45d1b3a323f1433bd6").unwrap());
+
+        let mut u5 = vec![0u8];
+        u5.extend(bytes_to_u5(&program));
+        assert_eq!(
+            encode_u5("bc", &u5, Variant::Bech32).unwrap(),
+            "bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t4"
+        );
+    }
+
+    #[test]
+    fn round_trip() {
+        for variant in [Variant::Bech32, Variant::Bech32m] {
+            for len in 0..40 {
+                let data = (0..len).map(|i| (i * 37) as u8).collect::<Vec<_>>();
+                let encoded = encode("TEST", &data, variant).unwrap();
+                assert!(encoded.starts_with("test1"));
+                assert_eq!(decode(&encoded).unwrap(), ("test".into(), data, variant));
+                assert_eq!(decode(&encoded.to_ascii_uppercase()).unwrap().0, "test");
+            }
+        }
+        assert!(matches!(
+            encode("hrp", &[0; 60], Variant::Bech32),
+            Err(Bech32Error::InvalidLength { .. })
+        ));
+        assert_eq!(
+            encode_u5("hrp", &[32], Variant::Bech32),
+            Err(Bech32Error::InvalidValue { value: 32 })
+        );
+        assert!(matches!(
+            encode("", &[], Variant::Bech32),
+            Err(Bech32Error::InvalidHrp { .. })
+        ));
+    }
+
+    #[test]
+    fn bit_conversion() {
+        assert_eq!(bytes_to_u5(&[0xff]), vec![31, 28]);
+        assert_eq!(u5_to_bytes(&[31, 28]).unwrap(), vec![0xff]);
+        assert_eq!(u5_to_bytes(&[31, 29]), Err(Bech32Error::InvalidPadding {}));
+        assert_eq!(u5_to_bytes(&[31, 28, 0]), Err(Bech32Error::InvalidPadding {}));
+        assert_eq!(convert_bits(&[1, 2, 3], 8, 8, false).unwrap(), vec![1, 2, 3]);
+        assert_eq!(
+            convert_bits(&[1], 0, 5, true),
+            Err(Bech32Error::InvalidBitWidth { from: 0, to: 5 })
+        );
+        assert_eq!(
+            convert_bits(&[1], 8, 9, false),
+            Err(Bech32Error::InvalidBitWidth { from: 8, to: 9 })
+        );
+    }
+}
@@ -0,0 +1,263 @@
+// Copyright 2026, The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+//! A growable vector of bits, packed eight to a byte.
+
+use alloc::vec::Vec;
+use core::iter::FromIterator;
+
+use super::BitOrder;
+
+/// A growable vector of bits that stores each bit in a single bit of memory, rather than a whole `bool`.
+///
+/// ```edition2018
+/// # use tari_utilities::bit::{BitOrder, BitVec};
+/// let mut bits = BitVec::from_bytes(&[0b1000_0001], BitOrder::LsbFirst);
+/// bits.push(true);
+/// assert_eq!(bits.len(), 9);
+/// assert_eq!(bits.get(0), Some(true));
+/// assert_eq!(bits.get(1), Some(false));
+/// assert_eq!(bits.to_bytes(BitOrder::LsbFirst), vec![
+///     0b1000_0001,
+///     0b0000_0001
+/// ]);
+/// assert_eq!(bits.to_bytes(BitOrder::MsbFirst), vec![
+///     0b1000_0001,
+///     0b1000_0000
+/// ]);
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct BitVec {
+    // Bits are stored least significant bit first, and unused bits in the last byte are always zero
+    bytes: Vec<u8>,
+    len: usize,
+}
+
+impl BitVec {
+    /// Creates an empty bit vector.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates an empty bit vector with room for at least `bits` bits.
+    pub fn with_capacity(bits: usize) -> Self {
+        Self {
+            bytes: Vec::with_capacity(bits.div_ceil(8)),
+            len: 0,
+        }
+    }
+
+    /// Creates a bit vector of `len` bits, all set to `value`.
+    pub fn repeat(value: bool, len: usize) -> Self {
+        let mut bits = Self {
+            bytes: vec![if value { u8::MAX } else { 0 }; len.div_ceil(8)],
+            len,
+        };
+        bits.clear_unused_bits();
+        bits
+    }
+
+    /// Creates a bit vector holding every bit of `bytes`, reading the bits of each byte in `order`.
+    pub fn from_bytes(bytes: &[u8], order: BitOrder) -> Self {
+        let bytes = match order {
+            BitOrder::LsbFirst => bytes.to_vec(),
+            BitOrder::MsbFirst => bytes.iter().map(|b| b.reverse_bits()).collect(),
+        };
+        Self {
+            len: bytes.len() * 8,
+            bytes,
+        }
+    }
+
+    /// Packs the bits into bytes, writing the bits of each byte in `order`. If the length is not a multiple of 8, the
+    /// last byte is padded with zero bits.
+    pub fn to_bytes(&self, order: BitOrder) -> Vec<u8> {
+        match order {
+            BitOrder::LsbFirst => self.bytes.clone(),
+            BitOrder::MsbFirst => self.bytes.iter().map(|b| b.reverse_bits()).collect(),
+        }
+    }
+
+    /// Returns the number of bits.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns true if there are no bits.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Appends a bit.
+    pub fn push(&mut self, bit: bool) {
+        if self.len.is_multiple_of(8) {
+            self.bytes.push(0);
+        }
+        self.len += 1;
+        self.set(self.len - 1, bit);
+    }
+
+    /// Removes and returns the last bit.
+    pub fn pop(&mut self) -> Option<bool> {
+        let bit = self.get(self.len.checked_sub(1)?)?;
+        self.set(self.len - 1, false);
+        self.len -= 1;
+        if self.len.is_multiple_of(8) {
+            self.bytes.pop();
+        }
+        Some(bit)
+    }
+
+    /// Returns the bit at `index`.
+    pub fn get(&self, index: usize) -> Option<bool> {
+        if index >= self.len {
+            return None;
+        }
+        Some(self.bytes[index / 8] & (1 << (index % 8)) != 0)
+    }
+
+    /// Sets the bit at `index`, returning false if `index` is out of range.
+    pub fn set(&mut self, index: usize, bit: bool) -> bool {
+        if index >= self.len {
+            return false;
+        }
+        let mask = 1 << (index % 8);
+        if bit {
+            self.bytes[index / 8] |= mask;
+        } else {
+            self.bytes[index / 8] &= !mask;
+        }
+        true
+    }
+
+    /// Shortens the vector to `len` bits. Has no effect if the vector is already shorter.
+    pub fn truncate(&mut self, len: usize) {
+        if len < self.len {
+            self.len = len;
+            self.bytes.truncate(len.div_ceil(8));
+            self.clear_unused_bits();
+        }
+    }
+
+    /// Removes every bit.
+    pub fn clear(&mut self) {
+        self.bytes.clear();
+        self.len = 0;
+    }
+
+    /// Returns the number of bits that are set.
+    pub fn count_ones(&self) -> usize {
+        self.bytes.iter().map(|b| b.count_ones() as usize).sum()
+    }
+
+    /// Returns an iterator over the bits.
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = bool> + ExactSizeIterator + '_ {
+        (0..self.len).map(move |index| self.bytes[index / 8] & (1 << (index % 8)) != 0)
+    }
+
+    fn clear_unused_bits(&mut self) {
+        let used = self.len % 8;
+        if let (Some(last), true) = (self.bytes.last_mut(), used != 0) {
+            *last &= (1 << used) - 1;
+        }
+    }
+}
+
+impl From<&[bool]> for BitVec {
+    fn from(bits: &[bool]) -> Self {
+        bits.iter().copied().collect()
+    }
+}
+
+impl FromIterator<bool> for BitVec {
+    fn from_iter<I: IntoIterator<Item = bool>>(iter: I) -> Self {
+        let mut bits = BitVec::new();
+        bits.extend(iter);
+        bits
+    }
+}
+
+impl Extend<bool> for BitVec {
+    fn extend<I: IntoIterator<Item = bool>>(&mut self, iter: I) {
+        for bit in iter {
+            self.push(bit);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::bit::bytes_to_bits;
+
+    #[test]
+    fn push_pop_get_set() {
+        let mut bits = BitVec::with_capacity(16);
+        assert!(bits.is_empty());
+        for i in 0..10 {
+            bits.push(i % 3 == 0);
+        }
+        assert_eq!(bits.len(), 10);
+        assert_eq!(bits.count_ones(), 4);
+        assert_eq!(bits.get(3), Some(true));
+        assert_eq!(bits.get(10), None);
+        assert!(bits.set(1, true));
+        assert!(!bits.set(10, true));
+        assert_eq!(bits.pop(), Some(true));
+        assert_eq!(bits.pop(), Some(false));
+        assert_eq!(bits.len(), 8);
+        assert_eq!(bits.to_bytes(BitOrder::LsbFirst), vec![0b0100_1011]);
+        bits.clear();
+        assert_eq!(bits.pop(), None);
+    }
+
+    #[test]
+    fn byte_conversions() {
+        let bytes = [0b0000_0001u8, 0b1100_0000];
+        let lsb = BitVec::from_bytes(&bytes, BitOrder::LsbFirst);
+        assert_eq!(lsb.iter().collect::<Vec<_>>(), bytes_to_bits(&bytes));
+        assert_eq!(lsb.to_bytes(BitOrder::LsbFirst), bytes.to_vec());
+
+        let msb = BitVec::from_bytes(&bytes, BitOrder::MsbFirst);
+        assert_eq!(msb.get(7), Some(true));
+        assert_eq!(msb.get(8), Some(true));
+        assert_eq!(msb.to_bytes(BitOrder::MsbFirst), bytes.to_vec());
+        assert_eq!(msb.to_bytes(BitOrder::LsbFirst), vec![0b1000_0000, 0b0000_0011]);
+    }
+
+    #[test]
+    fn partial_bytes() {
+        let mut bits = BitVec::from(&[true, true, true][..]);
+        assert_eq!(bits.to_bytes(BitOrder::LsbFirst), vec![0b0000_0111]);
+        assert_eq!(bits.to_bytes(BitOrder::MsbFirst), vec![0b1110_0000]);
+        bits.truncate(1);
+        assert_eq!(bits.to_bytes(BitOrder::LsbFirst), vec![1]);
+        // Truncated bits do not reappear when the vector grows again
+        bits.push(false);
+        bits.push(false);
+        assert_eq!(bits, [true, false, false].iter().copied().collect());
+
+        let ones = BitVec::repeat(true, 11);
+        assert_eq!(ones.to_bytes(BitOrder::LsbFirst), vec![0xff, 0b0000_0111]);
+        assert_eq!(BitVec::repeat(false, 3).count_ones(), 0);
+    }
+}
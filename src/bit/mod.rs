@@ -0,0 +1,731 @@
+// Copyright 2019 The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+#![allow(clippy::needless_range_loop)]
+//! Functions for conversion between integer and bit array.
+
+use alloc::{string::String, vec::Vec};
+use core::mem;
+
+use snafu::prelude::*;
+#[cfg(feature = "subtle")]
+use subtle::{Choice, ConditionallySelectable};
+
+pub mod bit_vec;
+
+pub use bit_vec::BitVec;
+
+/// Errors for checked bit conversions.
+#[derive(Debug, Snafu, PartialEq, Eq)]
+pub enum BitError {
+    /// The value needs more bits than are available.
+    #[snafu(display("The value {value} does not fit in {bits} bits"))]
+    ValueTooLarge {
+        /// The value being converted
+        value: u64,
+        /// The number of bits available
+        bits: usize,
+    },
+    /// A set bit lies beyond the range of the target integer.
+    #[snafu(display("Bit {index} is set, which overflows a {bits}-bit integer"))]
+    Overflow {
+        /// The index of the first set bit that does not fit
+        index: usize,
+        /// The number of bits in the target integer
+        bits: usize,
+    },
+    /// Two slices that must be the same length are not.
+    #[snafu(display("Length mismatch: {left} bytes versus {right} bytes"))]
+    LengthMismatch {
+        /// The length of the first slice
+        left: usize,
+        /// The length of the second slice
+        right: usize,
+    },
+    /// A checksum width outside the supported range was requested.
+    #[snafu(display("Checksum width {width} is not between 1 and 64 bits"))]
+    InvalidChecksumWidth {
+        /// The requested width
+        width: usize,
+    },
+    /// A binary string contained a character other than a digit or a separator.
+    #[snafu(display("Invalid character `{character}' in binary string"))]
+    InvalidBinaryCharacter {
+        /// The offending character
+        character: char,
+    },
+    /// A binary string did not contain a whole number of bytes.
+    #[snafu(display("A binary string of {digits} digits is not a whole number of bytes"))]
+    InvalidBinaryLength {
+        /// The number of binary digits found
+        digits: usize,
+    },
+}
+
+/// The order in which the bits of each byte are read or written.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum BitOrder {
+    /// The least significant bit of each byte comes first.
+    #[default]
+    LsbFirst,
+    /// The most significant bit of each byte comes first.
+    MsbFirst,
+}
+
+/// The parity of the number of set bits in some data.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Parity {
+    /// An even number of bits are set.
+    Even,
+    /// An odd number of bits are set.
+    Odd,
+}
+
+impl BitOrder {
+    /// Returns the mask selecting the `position`-th bit (0..8) of a byte in this order.
+    pub(crate) fn mask(self, position: usize) -> u8 {
+        match self {
+            BitOrder::LsbFirst => 1 << position,
+            BitOrder::MsbFirst => 0x80 >> position,
+        }
+    }
+}
+
+/// Extension trait for lazily iterating over the bits of a byte slice, without collecting them into a vector first.
+///
+/// ```edition2018
+/// # use tari_utilities::bit::IterBits;
+/// let bytes = [0b0000_0101u8];
+/// assert!(bytes.iter_bits_le().take(3).eq([true, false, true]));
+/// assert_eq!(bytes.iter_bits_be().as_u8().last(), Some(1));
+/// ```
+pub trait IterBits {
+    /// Iterates over the bits, least significant bit of each byte first.
+    fn iter_bits_le(&self) -> Bits<'_>;
+    /// Iterates over the bits, most significant bit of each byte first.
+    fn iter_bits_be(&self) -> Bits<'_>;
+}
+
+impl IterBits for [u8] {
+    fn iter_bits_le(&self) -> Bits<'_> {
+        Bits::new(self, BitOrder::LsbFirst)
+    }
+
+    fn iter_bits_be(&self) -> Bits<'_> {
+        Bits::new(self, BitOrder::MsbFirst)
+    }
+}
+
+/// A lazy iterator over the bits of a byte slice. See [IterBits].
+#[derive(Clone, Debug)]
+pub struct Bits<'a> {
+    bytes: &'a [u8],
+    order: BitOrder,
+    front: usize,
+    back: usize,
+}
+
+impl<'a> Bits<'a> {
+    /// Creates an iterator over the bits of `bytes`, reading the bits of each byte in `order`.
+    pub fn new(bytes: &'a [u8], order: BitOrder) -> Self {
+        Self {
+            bytes,
+            order,
+            front: 0,
+            back: bytes.len() * 8,
+        }
+    }
+
+    /// Yields each bit as a `u8` that is either 0 or 1, instead of a `bool`.
+    pub fn as_u8(self) -> impl DoubleEndedIterator<Item = u8> + ExactSizeIterator + 'a {
+        self.map(u8::from)
+    }
+
+    fn bit(&self, index: usize) -> bool {
+        self.bytes[index / 8] & self.order.mask(index % 8) != 0
+    }
+}
+
+impl Iterator for Bits<'_> {
+    type Item = bool;
+
+    fn next(&mut self) -> Option<bool> {
+        if self.front >= self.back {
+            return None;
+        }
+        self.front += 1;
+        Some(self.bit(self.front - 1))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.back - self.front;
+        (remaining, Some(remaining))
+    }
+}
+
+impl DoubleEndedIterator for Bits<'_> {
+    fn next_back(&mut self) -> Option<bool> {
+        if self.front >= self.back {
+            return None;
+        }
+        self.back -= 1;
+        Some(self.bit(self.back))
+    }
+}
+
+impl ExactSizeIterator for Bits<'_> {}
+
+/// Converts a single input byte to 8 bits (little-endian).
+pub fn byte_to_bits(value: u8) -> [bool; 8] {
+    let mut bits = [false; 8];
+    for i in 0..8 {
+        bits[i] = value & (1 << i) != 0;
+    }
+    bits
+}
+
+/// Converts a vector of input bytes to a vector of bits, least significant bit of each byte first. This is the same as
+/// [bytes_to_bits_le].
+pub fn bytes_to_bits(bytes: &[u8]) -> Vec<bool> {
+    let mut bits: Vec<bool> = vec![false; bytes.len() * 8];
+    for i in 0..bytes.len() {
+        let bit_index = i * 8;
+        bits[bit_index..(bit_index + 8)].copy_from_slice(&byte_to_bits(bytes[i]));
+    }
+    bits
+}
+
+/// Converts bytes to bits, least significant bit of each byte first.
+pub fn bytes_to_bits_le(bytes: &[u8]) -> Vec<bool> {
+    bytes_to_bits_ordered(bytes, BitOrder::LsbFirst)
+}
+
+/// Converts bytes to bits, most significant bit of each byte first.
+pub fn bytes_to_bits_be(bytes: &[u8]) -> Vec<bool> {
+    bytes_to_bits_ordered(bytes, BitOrder::MsbFirst)
+}
+
+/// Converts bits to bytes, filling each byte from its least significant bit. If the number of bits is not a multiple
+/// of 8, the unused high bits of the last byte are zero.
+pub fn bits_to_bytes_le(bits: &[bool]) -> Vec<u8> {
+    bits_to_bytes_ordered(bits, BitOrder::LsbFirst)
+}
+
+/// Converts bits to bytes, filling each byte from its most significant bit. If the number of bits is not a multiple
+/// of 8, the unused low bits of the last byte are zero.
+pub fn bits_to_bytes_be(bits: &[bool]) -> Vec<u8> {
+    bits_to_bytes_ordered(bits, BitOrder::MsbFirst)
+}
+
+/// Converts bytes to bits, reading the bits of each byte in `order`.
+pub fn bytes_to_bits_ordered(bytes: &[u8], order: BitOrder) -> Vec<bool> {
+    bytes
+        .iter()
+        .flat_map(|byte| (0..8).map(move |position| byte & order.mask(position) != 0))
+        .collect()
+}
+
+/// Converts bits to bytes, writing the bits of each byte in `order`. If the number of bits is not a multiple of 8, the
+/// positions of the last byte that come after the final bit are zero.
+pub fn bits_to_bytes_ordered(bits: &[bool], order: BitOrder) -> Vec<u8> {
+    bits.chunks(8)
+        .map(|chunk| {
+            chunk
+                .iter()
+                .enumerate()
+                .filter(|(_, bit)| **bit)
+                .fold(0u8, |byte, (position, _)| byte | order.mask(position))
+        })
+        .collect()
+}
+
+/// Converts a vector of input bits (little-endian) to its integer representation
+/// Returns None if the length of `bits` is greater than the number of bits in a `usize`, which would cause an attempt
+/// to shift left with overflow
+pub fn checked_bits_to_uint(bits: &[bool]) -> Option<usize> {
+    const PTR_SIZE_BITS: usize = mem::size_of::<usize>() * 8;
+
+    if bits.len() > PTR_SIZE_BITS {
+        None
+    } else {
+        let mut value: usize = 0;
+        for i in 0..bits.len() {
+            value |= usize::from(bits[i]) << i;
+        }
+        Some(value)
+    }
+}
+
+/// Converts `value` to exactly `N` bits, least significant bit first. Fails if `value` does not fit in `N` bits rather
+/// than silently truncating it. If `N` is greater than 64, the extra bits are zero.
+pub fn u64_to_bits<const N: usize>(value: u64) -> Result<[bool; N], BitError> {
+    if N < 64 && value >> N != 0 {
+        return ValueTooLargeSnafu { value, bits: N }.fail();
+    }
+    let mut bits = [false; N];
+    for i in 0..N.min(64) {
+        bits[i] = value & (1 << i) != 0;
+    }
+    Ok(bits)
+}
+
+/// Returns the bit at `index` (least significant bit of each byte first) without branching on the value of the bit.
+/// The index itself is not secret: an index beyond the end of `bytes` returns an unset bit.
+#[cfg(feature = "subtle")]
+pub fn ct_get_bit(bytes: &[u8], index: usize) -> Choice {
+    let byte = bytes.get(index / 8).copied().unwrap_or(0);
+    Choice::from((byte >> (index % 8)) & 1)
+}
+
+/// Sets the bit at `index` (least significant bit of each byte first) to `bit` without branching on the value of the
+/// bit or of the byte being updated. The index itself is not secret: returns false, leaving `bytes` unchanged, if it is
+/// beyond the end of `bytes`.
+#[cfg(feature = "subtle")]
+pub fn ct_set_bit(bytes: &mut [u8], index: usize, bit: Choice) -> bool {
+    let Some(byte) = bytes.get_mut(index / 8) else {
+        return false;
+    };
+    let mask = 1u8 << (index % 8);
+    *byte = u8::conditional_select(&(*byte & !mask), &(*byte | mask), bit);
+    true
+}
+
+/// Converts bits, least significant bit first, to a `u64`. Any number of bits is accepted, but fails if a set bit lies
+/// beyond the 64th.
+pub fn bits_to_u64(bits: &[bool]) -> Result<u64, BitError> {
+    if let Some(index) = bits.iter().skip(64).position(|bit| *bit) {
+        return OverflowSnafu {
+            index: index + 64,
+            bits: 64usize,
+        }
+        .fail();
+    }
+    Ok(bits
+        .iter()
+        .take(64)
+        .enumerate()
+        .fold(0, |value, (i, bit)| value | (u64::from(*bit) << i)))
+}
+
+/// Returns the number of set bits in `bytes`.
+pub fn hamming_weight(bytes: &[u8]) -> u32 {
+    bytes.iter().map(|b| b.count_ones()).sum()
+}
+
+/// Returns the number of bit positions at which `a` and `b` differ. Fails if the slices have different lengths.
+pub fn hamming_distance(a: &[u8], b: &[u8]) -> Result<u32, BitError> {
+    ensure!(a.len() == b.len(), LengthMismatchSnafu {
+        left: a.len(),
+        right: b.len()
+    });
+    Ok(a.iter().zip(b).map(|(x, y)| (x ^ y).count_ones()).sum())
+}
+
+/// Returns the number of set bits in `bytes`, using only arithmetic and bitwise operations so that the running time
+/// does not depend on the contents of `bytes`. Use this instead of [hamming_weight] for secret data.
+pub fn ct_hamming_weight(bytes: &[u8]) -> u32 {
+    bytes.iter().map(|b| ct_count_ones(*b)).sum()
+}
+
+/// Returns the number of bit positions at which `a` and `b` differ, with a running time that depends only on the
+/// lengths of the slices. Use this instead of [hamming_distance] for secret data. The lengths are not hidden.
+pub fn ct_hamming_distance(a: &[u8], b: &[u8]) -> Result<u32, BitError> {
+    ensure!(a.len() == b.len(), LengthMismatchSnafu {
+        left: a.len(),
+        right: b.len()
+    });
+    Ok(a.iter().zip(b).map(|(x, y)| ct_count_ones(x ^ y)).sum())
+}
+
+// Branch-free population count, which does not rely on the target having a constant-time popcount instruction
+fn ct_count_ones(b: u8) -> u32 {
+    let b = b - ((b >> 1) & 0x55);
+    let b = (b & 0x33) + ((b >> 2) & 0x33);
+    u32::from((b + (b >> 4)) & 0x0f)
+}
+
+/// Shifts `bytes`, treated as a big-endian integer, left by `bits` bits in place. Bits shifted out of the first byte
+/// are discarded and the vacated low bits are zero. Shifting by the bit length of the slice or more clears it.
+pub fn shl_slice(bytes: &mut [u8], bits: usize) {
+    let len = bytes.len();
+    let (byte_shift, bit_shift) = (bits / 8, bits % 8);
+    for i in 0..len {
+        let hi = i
+            .checked_add(byte_shift)
+            .and_then(|src| bytes.get(src))
+            .copied()
+            .unwrap_or(0);
+        let lo = i
+            .checked_add(byte_shift + 1)
+            .and_then(|src| bytes.get(src))
+            .copied()
+            .unwrap_or(0);
+        bytes[i] = if bit_shift == 0 {
+            hi
+        } else {
+            (hi << bit_shift) | (lo >> (8 - bit_shift))
+        };
+    }
+}
+
+/// Shifts `bytes`, treated as a big-endian integer, right by `bits` bits in place. Bits shifted out of the last byte
+/// are discarded and the vacated high bits are zero. Shifting by the bit length of the slice or more clears it.
+pub fn shr_slice(bytes: &mut [u8], bits: usize) {
+    let len = bytes.len();
+    let (byte_shift, bit_shift) = (bits / 8, bits % 8);
+    for i in (0..len).rev() {
+        let lo = i.checked_sub(byte_shift).map(|src| bytes[src]).unwrap_or(0);
+        let hi = i.checked_sub(byte_shift + 1).map(|src| bytes[src]).unwrap_or(0);
+        bytes[i] = if bit_shift == 0 {
+            lo
+        } else {
+            (lo >> bit_shift) | (hi << (8 - bit_shift))
+        };
+    }
+}
+
+/// Rotates `bytes`, treated as a big-endian integer, left by `bits` bits in place, so that bits shifted out of the
+/// first byte reappear at the end of the last byte. `bits` may exceed the bit length of the slice.
+pub fn rotate_left_slice(bytes: &mut [u8], bits: usize) {
+    if bytes.is_empty() {
+        return;
+    }
+    let bits = bits % (bytes.len() * 8);
+    bytes.rotate_left(bits / 8);
+    let bit_shift = bits % 8;
+    if bit_shift == 0 {
+        return;
+    }
+    let carry = bytes[0] >> (8 - bit_shift);
+    for i in 0..bytes.len() - 1 {
+        bytes[i] = (bytes[i] << bit_shift) | (bytes[i + 1] >> (8 - bit_shift));
+    }
+    let last = bytes.len() - 1;
+    bytes[last] = (bytes[last] << bit_shift) | carry;
+}
+
+/// Rotates `bytes`, treated as a big-endian integer, right by `bits` bits in place. See [rotate_left_slice].
+pub fn rotate_right_slice(bytes: &mut [u8], bits: usize) {
+    if bytes.is_empty() {
+        return;
+    }
+    let total = bytes.len() * 8;
+    rotate_left_slice(bytes, total - bits % total);
+}
+
+/// Returns the parity of the number of set bits in `bytes`.
+pub fn parity(bytes: &[u8]) -> Parity {
+    if bytes.iter().fold(0u8, |acc, b| acc ^ b).count_ones() % 2 == 0 {
+        Parity::Even
+    } else {
+        Parity::Odd
+    }
+}
+
+/// Returns the parity bit to transmit with `bytes` so that the total number of set bits, including the parity bit, has
+/// the parity given by `scheme`.
+pub fn parity_bit(bytes: &[u8], scheme: Parity) -> bool {
+    parity(bytes) != scheme
+}
+
+/// Computes a `width`-bit XOR checksum of `bytes`. The data is read as a stream of bits, most significant bit of each
+/// byte first, and split into words of `width` bits, with the last word padded with zero bits. The checksum is the XOR
+/// of every word. `width` must be between 1 and 64.
+pub fn xor_checksum(bytes: &[u8], width: usize) -> Result<u64, BitError> {
+    ensure!((1..=64).contains(&width), InvalidChecksumWidthSnafu { width });
+    let mut checksum = 0u64;
+    let mut word = 0u64;
+    let mut filled = 0;
+    for bit in bytes.iter_bits_be() {
+        word = (word << 1) | u64::from(bit);
+        filled += 1;
+        if filled == width {
+            checksum ^= word;
+            word = 0;
+            filled = 0;
+        }
+    }
+    if filled > 0 {
+        checksum ^= word << (width - filled);
+    }
+    Ok(checksum)
+}
+
+/// Formats bytes as a string of binary digits, most significant bit of each byte first, e.g. `"1010111100000001"`.
+pub fn to_binary_string(bytes: &[u8]) -> String {
+    to_binary_string_grouped(bytes, 0)
+}
+
+/// Formats bytes as a string of binary digits, most significant bit of each byte first, separating every `group`
+/// digits with a space, e.g. `"1010 1111"` for a group size of 4. A group size of 0 disables grouping.
+pub fn to_binary_string_grouped(bytes: &[u8], group: usize) -> String {
+    let mut s = String::with_capacity(bytes.len() * 9);
+    for (i, bit) in bytes.iter_bits_be().enumerate() {
+        if group > 0 && i > 0 && i % group == 0 {
+            s.push(' ');
+        }
+        s.push(if bit { '1' } else { '0' });
+    }
+    s
+}
+
+/// Parses a string of binary digits, most significant bit of each byte first, as produced by [to_binary_string]. An
+/// optional `0b` prefix is accepted, and whitespace and `_` separators are ignored, so grouped strings such as
+/// `"1010 1111"` or `"0b1010_1111"` can be parsed. The number of digits must be a multiple of 8.
+pub fn from_binary_string(s: &str) -> Result<Vec<u8>, BitError> {
+    let s = s.trim();
+    let s = s.strip_prefix("0b").unwrap_or(s);
+    let mut bits = Vec::with_capacity(s.len());
+    for character in s.chars() {
+        match character {
+            '0' => bits.push(false),
+            '1' => bits.push(true),
+            '_' => {},
+            c if c.is_whitespace() => {},
+            character => return InvalidBinaryCharacterSnafu { character }.fail(),
+        }
+    }
+    ensure!(bits.len().is_multiple_of(8), InvalidBinaryLengthSnafu {
+        digits: bits.len()
+    });
+    Ok(bits_to_bytes_be(&bits))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    pub fn test_bytes_to_bits() {
+        let bytes = [1u8, 127];
+        let bits = bytes_to_bits(&bytes);
+        assert_eq!(bits, [
+            true, false, false, false, false, false, false, false, true, true, true, true, true, true, true, false
+        ]);
+    }
+
+    #[test]
+    pub fn test_ordered_conversions() {
+        let bytes = [0b0000_0011u8, 0b1000_0000];
+        assert_eq!(bytes_to_bits_le(&bytes), bytes_to_bits(&bytes));
+        assert_eq!(bytes_to_bits_be(&bytes)[..8], [
+            false, false, false, false, false, false, true, true
+        ]);
+        assert!(bytes_to_bits_be(&bytes)[8]);
+        assert_eq!(bits_to_bytes_le(&bytes_to_bits_le(&bytes)), bytes.to_vec());
+        assert_eq!(bits_to_bytes_be(&bytes_to_bits_be(&bytes)), bytes.to_vec());
+        // Reading in one order and writing in the other reverses the bits of each byte
+        assert_eq!(bits_to_bytes_be(&bytes_to_bits_le(&bytes)), vec![
+            0b1100_0000,
+            0b0000_0001
+        ]);
+        assert!(bits_to_bytes_le(&[]).is_empty());
+    }
+
+    #[test]
+    pub fn test_iter_bits() {
+        let bytes = [0b1000_0001u8, 0b0000_0110];
+        assert_eq!(bytes.iter_bits_le().collect::<Vec<_>>(), bytes_to_bits_le(&bytes));
+        assert_eq!(bytes.iter_bits_be().collect::<Vec<_>>(), bytes_to_bits_be(&bytes));
+        assert_eq!(bytes.iter_bits_le().len(), 16);
+        assert_eq!(bytes.iter_bits_le().rev().collect::<Vec<_>>(), {
+            let mut bits = bytes_to_bits_le(&bytes);
+            bits.reverse();
+            bits
+        });
+        let mut bits = bytes[..].iter_bits_be();
+        assert_eq!(bits.next(), Some(true));
+        assert_eq!(bits.next_back(), Some(false));
+        assert_eq!(bits.len(), 14);
+        assert_eq!(bits.as_u8().filter(|bit| *bit == 1).count(), 3);
+        assert_eq!([0u8; 0].iter_bits_le().next(), None);
+    }
+
+    #[test]
+    #[cfg(feature = "subtle")]
+    pub fn test_ct_bits() {
+        let mut bytes = [0b0000_0100u8, 0];
+        assert!(bool::from(ct_get_bit(&bytes, 2)));
+        assert!(!bool::from(ct_get_bit(&bytes, 3)));
+        assert!(!bool::from(ct_get_bit(&bytes, 16)));
+        assert!(ct_set_bit(&mut bytes, 9, Choice::from(1)));
+        assert!(ct_set_bit(&mut bytes, 2, Choice::from(0)));
+        assert!(ct_set_bit(&mut bytes, 3, Choice::from(0)));
+        assert!(!ct_set_bit(&mut bytes, 16, Choice::from(1)));
+        assert_eq!(bytes, [0, 0b0000_0010]);
+        for (index, bit) in bytes.iter_bits_le().enumerate() {
+            assert_eq!(bool::from(ct_get_bit(&bytes, index)), bit);
+        }
+    }
+
+    #[test]
+    pub fn test_binary_strings() {
+        let bytes = [0b1010_1111u8, 1];
+        assert_eq!(to_binary_string(&bytes), "1010111100000001");
+        assert_eq!(to_binary_string_grouped(&bytes, 4), "1010 1111 0000 0001");
+        assert_eq!(to_binary_string_grouped(&bytes, 8), "10101111 00000001");
+        assert_eq!(to_binary_string(&[]), "");
+        assert_eq!(from_binary_string("1010111100000001"), Ok(bytes.to_vec()));
+        assert_eq!(from_binary_string(" 1010 1111\n0000 0001 "), Ok(bytes.to_vec()));
+        assert_eq!(from_binary_string("0b1010_1111"), Ok(vec![0b1010_1111]));
+        assert_eq!(from_binary_string(""), Ok(vec![]));
+        assert_eq!(
+            from_binary_string("1010 1112"),
+            Err(BitError::InvalidBinaryCharacter { character: '2' })
+        );
+        assert_eq!(
+            from_binary_string("1010 111"),
+            Err(BitError::InvalidBinaryLength { digits: 7 })
+        );
+    }
+
+    #[test]
+    pub fn test_hamming() {
+        assert_eq!(hamming_weight(&[0xff, 0x01, 0]), 9);
+        assert_eq!(hamming_weight(&[]), 0);
+        assert_eq!(hamming_distance(&[0b1010, 0xff], &[0b0110, 0xff]), Ok(2));
+        assert_eq!(
+            hamming_distance(&[0], &[0, 0]),
+            Err(BitError::LengthMismatch { left: 1, right: 2 })
+        );
+        for b in 0..=255u8 {
+            assert_eq!(ct_count_ones(b), b.count_ones());
+        }
+        assert_eq!(ct_hamming_weight(&[0xff, 0x01, 0]), 9);
+        assert_eq!(ct_hamming_distance(&[0b1010, 0xff], &[0b0110, 0xff]), Ok(2));
+        assert!(ct_hamming_distance(&[0, 0], &[0]).is_err());
+    }
+
+    #[test]
+    pub fn test_shifts() {
+        let value = 0x0123_4567_89ab_cdefu64;
+        for bits in [0usize, 1, 4, 7, 8, 9, 15, 16, 31, 63] {
+            let mut bytes = value.to_be_bytes();
+            shl_slice(&mut bytes, bits);
+            assert_eq!(u64::from_be_bytes(bytes), value << bits, "shl {}", bits);
+            let mut bytes = value.to_be_bytes();
+            shr_slice(&mut bytes, bits);
+            assert_eq!(u64::from_be_bytes(bytes), value >> bits, "shr {}", bits);
+        }
+        for bits in [64usize, 100, usize::MAX] {
+            let mut bytes = value.to_be_bytes();
+            shl_slice(&mut bytes, bits);
+            assert_eq!(bytes, [0; 8]);
+            let mut bytes = value.to_be_bytes();
+            shr_slice(&mut bytes, bits);
+            assert_eq!(bytes, [0; 8]);
+        }
+        shl_slice(&mut [], 3);
+        shr_slice(&mut [], 3);
+    }
+
+    #[test]
+    pub fn test_rotations() {
+        let value = 0x0123_4567_89ab_cdefu64;
+        for bits in [0usize, 1, 4, 8, 13, 63, 64, 65, 1000] {
+            let mut bytes = value.to_be_bytes();
+            rotate_left_slice(&mut bytes, bits);
+            assert_eq!(
+                u64::from_be_bytes(bytes),
+                value.rotate_left((bits % 64) as u32),
+                "rotl {}",
+                bits
+            );
+            rotate_right_slice(&mut bytes, bits);
+            assert_eq!(u64::from_be_bytes(bytes), value, "rotr {}", bits);
+        }
+        let mut bytes = [0b1000_0001u8];
+        rotate_left_slice(&mut bytes, 1);
+        assert_eq!(bytes, [0b0000_0011]);
+        rotate_left_slice(&mut [], 3);
+        rotate_right_slice(&mut [], 3);
+    }
+
+    #[test]
+    pub fn test_parity() {
+        assert_eq!(parity(&[]), Parity::Even);
+        assert_eq!(parity(&[0b0000_0011]), Parity::Even);
+        assert_eq!(parity(&[0b0000_0111]), Parity::Odd);
+        assert_eq!(parity(&[0b0000_0001, 0b1000_0000, 0x01]), Parity::Odd);
+        assert!(!parity_bit(&[0b0000_0011], Parity::Even));
+        assert!(parity_bit(&[0b0000_0011], Parity::Odd));
+        assert!(parity_bit(&[0b0000_0111], Parity::Even));
+    }
+
+    #[test]
+    pub fn test_xor_checksum() {
+        let data = [0x12u8, 0x34, 0x56];
+        assert_eq!(xor_checksum(&data, 8), Ok(0x12 ^ 0x34 ^ 0x56));
+        // 1 ^ 2 ^ 3 ^ 4 ^ 5 ^ 6
+        assert_eq!(xor_checksum(&data, 4), Ok(0x7));
+        assert_eq!(xor_checksum(&data, 16), Ok(0x1234 ^ 0x5600));
+        assert_eq!(xor_checksum(&data, 1), Ok(u64::from(parity(&data) == Parity::Odd)));
+        assert_eq!(xor_checksum(&data, 64), Ok(0x1234_5600_0000_0000));
+        assert_eq!(xor_checksum(&[], 8), Ok(0));
+        assert_eq!(xor_checksum(&data, 0), Err(BitError::InvalidChecksumWidth { width: 0 }));
+        assert!(xor_checksum(&data, 65).is_err());
+    }
+
+    #[test]
+    pub fn test_partial_bytes() {
+        let bits = [true, false, true, true, false, false, false, false, false, true];
+        assert_eq!(bits_to_bytes_le(&bits), vec![0b0000_1101, 0b0000_0010]);
+        assert_eq!(bits_to_bytes_be(&bits), vec![0b1011_0000, 0b0100_0000]);
+    }
+
+    #[test]
+    pub fn test_u64_bits() {
+        assert_eq!(u64_to_bits::<4>(0b1011), Ok([true, true, false, true]));
+        assert_eq!(
+            u64_to_bits::<4>(16),
+            Err(BitError::ValueTooLarge { value: 16, bits: 4 })
+        );
+        assert_eq!(u64_to_bits::<0>(0), Ok([]));
+        assert!(u64_to_bits::<0>(1).is_err());
+        let bits = u64_to_bits::<64>(u64::MAX).unwrap();
+        assert_eq!(bits_to_u64(&bits), Ok(u64::MAX));
+        let bits = u64_to_bits::<70>(u64::MAX).unwrap();
+        assert!(!bits[64]);
+        assert_eq!(bits_to_u64(&bits), Ok(u64::MAX));
+
+        let mut bits = [false; 66];
+        bits[0] = true;
+        assert_eq!(bits_to_u64(&bits), Ok(1));
+        bits[65] = true;
+        assert_eq!(bits_to_u64(&bits), Err(BitError::Overflow { index: 65, bits: 64 }));
+        assert_eq!(bits_to_u64(&[]), Ok(0));
+    }
+
+    #[test]
+    pub fn test_checked_bits_to_uint() {
+        let bits = [true, false, false, false, false, false, false, false];
+        let uint = checked_bits_to_uint(&bits).unwrap();
+        assert_eq!(uint, 1);
+        let bits = [
+            true, false, false, false, false, false, false, false, true, false, false, false, false, false, false,
+            false,
+        ];
+        let uint = checked_bits_to_uint(&bits).unwrap();
+        assert_eq!(uint, 257);
+        let bits = [false; mem::size_of::<usize>() * 8 + 1];
+        assert!(checked_bits_to_uint(&bits).is_none());
+    }
+}
@@ -0,0 +1,294 @@
+// Copyright 2026, The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+//! A FIFO queue with a fixed capacity and an explicit policy for what happens when it overflows.
+
+use alloc::collections::VecDeque;
+#[cfg(feature = "std")]
+use std::{
+    sync::{Condvar, Mutex, PoisonError},
+    time::{Duration, Instant},
+};
+
+#[cfg(feature = "std")]
+use crate::locks::poison::recover_lock;
+
+/// What a [BoundedQueue] does with a push when it is full.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum OverflowPolicy {
+    /// Evict the oldest item at the front of the queue to make room for the new item.
+    DropOldest,
+    /// Evict the newest item at the back of the queue to make room for the new item.
+    DropNewest,
+    /// Refuse the new item, handing it back to the caller.
+    #[default]
+    Reject,
+}
+
+/// A first-in, first-out queue holding at most `capacity` items.
+///
+/// ```edition2018
+/// # use tari_utilities::bounded_queue::{BoundedQueue, OverflowPolicy};
+/// let mut queue = BoundedQueue::new(2, OverflowPolicy::DropOldest);
+/// queue.push(1).unwrap();
+/// queue.push(2).unwrap();
+/// assert_eq!(queue.push(3), Ok(Some(1)));
+/// assert_eq!(queue.pop(), Some(2));
+/// ```
+#[derive(Clone, Debug)]
+pub struct BoundedQueue<T> {
+    items: VecDeque<T>,
+    capacity: usize,
+    policy: OverflowPolicy,
+}
+
+impl<T> BoundedQueue<T> {
+    /// Creates an empty queue that holds at most `capacity` items.
+    pub fn new(capacity: usize, policy: OverflowPolicy) -> Self {
+        Self {
+            items: VecDeque::with_capacity(capacity),
+            capacity,
+            policy,
+        }
+    }
+
+    /// Returns the maximum number of items the queue can hold.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Returns the overflow policy of the queue.
+    pub fn policy(&self) -> OverflowPolicy {
+        self.policy
+    }
+
+    /// Returns the number of items in the queue.
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    /// Returns true if the queue holds no items.
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    /// Returns true if the next push will overflow.
+    pub fn is_full(&self) -> bool {
+        self.items.len() >= self.capacity
+    }
+
+    /// Appends an item to the back of the queue. If the queue is full, the overflow policy decides the outcome:
+    /// - `DropOldest` and `DropNewest` queue `val` and return the evicted item as `Ok(Some(_))`;
+    /// - `Reject` returns `val` as `Err(val)` and leaves the queue unchanged.
+    ///
+    /// A zero-capacity queue never holds an item, so `val` itself is dropped or rejected.
+    pub fn push(&mut self, val: T) -> Result<Option<T>, T> {
+        if !self.is_full() {
+            self.items.push_back(val);
+            return Ok(None);
+        }
+        if self.capacity == 0 {
+            return match self.policy {
+                OverflowPolicy::Reject => Err(val),
+                _ => Ok(Some(val)),
+            };
+        }
+        let evicted = match self.policy {
+            OverflowPolicy::DropOldest => self.items.pop_front(),
+            OverflowPolicy::DropNewest => self.items.pop_back(),
+            OverflowPolicy::Reject => return Err(val),
+        };
+        self.items.push_back(val);
+        Ok(evicted)
+    }
+
+    /// Removes and returns the item at the front of the queue.
+    pub fn pop(&mut self) -> Option<T> {
+        self.items.pop_front()
+    }
+
+    /// Returns the item at the front of the queue without removing it.
+    pub fn peek(&self) -> Option<&T> {
+        self.items.front()
+    }
+
+    /// Returns an iterator over the items, front first.
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = &T> + ExactSizeIterator + '_ {
+        self.items.iter()
+    }
+
+    /// Removes every item from the queue.
+    pub fn clear(&mut self) {
+        self.items.clear();
+    }
+}
+
+/// A [BoundedQueue] that can be shared between threads, with pops that block until an item is available.
+#[cfg(feature = "std")]
+#[derive(Debug)]
+pub struct SyncBoundedQueue<T> {
+    queue: Mutex<BoundedQueue<T>>,
+    available: Condvar,
+}
+
+#[cfg(feature = "std")]
+impl<T> SyncBoundedQueue<T> {
+    /// Creates an empty queue that holds at most `capacity` items.
+    pub fn new(capacity: usize, policy: OverflowPolicy) -> Self {
+        Self {
+            queue: Mutex::new(BoundedQueue::new(capacity, policy)),
+            available: Condvar::new(),
+        }
+    }
+
+    /// Appends an item to the back of the queue, waking one blocked pop. See [BoundedQueue::push] for the overflow
+    /// behaviour.
+    pub fn push(&self, val: T) -> Result<Option<T>, T> {
+        let result = recover_lock(&self.queue).push(val);
+        if result.is_ok() {
+            self.available.notify_one();
+        }
+        result
+    }
+
+    /// Removes and returns the item at the front of the queue without blocking.
+    pub fn try_pop(&self) -> Option<T> {
+        recover_lock(&self.queue).pop()
+    }
+
+    /// Removes and returns the item at the front of the queue, blocking until one is available.
+    pub fn pop_blocking(&self) -> T {
+        let mut queue = recover_lock(&self.queue);
+        loop {
+            if let Some(val) = queue.pop() {
+                return val;
+            }
+            queue = self.available.wait(queue).unwrap_or_else(PoisonError::into_inner);
+        }
+    }
+
+    /// Removes and returns the item at the front of the queue, blocking for at most `timeout` until one is available.
+    /// A timeout too large to represent as a deadline waits indefinitely.
+    pub fn pop_timeout(&self, timeout: Duration) -> Option<T> {
+        let deadline = Instant::now().checked_add(timeout);
+        let mut queue = recover_lock(&self.queue);
+        loop {
+            if let Some(val) = queue.pop() {
+                return Some(val);
+            }
+            queue = match deadline {
+                Some(deadline) => {
+                    let remaining = deadline.checked_duration_since(Instant::now())?;
+                    self.available
+                        .wait_timeout(queue, remaining)
+                        .unwrap_or_else(PoisonError::into_inner)
+                        .0
+                },
+                None => self.available.wait(queue).unwrap_or_else(PoisonError::into_inner),
+            };
+        }
+    }
+
+    /// Returns the number of items in the queue.
+    pub fn len(&self) -> usize {
+        recover_lock(&self.queue).len()
+    }
+
+    /// Returns true if the queue holds no items.
+    pub fn is_empty(&self) -> bool {
+        recover_lock(&self.queue).is_empty()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use alloc::vec::Vec;
+
+    use super::*;
+
+    #[test]
+    fn overflow_policies() {
+        let mut queue = BoundedQueue::new(2, OverflowPolicy::DropOldest);
+        assert_eq!(queue.push(1), Ok(None));
+        assert_eq!(queue.push(2), Ok(None));
+        assert!(queue.is_full());
+        assert_eq!(queue.push(3), Ok(Some(1)));
+        assert_eq!(queue.iter().copied().collect::<Vec<_>>(), vec![2, 3]);
+
+        let mut queue = BoundedQueue::new(2, OverflowPolicy::DropNewest);
+        queue.push(1).unwrap();
+        queue.push(2).unwrap();
+        assert_eq!(queue.push(3), Ok(Some(2)));
+        assert_eq!(queue.iter().copied().collect::<Vec<_>>(), vec![1, 3]);
+
+        let mut queue = BoundedQueue::new(2, OverflowPolicy::default());
+        assert_eq!(queue.policy(), OverflowPolicy::Reject);
+        queue.push(1).unwrap();
+        queue.push(2).unwrap();
+        assert_eq!(queue.push(3), Err(3));
+        assert_eq!(queue.len(), 2);
+        assert_eq!(queue.peek(), Some(&1));
+        assert_eq!(queue.pop(), Some(1));
+        assert_eq!(queue.push(3), Ok(None));
+        queue.clear();
+        assert!(queue.is_empty());
+    }
+
+    #[test]
+    fn zero_capacity() {
+        let mut queue = BoundedQueue::new(0, OverflowPolicy::DropOldest);
+        assert_eq!(queue.push(1), Ok(Some(1)));
+        let mut queue = BoundedQueue::new(0, OverflowPolicy::Reject);
+        assert_eq!(queue.push(1), Err(1));
+        assert_eq!(queue.capacity(), 0);
+        assert!(queue.is_empty());
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn blocking_pops() {
+        use std::{sync::Arc, thread};
+
+        let queue = Arc::new(SyncBoundedQueue::new(4, OverflowPolicy::Reject));
+        assert_eq!(queue.pop_timeout(Duration::from_millis(10)), None);
+        let producer = {
+            let queue = queue.clone();
+            thread::spawn(move || {
+                for i in 0..10 {
+                    while queue.push(i).is_err() {
+                        thread::yield_now();
+                    }
+                }
+            })
+        };
+        let received = (0..10).map(|_| queue.pop_blocking()).collect::<Vec<_>>();
+        producer.join().unwrap();
+        assert_eq!(received, (0..10).collect::<Vec<_>>());
+        assert!(queue.is_empty());
+        assert_eq!(queue.try_pop(), None);
+        queue.push(7).unwrap();
+        assert_eq!(queue.len(), 1);
+        assert_eq!(queue.pop_timeout(Duration::from_millis(10)), Some(7));
+        queue.push(8).unwrap();
+        assert_eq!(queue.pop_timeout(Duration::MAX), Some(8));
+    }
+}
@@ -0,0 +1,208 @@
+// Copyright 2026, The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+//! A pool of reusable byte buffers, for serializing many messages without allocating a new buffer for each one.
+//!
+//! A [BufferPool] is owned by the caller and may be shared between threads. [BufferPool::get] hands out a
+//! [PooledBuffer], which dereferences to a `Vec<u8>` and returns its (cleared) allocation to the pool when dropped.
+//!
+//! ```edition2018
+//! # use tari_utilities::buffer_pool::BufferPool;
+//! let pool = BufferPool::new();
+//! {
+//!     let mut buf = pool.get();
+//!     buf.extend_from_slice(b"hello");
+//!     assert_eq!(&buf[..], b"hello");
+//! }
+//! assert_eq!(pool.available(), 1);
+//! assert!(pool.get().is_empty());
+//! ```
+
+use alloc::vec::Vec;
+use core::{
+    fmt,
+    mem,
+    ops::{Deref, DerefMut},
+};
+use std::sync::Mutex;
+
+use crate::recover_lock;
+
+/// A pool of reusable byte buffers.
+///
+/// The pool retains at most `max_buffers` idle buffers. Buffers that have grown beyond `max_capacity` bytes are
+/// released rather than retained, so that one unusually large message does not pin its allocation forever.
+#[derive(Debug)]
+pub struct BufferPool {
+    buffers: Mutex<Vec<Vec<u8>>>,
+    max_buffers: usize,
+    max_capacity: usize,
+}
+
+impl BufferPool {
+    /// The default number of idle buffers retained by the pool.
+    pub const DEFAULT_MAX_BUFFERS: usize = 64;
+    /// The default capacity, in bytes, above which a returned buffer is released instead of retained.
+    pub const DEFAULT_MAX_CAPACITY: usize = 64 * 1024;
+
+    /// Creates an empty pool with the default limits.
+    pub fn new() -> Self {
+        Self::with_limits(Self::DEFAULT_MAX_BUFFERS, Self::DEFAULT_MAX_CAPACITY)
+    }
+
+    /// Creates an empty pool that retains at most `max_buffers` idle buffers of at most `max_capacity` bytes each.
+    pub fn with_limits(max_buffers: usize, max_capacity: usize) -> Self {
+        Self {
+            buffers: Mutex::new(Vec::new()),
+            max_buffers,
+            max_capacity,
+        }
+    }
+
+    /// Takes an empty buffer from the pool, or allocates a new one if the pool has none available.
+    pub fn get(&self) -> PooledBuffer<'_> {
+        let buffer = recover_lock!(self.buffers.lock()).pop().unwrap_or_default();
+        PooledBuffer { buffer, pool: self }
+    }
+
+    /// Returns the number of idle buffers currently held by the pool.
+    pub fn available(&self) -> usize {
+        recover_lock!(self.buffers.lock()).len()
+    }
+
+    /// Releases all idle buffers held by the pool.
+    pub fn clear(&self) {
+        recover_lock!(self.buffers.lock()).clear();
+    }
+
+    fn recycle(&self, mut buffer: Vec<u8>) {
+        if buffer.capacity() == 0 || buffer.capacity() > self.max_capacity {
+            return;
+        }
+        buffer.clear();
+        let mut buffers = recover_lock!(self.buffers.lock());
+        if buffers.len() < self.max_buffers {
+            buffers.push(buffer);
+        }
+    }
+}
+
+impl Default for BufferPool {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A buffer borrowed from a [BufferPool], which is returned to the pool when dropped.
+pub struct PooledBuffer<'a> {
+    buffer: Vec<u8>,
+    pool: &'a BufferPool,
+}
+
+impl PooledBuffer<'_> {
+    /// Detaches the buffer from the pool and returns it. The allocation is not returned to the pool.
+    pub fn into_vec(mut self) -> Vec<u8> {
+        mem::take(&mut self.buffer)
+    }
+}
+
+impl Deref for PooledBuffer<'_> {
+    type Target = Vec<u8>;
+
+    fn deref(&self) -> &Vec<u8> {
+        &self.buffer
+    }
+}
+
+impl DerefMut for PooledBuffer<'_> {
+    fn deref_mut(&mut self) -> &mut Vec<u8> {
+        &mut self.buffer
+    }
+}
+
+impl AsRef<[u8]> for PooledBuffer<'_> {
+    fn as_ref(&self) -> &[u8] {
+        &self.buffer
+    }
+}
+
+impl fmt::Debug for PooledBuffer<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("PooledBuffer").field(&self.buffer).finish()
+    }
+}
+
+impl Drop for PooledBuffer<'_> {
+    fn drop(&mut self) {
+        self.pool.recycle(mem::take(&mut self.buffer));
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn buffers_are_reused() {
+        let pool = BufferPool::new();
+        let ptr = {
+            let mut buf = pool.get();
+            buf.extend_from_slice(&[1, 2, 3]);
+            buf.as_ptr()
+        };
+        assert_eq!(pool.available(), 1);
+        let buf = pool.get();
+        assert!(buf.is_empty());
+        assert!(buf.capacity() >= 3);
+        assert_eq!(buf.as_ptr(), ptr);
+        assert_eq!(pool.available(), 0);
+    }
+
+    #[test]
+    fn limits() {
+        let pool = BufferPool::with_limits(1, 16);
+        {
+            let mut a = pool.get();
+            let mut b = pool.get();
+            a.push(1);
+            b.push(2);
+        }
+        assert_eq!(pool.available(), 1);
+        pool.clear();
+
+        pool.get().extend_from_slice(&[0; 17]);
+        assert_eq!(pool.available(), 0);
+
+        // Unused buffers have no allocation to return
+        drop(pool.get());
+        assert_eq!(pool.available(), 0);
+    }
+
+    #[test]
+    fn into_vec_detaches() {
+        let pool = BufferPool::new();
+        let mut buf = pool.get();
+        buf.push(7);
+        assert_eq!(buf.into_vec(), vec![7]);
+        assert_eq!(pool.available(), 0);
+    }
+}
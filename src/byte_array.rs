@@ -26,7 +26,10 @@ use alloc::{string::String, vec::Vec};
 
 use snafu::prelude::*;
 
-use crate::hex::{from_hex, to_hex, Hex, HexError};
+use crate::{
+    encoding::DecodeMode,
+    hex::{from_hex, from_hex_with_mode, to_hex, Hex, HexError},
+};
 
 /// Errors for [ByteArray] trait.
 #[derive(Debug, Snafu, PartialEq, Eq)]
@@ -65,6 +68,13 @@ pub trait ByteArray: Sized {
     /// note.
     fn from_canonical_bytes(bytes: &[u8]) -> Result<Self, ByteArrayError>;
 
+    /// Try and convert the given hex string to the implemented type, decoding in the given mode.
+    /// [Hex::from_hex](crate::hex::Hex::from_hex) is the same as using [DecodeMode::Strict].
+    fn from_hex_with_mode(hex: &str, mode: DecodeMode) -> Result<Self, HexError> {
+        let v = from_hex_with_mode(hex, mode)?;
+        Self::from_canonical_bytes(&v).map_err(|_| HexError::HexConversionError {})
+    }
+
     /// Return the type as a byte array.
     fn as_bytes(&self) -> &[u8];
 }
@@ -154,6 +164,14 @@ mod test {
         assert_eq!(v.to_hex(), "deadbeef");
     }
 
+    #[test]
+    fn from_hex_with_mode() {
+        assert!(<[u8; 2]>::from_hex(" 0xBEEF ").is_err());
+        let v = <[u8; 2]>::from_hex_with_mode(" 0xBEEF ", DecodeMode::Lenient).unwrap();
+        assert_eq!(v, [0xbe, 0xef]);
+        assert!(<[u8; 2]>::from_hex_with_mode("BEEF", DecodeMode::Strict).is_err());
+    }
+
     #[test]
     fn test_error_handling() {
         let err = <[u8; 32]>::from_canonical_bytes(&[1, 2, 3, 4]).unwrap_err();
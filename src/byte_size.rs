@@ -0,0 +1,452 @@
+// Copyright 2026, The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+//! A byte count that formats as, and parses from, human-readable sizes such as "1.50 MiB" or "3.2 GB".
+//!
+//! Formatting uses binary (IEC) units by default; use [ByteSize::display] to choose SI units. The formatter precision
+//! sets the number of decimal places, which defaults to 2. Sizes below one kilobyte are always shown as whole bytes.
+//!
+//! Parsing accepts a decimal number, optionally with a fractional part, followed by an optional unit:
+//! * `B`, or no unit, for bytes
+//! * `kB`, `MB`, `GB`, `TB`, `PB` and `EB` for SI units, with powers of 1000
+//! * `KiB`, `MiB`, `GiB`, `TiB`, `PiB` and `EiB` for binary units, with powers of 1024
+//! * `K`, `M`, `G`, `T`, `P` and `E` as shorthand for the binary units, following common configuration file usage
+//!
+//! Units are matched ignoring ASCII case, and fractional bytes are rounded down.
+//!
+//! ```edition2018
+//! # use tari_utilities::byte_size::{ByteSize, Units};
+//! let size: ByteSize = "1.5 MiB".parse().unwrap();
+//! assert_eq!(size, ByteSize::kib(1536));
+//! assert_eq!(size.to_string(), "1.50 MiB");
+//! assert_eq!(
+//!     format!("{:.1}", ByteSize::new(3_210_000_000).display(Units::Si)),
+//!     "3.2 GB"
+//! );
+//! ```
+//!
+//! With the `serde` feature, human-readable formats use an exact string such as "512 MiB" and accept either a string
+//! or an integer number of bytes. Binary formats use the number of bytes.
+
+use alloc::string::{String, ToString};
+use core::{convert::TryFrom, fmt, str::FromStr};
+
+use snafu::prelude::*;
+
+const SI_UNITS: [&str; 6] = ["kB", "MB", "GB", "TB", "PB", "EB"];
+const BINARY_UNITS: [&str; 6] = ["KiB", "MiB", "GiB", "TiB", "PiB", "EiB"];
+const SHORT_UNITS: [&str; 6] = ["K", "M", "G", "T", "P", "E"];
+
+/// The unit system used to format a [ByteSize]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum Units {
+    /// Binary (IEC) units, with powers of 1024: KiB, MiB, GiB, ...
+    #[default]
+    Binary,
+    /// SI units, with powers of 1000: kB, MB, GB, ...
+    Si,
+}
+
+impl Units {
+    fn base(self) -> u64 {
+        match self {
+            Units::Binary => 1024,
+            Units::Si => 1000,
+        }
+    }
+
+    fn names(self) -> &'static [&'static str; 6] {
+        match self {
+            Units::Binary => &BINARY_UNITS,
+            Units::Si => &SI_UNITS,
+        }
+    }
+}
+
+/// Errors for parsing a [ByteSize].
+#[derive(Debug, Snafu, PartialEq, Eq)]
+pub enum ByteSizeError {
+    /// The string does not start with a number.
+    #[snafu(display("Invalid byte size number in `{input}'"))]
+    InvalidNumber {
+        /// The string being parsed
+        input: String,
+    },
+    /// The unit is not recognised.
+    #[snafu(display("Unknown byte size unit `{unit}'"))]
+    UnknownUnit {
+        /// The unrecognised unit
+        unit: String,
+    },
+    /// The size does not fit in a `u64`.
+    #[snafu(display("Byte size `{input}' is too large"))]
+    Overflow {
+        /// The string being parsed
+        input: String,
+    },
+}
+
+/// A number of bytes.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ByteSize(u64);
+
+impl ByteSize {
+    /// Create a size of `bytes` bytes.
+    pub const fn new(bytes: u64) -> Self {
+        Self(bytes)
+    }
+
+    /// Create a size of `n` kibibytes, saturating at `u64::MAX` bytes.
+    pub const fn kib(n: u64) -> Self {
+        Self(n.saturating_mul(1 << 10))
+    }
+
+    /// Create a size of `n` mebibytes, saturating at `u64::MAX` bytes.
+    pub const fn mib(n: u64) -> Self {
+        Self(n.saturating_mul(1 << 20))
+    }
+
+    /// Create a size of `n` gibibytes, saturating at `u64::MAX` bytes.
+    pub const fn gib(n: u64) -> Self {
+        Self(n.saturating_mul(1 << 30))
+    }
+
+    /// Create a size of `n` kilobytes, saturating at `u64::MAX` bytes.
+    pub const fn kb(n: u64) -> Self {
+        Self(n.saturating_mul(1_000))
+    }
+
+    /// Create a size of `n` megabytes, saturating at `u64::MAX` bytes.
+    pub const fn mb(n: u64) -> Self {
+        Self(n.saturating_mul(1_000_000))
+    }
+
+    /// Create a size of `n` gigabytes, saturating at `u64::MAX` bytes.
+    pub const fn gb(n: u64) -> Self {
+        Self(n.saturating_mul(1_000_000_000))
+    }
+
+    /// Return the number of bytes.
+    pub const fn as_u64(self) -> u64 {
+        self.0
+    }
+
+    /// Return a [Display](fmt::Display) adaptor that formats the size in the given units.
+    pub fn display(self, units: Units) -> ByteSizeDisplay {
+        ByteSizeDisplay { size: self, units }
+    }
+
+    /// Format the size exactly, in the largest unit that divides it, e.g. "512 MiB", "3 GB" or "1234 B". Binary units
+    /// are preferred over SI units of the same magnitude.
+    pub fn to_exact_string(self) -> String {
+        for exponent in (1..=6u32).rev() {
+            for units in [Units::Binary, Units::Si] {
+                let multiple = units.base().pow(exponent);
+                if self.0 >= multiple && self.0.is_multiple_of(multiple) {
+                    return format!("{} {}", self.0 / multiple, units.names()[exponent as usize - 1]);
+                }
+            }
+        }
+        format!("{} B", self.0)
+    }
+}
+
+impl From<u64> for ByteSize {
+    fn from(bytes: u64) -> Self {
+        Self(bytes)
+    }
+}
+
+impl From<ByteSize> for u64 {
+    fn from(size: ByteSize) -> Self {
+        size.0
+    }
+}
+
+impl fmt::Display for ByteSize {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.display(Units::Binary), f)
+    }
+}
+
+/// Formats a [ByteSize] in a chosen unit system. See [ByteSize::display].
+#[derive(Clone, Copy, Debug)]
+pub struct ByteSizeDisplay {
+    size: ByteSize,
+    units: Units,
+}
+
+impl fmt::Display for ByteSizeDisplay {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let bytes = self.size.0;
+        let base = self.units.base();
+        if bytes < base {
+            return write!(f, "{} B", bytes);
+        }
+        let mut exponent = 1;
+        while exponent < 6 && bytes >= base.pow(exponent + 1) {
+            exponent += 1;
+        }
+        let value = bytes as f64 / base.pow(exponent) as f64;
+        let precision = f.precision().unwrap_or(2);
+        write!(
+            f,
+            "{:.*} {}",
+            precision,
+            value,
+            self.units.names()[exponent as usize - 1]
+        )
+    }
+}
+
+impl FromStr for ByteSize {
+    type Err = ByteSizeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let input = s.trim();
+        let number_len = input
+            .find(|c: char| !(c.is_ascii_digit() || c == '.'))
+            .unwrap_or(input.len());
+        let (number, unit) = input.split_at(number_len);
+        let invalid_number = || ByteSizeError::InvalidNumber { input: s.to_string() };
+        let (whole, fraction) = number.split_once('.').unwrap_or((number, ""));
+        ensure!(!whole.is_empty() && !fraction.contains('.'), InvalidNumberSnafu {
+            input: s
+        });
+        let multiplier = parse_unit(unit.trim())?;
+        let overflow = || ByteSizeError::Overflow { input: s.to_string() };
+        let whole = whole.parse::<u64>().map_err(|_| overflow())?;
+        let mut bytes = u128::from(whole) * u128::from(multiplier);
+        if !fraction.is_empty() {
+            // Only as many fractional digits as can affect a whole number of bytes are needed
+            let digits = &fraction[..fraction.len().min(19)];
+            let numerator = digits.parse::<u128>().map_err(|_| invalid_number())?;
+            bytes += numerator * u128::from(multiplier) / 10u128.pow(digits.len() as u32);
+        }
+        u64::try_from(bytes).map(ByteSize).map_err(|_| overflow())
+    }
+}
+
+fn parse_unit(unit: &str) -> Result<u64, ByteSizeError> {
+    if unit.is_empty() || unit.eq_ignore_ascii_case("b") {
+        return Ok(1);
+    }
+    for (units, names) in [
+        (Units::Si, &SI_UNITS),
+        (Units::Binary, &BINARY_UNITS),
+        (Units::Binary, &SHORT_UNITS),
+    ] {
+        if let Some(i) = names.iter().position(|name| name.eq_ignore_ascii_case(unit)) {
+            return Ok(units.base().pow(i as u32 + 1));
+        }
+    }
+    UnknownUnitSnafu { unit }.fail()
+}
+
+#[cfg(feature = "serde")]
+mod serialize {
+    use alloc::string::{String, ToString};
+    use core::{convert::TryFrom, fmt};
+
+    use serde::{
+        de::{Error, Visitor},
+        Deserialize,
+        Deserializer,
+        Serialize,
+        Serializer,
+    };
+
+    use super::ByteSize;
+
+    impl Serialize for ByteSize {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where S: Serializer {
+            if serializer.is_human_readable() {
+                serializer.serialize_str(&self.to_exact_string())
+            } else {
+                serializer.serialize_u64(self.0)
+            }
+        }
+    }
+
+    impl<'de> Deserialize<'de> for ByteSize {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where D: Deserializer<'de> {
+            if deserializer.is_human_readable() {
+                deserializer.deserialize_any(ByteSizeVisitor)
+            } else {
+                deserializer.deserialize_u64(ByteSizeVisitor)
+            }
+        }
+    }
+
+    struct ByteSizeVisitor;
+
+    impl<'de> Visitor<'de> for ByteSizeVisitor {
+        type Value = ByteSize;
+
+        fn expecting(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+            fmt.write_str("a byte size as a number of bytes or a string such as \"512 MiB\"")
+        }
+
+        fn visit_u64<E>(self, v: u64) -> Result<Self::Value, E>
+        where E: Error {
+            Ok(ByteSize(v))
+        }
+
+        fn visit_i64<E>(self, v: i64) -> Result<Self::Value, E>
+        where E: Error {
+            u64::try_from(v)
+                .map(ByteSize)
+                .map_err(|_| E::custom("byte size cannot be negative"))
+        }
+
+        fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+        where E: Error {
+            v.parse().map_err(|e: super::ByteSizeError| E::custom(e.to_string()))
+        }
+
+        fn visit_string<E>(self, v: String) -> Result<Self::Value, E>
+        where E: Error {
+            self.visit_str(&v)
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn display() {
+        assert_eq!(ByteSize::new(0).to_string(), "0 B");
+        assert_eq!(ByteSize::new(1023).to_string(), "1023 B");
+        assert_eq!(ByteSize::new(1024).to_string(), "1.00 KiB");
+        assert_eq!(ByteSize::kib(1536).to_string(), "1.50 MiB");
+        assert_eq!(format!("{:.0}", ByteSize::gib(3)), "3 GiB");
+        assert_eq!(ByteSize::new(u64::MAX).to_string(), "16.00 EiB");
+        assert_eq!(ByteSize::new(999).display(Units::Si).to_string(), "999 B");
+        assert_eq!(
+            format!("{:.1}", ByteSize::new(3_210_000_000).display(Units::Si)),
+            "3.2 GB"
+        );
+        assert_eq!(ByteSize::mb(1).display(Units::Si).to_string(), "1.00 MB");
+    }
+
+    #[test]
+    fn exact_string() {
+        assert_eq!(ByteSize::mib(512).to_exact_string(), "512 MiB");
+        assert_eq!(ByteSize::gb(3).to_exact_string(), "3 GB");
+        assert_eq!(ByteSize::new(1234).to_exact_string(), "1234 B");
+        assert_eq!(ByteSize::new(0).to_exact_string(), "0 B");
+        for size in [0, 1, 1000, 1024, 1536, 1_000_000, 123_456_789, u64::MAX] {
+            let size = ByteSize::new(size);
+            assert_eq!(size.to_exact_string().parse::<ByteSize>().unwrap(), size);
+        }
+    }
+
+    #[test]
+    fn parse() {
+        let cases = [
+            ("0", 0),
+            ("42", 42),
+            ("42 B", 42),
+            ("1kB", 1000),
+            ("1 KiB", 1024),
+            ("1.5 MiB", 1_572_864),
+            ("1.5MB", 1_500_000),
+            ("3.2 GB", 3_200_000_000),
+            ("512M", 536_870_912),
+            ("2 gib", 2_147_483_648),
+            ("  7 k  ", 7168),
+            ("1.", 1),
+            ("0.0009765625 KiB", 1),
+            ("1.9999 B", 1),
+        ];
+        for (s, bytes) in cases {
+            assert_eq!(s.parse::<ByteSize>().unwrap(), ByteSize::new(bytes), "{}", s);
+        }
+        assert_eq!(
+            "16 EiB".parse::<ByteSize>(),
+            Err(ByteSizeError::Overflow { input: "16 EiB".into() })
+        );
+        assert_eq!(
+            "15.99999999999999999999 EiB".parse::<ByteSize>().unwrap(),
+            ByteSize::new(u64::MAX)
+        );
+    }
+
+    #[test]
+    fn parse_errors() {
+        assert_eq!(
+            "".parse::<ByteSize>(),
+            Err(ByteSizeError::InvalidNumber { input: "".into() })
+        );
+        assert_eq!(
+            "MiB".parse::<ByteSize>(),
+            Err(ByteSizeError::InvalidNumber { input: "MiB".into() })
+        );
+        assert_eq!(
+            ".5 MiB".parse::<ByteSize>(),
+            Err(ByteSizeError::InvalidNumber { input: ".5 MiB".into() })
+        );
+        assert_eq!(
+            "1.2.3 MiB".parse::<ByteSize>(),
+            Err(ByteSizeError::InvalidNumber {
+                input: "1.2.3 MiB".into()
+            })
+        );
+        assert_eq!(
+            "-1 MiB".parse::<ByteSize>(),
+            Err(ByteSizeError::InvalidNumber { input: "-1 MiB".into() })
+        );
+        assert_eq!(
+            "5 parsecs".parse::<ByteSize>(),
+            Err(ByteSizeError::UnknownUnit { unit: "parsecs".into() })
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde() {
+        #[derive(Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+        struct Config {
+            cache: ByteSize,
+        }
+        let config = Config {
+            cache: ByteSize::mib(512),
+        };
+        let json = serde_json::to_string(&config).unwrap();
+        assert_eq!(json, r#"{"cache":"512 MiB"}"#);
+        assert_eq!(serde_json::from_str::<Config>(&json).unwrap(), config);
+        assert_eq!(
+            serde_json::from_str::<Config>(r#"{"cache":536870912}"#).unwrap(),
+            config
+        );
+        assert!(serde_json::from_str::<Config>(r#"{"cache":-1}"#).is_err());
+        assert!(serde_json::from_str::<Config>(r#"{"cache":"lots"}"#).is_err());
+
+        let bytes = bincode::serialize(&config).unwrap();
+        assert_eq!(bytes, (512u64 << 20).to_le_bytes());
+        assert_eq!(bincode::deserialize::<Config>(&bytes).unwrap(), config);
+    }
+}
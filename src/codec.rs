@@ -0,0 +1,234 @@
+// Copyright 2026, The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+//! A `tokio_util` codec that frames [MessageFormat] messages on a byte stream.
+//!
+//! Each frame is the binary form of one message, preceded by its length as a big-endian `u32`. Frames longer than the
+//! configured maximum are rejected in both directions, so a peer cannot make the decoder buffer an arbitrary amount of
+//! data.
+//!
+//! ```edition2018
+//! # use bytes::BytesMut;
+//! # use tari_utilities::codec::MessageFormatCodec;
+//! # use tokio_util::codec::{Decoder, Encoder};
+//! let mut codec = MessageFormatCodec::<String>::new();
+//! let mut buf = BytesMut::new();
+//! codec.encode("hello".to_string(), &mut buf).unwrap();
+//! assert_eq!(codec.decode(&mut buf).unwrap(), Some("hello".to_string()));
+//! assert_eq!(codec.decode(&mut buf).unwrap(), None);
+//! ```
+
+use core::{fmt, marker::PhantomData};
+use std::io;
+
+use bytes::{Buf, BufMut, BytesMut};
+use snafu::prelude::*;
+use tokio_util::codec::{Decoder, Encoder};
+
+use crate::message_format::{MessageFormat, MessageFormatError};
+
+/// The size of the length prefix of each frame.
+const HEADER_LEN: usize = 4;
+
+/// Errors for [MessageFormatCodec].
+#[derive(Debug, Snafu)]
+pub enum CodecError {
+    /// The underlying stream failed.
+    #[snafu(display("I/O error: {error}"))]
+    Io {
+        /// The I/O error
+        error: io::Error,
+    },
+    /// A frame is longer than the maximum frame size.
+    #[snafu(display("Frame of {len} bytes exceeds the maximum of {max}"))]
+    FrameTooLarge {
+        /// The length of the frame
+        len: usize,
+        /// The maximum frame size
+        max: usize,
+    },
+    /// A message could not be converted to or from binary.
+    #[snafu(display("Invalid message: {error}"))]
+    Format {
+        /// The conversion error
+        error: MessageFormatError,
+    },
+}
+
+impl From<io::Error> for CodecError {
+    fn from(error: io::Error) -> Self {
+        CodecError::Io { error }
+    }
+}
+
+/// A length-delimited codec for messages of type `T`.
+pub struct MessageFormatCodec<T> {
+    max_frame_size: usize,
+    _message: PhantomData<fn() -> T>,
+}
+
+impl<T> MessageFormatCodec<T> {
+    /// The default maximum frame size, 8 MiB.
+    pub const DEFAULT_MAX_FRAME_SIZE: usize = 8 * 1024 * 1024;
+
+    /// Creates a codec with the default maximum frame size.
+    pub fn new() -> Self {
+        Self::with_max_frame_size(Self::DEFAULT_MAX_FRAME_SIZE)
+    }
+
+    /// Creates a codec that rejects frames longer than `max_frame_size` bytes, excluding the length prefix. Sizes
+    /// above `u32::MAX` are treated as `u32::MAX`.
+    pub fn with_max_frame_size(max_frame_size: usize) -> Self {
+        Self {
+            max_frame_size: max_frame_size.min(u32::MAX as usize),
+            _message: PhantomData,
+        }
+    }
+
+    /// Returns the maximum frame size.
+    pub fn max_frame_size(&self) -> usize {
+        self.max_frame_size
+    }
+}
+
+impl<T> Default for MessageFormatCodec<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Clone for MessageFormatCodec<T> {
+    fn clone(&self) -> Self {
+        Self::with_max_frame_size(self.max_frame_size)
+    }
+}
+
+impl<T> fmt::Debug for MessageFormatCodec<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MessageFormatCodec")
+            .field("max_frame_size", &self.max_frame_size)
+            .finish()
+    }
+}
+
+impl<T: MessageFormat> Encoder<T> for MessageFormatCodec<T> {
+    type Error = CodecError;
+
+    fn encode(&mut self, item: T, dst: &mut BytesMut) -> Result<(), CodecError> {
+        // Serialize straight into the buffer after a placeholder header, then fill in the length
+        let start = dst.len();
+        dst.put_u32(0);
+        if let Err(error) = item.to_buf(dst) {
+            dst.truncate(start);
+            return FormatSnafu { error }.fail();
+        }
+        let len = dst.len() - start - HEADER_LEN;
+        if len > self.max_frame_size {
+            dst.truncate(start);
+            return FrameTooLargeSnafu {
+                len,
+                max: self.max_frame_size,
+            }
+            .fail();
+        }
+        dst[start..start + HEADER_LEN].copy_from_slice(&(len as u32).to_be_bytes());
+        Ok(())
+    }
+}
+
+impl<T: MessageFormat> Decoder for MessageFormatCodec<T> {
+    type Error = CodecError;
+    type Item = T;
+
+    fn decode(&mut self, src: &mut BytesMut) -> Result<Option<T>, CodecError> {
+        let Some(header) = src.get(..HEADER_LEN) else {
+            return Ok(None);
+        };
+        let mut len_bytes = [0u8; HEADER_LEN];
+        len_bytes.copy_from_slice(header);
+        let len = u32::from_be_bytes(len_bytes) as usize;
+        ensure!(len <= self.max_frame_size, FrameTooLargeSnafu {
+            len,
+            max: self.max_frame_size
+        });
+        if src.len() < HEADER_LEN + len {
+            src.reserve(HEADER_LEN + len - src.len());
+            return Ok(None);
+        }
+        src.advance(HEADER_LEN);
+        let frame = src.split_to(len);
+        T::from_binary(&frame)
+            .map(Some)
+            .map_err(|error| CodecError::Format { error })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use alloc::{string::String, vec::Vec};
+
+    use super::*;
+
+    #[test]
+    fn frames() {
+        let mut codec = MessageFormatCodec::<Vec<u16>>::new();
+        let mut buf = BytesMut::new();
+        codec.encode(vec![1, 2, 3], &mut buf).unwrap();
+        codec.encode(vec![], &mut buf).unwrap();
+        let first_len = HEADER_LEN + vec![1u16, 2, 3].to_binary().unwrap().len();
+        assert_eq!(buf[..HEADER_LEN], [0, 0, 0, 14]);
+
+        // Partial frames are held until complete
+        let mut partial = buf.split_to(first_len - 1);
+        assert_eq!(codec.decode(&mut partial).unwrap(), None);
+        partial.unsplit(buf);
+        let mut buf = partial;
+        assert_eq!(codec.decode(&mut buf).unwrap(), Some(vec![1, 2, 3]));
+        assert_eq!(codec.decode(&mut buf).unwrap(), Some(vec![]));
+        assert_eq!(codec.decode(&mut buf).unwrap(), None);
+    }
+
+    #[test]
+    fn max_frame_size() {
+        let mut codec = MessageFormatCodec::<String>::with_max_frame_size(10);
+        let mut buf = BytesMut::from(&b"x"[..]);
+        assert!(matches!(
+            codec.encode("this is too long".into(), &mut buf),
+            Err(CodecError::FrameTooLarge { len: 24, max: 10 })
+        ));
+        // A rejected frame leaves the buffer as it was
+        assert_eq!(&buf[..], b"x");
+
+        let mut buf = BytesMut::from(&[0u8, 0, 0, 11][..]);
+        assert!(matches!(
+            codec.decode(&mut buf),
+            Err(CodecError::FrameTooLarge { len: 11, max: 10 })
+        ));
+    }
+
+    #[test]
+    fn invalid_message() {
+        let mut codec = MessageFormatCodec::<String>::new();
+        let mut buf = BytesMut::from(&[0u8, 0, 0, 1, 0xff][..]);
+        assert!(matches!(codec.decode(&mut buf), Err(CodecError::Format { .. })));
+    }
+}
@@ -0,0 +1,288 @@
+// Copyright 2026, The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+//! Mapping of HTTP `Content-Type` and `Accept` header values onto [MessageFormat] conversions.
+//!
+//! [ContentType::from_header] identifies the format of a request body, and [ContentType::negotiate] chooses the format
+//! of a response from an `Accept` header. Either way, [ContentType::encoder] and [ContentType::decoder] return the
+//! matching conversion, so supporting a new format only means adding a variant here.
+//!
+//! ```edition2018
+//! # use tari_utilities::content_type::ContentType;
+//! let request = ContentType::from_header("application/json; charset=utf-8").unwrap();
+//! let value: Vec<u32> = request.decode(b"[1,2,3]").unwrap();
+//!
+//! let response =
+//!     ContentType::negotiate("application/json;q=0.5, application/octet-stream").unwrap();
+//! assert_eq!(response, ContentType::OctetStream);
+//! let body = response.encode(&value).unwrap();
+//! assert_eq!(response.decoder::<Vec<u32>>()(&body).unwrap(), value);
+//! ```
+
+use alloc::{string::String, vec::Vec};
+use core::{fmt, str};
+
+use snafu::prelude::*;
+
+use crate::message_format::{MessageFormat, MessageFormatError};
+
+/// Errors for content type negotiation.
+#[derive(Debug, Snafu, PartialEq, Eq)]
+pub enum ContentTypeError {
+    /// The `Content-Type` is not a supported format.
+    #[snafu(display("Unsupported content type `{content_type}'"))]
+    Unsupported {
+        /// The header value
+        content_type: String,
+    },
+    /// The `Accept` header does not allow any supported format.
+    #[snafu(display("No supported content type is acceptable for `{accept}'"))]
+    NotAcceptable {
+        /// The header value
+        accept: String,
+    },
+}
+
+/// A message format that can be requested over HTTP.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ContentType {
+    /// `application/json`, using [MessageFormat::to_json]
+    Json,
+    /// `application/octet-stream`, using [MessageFormat::to_binary]
+    OctetStream,
+}
+
+impl ContentType {
+    /// All supported content types, in order of preference when a client accepts several equally.
+    pub const ALL: &'static [ContentType] = &[ContentType::Json, ContentType::OctetStream];
+
+    /// Returns the media type, for use as a `Content-Type` header value.
+    pub fn mime(self) -> &'static str {
+        match self {
+            ContentType::Json => "application/json",
+            ContentType::OctetStream => "application/octet-stream",
+        }
+    }
+
+    /// Identifies the content type of a `Content-Type` header value. Parameters such as `charset` are ignored, and the
+    /// comparison ignores ASCII case.
+    pub fn from_header(content_type: &str) -> Result<Self, ContentTypeError> {
+        let media_type = content_type.split(';').next().unwrap_or_default().trim();
+        Self::ALL
+            .iter()
+            .copied()
+            .find(|t| t.mime().eq_ignore_ascii_case(media_type))
+            .context(UnsupportedSnafu { content_type })
+    }
+
+    /// Chooses the content type for a response from an `Accept` header value. The supported type with the highest
+    /// quality wins, with ties broken by the order of [ContentType::ALL]. Wildcards are supported, and an empty header
+    /// accepts anything.
+    pub fn negotiate(accept: &str) -> Result<Self, ContentTypeError> {
+        if accept.trim().is_empty() {
+            return Ok(Self::ALL[0]);
+        }
+        let ranges = accept.split(',').filter_map(MediaRange::parse).collect::<Vec<_>>();
+        let mut best: Option<(ContentType, u16)> = None;
+        for content_type in Self::ALL.iter().copied() {
+            // The most specific matching range determines the quality
+            let quality = ranges
+                .iter()
+                .filter(|range| range.matches(content_type.mime()))
+                .max_by_key(|range| range.specificity())
+                .map_or(0, |range| range.quality);
+            if quality > 0 && best.is_none_or(|(_, q)| quality > q) {
+                best = Some((content_type, quality));
+            }
+        }
+        best.map(|(content_type, _)| content_type)
+            .context(NotAcceptableSnafu { accept })
+    }
+
+    /// Returns the function that converts a message to a body of this content type.
+    pub fn encoder<T: MessageFormat>(self) -> fn(&T) -> Result<Vec<u8>, MessageFormatError> {
+        match self {
+            ContentType::Json => |value| value.to_json().map(String::into_bytes),
+            ContentType::OctetStream => T::to_binary,
+        }
+    }
+
+    /// Returns the function that converts a body of this content type to a message.
+    pub fn decoder<T: MessageFormat>(self) -> fn(&[u8]) -> Result<T, MessageFormatError> {
+        match self {
+            ContentType::Json => |body| {
+                let json = str::from_utf8(body).map_err(|_| MessageFormatError::JSONError {})?;
+                T::from_json(json)
+            },
+            ContentType::OctetStream => T::from_binary,
+        }
+    }
+
+    /// Converts a message to a body of this content type.
+    pub fn encode<T: MessageFormat>(self, value: &T) -> Result<Vec<u8>, MessageFormatError> {
+        self.encoder()(value)
+    }
+
+    /// Converts a body of this content type to a message.
+    pub fn decode<T: MessageFormat>(self, body: &[u8]) -> Result<T, MessageFormatError> {
+        self.decoder()(body)
+    }
+}
+
+impl fmt::Display for ContentType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.mime())
+    }
+}
+
+/// One media range of an `Accept` header, with its quality in thousandths.
+struct MediaRange<'a> {
+    main: &'a str,
+    sub: &'a str,
+    quality: u16,
+}
+
+impl<'a> MediaRange<'a> {
+    fn parse(range: &'a str) -> Option<Self> {
+        let mut parts = range.split(';');
+        let (main, sub) = parts.next()?.trim().split_once('/')?;
+        let mut quality = 1000;
+        for param in parts {
+            if let Some((name, value)) = param.split_once('=') {
+                if name.trim().eq_ignore_ascii_case("q") {
+                    quality = parse_quality(value.trim())?;
+                }
+            }
+        }
+        Some(Self { main, sub, quality })
+    }
+
+    fn matches(&self, mime: &str) -> bool {
+        let Some((main, sub)) = mime.split_once('/') else {
+            return false;
+        };
+        (self.main == "*" || self.main.eq_ignore_ascii_case(main)) &&
+            (self.sub == "*" || self.sub.eq_ignore_ascii_case(sub))
+    }
+
+    fn specificity(&self) -> u8 {
+        u8::from(self.main != "*") + u8::from(self.sub != "*")
+    }
+}
+
+/// Parses a quality value, `0` to `1` with up to 3 decimal places, as thousandths.
+fn parse_quality(value: &str) -> Option<u16> {
+    let (whole, fraction) = value.split_once('.').unwrap_or((value, ""));
+    if fraction.len() > 3 || !fraction.bytes().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+    let thousandths = match whole {
+        "0" => fraction
+            .bytes()
+            .chain(core::iter::repeat(b'0'))
+            .take(3)
+            .fold(0u16, |acc, c| acc * 10 + u16::from(c - b'0')),
+        "1" if fraction.bytes().all(|c| c == b'0') => 1000,
+        _ => return None,
+    };
+    Some(thousandths)
+}
+
+#[cfg(test)]
+mod test {
+    use alloc::string::ToString;
+
+    use super::*;
+
+    #[test]
+    fn from_header() {
+        assert_eq!(ContentType::from_header("application/json"), Ok(ContentType::Json));
+        assert_eq!(
+            ContentType::from_header("Application/JSON; charset=utf-8"),
+            Ok(ContentType::Json)
+        );
+        assert_eq!(
+            ContentType::from_header("application/octet-stream"),
+            Ok(ContentType::OctetStream)
+        );
+        assert_eq!(
+            ContentType::from_header("text/html"),
+            Err(ContentTypeError::Unsupported {
+                content_type: "text/html".into()
+            })
+        );
+    }
+
+    #[test]
+    fn negotiate() {
+        let cases = [
+            ("", ContentType::Json),
+            ("*/*", ContentType::Json),
+            ("application/octet-stream", ContentType::OctetStream),
+            (
+                "application/json;q=0.5, application/octet-stream",
+                ContentType::OctetStream,
+            ),
+            ("application/*;q=0.2, application/json;q=0.1", ContentType::OctetStream),
+            ("text/html, */*;q=0.1", ContentType::Json),
+            ("application/json;q=0, */*", ContentType::OctetStream),
+            (
+                "application/json;q=0.25 , application/octet-stream;q=0.250",
+                ContentType::Json,
+            ),
+        ];
+        for (accept, expected) in cases {
+            assert_eq!(ContentType::negotiate(accept), Ok(expected), "{}", accept);
+        }
+        for accept in ["text/html", "application/json;q=0, application/octet-stream;q=0.000"] {
+            assert_eq!(
+                ContentType::negotiate(accept),
+                Err(ContentTypeError::NotAcceptable { accept: accept.into() })
+            );
+        }
+    }
+
+    #[test]
+    fn quality() {
+        assert_eq!(parse_quality("1"), Some(1000));
+        assert_eq!(parse_quality("1.000"), Some(1000));
+        assert_eq!(parse_quality("0.5"), Some(500));
+        assert_eq!(parse_quality("0.125"), Some(125));
+        assert_eq!(parse_quality("0"), Some(0));
+        for invalid in ["1.5", "2", "0.1234", "-1", "0.x", ""] {
+            assert_eq!(parse_quality(invalid), None, "{}", invalid);
+        }
+    }
+
+    #[test]
+    fn round_trip() {
+        let value = vec![String::from("a"), String::from("b")];
+        for content_type in ContentType::ALL.iter().copied() {
+            let body = content_type.encode(&value).unwrap();
+            assert_eq!(content_type.decode::<Vec<String>>(&body).unwrap(), value);
+        }
+        assert_eq!(ContentType::Json.encode(&value).unwrap(), br#"["a","b"]"#);
+        assert!(ContentType::Json.decode::<Vec<String>>(&[0xff]).is_err());
+        assert_eq!(ContentType::Json.to_string(), "application/json");
+    }
+}
@@ -0,0 +1,257 @@
+// Copyright 2026, The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+//! Table-driven CRC32 and CRC16 checksums, with streaming `update` APIs.
+//!
+//! ```edition2018
+//! # use tari_utilities::crc::{crc32, Crc32};
+//! let mut crc = Crc32::ieee();
+//! crc.update(b"1234");
+//! crc.update(b"56789");
+//! assert_eq!(crc.finalize(), 0xcbf4_3926);
+//! assert_eq!(crc32(b"123456789"), 0xcbf4_3926);
+//! ```
+
+const IEEE_TABLE: [u32; 256] = reflected_table_32(0xedb8_8320);
+const CASTAGNOLI_TABLE: [u32; 256] = reflected_table_32(0x82f6_3b78);
+const ARC_TABLE: [u16; 256] = reflected_table_16(0xa001);
+const CCITT_TABLE: [u16; 256] = table_16(0x1021);
+
+const fn reflected_table_32(poly: u32) -> [u32; 256] {
+    let mut table = [0u32; 256];
+    let mut i = 0;
+    while i < 256 {
+        let mut crc = i as u32;
+        let mut bit = 0;
+        while bit < 8 {
+            crc = if crc & 1 == 1 { (crc >> 1) ^ poly } else { crc >> 1 };
+            bit += 1;
+        }
+        table[i] = crc;
+        i += 1;
+    }
+    table
+}
+
+const fn reflected_table_16(poly: u16) -> [u16; 256] {
+    let mut table = [0u16; 256];
+    let mut i = 0;
+    while i < 256 {
+        let mut crc = i as u16;
+        let mut bit = 0;
+        while bit < 8 {
+            crc = if crc & 1 == 1 { (crc >> 1) ^ poly } else { crc >> 1 };
+            bit += 1;
+        }
+        table[i] = crc;
+        i += 1;
+    }
+    table
+}
+
+const fn table_16(poly: u16) -> [u16; 256] {
+    let mut table = [0u16; 256];
+    let mut i = 0;
+    while i < 256 {
+        let mut crc = (i as u16) << 8;
+        let mut bit = 0;
+        while bit < 8 {
+            crc = if crc & 0x8000 != 0 { (crc << 1) ^ poly } else { crc << 1 };
+            bit += 1;
+        }
+        table[i] = crc;
+        i += 1;
+    }
+    table
+}
+
+/// A streaming CRC32 computation.
+#[derive(Clone, Debug)]
+pub struct Crc32 {
+    table: &'static [u32; 256],
+    state: u32,
+}
+
+impl Crc32 {
+    /// CRC-32/ISO-HDLC, the IEEE 802.3 CRC used by Ethernet, zlib and PNG.
+    pub fn ieee() -> Self {
+        Self {
+            table: &IEEE_TABLE,
+            state: u32::MAX,
+        }
+    }
+
+    /// CRC-32C (Castagnoli), as used by iSCSI, ext4 and SCTP.
+    pub fn castagnoli() -> Self {
+        Self {
+            table: &CASTAGNOLI_TABLE,
+            state: u32::MAX,
+        }
+    }
+
+    /// Adds `data` to the checksum.
+    pub fn update(&mut self, data: &[u8]) {
+        for byte in data {
+            self.state = self.table[usize::from(self.state as u8 ^ byte)] ^ (self.state >> 8);
+        }
+    }
+
+    /// Returns the checksum of the data added so far. More data can still be added afterwards.
+    pub fn finalize(&self) -> u32 {
+        !self.state
+    }
+
+    /// Discards the data added so far.
+    pub fn reset(&mut self) {
+        self.state = u32::MAX;
+    }
+}
+
+/// The CRC16 algorithms supported by [Crc16].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Crc16Algorithm {
+    /// CRC-16/ARC (also known as CRC-16/IBM): reflected polynomial 0x8005, initial value 0.
+    Arc,
+    /// CRC-16/IBM-3740 (commonly known as CRC-16/CCITT-FALSE): polynomial 0x1021, initial value 0xffff.
+    CcittFalse,
+}
+
+/// A streaming CRC16 computation.
+#[derive(Clone, Debug)]
+pub struct Crc16 {
+    algorithm: Crc16Algorithm,
+    state: u16,
+}
+
+impl Crc16 {
+    /// Starts a checksum with the given algorithm.
+    pub fn new(algorithm: Crc16Algorithm) -> Self {
+        Self {
+            algorithm,
+            state: Self::initial(algorithm),
+        }
+    }
+
+    /// Returns the algorithm used by this checksum.
+    pub fn algorithm(&self) -> Crc16Algorithm {
+        self.algorithm
+    }
+
+    /// Adds `data` to the checksum.
+    pub fn update(&mut self, data: &[u8]) {
+        match self.algorithm {
+            Crc16Algorithm::Arc => {
+                for byte in data {
+                    self.state = ARC_TABLE[usize::from(self.state as u8 ^ byte)] ^ (self.state >> 8);
+                }
+            },
+            Crc16Algorithm::CcittFalse => {
+                for byte in data {
+                    self.state = CCITT_TABLE[usize::from((self.state >> 8) as u8 ^ byte)] ^ (self.state << 8);
+                }
+            },
+        }
+    }
+
+    /// Returns the checksum of the data added so far. More data can still be added afterwards.
+    pub fn finalize(&self) -> u16 {
+        self.state
+    }
+
+    /// Discards the data added so far.
+    pub fn reset(&mut self) {
+        self.state = Self::initial(self.algorithm);
+    }
+
+    fn initial(algorithm: Crc16Algorithm) -> u16 {
+        match algorithm {
+            Crc16Algorithm::Arc => 0,
+            Crc16Algorithm::CcittFalse => u16::MAX,
+        }
+    }
+}
+
+/// Computes the IEEE CRC32 of `data`. See [Crc32::ieee].
+pub fn crc32(data: &[u8]) -> u32 {
+    let mut crc = Crc32::ieee();
+    crc.update(data);
+    crc.finalize()
+}
+
+/// Computes the CRC-32C (Castagnoli) of `data`. See [Crc32::castagnoli].
+pub fn crc32c(data: &[u8]) -> u32 {
+    let mut crc = Crc32::castagnoli();
+    crc.update(data);
+    crc.finalize()
+}
+
+/// Computes the CRC16 of `data` with the given algorithm.
+pub fn crc16(algorithm: Crc16Algorithm, data: &[u8]) -> u16 {
+    let mut crc = Crc16::new(algorithm);
+    crc.update(data);
+    crc.finalize()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const CHECK: &[u8] = b"123456789";
+
+    #[test]
+    fn check_values() {
+        assert_eq!(crc32(CHECK), 0xcbf4_3926);
+        assert_eq!(crc32c(CHECK), 0xe306_9283);
+        assert_eq!(crc16(Crc16Algorithm::Arc, CHECK), 0xbb3d);
+        assert_eq!(crc16(Crc16Algorithm::CcittFalse, CHECK), 0x29b1);
+        assert_eq!(crc32(b""), 0);
+        assert_eq!(crc32(b"The quick brown fox jumps over the lazy dog"), 0x414f_a339);
+    }
+
+    #[test]
+    fn streaming() {
+        for split in 0..=CHECK.len() {
+            let (a, b) = CHECK.split_at(split);
+            let mut crc = Crc32::castagnoli();
+            crc.update(a);
+            crc.update(b);
+            assert_eq!(crc.finalize(), crc32c(CHECK));
+
+            let mut crc = Crc16::new(Crc16Algorithm::CcittFalse);
+            crc.update(a);
+            crc.update(b);
+            assert_eq!(crc.finalize(), 0x29b1);
+        }
+        let mut crc = Crc32::ieee();
+        crc.update(b"garbage");
+        crc.reset();
+        crc.update(CHECK);
+        assert_eq!(crc.finalize(), 0xcbf4_3926);
+
+        let mut crc = Crc16::new(Crc16Algorithm::Arc);
+        assert_eq!(crc.algorithm(), Crc16Algorithm::Arc);
+        crc.update(b"garbage");
+        crc.reset();
+        crc.update(CHECK);
+        assert_eq!(crc.finalize(), 0xbb3d);
+    }
+}
@@ -0,0 +1,98 @@
+// Copyright 2026, The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+//! Constant-time checks over byte slices, for use with secret data such as keys and nonces.
+//!
+//! The functions here examine every byte and combine the results with bitwise operations, so their running time depends
+//! only on the length of the input, never on its contents. The result is a `subtle::Choice`, which should stay in
+//! constant-time code for as long as possible before being converted to a `bool`.
+//!
+//! [ct_eq_padded] compares inputs of different lengths, such as a MAC tag against attacker-supplied input, without
+//! returning early when the lengths differ.
+//!
+//! ```edition2018
+//! # use tari_utilities::ct::ct_is_zero;
+//! let nonce = [0u8; 32];
+//! assert!(bool::from(ct_is_zero(&nonce)));
+//! ```
+
+use subtle::{Choice, ConstantTimeEq};
+
+/// Returns true if every byte of `bytes` is zero, including when `bytes` is empty.
+pub fn ct_is_zero(bytes: &[u8]) -> Choice {
+    ct_all_eq(bytes, 0)
+}
+
+/// Returns true if every byte of `bytes` is equal to `byte`, including when `bytes` is empty.
+pub fn ct_all_eq(bytes: &[u8], byte: u8) -> Choice {
+    let diff = bytes.iter().fold(0u8, |acc, b| acc | (b ^ byte));
+    diff.ct_eq(&0)
+}
+
+/// Returns true if `a` and `b` have the same length and contents. Both inputs are read in full, with the shorter one
+/// padded with zeros to the length of the longer, so the running time depends only on the length of the longer input.
+/// Nothing is revealed about where the inputs differ, or which of them is longer.
+pub fn ct_eq_padded(a: &[u8], b: &[u8]) -> Choice {
+    let len = a.len().max(b.len());
+    let mut diff = 0u8;
+    for i in 0..len {
+        let x = a.get(i).copied().unwrap_or(0);
+        let y = b.get(i).copied().unwrap_or(0);
+        diff |= x ^ y;
+    }
+    diff.ct_eq(&0) & (a.len() as u64).ct_eq(&(b.len() as u64))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn is_zero() {
+        assert!(bool::from(ct_is_zero(&[])));
+        assert!(bool::from(ct_is_zero(&[0; 32])));
+        let mut bytes = [0u8; 32];
+        bytes[31] = 1;
+        assert!(!bool::from(ct_is_zero(&bytes)));
+        bytes[31] = 0x80;
+        assert!(!bool::from(ct_is_zero(&bytes)));
+    }
+
+    #[test]
+    fn eq_padded() {
+        assert!(bool::from(ct_eq_padded(&[], &[])));
+        assert!(bool::from(ct_eq_padded(b"tag", b"tag")));
+        assert!(!bool::from(ct_eq_padded(b"tag", b"tac")));
+        assert!(!bool::from(ct_eq_padded(b"tag", b"ta")));
+        assert!(!bool::from(ct_eq_padded(b"", b"t")));
+        // Padding with zeros must not make a shorter input equal
+        assert!(!bool::from(ct_eq_padded(&[1, 0], &[1])));
+        assert!(!bool::from(ct_eq_padded(&[1], &[1, 0])));
+    }
+
+    #[test]
+    fn all_eq() {
+        assert!(bool::from(ct_all_eq(&[], 0xff)));
+        assert!(bool::from(ct_all_eq(&[0xff; 8], 0xff)));
+        assert!(!bool::from(ct_all_eq(&[0xff, 0xff, 0xfe], 0xff)));
+        assert!(!bool::from(ct_all_eq(&[0; 4], 0xff)));
+    }
+}
@@ -0,0 +1,244 @@
+// Copyright 2026, The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+//! The DammSum checksum, a generalisation of the [Damm algorithm](https://en.wikipedia.org/wiki/Damm_algorithm).
+//!
+//! A Damm checksum detects every single-digit error and every adjacent transposition. For alphabets whose size is a
+//! power of two, the quasigroup is `x * y = 2(x + y)` over the finite field GF(2^k); for the decimal alphabet, the
+//! classic order-10 table is used. The 256-digit alphabet uses the same field as Tari addresses, so
+//! `DammSum::BYTES` computes Tari address checksums.
+//!
+//! ```edition2018
+//! # use tari_utilities::dammsum::DammSum;
+//! let mut digits = vec![5, 7, 2];
+//! DammSum::DECIMAL.append(&mut digits).unwrap();
+//! assert_eq!(digits, vec![5, 7, 2, 4]);
+//! assert!(DammSum::DECIMAL.validate(&digits).is_ok());
+//!
+//! digits.swap(0, 1);
+//! assert!(DammSum::DECIMAL.validate(&digits).is_err());
+//! ```
+
+use alloc::vec::Vec;
+
+use snafu::prelude::*;
+
+/// Errors for DammSum checksums.
+#[derive(Debug, Snafu, PartialEq, Eq)]
+pub enum DammSumError {
+    /// There is no DammSum quasigroup for the requested alphabet size.
+    #[snafu(display("Unsupported alphabet size {size}"))]
+    UnsupportedAlphabetSize {
+        /// The requested alphabet size
+        size: usize,
+    },
+    /// A digit is not in the alphabet.
+    #[snafu(display("Digit {digit} at position {index} is not in the alphabet"))]
+    InvalidDigit {
+        /// The offending digit
+        digit: u8,
+        /// The position of the digit
+        index: usize,
+    },
+    /// There is no checksum digit to validate.
+    #[snafu(display("Data is empty"))]
+    EmptyData {},
+    /// The checksum digit does not match the data.
+    #[snafu(display("Invalid checksum"))]
+    InvalidChecksum {},
+}
+
+// The classic order-10 Damm quasigroup, with a zero diagonal
+const DECIMAL_TABLE: [[u8; 10]; 10] = [
+    [0, 3, 1, 7, 5, 9, 8, 6, 4, 2],
+    [7, 0, 9, 2, 1, 5, 4, 8, 6, 3],
+    [4, 2, 0, 6, 8, 7, 1, 3, 5, 9],
+    [1, 7, 5, 0, 9, 8, 3, 4, 2, 6],
+    [6, 1, 2, 3, 0, 4, 5, 9, 7, 8],
+    [3, 6, 7, 4, 2, 0, 9, 5, 8, 1],
+    [5, 8, 6, 9, 7, 2, 0, 1, 3, 4],
+    [8, 9, 4, 5, 3, 6, 2, 0, 1, 7],
+    [9, 4, 3, 8, 6, 1, 7, 2, 0, 5],
+    [2, 5, 8, 1, 4, 3, 6, 7, 9, 0],
+];
+
+// The low terms of a primitive polynomial of degree k, for each supported k
+const FIELD_MASKS: [u8; 9] = [0, 0, 0b11, 0b011, 0b0011, 0b0_0101, 0b00_0011, 0b000_0011, 0b0001_1011];
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Quasigroup {
+    Decimal,
+    Field { bits: u32, mask: u8 },
+}
+
+/// A DammSum checksum over an alphabet of a given size. Digits are the values `0..size`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DammSum {
+    size: usize,
+    quasigroup: Quasigroup,
+}
+
+impl DammSum {
+    /// The checksum over bytes, as used by Tari addresses.
+    pub const BYTES: DammSum = DammSum::field(8);
+    /// The classic Damm checksum over decimal digits.
+    pub const DECIMAL: DammSum = DammSum {
+        size: 10,
+        quasigroup: Quasigroup::Decimal,
+    };
+
+    /// Creates a checksum over an alphabet of `size` digits. Supported sizes are 10 and the powers of two from 4 to
+    /// 256.
+    pub fn new(size: usize) -> Result<DammSum, DammSumError> {
+        if size == 10 {
+            return Ok(DammSum::DECIMAL);
+        }
+        ensure!(
+            size.is_power_of_two() && (4..=256).contains(&size),
+            UnsupportedAlphabetSizeSnafu { size }
+        );
+        Ok(DammSum::field(size.trailing_zeros()))
+    }
+
+    const fn field(bits: u32) -> DammSum {
+        DammSum {
+            size: 1 << bits,
+            quasigroup: Quasigroup::Field {
+                bits,
+                mask: FIELD_MASKS[bits as usize],
+            },
+        }
+    }
+
+    /// Returns the number of digits in the alphabet.
+    pub fn alphabet_size(&self) -> usize {
+        self.size
+    }
+
+    /// Computes the checksum digit for `data`.
+    pub fn compute(&self, data: &[u8]) -> Result<u8, DammSumError> {
+        let mut interim = 0u8;
+        for (index, digit) in data.iter().enumerate() {
+            ensure!(usize::from(*digit) < self.size, InvalidDigitSnafu {
+                digit: *digit,
+                index
+            });
+            interim = self.combine(interim, *digit);
+        }
+        Ok(interim)
+    }
+
+    /// Appends the checksum digit for `data` to it.
+    pub fn append(&self, data: &mut Vec<u8>) -> Result<(), DammSumError> {
+        let checksum = self.compute(data)?;
+        data.push(checksum);
+        Ok(())
+    }
+
+    /// Validates data whose last digit is its checksum.
+    pub fn validate(&self, data: &[u8]) -> Result<(), DammSumError> {
+        ensure!(!data.is_empty(), EmptyDataSnafu);
+        ensure!(self.compute(data)? == 0, InvalidChecksumSnafu);
+        Ok(())
+    }
+
+    fn combine(&self, interim: u8, digit: u8) -> u8 {
+        match self.quasigroup {
+            Quasigroup::Decimal => DECIMAL_TABLE[usize::from(interim)][usize::from(digit)],
+            Quasigroup::Field { bits, mask } => {
+                // Multiply the sum by x in GF(2^bits), reducing by the field polynomial on overflow
+                let sum = interim ^ digit;
+                let overflow = (sum >> (bits - 1)) & 1;
+                let shifted = ((u16::from(sum) << 1) & ((1 << bits) - 1)) as u8;
+                shifted ^ (mask & overflow.wrapping_neg())
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn decimal() {
+        assert_eq!(DammSum::DECIMAL.compute(&[5, 7, 2]), Ok(4));
+        assert_eq!(DammSum::new(10).unwrap(), DammSum::DECIMAL);
+        assert!(DammSum::DECIMAL.validate(&[5, 7, 2, 4]).is_ok());
+        assert_eq!(
+            DammSum::DECIMAL.validate(&[5, 7, 2, 5]),
+            Err(DammSumError::InvalidChecksum {})
+        );
+        assert_eq!(
+            DammSum::DECIMAL.compute(&[5, 10]),
+            Err(DammSumError::InvalidDigit { digit: 10, index: 1 })
+        );
+    }
+
+    #[test]
+    fn unsupported_sizes() {
+        for size in [0usize, 1, 2, 3, 5, 12, 512] {
+            assert_eq!(DammSum::new(size), Err(DammSumError::UnsupportedAlphabetSize { size }));
+        }
+        assert_eq!(DammSum::new(32).unwrap().alphabet_size(), 32);
+        assert_eq!(DammSum::new(256).unwrap(), DammSum::BYTES);
+    }
+
+    #[test]
+    fn detects_errors() {
+        let sizes = [4usize, 8, 10, 16, 32, 64, 128, 256];
+        for size in sizes {
+            let dammsum = DammSum::new(size).unwrap();
+            let max = (size - 1) as u8;
+            let data = (0..12usize).map(|i| (i * 7 % size) as u8).collect::<Vec<_>>();
+            let mut checked = data.clone();
+            dammsum.append(&mut checked).unwrap();
+            assert!(dammsum.validate(&checked).is_ok());
+
+            // Every single-digit substitution is detected
+            for index in 0..checked.len() {
+                for digit in 0..=max {
+                    if digit != checked[index] {
+                        let mut corrupted = checked.clone();
+                        corrupted[index] = digit;
+                        assert!(dammsum.validate(&corrupted).is_err(), "size {} index {}", size, index);
+                    }
+                }
+            }
+            // Every adjacent transposition of distinct digits is detected
+            for index in 0..checked.len() - 1 {
+                if checked[index] != checked[index + 1] {
+                    let mut corrupted = checked.clone();
+                    corrupted.swap(index, index + 1);
+                    assert!(dammsum.validate(&corrupted).is_err(), "size {} swap {}", size, index);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn empty_data() {
+        assert_eq!(DammSum::BYTES.validate(&[]), Err(DammSumError::EmptyData {}));
+        assert_eq!(DammSum::BYTES.compute(&[]), Ok(0));
+        assert!(DammSum::BYTES.validate(&[0]).is_ok());
+    }
+}
@@ -0,0 +1,288 @@
+// Copyright 2026, The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+//! Throttling and debouncing of repeated events.
+//!
+//! A [Throttle] lets an action fire at most once per interval, such as logging a recurring warning, and suppresses
+//! the calls in between. A [Debouncer] coalesces a burst of events into one action that fires once the events have
+//! been quiet for a delay, such as notifying listeners once after a chain reorg settles; an optional maximum wait
+//! stops a steady stream of events from delaying it forever.
+//!
+//! Both are driven by the caller passing in the current time, which can be an `Instant` or an [EpochTime], and report
+//! whether to fire now along with the next time of interest, so they suit polling loops and timers alike and are easy
+//! to test.
+//!
+//! ```edition2018
+//! # use std::time::{Duration, Instant};
+//! # use tari_utilities::debounce::Throttle;
+//! let mut throttle = Throttle::new(Duration::from_secs(10));
+//! let start = Instant::now();
+//! assert!(throttle.check(start).fire);
+//! let decision = throttle.check(start + Duration::from_secs(1));
+//! assert!(!decision.fire);
+//! assert_eq!(decision.next, Some(start + Duration::from_secs(10)));
+//! ```
+
+use core::time::Duration;
+use std::time::Instant;
+
+use crate::epoch_time::EpochTime;
+
+/// A point in time that [Throttle] and [Debouncer] can schedule against.
+pub trait Moment: Copy + Ord {
+    /// The time `duration` after this one, or `None` if it cannot be represented.
+    fn checked_add(self, duration: Duration) -> Option<Self>;
+}
+
+impl Moment for Instant {
+    fn checked_add(self, duration: Duration) -> Option<Self> {
+        Instant::checked_add(&self, duration)
+    }
+}
+
+/// Partial seconds are rounded up, so that an interval is never shortened.
+impl Moment for EpochTime {
+    fn checked_add(self, duration: Duration) -> Option<Self> {
+        let secs = duration.as_secs().checked_add(u64::from(duration.subsec_nanos() > 0))?;
+        self.checked_add(EpochTime::from(secs))
+    }
+}
+
+/// Whether to fire now, and the next time of interest.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Decision<T> {
+    /// Whether the action should fire now
+    pub fire: bool,
+    /// For a [Throttle], the earliest time the action may fire again. For a [Debouncer], the time a pending action
+    /// will fire if no more events arrive, or `None` if nothing is pending.
+    pub next: Option<T>,
+}
+
+/// Lets an action fire at most once per interval. See the [module](self) documentation.
+#[derive(Debug, Clone)]
+pub struct Throttle<T = Instant> {
+    interval: Duration,
+    state: ThrottleState<T>,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum ThrottleState<T> {
+    Ready,
+    Until(T),
+    // The end of the interval is too far in the future to represent
+    Never,
+}
+
+impl<T: Moment> Throttle<T> {
+    /// Create a throttle that fires at most once per `interval`, starting with the first check.
+    pub fn new(interval: Duration) -> Self {
+        Self {
+            interval,
+            state: ThrottleState::Ready,
+        }
+    }
+
+    /// Check whether the action may fire at `now`, and if it may, start a new interval. If the end of the interval
+    /// cannot be represented, the action never fires again and `next` is `None`.
+    pub fn check(&mut self, now: T) -> Decision<T> {
+        match self.state {
+            ThrottleState::Until(next) if now < next => Decision {
+                fire: false,
+                next: Some(next),
+            },
+            ThrottleState::Never => Decision {
+                fire: false,
+                next: None,
+            },
+            _ => {
+                let next = now.checked_add(self.interval);
+                self.state = next.map_or(ThrottleState::Never, ThrottleState::Until);
+                Decision { fire: true, next }
+            },
+        }
+    }
+
+    /// Forget the last firing, so that the next check fires.
+    pub fn reset(&mut self) {
+        self.state = ThrottleState::Ready;
+    }
+}
+
+/// Fires an action once events have been quiet for a delay. See the [module](self) documentation.
+#[derive(Debug, Clone)]
+pub struct Debouncer<T = Instant> {
+    delay: Duration,
+    max_wait: Option<Duration>,
+    pending: Option<Pending<T>>,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Pending<T> {
+    // When the action fires if no more events arrive; `None` if that is too far in the future to represent
+    due: Option<T>,
+    // The latest time the action can be put off to, from the first event in the burst
+    deadline: Option<T>,
+}
+
+impl<T: Moment> Debouncer<T> {
+    /// Create a debouncer that fires once no events have arrived for `delay`.
+    pub fn new(delay: Duration) -> Self {
+        Self {
+            delay,
+            max_wait: None,
+            pending: None,
+        }
+    }
+
+    /// Fire no later than `max_wait` after the first event of a burst, even if events keep arriving.
+    #[must_use]
+    pub fn with_max_wait(mut self, max_wait: Duration) -> Self {
+        self.max_wait = Some(max_wait);
+        self
+    }
+
+    /// Record an event at `now`, which puts off the pending action. Returns when it is now due to fire.
+    pub fn event(&mut self, now: T) -> Option<T> {
+        let deadline = match self.pending {
+            Some(pending) => pending.deadline,
+            None => self.max_wait.and_then(|max_wait| now.checked_add(max_wait)),
+        };
+        let due = match (now.checked_add(self.delay), deadline) {
+            (Some(due), Some(deadline)) => Some(due.min(deadline)),
+            (due, deadline) => due.or(deadline),
+        };
+        self.pending = Some(Pending { due, deadline });
+        due
+    }
+
+    /// Check whether the pending action should fire at `now`. Firing clears it, so each burst fires once.
+    pub fn poll(&mut self, now: T) -> Decision<T> {
+        match self.pending {
+            Some(Pending { due: Some(due), .. }) if now >= due => {
+                self.pending = None;
+                Decision { fire: true, next: None }
+            },
+            Some(pending) => Decision {
+                fire: false,
+                next: pending.due,
+            },
+            None => Decision {
+                fire: false,
+                next: None,
+            },
+        }
+    }
+
+    /// Whether an event is waiting to fire
+    pub fn is_pending(&self) -> bool {
+        self.pending.is_some()
+    }
+
+    /// Drop the pending action without firing it.
+    pub fn cancel(&mut self) {
+        self.pending = None;
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn throttle() {
+        let mut throttle = Throttle::new(Duration::from_secs(10));
+        let start = EpochTime::from(100);
+        assert_eq!(throttle.check(start), Decision {
+            fire: true,
+            next: Some(EpochTime::from(110))
+        });
+        assert_eq!(throttle.check(EpochTime::from(109)), Decision {
+            fire: false,
+            next: Some(EpochTime::from(110))
+        });
+        assert!(throttle.check(EpochTime::from(110)).fire);
+        throttle.reset();
+        assert!(throttle.check(EpochTime::from(111)).fire);
+
+        let mut forever = Throttle::new(Duration::MAX);
+        assert_eq!(forever.check(start), Decision { fire: true, next: None });
+        assert_eq!(forever.check(EpochTime::from(u64::MAX)), Decision {
+            fire: false,
+            next: None
+        });
+    }
+
+    #[test]
+    fn epoch_time_rounds_up() {
+        let time = EpochTime::from(10);
+        assert_eq!(
+            Moment::checked_add(time, Duration::from_millis(1500)),
+            Some(EpochTime::from(12))
+        );
+        assert_eq!(
+            Moment::checked_add(time, Duration::from_secs(2)),
+            Some(EpochTime::from(12))
+        );
+        assert_eq!(
+            Moment::checked_add(EpochTime::from(u64::MAX), Duration::from_secs(1)),
+            None
+        );
+    }
+
+    #[test]
+    fn debouncer() {
+        let start = Instant::now();
+        let at = |millis| start + Duration::from_millis(millis);
+        let mut debouncer = Debouncer::new(Duration::from_millis(100));
+        assert_eq!(debouncer.poll(start), Decision {
+            fire: false,
+            next: None
+        });
+
+        assert_eq!(debouncer.event(at(0)), Some(at(100)));
+        assert_eq!(debouncer.event(at(50)), Some(at(150)));
+        assert_eq!(debouncer.poll(at(120)), Decision {
+            fire: false,
+            next: Some(at(150))
+        });
+        assert!(debouncer.poll(at(150)).fire);
+        assert!(!debouncer.is_pending());
+        assert!(!debouncer.poll(at(200)).fire);
+
+        debouncer.event(at(300));
+        debouncer.cancel();
+        assert!(!debouncer.poll(at(1000)).fire);
+    }
+
+    #[test]
+    fn debouncer_max_wait() {
+        let mut debouncer = Debouncer::new(Duration::from_secs(5)).with_max_wait(Duration::from_secs(12));
+        for secs in (0..12).step_by(4) {
+            debouncer.event(EpochTime::from(secs));
+        }
+        assert_eq!(debouncer.event(EpochTime::from(10)), Some(EpochTime::from(12)));
+        assert!(!debouncer.poll(EpochTime::from(11)).fire);
+        assert!(debouncer.poll(EpochTime::from(12)).fire);
+
+        // The next burst starts a new maximum wait
+        assert_eq!(debouncer.event(EpochTime::from(20)), Some(EpochTime::from(25)));
+    }
+}
@@ -0,0 +1,187 @@
+// Copyright 2026, The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+//! Comparison of byte slices, reporting where they differ.
+//!
+//! [diff_bytes] returns the ranges at which two slices differ. Its [Display](fmt::Display) rendering is a hex dump of
+//! each 16-byte row that contains a difference, showing the left slice, the right slice and a line of `^^` markers
+//! under the differing bytes. Bytes past the end of the shorter slice are shown as `--`.
+//!
+//! ```edition2018
+//! # use tari_utilities::diff::{diff_bytes, DiffRange};
+//! let diff = diff_bytes(&[1, 2, 3, 4], &[1, 9, 9, 4, 5]);
+//! assert_eq!(diff.ranges(), &[
+//!     DiffRange { offset: 1, len: 2 },
+//!     DiffRange { offset: 4, len: 1 }
+//! ]);
+//! assert_eq!(
+//!     diff.to_string(),
+//!     "00000000  01 02 03 04 --\n00000000  01 09 09 04 05\n\x20            ^^ ^^    ^^\n"
+//! );
+//! assert!(diff_bytes(b"same", b"same").is_empty());
+//! ```
+
+use alloc::vec::Vec;
+use core::{fmt, ops::Range};
+
+const ROW_LEN: usize = 16;
+
+/// A run of consecutive differing bytes.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DiffRange {
+    /// The offset of the first differing byte
+    pub offset: usize,
+    /// The number of differing bytes
+    pub len: usize,
+}
+
+impl DiffRange {
+    /// Returns the differing positions as a range.
+    pub fn range(&self) -> Range<usize> {
+        self.offset..self.offset + self.len
+    }
+}
+
+/// The differences between two byte slices. See [diff_bytes].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ByteDiff<'a> {
+    left: &'a [u8],
+    right: &'a [u8],
+    ranges: Vec<DiffRange>,
+}
+
+impl ByteDiff<'_> {
+    /// Returns the ranges at which the slices differ, in order.
+    pub fn ranges(&self) -> &[DiffRange] {
+        &self.ranges
+    }
+
+    /// Returns true if the slices are identical.
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    /// Returns the total number of differing bytes.
+    pub fn differing_bytes(&self) -> usize {
+        self.ranges.iter().map(|r| r.len).sum()
+    }
+
+    fn differs(&self, offset: usize) -> bool {
+        self.left.get(offset) != self.right.get(offset)
+    }
+
+    fn write_row(f: &mut fmt::Formatter<'_>, row: usize, bytes: &[u8], end: usize) -> fmt::Result {
+        write!(f, "{:08x} ", row)?;
+        for offset in row..end {
+            match bytes.get(offset) {
+                Some(b) => write!(f, " {:02x}", b)?,
+                None => f.write_str(" --")?,
+            }
+        }
+        writeln!(f)
+    }
+}
+
+/// Compare two byte slices, returning the ranges at which they differ. Where one slice is longer, its extra bytes
+/// are a difference.
+pub fn diff_bytes<'a>(left: &'a [u8], right: &'a [u8]) -> ByteDiff<'a> {
+    let mut ranges: Vec<DiffRange> = Vec::new();
+    for offset in 0..left.len().max(right.len()) {
+        if left.get(offset) == right.get(offset) {
+            continue;
+        }
+        match ranges.last_mut() {
+            Some(last) if last.offset + last.len == offset => last.len += 1,
+            _ => ranges.push(DiffRange { offset, len: 1 }),
+        }
+    }
+    ByteDiff { left, right, ranges }
+}
+
+impl fmt::Display for ByteDiff<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let len = self.left.len().max(self.right.len());
+        let mut last_row = None;
+        for range in &self.ranges {
+            for offset in range.range() {
+                let row = offset - offset % ROW_LEN;
+                if last_row == Some(row) {
+                    continue;
+                }
+                last_row = Some(row);
+                let end = (row + ROW_LEN).min(len);
+                Self::write_row(f, row, self.left, end)?;
+                Self::write_row(f, row, self.right, end)?;
+                // Line the markers up under the bytes, trimming trailing spaces
+                let last_diff = (row..end).rev().find(|i| self.differs(*i)).unwrap_or(row);
+                f.write_str("         ")?;
+                for i in row..=last_diff {
+                    f.write_str(if self.differs(i) { " ^^" } else { "   " })?;
+                }
+                writeln!(f)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use alloc::string::ToString;
+
+    use super::*;
+
+    #[test]
+    fn ranges() {
+        assert!(diff_bytes(&[], &[]).is_empty());
+        assert!(diff_bytes(&[1, 2], &[1, 2]).is_empty());
+        let diff = diff_bytes(&[0, 1, 2, 3, 4, 5], &[0, 9, 9, 3, 9, 5]);
+        assert_eq!(diff.ranges(), &[DiffRange { offset: 1, len: 2 }, DiffRange {
+            offset: 4,
+            len: 1
+        }]);
+        assert_eq!(diff.differing_bytes(), 3);
+        assert_eq!(diff.ranges()[0].range(), 1..3);
+        let diff = diff_bytes(&[1, 2, 3], &[]);
+        assert_eq!(diff.ranges(), &[DiffRange { offset: 0, len: 3 }]);
+        let diff = diff_bytes(&[1], &[1, 2, 3]);
+        assert_eq!(diff.ranges(), &[DiffRange { offset: 1, len: 2 }]);
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(diff_bytes(b"abc", b"abc").to_string(), "");
+        let left = (0..40u8).collect::<Vec<_>>();
+        let mut right = left.clone();
+        right[3] = 0xff;
+        right[35] = 0xee;
+        let expected = "\
+00000000  00 01 02 03 04 05 06 07 08 09 0a 0b 0c 0d 0e 0f
+00000000  00 01 02 ff 04 05 06 07 08 09 0a 0b 0c 0d 0e 0f
+                   ^^
+00000020  20 21 22 23 24 25 26 27
+00000020  20 21 22 ee 24 25 26 27
+                   ^^
+";
+        assert_eq!(diff_bytes(&left, &right).to_string(), expected);
+    }
+}
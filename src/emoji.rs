@@ -0,0 +1,193 @@
+// Copyright 2026, The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+//! Encoding of bytes as emoji, one emoji per byte, for identifiers that people compare by eye.
+//!
+//! The [EMOJI] alphabet has 256 distinct emoji, each a single Unicode code point with default emoji presentation, so
+//! that no variation selectors or joiners are needed. The alphabet is sorted by code point. [encode_with_checksum]
+//! appends a [DammSum](crate::dammsum::DammSum) checksum emoji, which detects any single changed emoji and any two
+//! adjacent emoji that have been swapped.
+//!
+//! ```edition2018
+//! # use tari_utilities::emoji;
+//! let id = emoji::encode(&[0, 1, 255]);
+//! assert_eq!(id, "🌀🌂🛁");
+//! assert_eq!(emoji::decode(&id).unwrap(), vec![0, 1, 255]);
+//!
+//! let checked = emoji::encode_with_checksum(&[0, 1, 255]);
+//! assert_eq!(emoji::decode_with_checksum(&checked).unwrap(), vec![
+//!     0, 1, 255
+//! ]);
+//! ```
+
+use alloc::{string::String, vec::Vec};
+
+use snafu::prelude::*;
+
+use crate::dammsum::DammSum;
+
+/// The emoji alphabet. The emoji at index `i` encodes the byte `i`.
+pub const EMOJI: [char; 256] = [
+    '🌀', '🌂', '🌈', '🌊', '🌋', '🌍', '🌙', '🌝', '🌞', '🌟', '🌠', '🌰', '🌴', '🌵', '🌷', '🌸', '🌹', '🌻', '🌽',
+    '🍀', '🍁', '🍄', '🍅', '🍆', '🍇', '🍈', '🍉', '🍊', '🍋', '🍌', '🍍', '🍎', '🍐', '🍑', '🍒', '🍓', '🍔', '🍕',
+    '🍗', '🍚', '🍞', '🍟', '🍠', '🍣', '🍦', '🍩', '🍪', '🍫', '🍬', '🍭', '🍯', '🍰', '🍳', '🍴', '🍵', '🍶', '🍷',
+    '🍸', '🍹', '🍺', '🍼', '🎀', '🎁', '🎂', '🎃', '🎄', '🎈', '🎉', '🎒', '🎓', '🎠', '🎡', '🎢', '🎣', '🎤', '🎥',
+    '🎧', '🎨', '🎩', '🎪', '🎬', '🎭', '🎮', '🎰', '🎱', '🎲', '🎳', '🎵', '🎷', '🎸', '🎹', '🎺', '🎻', '🎼', '🎽',
+    '🎾', '🎿', '🏀', '🏁', '🏆', '🏈', '🏉', '🏠', '🏥', '🏦', '🏭', '🏰', '🐀', '🐉', '🐊', '🐌', '🐍', '🐎', '🐐',
+    '🐑', '🐓', '🐖', '🐗', '🐘', '🐙', '🐚', '🐛', '🐜', '🐝', '🐞', '🐢', '🐣', '🐨', '🐪', '🐫', '🐬', '🐭', '🐮',
+    '🐯', '🐰', '🐲', '🐳', '🐴', '🐵', '🐶', '🐷', '🐸', '🐺', '🐻', '🐼', '🐽', '🐾', '👀', '👅', '👑', '👒', '👓',
+    '👔', '👕', '👖', '👗', '👘', '👙', '👚', '👛', '👞', '👟', '👠', '👡', '👢', '👣', '👹', '👻', '👽', '👾', '👿',
+    '💀', '💄', '💈', '💉', '💊', '💋', '💌', '💍', '💎', '💐', '💔', '💕', '💘', '💡', '💣', '💤', '💦', '💧', '💩',
+    '💭', '💯', '💰', '💳', '💸', '💺', '💻', '💼', '📈', '📉', '📌', '📎', '📚', '📝', '📡', '📣', '📱', '📷', '🔋',
+    '🔌', '🔎', '🔑', '🔔', '🔥', '🔦', '🔧', '🔨', '🔩', '🔪', '🔫', '🔬', '🔭', '🔮', '🔱', '🗽', '😂', '😇', '😈',
+    '😉', '😍', '😎', '😱', '😷', '😹', '😻', '😿', '🚀', '🚁', '🚂', '🚌', '🚑', '🚒', '🚓', '🚕', '🚗', '🚜', '🚢',
+    '🚦', '🚧', '🚨', '🚪', '🚫', '🚲', '🚽', '🚿', '🛁',
+];
+
+/// Errors for emoji decoding.
+#[derive(Debug, Snafu, PartialEq, Eq)]
+pub enum EmojiError {
+    /// The input contains a character that is not in the alphabet.
+    #[snafu(display("Invalid emoji `{character}' at position {index}"))]
+    InvalidEmoji {
+        /// The offending character
+        character: char,
+        /// The position of the character in the input, counted in characters
+        index: usize,
+    },
+    /// The input is empty, so has no checksum.
+    #[snafu(display("Emoji string is empty"))]
+    MissingChecksum {},
+    /// The checksum does not match the data.
+    #[snafu(display("Invalid emoji checksum"))]
+    InvalidChecksum {},
+}
+
+/// Returns the emoji for a byte.
+pub fn byte_to_emoji(byte: u8) -> char {
+    EMOJI[usize::from(byte)]
+}
+
+/// Returns the byte for an emoji, or `None` if it is not in the alphabet.
+pub fn emoji_to_byte(emoji: char) -> Option<u8> {
+    // The alphabet is sorted and has 256 entries, so the position always fits in a byte
+    EMOJI.binary_search(&emoji).ok().map(|i| i as u8)
+}
+
+/// Returns true if every character of `s` is in the alphabet.
+pub fn is_valid(s: &str) -> bool {
+    s.chars().all(|c| emoji_to_byte(c).is_some())
+}
+
+/// Encode bytes as emoji.
+pub fn encode(data: &[u8]) -> String {
+    data.iter().map(|b| byte_to_emoji(*b)).collect()
+}
+
+/// Decode emoji to bytes.
+pub fn decode(s: &str) -> Result<Vec<u8>, EmojiError> {
+    s.chars()
+        .enumerate()
+        .map(|(index, character)| emoji_to_byte(character).context(InvalidEmojiSnafu { character, index }))
+        .collect()
+}
+
+/// Encode bytes as emoji, followed by a checksum emoji.
+pub fn encode_with_checksum(data: &[u8]) -> String {
+    let mut s = encode(data);
+    // The checksum only fails for digits outside its alphabet, and the byte alphabet has all 256 digits
+    let checksum = DammSum::BYTES
+        .compute(data)
+        .expect("every byte is a digit of the 256-digit alphabet");
+    s.push(byte_to_emoji(checksum));
+    s
+}
+
+/// Decode emoji followed by a checksum emoji, validating the checksum and returning the data without it.
+pub fn decode_with_checksum(s: &str) -> Result<Vec<u8>, EmojiError> {
+    let mut data = decode(s)?;
+    ensure!(!data.is_empty(), MissingChecksumSnafu);
+    ensure!(DammSum::BYTES.validate(&data).is_ok(), InvalidChecksumSnafu);
+    data.pop();
+    Ok(data)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn alphabet() {
+        for (i, emoji) in EMOJI.iter().enumerate() {
+            assert_eq!(emoji_to_byte(*emoji), Some(i as u8));
+            assert_eq!(byte_to_emoji(i as u8), *emoji);
+            assert!(i == 0 || EMOJI[i - 1] < *emoji);
+        }
+        assert_eq!(emoji_to_byte('a'), None);
+    }
+
+    #[test]
+    fn round_trip() {
+        let data = (0..=255u8).rev().collect::<Vec<_>>();
+        let encoded = encode(&data);
+        assert_eq!(encoded.chars().count(), 256);
+        assert!(is_valid(&encoded));
+        assert_eq!(decode(&encoded).unwrap(), data);
+        assert_eq!(decode("").unwrap(), Vec::<u8>::new());
+    }
+
+    #[test]
+    fn invalid_emoji() {
+        assert!(!is_valid("🌀x"));
+        assert_eq!(
+            decode("🌀🌂x"),
+            Err(EmojiError::InvalidEmoji {
+                character: 'x',
+                index: 2
+            })
+        );
+        // A variation selector is not part of the alphabet
+        assert!(decode("🌀\u{fe0f}").is_err());
+    }
+
+    #[test]
+    fn checksum() {
+        let data = [0x12, 0x34, 0x56, 0x78];
+        let encoded = encode_with_checksum(&data);
+        assert_eq!(encoded.chars().count(), 5);
+        assert_eq!(decode_with_checksum(&encoded).unwrap(), data);
+        assert_eq!(
+            decode_with_checksum(&encode_with_checksum(&[])).unwrap(),
+            Vec::<u8>::new()
+        );
+
+        let mut chars = encoded.chars().collect::<Vec<_>>();
+        chars.swap(1, 2);
+        let swapped = chars.iter().collect::<String>();
+        assert_eq!(decode_with_checksum(&swapped), Err(EmojiError::InvalidChecksum {}));
+        chars.swap(1, 2);
+        chars[0] = byte_to_emoji(0x13);
+        let changed = chars.iter().collect::<String>();
+        assert_eq!(decode_with_checksum(&changed), Err(EmojiError::InvalidChecksum {}));
+        assert_eq!(decode_with_checksum(""), Err(EmojiError::MissingChecksum {}));
+    }
+}
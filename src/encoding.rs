@@ -20,15 +20,334 @@
 // WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
 // USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
 
-//! A trait that handles [base58](https://crates.io/crates/base58-monero) encoding and decoding.
+//! Traits for encoding and decoding bytes as text.
+//!
+//! The [Encoding] trait is implemented by each of the crate's codecs, and by [EncodingKind], which selects a codec at
+//! runtime, e.g. from a command line flag or a configuration field:
+//!
+//! ```edition2018
+//! # use tari_utilities::encoding::{Encoding, EncodingKind};
+//! let encoding: EncodingKind = "base32".parse().unwrap();
+//! assert_eq!(encoding.encode(b"foobar"), "mzxw6ytboi");
+//! assert_eq!(encoding.decode("mzxw6ytboi").unwrap(), b"foobar");
+//! ```
+//!
+//! Decoders take a [DecodeMode], which controls whether whitespace, non-canonical case and optional padding are
+//! tolerated. [Encoding::decode] is strict.
+//!
+//! The [Base58] trait handles [base58](https://crates.io/crates/base58-monero) encoding and decoding of byte arrays.
 
-use alloc::string::{String, ToString};
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::{fmt, str::FromStr};
 
 use snafu::prelude::*;
 
+#[cfg(feature = "std")]
 use crate::ByteArray;
+use crate::{base32, base45, base64, hex};
+
+/// How strictly a decoder treats input that is not exactly as its encoder would produce it.
+///
+/// Each codec documents precisely what its modes accept. Strict mode should be used for consensus data, where
+/// accepting more than one encoding of the same bytes can hide malformed or malicious input.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum DecodeMode {
+    /// Accept only the canonical encoding: no whitespace, the canonical case, and padding exactly as specified. This
+    /// is the default.
+    #[default]
+    Strict,
+    /// Ignore ASCII whitespace, and accept either case and optional padding where the encoding allows
+    Lenient,
+}
+
+/// A text encoding for bytes.
+pub trait Encoding {
+    /// The name of the encoding, as accepted by [EncodingKind::from_str].
+    fn name(&self) -> &'static str;
+
+    /// Encode bytes as a string.
+    fn encode(&self, data: &[u8]) -> String;
+
+    /// Decode a string to bytes, in the given mode.
+    fn decode_with_mode(&self, s: &str, mode: DecodeMode) -> Result<Vec<u8>, EncodingError>;
+
+    /// Decode a string to bytes, in [DecodeMode::Strict] mode.
+    fn decode(&self, s: &str) -> Result<Vec<u8>, EncodingError> {
+        self.decode_with_mode(s, DecodeMode::Strict)
+    }
+}
+
+/// Errors for the [Encoding] trait.
+#[derive(Debug, Snafu, PartialEq, Eq)]
+pub enum EncodingError {
+    /// The encoding name is not recognised.
+    #[snafu(display("Unknown encoding `{name}'"))]
+    UnknownEncoding {
+        /// The unrecognised name
+        name: String,
+    },
+    /// The string is not valid in the encoding.
+    #[snafu(display("Invalid {encoding} data: `{reason}'"))]
+    InvalidData {
+        /// The name of the encoding
+        encoding: &'static str,
+        /// Why the data is invalid
+        reason: String,
+    },
+}
+
+fn invalid<E: fmt::Display>(encoding: &'static str) -> impl FnOnce(E) -> EncodingError {
+    move |e| EncodingError::InvalidData {
+        encoding,
+        reason: e.to_string(),
+    }
+}
+
+/// Lower case hexadecimal. [DecodeMode::Lenient] decoding also accepts upper case digits and a `0x` prefix.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct HexEncoding;
+
+impl Encoding for HexEncoding {
+    fn name(&self) -> &'static str {
+        "hex"
+    }
+
+    fn encode(&self, data: &[u8]) -> String {
+        let mut s = String::new();
+        hex::to_hex_into(data, &mut s);
+        s
+    }
+
+    fn decode_with_mode(&self, s: &str, mode: DecodeMode) -> Result<Vec<u8>, EncodingError> {
+        hex::from_hex_with_mode(s, mode).map_err(invalid(self.name()))
+    }
+}
+
+/// Unpadded lower case RFC 4648 Base32. See [base32](crate::base32).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Base32Encoding;
+
+impl Encoding for Base32Encoding {
+    fn name(&self) -> &'static str {
+        "base32"
+    }
+
+    fn encode(&self, data: &[u8]) -> String {
+        base32::encode(data)
+    }
+
+    fn decode_with_mode(&self, s: &str, mode: DecodeMode) -> Result<Vec<u8>, EncodingError> {
+        base32::decode_with_mode(s, mode).map_err(invalid(self.name()))
+    }
+}
+
+/// z-base-32. See [base32](crate::base32).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ZBase32Encoding;
+
+impl Encoding for ZBase32Encoding {
+    fn name(&self) -> &'static str {
+        "zbase32"
+    }
+
+    fn encode(&self, data: &[u8]) -> String {
+        base32::encode_z(data)
+    }
+
+    fn decode_with_mode(&self, s: &str, mode: DecodeMode) -> Result<Vec<u8>, EncodingError> {
+        base32::decode_z_with_mode(s, mode).map_err(invalid(self.name()))
+    }
+}
+
+/// Base45, using the QR code alphanumeric character set. See [base45](crate::base45).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Base45Encoding;
+
+impl Encoding for Base45Encoding {
+    fn name(&self) -> &'static str {
+        "base45"
+    }
+
+    fn encode(&self, data: &[u8]) -> String {
+        base45::encode(data)
+    }
+
+    fn decode_with_mode(&self, s: &str, mode: DecodeMode) -> Result<Vec<u8>, EncodingError> {
+        // The space character is part of the alphabet, so only case is relaxed in lenient mode
+        match mode {
+            DecodeMode::Strict => base45::decode(s),
+            DecodeMode::Lenient => base45::decode(&s.to_ascii_uppercase()),
+        }
+        .map_err(invalid(self.name()))
+    }
+}
+
+/// Bitcoin Base58. See [base58](crate::base58).
+#[cfg(feature = "base58")]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Base58Encoding;
+
+#[cfg(feature = "base58")]
+impl Encoding for Base58Encoding {
+    fn name(&self) -> &'static str {
+        "base58"
+    }
+
+    fn encode(&self, data: &[u8]) -> String {
+        crate::base58::encode(data)
+    }
+
+    fn decode_with_mode(&self, s: &str, mode: DecodeMode) -> Result<Vec<u8>, EncodingError> {
+        crate::base58::decode_with_mode(s, mode).map_err(invalid(self.name()))
+    }
+}
+
+/// Monero block-based Base58, as used by the [Base58] trait.
+#[cfg(feature = "std")]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Base58MoneroEncoding;
+
+#[cfg(feature = "std")]
+impl Encoding for Base58MoneroEncoding {
+    fn name(&self) -> &'static str {
+        "base58-monero"
+    }
+
+    fn encode(&self, data: &[u8]) -> String {
+        // `encode` only fails for a block that is empty or longer than 8 bytes, and it splits the data into 8-byte
+        // chunks itself
+        base58_monero::encode(data).expect("base58_monero::encode is infallible")
+    }
+
+    fn decode_with_mode(&self, s: &str, mode: DecodeMode) -> Result<Vec<u8>, EncodingError> {
+        match mode {
+            DecodeMode::Strict => base58_monero::decode(s),
+            DecodeMode::Lenient => base58_monero::decode(&s.split_ascii_whitespace().collect::<String>()),
+        }
+        .map_err(invalid(self.name()))
+    }
+}
+
+/// Base64 with the given alphabet and padding. See [base64](crate::base64).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Base64Encoding(pub base64::Config);
+
+impl Encoding for Base64Encoding {
+    fn name(&self) -> &'static str {
+        match (self.0.alphabet, self.0.padding) {
+            (base64::Alphabet::Standard, true) => "base64",
+            (base64::Alphabet::Standard, false) => "base64-nopad",
+            (base64::Alphabet::UrlSafe, true) => "base64url",
+            (base64::Alphabet::UrlSafe, false) => "base64url-nopad",
+        }
+    }
+
+    fn encode(&self, data: &[u8]) -> String {
+        base64::encode(data, self.0)
+    }
+
+    fn decode_with_mode(&self, s: &str, mode: DecodeMode) -> Result<Vec<u8>, EncodingError> {
+        base64::decode_with_mode(s, self.0, mode).map_err(invalid(self.name()))
+    }
+}
+
+/// Runtime selection of one of the crate's encodings.
+///
+/// Parses from, and displays as, the [Encoding::name] of the selected encoding. Parsing ignores ASCII case.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EncodingKind {
+    /// [HexEncoding]
+    Hex,
+    /// [Base32Encoding]
+    Base32,
+    /// [ZBase32Encoding]
+    ZBase32,
+    /// [Base45Encoding]
+    Base45,
+    /// [Base58Encoding]
+    #[cfg(feature = "base58")]
+    Base58,
+    /// [Base58MoneroEncoding]
+    #[cfg(feature = "std")]
+    Base58Monero,
+    /// [Base64Encoding] with the given configuration
+    Base64(base64::Config),
+}
+
+impl EncodingKind {
+    /// All the available encodings.
+    pub const ALL: &'static [EncodingKind] = &[
+        EncodingKind::Hex,
+        EncodingKind::Base32,
+        EncodingKind::ZBase32,
+        EncodingKind::Base45,
+        #[cfg(feature = "base58")]
+        EncodingKind::Base58,
+        #[cfg(feature = "std")]
+        EncodingKind::Base58Monero,
+        EncodingKind::Base64(base64::Config::STANDARD),
+        EncodingKind::Base64(base64::Config::STANDARD_NO_PAD),
+        EncodingKind::Base64(base64::Config::URL_SAFE),
+        EncodingKind::Base64(base64::Config::URL_SAFE_NO_PAD),
+    ];
+
+    fn encoding(&self) -> &dyn Encoding {
+        match self {
+            EncodingKind::Hex => &HexEncoding,
+            EncodingKind::Base32 => &Base32Encoding,
+            EncodingKind::ZBase32 => &ZBase32Encoding,
+            EncodingKind::Base45 => &Base45Encoding,
+            #[cfg(feature = "base58")]
+            EncodingKind::Base58 => &Base58Encoding,
+            #[cfg(feature = "std")]
+            EncodingKind::Base58Monero => &Base58MoneroEncoding,
+            EncodingKind::Base64(config) => match (config.alphabet, config.padding) {
+                (base64::Alphabet::Standard, true) => &Base64Encoding(base64::Config::STANDARD),
+                (base64::Alphabet::Standard, false) => &Base64Encoding(base64::Config::STANDARD_NO_PAD),
+                (base64::Alphabet::UrlSafe, true) => &Base64Encoding(base64::Config::URL_SAFE),
+                (base64::Alphabet::UrlSafe, false) => &Base64Encoding(base64::Config::URL_SAFE_NO_PAD),
+            },
+        }
+    }
+}
+
+impl Encoding for EncodingKind {
+    fn name(&self) -> &'static str {
+        self.encoding().name()
+    }
+
+    fn encode(&self, data: &[u8]) -> String {
+        self.encoding().encode(data)
+    }
+
+    fn decode_with_mode(&self, s: &str, mode: DecodeMode) -> Result<Vec<u8>, EncodingError> {
+        self.encoding().decode_with_mode(s, mode)
+    }
+}
+
+impl FromStr for EncodingKind {
+    type Err = EncodingError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        EncodingKind::ALL
+            .iter()
+            .find(|kind| kind.name().eq_ignore_ascii_case(s))
+            .copied()
+            .context(UnknownEncodingSnafu { name: s })
+    }
+}
+
+impl fmt::Display for EncodingKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
 
 /// Trait for encoding/decoding to base58.
+#[cfg(feature = "std")]
 pub trait Base58 {
     /// Convert from base58 string.
     fn from_base58(hex: &str) -> Result<Self, Base58Error>
@@ -36,9 +355,13 @@ pub trait Base58 {
 
     /// Convert to base58 string.
     fn to_base58(&self) -> String;
+
+    /// Convert to base58 string, returning an error instead of panicking if the encoder fails.
+    fn try_to_base58(&self) -> Result<String, Base58Error>;
 }
 
 /// Errors for trait Base58.
+#[cfg(feature = "std")]
 #[derive(Debug, Snafu)]
 #[allow(missing_docs)]
 pub enum Base58Error {
@@ -46,8 +369,11 @@ pub enum Base58Error {
     ByteArrayError { reason: String },
     #[snafu(display("Decode error: `{reason}'"))]
     DecodeError { reason: String },
+    #[snafu(display("Encode error: `{reason}'"))]
+    EncodeError { reason: String },
 }
 
+#[cfg(feature = "std")]
 impl<T: ByteArray> Base58 for T {
     fn from_base58(data: &str) -> Result<Self, Base58Error>
     where Self: Sized {
@@ -56,19 +382,74 @@ impl<T: ByteArray> Base58 for T {
     }
 
     fn to_base58(&self) -> String {
+        // See `Base58MoneroEncoding::encode`: the chunking in `encode` means it cannot fail
         base58_monero::encode(self.as_bytes()).expect("base58_monero::encode is infallible")
     }
+
+    fn try_to_base58(&self) -> Result<String, Base58Error> {
+        base58_monero::encode(self.as_bytes()).map_err(|e| Base58Error::EncodeError { reason: e.to_string() })
+    }
 }
 
 #[cfg(test)]
 mod test {
-    use alloc::vec::Vec;
+    use alloc::boxed::Box;
 
+    #[cfg(feature = "std")]
     use rand::{rngs::OsRng, RngCore};
 
     use super::*;
 
     #[test]
+    fn runtime_selection() {
+        let data = b"\x00\xfbfoobar\xff";
+        for kind in EncodingKind::ALL {
+            let encoded = kind.encode(data);
+            assert_eq!(kind.decode(&encoded).unwrap(), data);
+            assert_eq!(kind.to_string().parse::<EncodingKind>().unwrap(), *kind);
+        }
+        assert_eq!("HEX".parse::<EncodingKind>().unwrap(), EncodingKind::Hex);
+        assert_eq!(
+            "base64url-nopad".parse::<EncodingKind>().unwrap(),
+            EncodingKind::Base64(base64::Config::URL_SAFE_NO_PAD)
+        );
+        assert_eq!(
+            "base65".parse::<EncodingKind>(),
+            Err(EncodingError::UnknownEncoding { name: "base65".into() })
+        );
+        assert_eq!(EncodingKind::Hex.encode(&[0xab, 0x01]), "ab01");
+        assert!(EncodingKind::Hex.decode("AB01").is_err());
+        assert_eq!(
+            EncodingKind::Hex.decode_with_mode("AB01", DecodeMode::Lenient).unwrap(),
+            [0xab, 0x01]
+        );
+        assert_eq!(
+            EncodingKind::Base45
+                .decode_with_mode("bb8", DecodeMode::Lenient)
+                .unwrap(),
+            b"AB"
+        );
+        assert!(matches!(
+            EncodingKind::Base32.decode("1"),
+            Err(EncodingError::InvalidData { encoding: "base32", .. })
+        ));
+    }
+
+    #[test]
+    fn generic_use() {
+        fn round_trip<E: Encoding>(encoding: E, data: &[u8]) -> Vec<u8> {
+            encoding.decode(&encoding.encode(data)).unwrap()
+        }
+        assert_eq!(round_trip(HexEncoding, b"abc"), b"abc");
+        assert_eq!(round_trip(ZBase32Encoding, b"abc"), b"abc");
+        assert_eq!(round_trip(Base64Encoding::default(), b"abc"), b"abc");
+        let boxed: Vec<Box<dyn Encoding>> = vec![Box::new(Base32Encoding), Box::new(EncodingKind::Hex)];
+        assert_eq!(boxed[0].encode(b"f"), "my");
+        assert_eq!(boxed[1].name(), "hex");
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
     fn decoding() {
         assert_eq!(Vec::from_base58("111111").unwrap(), vec![0; 4]);
         assert_eq!(Vec::from_base58("11115Q").unwrap(), vec![0, 0, 0, 255]);
@@ -77,12 +458,16 @@ mod test {
     }
 
     #[test]
+    #[cfg(feature = "std")]
     fn encoding() {
         assert_eq!(vec![0; 4].to_base58(), "111111");
         assert_eq!(vec![0, 2, 250, 39].to_base58(), "111zzz");
+        assert_eq!(vec![0, 2, 250, 39].try_to_base58().unwrap(), "111zzz");
+        assert_eq!(Vec::<u8>::new().try_to_base58().unwrap(), "");
     }
 
     #[test]
+    #[cfg(feature = "std")]
     fn inverse_operations() {
         let mut bytes = vec![0; 10];
         OsRng.fill_bytes(&mut bytes);
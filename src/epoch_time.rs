@@ -24,7 +24,8 @@
 
 use std::{
     fmt,
-    time::{SystemTime, UNIX_EPOCH},
+    ops::Sub,
+    time::{Duration, SystemTime, UNIX_EPOCH},
 };
 
 #[cfg(feature = "borsh")]
@@ -66,7 +67,29 @@ impl EpochTime {
     pub fn checked_sub(self, other: EpochTime) -> Option<EpochTime> {
         self.0.checked_sub(other.0).map(EpochTime)
     }
+
+    /// Returns the time elapsed from `earlier` to this time, or None if `earlier` is later than this time, which can
+    /// happen when comparing timestamps from clocks that are out of sync.
+    pub fn checked_duration_since(self, earlier: EpochTime) -> Option<Duration> {
+        self.0.checked_sub(earlier.0).map(Duration::from_secs)
+    }
+
+    /// Returns the time elapsed from `earlier` to this time, or a zero duration if `earlier` is later than this time.
+    pub fn saturating_duration_since(self, earlier: EpochTime) -> Duration {
+        self.checked_duration_since(earlier).unwrap_or_default()
+    }
 }
+
+/// The time elapsed from `rhs` to `self`. This never panics: it is zero if `rhs` is later than `self`, as for
+/// [saturating_duration_since](EpochTime::saturating_duration_since).
+impl Sub for EpochTime {
+    type Output = Duration;
+
+    fn sub(self, rhs: EpochTime) -> Duration {
+        self.saturating_duration_since(rhs)
+    }
+}
+
 impl fmt::Display for EpochTime {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "{}", self.0)
@@ -79,6 +102,12 @@ impl From<u64> for EpochTime {
     }
 }
 
+// Foreign code sees the number of seconds. The macro expects `Vec` from the std prelude.
+#[cfg(feature = "uniffi")]
+use std::vec::Vec;
+#[cfg(feature = "uniffi")]
+uniffi::custom_newtype!(EpochTime, u64);
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -113,6 +142,19 @@ mod test {
         assert_eq!(b.checked_sub(a), None);
     }
 
+    #[test]
+    fn duration_since() {
+        let earlier = EpochTime::from(100);
+        let later = EpochTime::from(160);
+        assert_eq!(later.checked_duration_since(earlier), Some(Duration::from_secs(60)));
+        assert_eq!(earlier.checked_duration_since(later), None);
+        assert_eq!(later.saturating_duration_since(earlier), Duration::from_secs(60));
+        assert_eq!(earlier.saturating_duration_since(later), Duration::ZERO);
+        assert_eq!(later - earlier, Duration::from_secs(60));
+        assert_eq!(earlier - later, Duration::ZERO);
+        assert_eq!(EpochTime::from(0) - EpochTime::from(u64::MAX), Duration::ZERO);
+    }
+
     #[test]
     fn display() {
         let time = EpochTime::from(1234567);
@@ -0,0 +1,332 @@
+// Copyright 2026, The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+//! Stable numeric codes for the crate's error types.
+//!
+//! Every error enum in the crate implements [ErrorCode], which gives each variant a `u32` code that does not change
+//! when error messages are reworded. FFI and other non-Rust consumers should match on these codes rather than on the
+//! `Display` output. [registry] lists every code with the error type and variant it belongs to.
+//!
+//! Each error type is assigned a block of 100 codes, and each variant a code within its type's block. Codes are never
+//! reused or renumbered: new variants take the next free code in their block, and new error types take the next free
+//! block. Code 0 is never assigned, so it can be used to signal success.
+//!
+//! ```edition2018
+//! # use tari_utilities::{error_code::{registry, ErrorCode}, hex::{from_hex, HexError}};
+//! let err = from_hex("0g").unwrap_err();
+//! assert_eq!(err.error_code(), 201);
+//!
+//! let entry = registry()
+//!     .into_iter()
+//!     .find(|entry| entry.code == 201)
+//!     .unwrap();
+//! assert_eq!(
+//!     (entry.error, entry.variant),
+//!     ("HexError", "InvalidCharacter")
+//! );
+//! ```
+
+use alloc::vec::Vec;
+
+/// A stable numeric code for each variant of an error type.
+pub trait ErrorCode {
+    /// The code of this error, which is unique across the crate and never changes.
+    fn error_code(&self) -> u32;
+}
+
+/// An entry in the error code [registry].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ErrorCodeEntry {
+    /// The error code
+    pub code: u32,
+    /// The name of the error type
+    pub error: &'static str,
+    /// The name of the variant
+    pub variant: &'static str,
+}
+
+/// Assigns codes to the variants of each error type, implementing [ErrorCode] and generating [registry]. The match in
+/// each implementation is exhaustive, so adding a variant without assigning it a code fails to compile.
+macro_rules! error_codes {
+    ($($(#[$meta:meta])* $path:path as $name:ident = $base:literal { $($variant:ident = $offset:literal),* $(,)? })*) => {
+        $(
+            $(#[$meta])*
+            impl ErrorCode for $path {
+                fn error_code(&self) -> u32 {
+                    use $path as $name;
+                    match self {
+                        $($name::$variant { .. } => $base + $offset,)*
+                    }
+                }
+            }
+        )*
+
+        /// All of the error codes for the features that are enabled, in ascending order.
+        pub fn registry() -> Vec<ErrorCodeEntry> {
+            let mut entries = Vec::new();
+            $(
+                $(#[$meta])*
+                entries.extend_from_slice(&[$(ErrorCodeEntry {
+                    code: $base + $offset,
+                    error: stringify!($name),
+                    variant: stringify!($variant),
+                }),*]);
+            )*
+            entries
+        }
+    };
+}
+
+error_codes! {
+    crate::byte_array::ByteArrayError as ByteArrayError = 100 {
+        ConversionError = 1,
+        IncorrectLength = 2,
+    }
+    crate::hex::HexError as HexError = 200 {
+        InvalidCharacter = 1,
+        LengthError = 2,
+        HexConversionError = 3,
+        OutputTooSmall = 4,
+    }
+    #[cfg(feature = "serde")]
+    crate::message_format::MessageFormatError as MessageFormatError = 300 {
+        BinarySerializeError = 1,
+        BinaryDeserializeError = 2,
+        JSONError = 3,
+        Base64DeserializeError = 4,
+        UnknownJSONFieldError = 5,
+        ValidationFailedError = 6,
+        JSONDepthError = 7,
+    }
+    crate::base64::Base64Error as Base64Error = 400 {
+        InvalidCharacter = 1,
+        InvalidLength = 2,
+        InvalidPadding = 3,
+        InvalidTrailingBits = 4,
+        OutputTooSmall = 5,
+    }
+    crate::base32::Base32Error as Base32Error = 500 {
+        InvalidCharacter = 1,
+        InvalidLength = 2,
+        InvalidTrailingBits = 3,
+        InvalidPadding = 4,
+    }
+    crate::base45::Base45Error as Base45Error = 600 {
+        InvalidCharacter = 1,
+        InvalidLength = 2,
+        Overflow = 3,
+    }
+    #[cfg(feature = "base58")]
+    crate::base58::Base58DecodeError as Base58DecodeError = 700 {
+        InvalidCharacter = 1,
+        TooShort = 2,
+        InvalidChecksum = 3,
+    }
+    #[cfg(feature = "std")]
+    crate::encoding::Base58Error as Base58Error = 800 {
+        ByteArrayError = 1,
+        DecodeError = 2,
+        EncodeError = 3,
+    }
+    crate::encoding::EncodingError as EncodingError = 900 {
+        UnknownEncoding = 1,
+        InvalidData = 2,
+    }
+    crate::bech32::Bech32Error as Bech32Error = 1000 {
+        InvalidHrp = 1,
+        MixedCase = 2,
+        MissingSeparator = 3,
+        InvalidCharacter = 4,
+        InvalidLength = 5,
+        InvalidChecksum = 6,
+        InvalidValue = 7,
+        InvalidPadding = 8,
+        InvalidBitWidth = 9,
+    }
+    crate::bit::BitError as BitError = 1100 {
+        ValueTooLarge = 1,
+        Overflow = 2,
+        LengthMismatch = 3,
+        InvalidChecksumWidth = 4,
+        InvalidBinaryCharacter = 5,
+        InvalidBinaryLength = 6,
+    }
+    crate::byte_size::ByteSizeError as ByteSizeError = 1200 {
+        InvalidNumber = 1,
+        UnknownUnit = 2,
+        Overflow = 3,
+    }
+    #[cfg(feature = "codec")]
+    crate::codec::CodecError as CodecError = 1300 {
+        Io = 1,
+        FrameTooLarge = 2,
+        Format = 3,
+    }
+    #[cfg(feature = "serde")]
+    crate::content_type::ContentTypeError as ContentTypeError = 1400 {
+        Unsupported = 1,
+        NotAcceptable = 2,
+    }
+    crate::dammsum::DammSumError as DammSumError = 1500 {
+        UnsupportedAlphabetSize = 1,
+        InvalidDigit = 2,
+        EmptyData = 3,
+        InvalidChecksum = 4,
+    }
+    crate::emoji::EmojiError as EmojiError = 1600 {
+        InvalidEmoji = 1,
+        MissingChecksum = 2,
+        InvalidChecksum = 3,
+    }
+    crate::fixed_set::FixedSetError as FixedSetError = 1700 {
+        CapacityExceeded = 1,
+        SizeMismatch = 2,
+        OccupiedPosition = 3,
+        DuplicateItem = 4,
+        PositionConflict = 5,
+    }
+    crate::index_set::IndexSetError as IndexSetError = 1800 {
+        OutOfRange = 1,
+    }
+    crate::locks::LockError as LockError = 1900 {
+        WouldBlock = 1,
+        Poisoned = 2,
+        OrderViolation = 3,
+        Timeout = 4,
+    }
+    crate::multibase::MultibaseError as MultibaseError = 2000 {
+        Empty = 1,
+        UnknownBase = 2,
+        InvalidData = 3,
+        InvalidCodec = 4,
+    }
+    #[cfg(all(feature = "zeroize", feature = "std"))]
+    crate::password::input::PasswordInputError as PasswordInputError = 2100 {
+        EnvVarNotPresent = 1,
+        EnvVarNotUnicode = 2,
+        TerminalError = 3,
+        Unsupported = 4,
+    }
+    #[cfg(all(feature = "zeroize", feature = "std"))]
+    crate::password::kdf::KdfError as KdfError = 2200 {
+        InvalidParameters = 1,
+        DerivationFailed = 2,
+    }
+    #[cfg(feature = "zeroize")]
+    crate::password::policy::PolicyViolation as PolicyViolation = 2300 {
+        TooShort = 1,
+        TooLong = 2,
+        MissingCharacterClass = 3,
+        DenyListed = 4,
+        InvalidUtf8 = 5,
+    }
+    #[cfg(feature = "prost")]
+    crate::proto::ProtoError as ProtoError = 2400 {
+        Decode = 1,
+        Base64 = 2,
+        MissingField = 3,
+        InvalidField = 4,
+    }
+    crate::ratio::RatioError as RatioError = 2500 {
+        OutOfRange = 1,
+        InvalidFraction = 2,
+        InvalidFormat = 3,
+    }
+    crate::sorted_vec::SortedVecError as SortedVecError = 2600 {
+        Full = 1,
+        Duplicate = 2,
+    }
+    #[cfg(feature = "uniffi")]
+    crate::uniffi_bindings::BindingError as BindingError = 2700 {
+        InvalidHex = 1,
+    }
+    crate::uri::UriError as UriError = 2800 {
+        InvalidScheme = 1,
+        SchemeMismatch = 2,
+        Malformed = 3,
+        InvalidVersion = 4,
+        InvalidData = 5,
+        ChecksumMismatch = 6,
+    }
+    crate::varint::VarintError as VarintError = 2900 {
+        Truncated = 1,
+        TooLong = 2,
+        Overflow = 3,
+        BufferTooSmall = 4,
+        Io = 5,
+    }
+    crate::z85::Z85Error as Z85Error = 3000 {
+        InvalidDataLength = 1,
+        InvalidEncodedLength = 2,
+        InvalidCharacter = 3,
+        Overflow = 4,
+    }
+    crate::validate::ValidationError as ValidationError = 3100 {
+        InvalidField = 1,
+        Invalid = 2,
+    }
+    #[cfg(feature = "std")]
+    crate::rate_limit::RateLimitError as RateLimitError = 3200 {
+        ExceedsCapacity = 1,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use alloc::string::String;
+
+    use super::*;
+    use crate::{byte_array::ByteArrayError, hex::HexError};
+
+    #[test]
+    fn codes_are_unique_and_ordered() {
+        let entries = registry();
+        assert!(entries.windows(2).all(|pair| pair[0].code < pair[1].code));
+        assert!(entries.iter().all(|entry| entry.code > 0));
+        assert!(entries
+            .iter()
+            .all(|entry| entry.code / 100 == entries.iter().find(|e| e.error == entry.error).unwrap().code / 100));
+    }
+
+    #[test]
+    fn codes_are_stable() {
+        assert_eq!(ByteArrayError::IncorrectLength {}.error_code(), 102);
+        assert_eq!(HexError::InvalidCharacter {}.error_code(), 201);
+        assert_eq!(
+            ByteArrayError::ConversionError { reason: String::new() }.error_code(),
+            101
+        );
+    }
+
+    #[test]
+    fn registry_matches_codes() {
+        let err = HexError::OutputTooSmall {
+            required: 2,
+            available: 1,
+        };
+        let entry = registry().into_iter().find(|e| e.code == err.error_code()).unwrap();
+        assert_eq!(entry, ErrorCodeEntry {
+            code: 204,
+            error: "HexError",
+            variant: "OutputTooSmall",
+        });
+    }
+}
@@ -0,0 +1,354 @@
+// Copyright 2026, The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+//! A C interface to the byte utilities, for mobile and other non-Rust callers.
+//!
+//! # Ownership
+//! - Byte data returned by this module is held in an opaque [ByteVector], which the caller owns and must release with
+//!   [byte_vector_destroy].
+//! - Strings returned by this module are NUL-terminated and owned by the caller, who must release them with
+//!   [string_destroy].
+//! - Pointers passed in are only borrowed for the duration of the call.
+//!
+//! # Errors
+//! Every fallible function takes an `error_out` pointer, which may be null. On return it holds
+//! [FfiErrorCode::Ok] on success, and on failure the function returns a null pointer or zero. A failure in the FFI
+//! layer itself, such as a null argument, is reported as an [FfiErrorCode], whose values are all below 100. A failure
+//! to decode is reported as the decoder's [ErrorCode], such as 201 for [HexError::InvalidCharacter], so C callers see
+//! the same stable codes as the rest of the crate.
+
+use alloc::{boxed::Box, string::String, vec::Vec};
+use core::{convert::TryFrom, ptr, slice};
+use std::ffi::{c_char, CStr, CString};
+
+#[cfg(doc)]
+use crate::hex::HexError;
+use crate::{
+    base64::{self, Config},
+    encoding::DecodeMode,
+    error_code::ErrorCode,
+    hex,
+};
+
+/// The error codes written to `error_out` for failures in the FFI layer. The values are stable and never reused, and
+/// do not overlap the [ErrorCode] values written for decoding failures.
+#[repr(i32)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FfiErrorCode {
+    /// The call succeeded
+    Ok = 0,
+    /// A required pointer argument was null
+    NullPointer = 1,
+    /// A string argument was not valid UTF-8
+    InvalidUtf8 = 2,
+    /// An index was beyond the end of the data
+    OutOfBounds = 5,
+    /// The output would contain a NUL byte, and cannot be returned as a C string
+    InteriorNul = 6,
+}
+
+/// An owned, growable array of bytes.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ByteVector(Vec<u8>);
+
+impl From<FfiErrorCode> for i32 {
+    fn from(code: FfiErrorCode) -> Self {
+        code as i32
+    }
+}
+
+/// The value written to `error_out` for a decoding error.
+fn decode_error<E: ErrorCode>(error: E) -> i32 {
+    // Codes are allocated in blocks of 100 per error type, so they are far below `i32::MAX`
+    i32::try_from(error.error_code()).unwrap_or(i32::MAX)
+}
+
+unsafe fn set_error<E: Into<i32>>(error_out: *mut i32, code: E) {
+    if !error_out.is_null() {
+        *error_out = code.into();
+    }
+}
+
+/// Borrows `len` bytes at `data`, treating a null pointer as empty only when `len` is zero.
+unsafe fn borrow_bytes<'a>(data: *const u8, len: usize) -> Result<&'a [u8], FfiErrorCode> {
+    if data.is_null() {
+        return if len == 0 {
+            Ok(&[])
+        } else {
+            Err(FfiErrorCode::NullPointer)
+        };
+    }
+    Ok(slice::from_raw_parts(data, len))
+}
+
+unsafe fn borrow_str<'a>(s: *const c_char) -> Result<&'a str, FfiErrorCode> {
+    if s.is_null() {
+        return Err(FfiErrorCode::NullPointer);
+    }
+    CStr::from_ptr(s).to_str().map_err(|_| FfiErrorCode::InvalidUtf8)
+}
+
+/// Writes the outcome of `result` to `error_out`, and converts it to a pointer that is null on failure.
+unsafe fn into_raw<T>(result: Result<T, i32>, error_out: *mut i32) -> *mut T {
+    match result {
+        Ok(value) => {
+            set_error(error_out, FfiErrorCode::Ok);
+            Box::into_raw(Box::new(value))
+        },
+        Err(code) => {
+            set_error(error_out, code);
+            ptr::null_mut()
+        },
+    }
+}
+
+/// Borrows `s` and decodes it with `decode`, reporting a decoding failure by its [ErrorCode].
+unsafe fn decode_str<E, F>(s: *const c_char, decode: F) -> Result<ByteVector, i32>
+where
+    E: ErrorCode,
+    F: FnOnce(&str) -> Result<Vec<u8>, E>,
+{
+    let s = borrow_str(s).map_err(i32::from)?;
+    decode(s).map(ByteVector).map_err(decode_error)
+}
+
+unsafe fn into_c_string(result: Result<String, FfiErrorCode>, error_out: *mut i32) -> *mut c_char {
+    match result.and_then(|s| CString::new(s).map_err(|_| FfiErrorCode::InteriorNul)) {
+        Ok(s) => {
+            set_error(error_out, FfiErrorCode::Ok);
+            s.into_raw()
+        },
+        Err(code) => {
+            set_error(error_out, code);
+            ptr::null_mut()
+        },
+    }
+}
+
+/// Creates a byte vector holding a copy of `len` bytes at `data`.
+///
+/// # Safety
+/// `data` must be valid for reads of `len` bytes, or may be null if `len` is zero. The result must be released with
+/// [byte_vector_destroy].
+#[no_mangle]
+pub unsafe extern "C" fn byte_vector_create(data: *const u8, len: usize, error_out: *mut i32) -> *mut ByteVector {
+    into_raw(
+        borrow_bytes(data, len)
+            .map(|bytes| ByteVector(bytes.to_vec()))
+            .map_err(i32::from),
+        error_out,
+    )
+}
+
+/// Returns the number of bytes in a byte vector.
+///
+/// # Safety
+/// `bytes` must be null or a pointer returned by this module that has not been destroyed.
+#[no_mangle]
+pub unsafe extern "C" fn byte_vector_get_length(bytes: *const ByteVector, error_out: *mut i32) -> usize {
+    match bytes.as_ref() {
+        Some(bytes) => {
+            set_error(error_out, FfiErrorCode::Ok);
+            bytes.0.len()
+        },
+        None => {
+            set_error(error_out, FfiErrorCode::NullPointer);
+            0
+        },
+    }
+}
+
+/// Returns the byte at `position` in a byte vector.
+///
+/// # Safety
+/// `bytes` must be null or a pointer returned by this module that has not been destroyed.
+#[no_mangle]
+pub unsafe extern "C" fn byte_vector_get_at(bytes: *const ByteVector, position: usize, error_out: *mut i32) -> u8 {
+    let result = bytes
+        .as_ref()
+        .ok_or(FfiErrorCode::NullPointer)
+        .and_then(|bytes| bytes.0.get(position).copied().ok_or(FfiErrorCode::OutOfBounds));
+    match result {
+        Ok(byte) => {
+            set_error(error_out, FfiErrorCode::Ok);
+            byte
+        },
+        Err(code) => {
+            set_error(error_out, code);
+            0
+        },
+    }
+}
+
+/// Releases a byte vector. Passing null does nothing.
+///
+/// # Safety
+/// `bytes` must be null or a pointer returned by this module that has not already been destroyed.
+#[no_mangle]
+pub unsafe extern "C" fn byte_vector_destroy(bytes: *mut ByteVector) {
+    if !bytes.is_null() {
+        drop(Box::from_raw(bytes));
+    }
+}
+
+/// Encodes the contents of a byte vector as lower case hex.
+///
+/// # Safety
+/// `bytes` must be null or a pointer returned by this module that has not been destroyed. The result must be released
+/// with [string_destroy].
+#[no_mangle]
+pub unsafe extern "C" fn hex_encode(bytes: *const ByteVector, error_out: *mut i32) -> *mut c_char {
+    let result = bytes.as_ref().ok_or(FfiErrorCode::NullPointer).map(|bytes| {
+        let mut s = String::new();
+        hex::to_hex_into(&bytes.0, &mut s);
+        s
+    });
+    into_c_string(result, error_out)
+}
+
+/// Decodes lower case hex, as [hex::from_hex] does.
+///
+/// # Safety
+/// `s` must be null or a valid NUL-terminated string. The result must be released with [byte_vector_destroy].
+#[no_mangle]
+pub unsafe extern "C" fn hex_decode(s: *const c_char, error_out: *mut i32) -> *mut ByteVector {
+    into_raw(decode_str(s, hex::from_hex), error_out)
+}
+
+/// Decodes hex, ignoring whitespace, case and any `0x` prefix.
+///
+/// # Safety
+/// `s` must be null or a valid NUL-terminated string. The result must be released with [byte_vector_destroy].
+#[no_mangle]
+pub unsafe extern "C" fn hex_decode_lenient(s: *const c_char, error_out: *mut i32) -> *mut ByteVector {
+    into_raw(
+        decode_str(s, |s| hex::from_hex_with_mode(s, DecodeMode::Lenient)),
+        error_out,
+    )
+}
+
+/// Encodes the contents of a byte vector as padded standard Base64.
+///
+/// # Safety
+/// `bytes` must be null or a pointer returned by this module that has not been destroyed. The result must be released
+/// with [string_destroy].
+#[no_mangle]
+pub unsafe extern "C" fn base64_encode(bytes: *const ByteVector, error_out: *mut i32) -> *mut c_char {
+    let result = bytes
+        .as_ref()
+        .ok_or(FfiErrorCode::NullPointer)
+        .map(|bytes| base64::encode(&bytes.0, Config::STANDARD));
+    into_c_string(result, error_out)
+}
+
+/// Decodes padded standard Base64.
+///
+/// # Safety
+/// `s` must be null or a valid NUL-terminated string. The result must be released with [byte_vector_destroy].
+#[no_mangle]
+pub unsafe extern "C" fn base64_decode(s: *const c_char, error_out: *mut i32) -> *mut ByteVector {
+    into_raw(decode_str(s, |s| base64::decode(s, Config::STANDARD)), error_out)
+}
+
+/// Releases a string returned by this module. Passing null does nothing.
+///
+/// # Safety
+/// `s` must be null or a pointer returned by this module that has not already been destroyed.
+#[no_mangle]
+pub unsafe extern "C" fn string_destroy(s: *mut c_char) {
+    if !s.is_null() {
+        drop(CString::from_raw(s));
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn byte_vector() {
+        let mut error = -1;
+        unsafe {
+            let bytes = byte_vector_create([1u8, 2, 3].as_ptr(), 3, &mut error);
+            assert_eq!(error, 0);
+            assert_eq!(byte_vector_get_length(bytes, &mut error), 3);
+            assert_eq!(byte_vector_get_at(bytes, 2, &mut error), 3);
+            assert_eq!(error, 0);
+            assert_eq!(byte_vector_get_at(bytes, 3, &mut error), 0);
+            assert_eq!(error, FfiErrorCode::OutOfBounds as i32);
+            byte_vector_destroy(bytes);
+
+            let empty = byte_vector_create(ptr::null(), 0, ptr::null_mut());
+            assert_eq!(byte_vector_get_length(empty, &mut error), 0);
+            byte_vector_destroy(empty);
+
+            assert!(byte_vector_create(ptr::null(), 1, &mut error).is_null());
+            assert_eq!(error, FfiErrorCode::NullPointer as i32);
+            assert_eq!(byte_vector_get_length(ptr::null(), &mut error), 0);
+            assert_eq!(error, FfiErrorCode::NullPointer as i32);
+            byte_vector_destroy(ptr::null_mut());
+        }
+    }
+
+    #[test]
+    fn codecs() {
+        let mut error = -1;
+        unsafe {
+            let bytes = byte_vector_create(b"foob".as_ptr(), 4, &mut error);
+
+            let hex = hex_encode(bytes, &mut error);
+            assert_eq!(CStr::from_ptr(hex).to_str().unwrap(), "666f6f62");
+            let decoded = hex_decode(hex, &mut error);
+            assert_eq!(*decoded, *bytes);
+            string_destroy(hex);
+            byte_vector_destroy(decoded);
+
+            let upper = CString::new("0x666F6F62").unwrap();
+            assert!(hex_decode(upper.as_ptr(), &mut error).is_null());
+            assert_eq!(error, 201);
+            let decoded = hex_decode_lenient(upper.as_ptr(), &mut error);
+            assert_eq!(error, 0);
+            assert_eq!(*decoded, *bytes);
+            byte_vector_destroy(decoded);
+
+            let b64 = base64_encode(bytes, &mut error);
+            assert_eq!(CStr::from_ptr(b64).to_str().unwrap(), "Zm9vYg==");
+            let decoded = base64_decode(b64, &mut error);
+            assert_eq!(error, 0);
+            assert_eq!(*decoded, *bytes);
+            string_destroy(b64);
+            byte_vector_destroy(decoded);
+            byte_vector_destroy(bytes);
+
+            let invalid = CString::new("xyz").unwrap();
+            assert!(hex_decode(invalid.as_ptr(), &mut error).is_null());
+            assert_eq!(error, 202);
+            assert!(base64_decode(invalid.as_ptr(), &mut error).is_null());
+            assert_eq!(error, 403);
+            assert!(hex_decode(ptr::null(), &mut error).is_null());
+            assert_eq!(error, FfiErrorCode::NullPointer as i32);
+            assert!(hex_encode(ptr::null(), &mut error).is_null());
+            assert_eq!(error, FfiErrorCode::NullPointer as i32);
+            string_destroy(ptr::null_mut());
+        }
+    }
+}
@@ -22,8 +22,49 @@
 
 //! Data structure describing a fixed set of size _n_.
 
-use alloc::vec::Vec;
-use core::ops::Add;
+use alloc::vec::{self, Vec};
+use core::{iter::FromIterator, ops::Add, slice};
+
+#[cfg(feature = "serde")]
+use serde::{de::Error as _, Deserialize, Deserializer, Serialize, Serializer};
+use snafu::prelude::*;
+
+/// Errors that can occur when building a [FixedSet].
+#[derive(Debug, Snafu, PartialEq, Eq)]
+pub enum FixedSetError {
+    /// More items were provided than the set can hold.
+    #[snafu(display("More than {capacity} items were provided to a fixed set of size {capacity}"))]
+    CapacityExceeded {
+        /// The size of the fixed set
+        capacity: usize,
+    },
+    /// The declared size of the set does not match the number of positions provided.
+    #[snafu(display("A fixed set of size {size} cannot hold {len} positions"))]
+    SizeMismatch {
+        /// The declared size of the fixed set
+        size: usize,
+        /// The number of positions provided
+        len: usize,
+    },
+    /// Shrinking the set would discard an item that has been set.
+    #[snafu(display("Cannot shrink the set: position {index} is occupied"))]
+    OccupiedPosition {
+        /// The first occupied position that would be discarded
+        index: usize,
+    },
+    /// The same item appears in more than one position.
+    #[snafu(display("The item at position {index} duplicates an earlier item"))]
+    DuplicateItem {
+        /// The position of the duplicate item
+        index: usize,
+    },
+    /// Two sets hold different items at the same position.
+    #[snafu(display("The sets hold different items at position {index}"))]
+    PositionConflict {
+        /// The conflicting position
+        index: usize,
+    },
+}
 
 /// Data structure for fixed set of size _n_.
 #[derive(Clone, Debug)]
@@ -71,6 +112,30 @@ impl<T: Clone + PartialEq + Default> FixedSet<T> {
         self.items.iter().all(Option::is_some)
     }
 
+    /// Returns the number of positions that have been set.
+    pub fn count(&self) -> usize {
+        self.items.iter().filter(|item| item.is_some()).count()
+    }
+
+    /// Returns true if at least `threshold` positions have been set. A threshold of zero is always met.
+    pub fn is_quorum(&self, threshold: usize) -> bool {
+        self.count() >= threshold
+    }
+
+    /// Returns the positions that have not been set yet, in ascending order.
+    pub fn missing_indices(&self) -> Vec<usize> {
+        self.items
+            .iter()
+            .enumerate()
+            .filter_map(|(index, item)| item.is_none().then_some(index))
+            .collect()
+    }
+
+    /// Returns the lowest position that has not been set yet, or `None` if the set is full.
+    pub fn first_vacancy(&self) -> Option<usize> {
+        self.items.iter().position(Option::is_none)
+    }
+
     /// Return the first index of the given item in the set by performing a linear search through the set.
     pub fn search(&self, val: &T) -> Option<usize> {
         self.items
@@ -91,8 +156,7 @@ impl<T: Clone + PartialEq + Default> FixedSet<T> {
         }
         let mut iter = self.items.iter().filter_map(Option::as_ref);
         // Take the first item
-        // unwrap wont fail as we know there is a first item.
-        let mut sum = iter.next().unwrap().clone();
+        let mut sum = iter.next()?.clone();
         for v in iter {
             sum = &sum + v;
         }
@@ -106,8 +170,331 @@ impl<T: Clone + PartialEq + Default> FixedSet<T> {
 
     /// Returns an iterator that yields exactly `n` elements of the FixedSet. An element may be not be set which yields
     /// a `None`.
-    pub fn iter(&self) -> impl Iterator<Item = Option<&T>> + '_ {
-        self.items.iter().map(|e| e.as_ref())
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter {
+            inner: self.items.iter(),
+        }
+    }
+
+    /// Creates a fixed set of size `n`, filling it in order from `iter`. Fails if `iter` yields more than `n` items.
+    /// Positions beyond the end of `iter` are left unset.
+    pub fn try_from_iter<I: IntoIterator<Item = T>>(n: usize, iter: I) -> Result<FixedSet<T>, FixedSetError> {
+        let mut set = FixedSet::new(n);
+        for (index, val) in iter.into_iter().enumerate() {
+            if !set.set_item(index, val) {
+                return Err(FixedSetError::CapacityExceeded { capacity: n });
+            }
+        }
+        Ok(set)
+    }
+
+    /// Creates a fixed set of size `n`, filling it in order from `iter`. Any items beyond the first `n` are discarded.
+    /// Positions beyond the end of `iter` are left unset.
+    pub fn from_iter_truncated<I: IntoIterator<Item = T>>(n: usize, iter: I) -> FixedSet<T> {
+        let mut set = FixedSet::new(n);
+        for (index, val) in iter.into_iter().take(n).enumerate() {
+            set.set_item(index, val);
+        }
+        set
+    }
+
+    /// Returns true if `val` has been set at any position.
+    pub fn contains(&self, val: &T) -> bool {
+        self.search(val).is_some()
+    }
+
+    /// Returns a set of the same size as `self` containing every item in either set, each at its original position.
+    /// Fails if the sets hold different items at the same position, if an item would appear at more than one
+    /// position, or if `other` holds an item beyond the size of `self`.
+    pub fn union(&self, other: &FixedSet<T>) -> Result<FixedSet<T>, FixedSetError> {
+        if other.items.iter().skip(self.size()).any(Option::is_some) {
+            return Err(FixedSetError::CapacityExceeded { capacity: self.size() });
+        }
+        let mut result = self.clone();
+        for (index, val) in other.items.iter().enumerate() {
+            let val = match val {
+                Some(val) => val,
+                None => continue,
+            };
+            match &result.items[index] {
+                Some(existing) if existing == val => {},
+                Some(_) => return Err(FixedSetError::PositionConflict { index }),
+                None => result.items[index] = Some(val.clone()),
+            }
+        }
+        for (index, item) in result.items.iter().enumerate() {
+            if let Some(item) = item {
+                if result.items[..index].iter().flatten().any(|earlier| earlier == item) {
+                    return Err(FixedSetError::DuplicateItem { index });
+                }
+            }
+        }
+        Ok(result)
+    }
+
+    /// Returns a set of the same size containing the items of `self` that are also in `other`, at their original
+    /// positions.
+    pub fn intersection(&self, other: &FixedSet<T>) -> FixedSet<T> {
+        self.retain(|val| other.contains(val))
+    }
+
+    /// Returns a set of the same size containing the items of `self` that are not in `other`, at their original
+    /// positions.
+    pub fn difference(&self, other: &FixedSet<T>) -> FixedSet<T> {
+        self.retain(|val| !other.contains(val))
+    }
+
+    /// Returns true if every item in `self` is also in `other`, regardless of position or size.
+    pub fn is_subset(&self, other: &FixedSet<T>) -> bool {
+        self.items.iter().flatten().all(|val| other.contains(val))
+    }
+
+    fn retain<F: Fn(&T) -> bool>(&self, keep: F) -> FixedSet<T> {
+        FixedSet {
+            items: self
+                .items
+                .iter()
+                .map(|item| item.as_ref().filter(|val| keep(val)).cloned())
+                .collect(),
+        }
+    }
+}
+
+/// An iterator over the positions of a [FixedSet], yielding `None` for positions that have not been set.
+#[derive(Clone, Debug)]
+pub struct Iter<'a, T> {
+    inner: slice::Iter<'a, Option<T>>,
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = Option<&'a T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next().map(Option::as_ref)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<T> DoubleEndedIterator for Iter<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.inner.next_back().map(Option::as_ref)
+    }
+}
+
+impl<T> ExactSizeIterator for Iter<'_, T> {}
+
+impl<'a, T: Clone + PartialEq + Default> IntoIterator for &'a FixedSet<T> {
+    type IntoIter = Iter<'a, T>;
+    type Item = Option<&'a T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<T> IntoIterator for FixedSet<T> {
+    type IntoIter = vec::IntoIter<Option<T>>;
+    type Item = Option<T>;
+
+    /// Consumes the set, yielding exactly `n` elements, with `None` for positions that have not been set.
+    fn into_iter(self) -> Self::IntoIter {
+        self.items.into_iter()
+    }
+}
+
+/// Collects every item into a full fixed set whose size is the number of items collected. Use
+/// [FixedSet::try_from_iter] or [FixedSet::from_iter_truncated] to collect into a set of a given size.
+impl<T> FromIterator<T> for FixedSet<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        FixedSet {
+            items: iter.into_iter().map(Some).collect(),
+        }
+    }
+}
+
+/// A set like [FixedSet], but whose capacity can be changed after construction. It offers the read-only methods of
+/// [FixedSet], and [set_item](BoundedSet::set_item) and [clear_item](BoundedSet::clear_item), which respect the
+/// capacity.
+///
+/// Growing the set adds vacant positions at the end. Shrinking with [resize](BoundedSet::resize) fails if an occupied
+/// position would be discarded; use [resize_truncating](BoundedSet::resize_truncating) to discard them instead.
+///
+/// ```edition2018
+/// # use tari_utilities::fixed_set::BoundedSet;
+/// let mut committee = BoundedSet::<u32>::new(2);
+/// committee.set_item(0, 10);
+/// committee.set_item(1, 11);
+/// assert!(committee.is_full());
+///
+/// committee.resize(3).unwrap();
+/// assert_eq!(committee.capacity(), 3);
+/// assert!(!committee.is_full());
+/// assert!(committee.resize(1).is_err());
+/// assert_eq!(committee.resize_truncating(1), vec![11]);
+/// ```
+#[derive(Clone, Debug)]
+pub struct BoundedSet<T> {
+    set: FixedSet<T>,
+}
+
+impl<T: Clone + PartialEq + Default> BoundedSet<T> {
+    /// Creates a new set with the given initial capacity.
+    pub fn new(capacity: usize) -> BoundedSet<T> {
+        BoundedSet {
+            set: FixedSet::new(capacity),
+        }
+    }
+
+    /// Returns the current capacity of the set. This is the same as [size](FixedSet::size).
+    pub fn capacity(&self) -> usize {
+        self.set.size()
+    }
+
+    /// Changes the capacity of the set. Fails, leaving the set unchanged, if shrinking would discard an occupied
+    /// position.
+    pub fn resize(&mut self, capacity: usize) -> Result<(), FixedSetError> {
+        if let Some(index) = (capacity..self.set.size()).find(|&i| self.set.items[i].is_some()) {
+            return Err(FixedSetError::OccupiedPosition { index });
+        }
+        self.set.items.resize(capacity, None);
+        Ok(())
+    }
+
+    /// Changes the capacity of the set, returning any items in discarded positions.
+    pub fn resize_truncating(&mut self, capacity: usize) -> Vec<T> {
+        let discarded = if capacity < self.set.size() {
+            self.set.items.split_off(capacity).into_iter().flatten().collect()
+        } else {
+            Vec::new()
+        };
+        self.set.items.resize(capacity, None);
+        discarded
+    }
+
+    /// Converts into a [FixedSet] of the current capacity.
+    pub fn into_fixed(self) -> FixedSet<T> {
+        self.set
+    }
+
+    /// Borrows the set as a [FixedSet] of the current capacity, for read-only use.
+    pub fn as_fixed(&self) -> &FixedSet<T> {
+        &self.set
+    }
+
+    /// Set the `index`-th item to `val`, as for [FixedSet::set_item]. Fails if `index` is not below the capacity.
+    pub fn set_item(&mut self, index: usize, val: T) -> bool {
+        self.set.set_item(index, val)
+    }
+
+    /// Delete the `index`-th item, as for [FixedSet::clear_item].
+    pub fn clear_item(&mut self, index: usize) {
+        self.set.clear_item(index);
+    }
+
+    /// Return a reference to the `index`-th item, or `None` if that item has not been set yet.
+    pub fn get_item(&self, index: usize) -> Option<&T> {
+        self.set.get_item(index)
+    }
+
+    /// Returns true if every position has been set, as for [FixedSet::is_full].
+    pub fn is_full(&self) -> bool {
+        self.set.is_full()
+    }
+
+    /// Returns the number of positions that have been set.
+    pub fn count(&self) -> usize {
+        self.set.count()
+    }
+
+    /// Returns true if at least `threshold` positions have been set.
+    pub fn is_quorum(&self, threshold: usize) -> bool {
+        self.set.is_quorum(threshold)
+    }
+
+    /// Returns the positions that have not been set yet, in ascending order.
+    pub fn missing_indices(&self) -> Vec<usize> {
+        self.set.missing_indices()
+    }
+
+    /// Returns the lowest position that has not been set yet, or `None` if the set is full.
+    pub fn first_vacancy(&self) -> Option<usize> {
+        self.set.first_vacancy()
+    }
+
+    /// Return the first index of the given item in the set.
+    pub fn search(&self, val: &T) -> Option<usize> {
+        self.set.search(val)
+    }
+
+    /// Returns true if the item has been set at any position.
+    pub fn contains(&self, val: &T) -> bool {
+        self.set.contains(val)
+    }
+
+    /// Returns an iterator over every position, as for [FixedSet::iter].
+    pub fn iter(&self) -> Iter<'_, T> {
+        self.set.iter()
+    }
+}
+
+impl<T> From<FixedSet<T>> for BoundedSet<T> {
+    fn from(set: FixedSet<T>) -> Self {
+        BoundedSet { set }
+    }
+}
+
+impl<T> From<BoundedSet<T>> for FixedSet<T> {
+    fn from(set: BoundedSet<T>) -> Self {
+        set.set
+    }
+}
+
+/// Sets are serialized as their size together with every position, using `None` for positions that have not been set.
+#[cfg(feature = "serde")]
+impl<T: Serialize> Serialize for FixedSet<T> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where S: Serializer {
+        #[derive(Serialize)]
+        struct FixedSetRef<'a, T> {
+            size: usize,
+            items: &'a [Option<T>],
+        }
+
+        FixedSetRef {
+            size: self.items.len(),
+            items: &self.items,
+        }
+        .serialize(serializer)
+    }
+}
+
+/// Deserialization fails if the number of positions does not match the declared size, or if any item appears more than
+/// once.
+#[cfg(feature = "serde")]
+impl<'de, T: Deserialize<'de> + PartialEq> Deserialize<'de> for FixedSet<T> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where D: Deserializer<'de> {
+        #[derive(Deserialize)]
+        struct FixedSetOwned<T> {
+            size: usize,
+            items: Vec<Option<T>>,
+        }
+
+        let FixedSetOwned { size, items } = FixedSetOwned::deserialize(deserializer)?;
+        if items.len() != size {
+            return Err(D::Error::custom(FixedSetError::SizeMismatch { size, len: items.len() }));
+        }
+        for (index, item) in items.iter().enumerate() {
+            if let Some(item) = item {
+                if items[..index].iter().flatten().any(|earlier| earlier == item) {
+                    return Err(D::Error::custom(FixedSetError::DuplicateItem { index }));
+                }
+            }
+        }
+        Ok(FixedSet { items })
     }
 }
 
@@ -215,6 +602,167 @@ mod test {
         assert_eq!(elems, vec![Some(&3), Some(&1), None, Some(&2), None]);
     }
 
+    #[test]
+    fn into_iterators() {
+        let mut s = FixedSet::<usize>::new(3);
+        s.set_item(0, 3);
+        s.set_item(2, 1);
+        let mut count = 0;
+        for item in &s {
+            count += item.copied().unwrap_or(0);
+        }
+        assert_eq!(count, 4);
+        assert_eq!(s.iter().len(), 3);
+        assert_eq!(s.iter().next_back(), Some(Some(&1)));
+        assert_eq!(s.into_iter().collect::<Vec<_>>(), vec![Some(3), None, Some(1)]);
+    }
+
+    #[test]
+    fn collect() {
+        let s = (1..=3usize).collect::<FixedSet<_>>();
+        assert_eq!(s.size(), 3);
+        assert!(s.is_full());
+        assert_eq!(s.sum(), Some(6));
+
+        let s = FixedSet::try_from_iter(4, 1..=3usize).unwrap();
+        assert_eq!(s.size(), 4);
+        assert!(!s.is_full());
+        assert_eq!(s.get_item(2), Some(&3));
+        assert_eq!(
+            FixedSet::try_from_iter(2, 1..=3usize).unwrap_err(),
+            FixedSetError::CapacityExceeded { capacity: 2 }
+        );
+
+        let s = FixedSet::from_iter_truncated(2, 1..=3usize);
+        assert!(s.is_full());
+        assert_eq!(s.into_vec(), vec![1, 2]);
+    }
+
+    #[test]
+    fn quorum() {
+        let mut s = FixedSet::<usize>::new(4);
+        assert_eq!(s.count(), 0);
+        assert!(s.is_quorum(0));
+        assert!(!s.is_quorum(1));
+        assert_eq!(s.first_vacancy(), Some(0));
+        s.set_item(0, 1);
+        s.set_item(2, 3);
+        assert_eq!(s.count(), 2);
+        assert!(s.is_quorum(2));
+        assert!(!s.is_quorum(3));
+        assert_eq!(s.missing_indices(), vec![1, 3]);
+        assert_eq!(s.first_vacancy(), Some(1));
+        s.set_item(1, 2);
+        s.set_item(3, 4);
+        assert!(s.is_quorum(4));
+        assert!(!s.is_quorum(5));
+        assert!(s.missing_indices().is_empty());
+        assert_eq!(s.first_vacancy(), None);
+    }
+
+    #[test]
+    fn set_algebra() {
+        let a = FixedSet::from_iter_truncated(4, vec![1usize, 2, 3]);
+        let b = FixedSet::try_from_iter(3, vec![3usize, 4]).unwrap();
+
+        let mut c = FixedSet::<usize>::new(4);
+        c.set_item(2, 3);
+        c.set_item(3, 4);
+        let union = a.union(&c).unwrap();
+        assert_eq!(union.iter().collect::<Vec<_>>(), vec![
+            Some(&1),
+            Some(&2),
+            Some(&3),
+            Some(&4)
+        ]);
+        assert_eq!(b.union(&c).unwrap_err(), FixedSetError::CapacityExceeded {
+            capacity: 3
+        });
+        assert_eq!(b.union(&a).unwrap_err(), FixedSetError::PositionConflict { index: 0 });
+        let mut moved = FixedSet::<usize>::new(4);
+        moved.set_item(3, 1);
+        assert_eq!(a.union(&moved).unwrap_err(), FixedSetError::DuplicateItem { index: 3 });
+        let mut conflict = FixedSet::<usize>::new(4);
+        conflict.set_item(1, 5);
+        assert_eq!(a.union(&conflict).unwrap_err(), FixedSetError::PositionConflict {
+            index: 1
+        });
+
+        let intersection = a.intersection(&b);
+        assert_eq!(intersection.size(), 4);
+        assert_eq!(intersection.iter().collect::<Vec<_>>(), vec![
+            None,
+            None,
+            Some(&3),
+            None
+        ]);
+
+        let difference = a.difference(&b);
+        assert_eq!(difference.into_vec(), vec![1, 2]);
+
+        assert!(intersection.is_subset(&a));
+        assert!(intersection.is_subset(&b));
+        assert!(!a.is_subset(&b));
+        assert!(a.is_subset(&union));
+        assert!(FixedSet::<usize>::new(2).is_subset(&b));
+    }
+
+    #[test]
+    fn bounded_set() {
+        let mut s = BoundedSet::<usize>::new(2);
+        assert!(s.set_item(0, 1));
+        assert!(!s.set_item(2, 3));
+        s.resize(4).unwrap();
+        assert_eq!(s.capacity(), 4);
+        assert!(s.set_item(2, 3));
+        assert_eq!(s.iter().collect::<Vec<_>>(), vec![Some(&1), None, Some(&3), None]);
+
+        assert_eq!(s.resize(2), Err(FixedSetError::OccupiedPosition { index: 2 }));
+        assert_eq!(s.capacity(), 4);
+        s.resize(3).unwrap();
+        assert_eq!(s.capacity(), 3);
+
+        assert_eq!(s.resize_truncating(1), vec![3]);
+        assert!(s.is_full());
+        assert!(s.resize_truncating(2).is_empty());
+        let fixed: FixedSet<usize> = s.into();
+        assert_eq!(fixed.size(), 2);
+        assert_eq!(BoundedSet::from(fixed).into_fixed().into_vec(), vec![1]);
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serde() {
+        let mut s = FixedSet::<usize>::new(3);
+        s.set_item(0, 7);
+        s.set_item(2, 9);
+        let json = serde_json::to_string(&s).unwrap();
+        assert_eq!(json, r#"{"size":3,"items":[7,null,9]}"#);
+        let decoded: FixedSet<usize> = serde_json::from_str(&json).unwrap();
+        assert_eq!(decoded.iter().collect::<Vec<_>>(), s.iter().collect::<Vec<_>>());
+
+        let decoded: FixedSet<usize> = bincode::deserialize(&bincode::serialize(&s).unwrap()).unwrap();
+        assert_eq!(decoded.into_vec(), vec![7, 9]);
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serde_rejects_size_mismatch() {
+        use alloc::string::ToString;
+
+        let err = serde_json::from_str::<FixedSet<usize>>(r#"{"size":2,"items":[7,null,9]}"#).unwrap_err();
+        assert!(err.to_string().contains("size 2 cannot hold 3"));
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serde_rejects_duplicates() {
+        use alloc::string::ToString;
+
+        let err = serde_json::from_str::<FixedSet<usize>>(r#"{"size":3,"items":[7,null,7]}"#).unwrap_err();
+        assert!(err.to_string().contains("position 2 duplicates"));
+    }
+
     #[test]
     fn into_vec() {
         let mut s = FixedSet::<usize>::new(5);
@@ -0,0 +1,286 @@
+// Copyright 2026, The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+//! Display helpers for numbers and long values in logs and user interfaces.
+//!
+//! * [Grouped] and [format_grouped] separate the digits of large integers into groups of three.
+//! * [truncate_middle] elides the middle of long identifiers such as hashes, keys and addresses.
+
+use alloc::string::{String, ToString};
+use core::fmt;
+
+/// Format `value` with `separator` between groups of three digits, e.g. `1,234,567`.
+///
+/// ```edition2018
+/// # use tari_utilities::format::format_grouped;
+/// assert_eq!(format_grouped(1_234_567, ','), "1,234,567");
+/// assert_eq!(format_grouped(999, '_'), "999");
+/// ```
+pub fn format_grouped(value: u128, separator: char) -> String {
+    Grouped::from(value).with_separator(separator).to_string()
+}
+
+/// A [Display](fmt::Display) adaptor that writes an integer with its digits in groups of three.
+///
+/// Grouping is locale-agnostic: the separator is `,` unless set with [Grouped::with_separator], and is applied every
+/// three digits. Formatter width, fill and alignment apply to the whole grouped number.
+///
+/// ```edition2018
+/// # use tari_utilities::format::Grouped;
+/// assert_eq!(Grouped::from(-1_234_567i64).to_string(), "-1,234,567");
+/// assert_eq!(
+///     format!("{:>12}", Grouped::from(1_000_000u32).with_separator(' ')),
+///     "   1 000 000"
+/// );
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Grouped {
+    negative: bool,
+    magnitude: u128,
+    separator: char,
+}
+
+impl Grouped {
+    /// The default group separator
+    pub const DEFAULT_SEPARATOR: char = ',';
+
+    /// Use `separator` between groups of digits.
+    #[must_use]
+    pub fn with_separator(mut self, separator: char) -> Self {
+        self.separator = separator;
+        self
+    }
+}
+
+macro_rules! grouped_from_unsigned {
+    ($($t:ty),*) => {$(
+        impl From<$t> for Grouped {
+            fn from(value: $t) -> Self {
+                Self { negative: false, magnitude: u128::from(value), separator: Grouped::DEFAULT_SEPARATOR }
+            }
+        }
+    )*};
+}
+
+macro_rules! grouped_from_signed {
+    ($($t:ty),*) => {$(
+        impl From<$t> for Grouped {
+            fn from(value: $t) -> Self {
+                Self {
+                    negative: value < 0,
+                    magnitude: u128::from(value.unsigned_abs()),
+                    separator: Grouped::DEFAULT_SEPARATOR,
+                }
+            }
+        }
+    )*};
+}
+
+grouped_from_unsigned!(u8, u16, u32, u64, u128);
+grouped_from_signed!(i8, i16, i32, i64, i128);
+
+impl From<usize> for Grouped {
+    fn from(value: usize) -> Self {
+        Grouped::from(value as u128)
+    }
+}
+
+impl From<isize> for Grouped {
+    fn from(value: isize) -> Self {
+        Grouped::from(value as i128)
+    }
+}
+
+impl fmt::Display for Grouped {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // u128::MAX has 39 digits, so 12 separators of up to 4 bytes each and a sign fit comfortably
+        let mut buf = [0u8; 96];
+        let mut pos = buf.len();
+        let mut value = self.magnitude;
+        let mut separator = [0u8; 4];
+        let separator = self.separator.encode_utf8(&mut separator).as_bytes();
+        let mut digits = 0;
+        loop {
+            if digits > 0 && digits % 3 == 0 {
+                pos -= separator.len();
+                buf[pos..pos + separator.len()].copy_from_slice(separator);
+            }
+            pos -= 1;
+            buf[pos] = b'0' + (value % 10) as u8;
+            value /= 10;
+            digits += 1;
+            if value == 0 {
+                break;
+            }
+        }
+        if self.negative {
+            pos -= 1;
+            buf[pos] = b'-';
+        }
+        // Only ASCII digits, the sign and the UTF-8 encoding of a char have been written
+        let s = core::str::from_utf8(&buf[pos..]).map_err(|_| fmt::Error)?;
+        f.pad(s)
+    }
+}
+
+/// The ellipsis used by [truncate_middle]
+pub const ELLIPSIS: char = '…';
+
+/// Values that can be shown with the middle elided, such as hashes, keys and addresses.
+///
+/// Strings are truncated by character. Byte slices are shown as lower case hex and truncated by hex digit.
+pub trait TruncateMiddle {
+    /// Return a [Display](fmt::Display) adaptor showing the first `head` and last `tail` characters, joined by
+    /// [ELLIPSIS]. The value is shown in full if that would be no longer.
+    fn truncate_middle(&self, head: usize, tail: usize) -> TruncatedMiddle<'_>;
+}
+
+impl TruncateMiddle for str {
+    fn truncate_middle(&self, head: usize, tail: usize) -> TruncatedMiddle<'_> {
+        TruncatedMiddle {
+            value: Truncatable::Str(self),
+            head,
+            tail,
+        }
+    }
+}
+
+impl TruncateMiddle for [u8] {
+    fn truncate_middle(&self, head: usize, tail: usize) -> TruncatedMiddle<'_> {
+        TruncatedMiddle {
+            value: Truncatable::Bytes(self),
+            head,
+            tail,
+        }
+    }
+}
+
+/// Shorten `value` to its first `head` and last `tail` characters, joined by [ELLIPSIS].
+///
+/// ```edition2018
+/// # use tari_utilities::format::truncate_middle;
+/// assert_eq!(
+///     truncate_middle("ab12cd34ef5689ef", 6, 4).to_string(),
+///     "ab12cd…89ef"
+/// );
+/// let bytes = [0xab, 0x12, 0xcd, 0x34, 0xef, 0x56, 0x89, 0xef];
+/// assert_eq!(truncate_middle(&bytes[..], 6, 4).to_string(), "ab12cd…89ef");
+/// assert_eq!(truncate_middle("short", 6, 4).to_string(), "short");
+/// ```
+pub fn truncate_middle<T: TruncateMiddle + ?Sized>(value: &T, head: usize, tail: usize) -> TruncatedMiddle<'_> {
+    value.truncate_middle(head, tail)
+}
+
+/// A [Display](fmt::Display) adaptor created by [truncate_middle]. Formatter width, fill and alignment apply to the
+/// shortened value.
+#[derive(Clone, Copy, Debug)]
+pub struct TruncatedMiddle<'a> {
+    value: Truncatable<'a>,
+    head: usize,
+    tail: usize,
+}
+
+#[derive(Clone, Copy, Debug)]
+enum Truncatable<'a> {
+    Str(&'a str),
+    Bytes(&'a [u8]),
+}
+
+impl fmt::Display for TruncatedMiddle<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut hex = String::new();
+        let value = match self.value {
+            Truncatable::Str(s) => s,
+            Truncatable::Bytes(bytes) => {
+                crate::hex::to_hex_into(bytes, &mut hex);
+                hex.as_str()
+            },
+        };
+        let len = value.chars().count();
+        if len <= self.head.saturating_add(self.tail).saturating_add(1) {
+            return f.pad(value);
+        }
+        let mut s = String::with_capacity(value.len());
+        s.extend(value.chars().take(self.head));
+        s.push(ELLIPSIS);
+        s.extend(value.chars().skip(len - self.tail));
+        f.pad(&s)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn grouping() {
+        assert_eq!(format_grouped(0, ','), "0");
+        assert_eq!(format_grouped(12, ','), "12");
+        assert_eq!(format_grouped(123, ','), "123");
+        assert_eq!(format_grouped(1234, ','), "1,234");
+        assert_eq!(format_grouped(123_456, '.'), "123.456");
+        assert_eq!(format_grouped(1_234_567, '\''), "1'234'567");
+        assert_eq!(
+            format_grouped(u128::MAX, ','),
+            "340,282,366,920,938,463,463,374,607,431,768,211,455"
+        );
+        assert_eq!(format_grouped(1_000_000, '\u{202f}'), "1\u{202f}000\u{202f}000");
+    }
+
+    #[test]
+    fn adaptor() {
+        assert_eq!(
+            Grouped::from(i128::MIN).to_string(),
+            "-170,141,183,460,469,231,731,687,303,715,884,105,728"
+        );
+        assert_eq!(Grouped::from(-1i8).to_string(), "-1");
+        assert_eq!(Grouped::from(-999i16).to_string(), "-999");
+        assert_eq!(
+            Grouped::from(u64::MAX).with_separator('_').to_string(),
+            "18_446_744_073_709_551_615"
+        );
+        assert_eq!(format!("{:<8}|", Grouped::from(12345usize)), "12,345  |");
+        assert_eq!(format!("{:*^9}", Grouped::from(-1000isize)), "*-1,000**");
+    }
+
+    #[test]
+    fn truncation() {
+        let hash = "ab12cd34ef5689ef";
+        assert_eq!(truncate_middle(hash, 6, 4).to_string(), "ab12cd…89ef");
+        assert_eq!(hash.truncate_middle(0, 4).to_string(), "…89ef");
+        assert_eq!(hash.truncate_middle(4, 0).to_string(), "ab12…");
+        assert_eq!(hash.truncate_middle(8, 7).to_string(), hash);
+        assert_eq!(hash.truncate_middle(8, 6).to_string(), "ab12cd34…5689ef");
+        assert_eq!(hash.truncate_middle(usize::MAX, usize::MAX).to_string(), hash);
+        assert_eq!("été à la plage".truncate_middle(2, 2).to_string(), "ét…ge");
+        assert_eq!(format!("[{:>8}]", hash.truncate_middle(2, 2)), "[   ab…ef]");
+    }
+
+    #[test]
+    fn truncation_of_bytes() {
+        let bytes = [0xab, 0x12, 0xcd, 0x34, 0xef, 0x56, 0x89, 0xef];
+        assert_eq!(bytes[..].truncate_middle(6, 4).to_string(), "ab12cd…89ef");
+        assert_eq!(truncate_middle(&bytes[..2], 1, 1).to_string(), "a…2");
+        assert_eq!(truncate_middle(&bytes[..1], 1, 1).to_string(), "ab");
+        assert_eq!(truncate_middle(&[][..], 1, 1).to_string(), "");
+    }
+}
@@ -0,0 +1,304 @@
+// Copyright 2026, The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+//! Heap storage surrounded by guard pages, for secrets.
+//!
+//! A [GuardedBox] places its value in its own memory mapping, between two inaccessible guard pages, with the value
+//! pushed up against the trailing guard page and a random canary written just before it. Reading or writing past the
+//! end of the value faults immediately, turning an overread of key material into a crash instead of a leak, and an
+//! underflow that reaches the canary aborts the process when the box is dropped. This follows the approach of
+//! libsodium's `sodium_malloc` and the `memsec` crate.
+//!
+//! Each box costs at least three pages of address space, so it is only suitable for small, long-lived secrets. With
+//! the `guarded` feature, [Hidden](crate::hidden::Hidden) and [SafeArray](crate::safe_array::SafeArray) use it for
+//! their storage. It is only available on Unix.
+//!
+//! ```edition2018
+//! # use tari_utilities::guarded::GuardedBox;
+//! let mut key = GuardedBox::new([0u8; 32]);
+//! key[0] = 1;
+//! assert_eq!(key[..2], [1, 0]);
+//!
+//! let seed = GuardedBox::<[u8]>::from_fn(64, |i| i as u8);
+//! assert_eq!(seed.len(), 64);
+//! ```
+
+use core::{
+    convert::TryFrom,
+    fmt,
+    marker::PhantomData,
+    ops::{Deref, DerefMut},
+    ptr::{self, NonNull},
+};
+use std::{
+    alloc::{handle_alloc_error, Layout},
+    collections::hash_map::RandomState,
+    hash::{BuildHasher, Hasher},
+    process::abort,
+    sync::OnceLock,
+};
+
+const CANARY_SIZE: usize = 16;
+
+/// A random canary, chosen once per process so that it cannot be predicted and written back by an overflow
+fn canary() -> &'static [u8; CANARY_SIZE] {
+    static CANARY: OnceLock<[u8; CANARY_SIZE]> = OnceLock::new();
+    CANARY.get_or_init(|| {
+        let state = RandomState::new();
+        let mut canary = [0u8; CANARY_SIZE];
+        for (i, chunk) in canary.chunks_mut(8).enumerate() {
+            let mut hasher = state.build_hasher();
+            hasher.write_usize(i);
+            chunk.copy_from_slice(&hasher.finish().to_le_bytes());
+        }
+        canary
+    })
+}
+
+fn page_size() -> usize {
+    // SAFETY: `sysconf` has no preconditions
+    let size = unsafe { libc::sysconf(libc::_SC_PAGESIZE) };
+    usize::try_from(size).unwrap_or(4096)
+}
+
+/// A memory mapping laid out as a guard page, a canary, the data, and a second guard page
+struct Region {
+    base: NonNull<u8>,
+    total: usize,
+    data: NonNull<u8>,
+}
+
+impl Region {
+    /// Map a region for `layout`, aborting through [handle_alloc_error] if it cannot be mapped.
+    fn map(layout: Layout) -> Self {
+        let page = page_size();
+        let inner = layout
+            .size()
+            .checked_add(CANARY_SIZE + layout.align())
+            .and_then(|size| size.checked_next_multiple_of(page));
+        let total = inner.and_then(|inner| inner.checked_add(2 * page));
+        let (inner, total) = match (inner, total) {
+            (Some(inner), Some(total)) => (inner, total),
+            _ => handle_alloc_error(layout),
+        };
+        // SAFETY: an anonymous private mapping with no address hint has no preconditions
+        let base = unsafe {
+            libc::mmap(
+                ptr::null_mut(),
+                total,
+                libc::PROT_READ | libc::PROT_WRITE,
+                libc::MAP_PRIVATE | libc::MAP_ANON,
+                -1,
+                0,
+            )
+        };
+        if base == libc::MAP_FAILED {
+            handle_alloc_error(layout);
+        }
+        let base = base.cast::<u8>();
+        // SAFETY: both guard pages lie within the mapping, and are page aligned because the mapping is
+        unsafe {
+            if libc::mprotect(base.cast(), page, libc::PROT_NONE) != 0 ||
+                libc::mprotect(base.add(page + inner).cast(), page, libc::PROT_NONE) != 0
+            {
+                libc::munmap(base.cast(), total);
+                handle_alloc_error(layout);
+            }
+        }
+        // End the data at the trailing guard page. Sizes are multiples of their alignment, so rounding the address
+        // down to the alignment keeps the end of the data against the guard page, unless the alignment is larger than
+        // a page. The mapping is only page aligned, so the address rather than the offset is rounded, and the extra
+        // `layout.align()` bytes in `inner` leave room for the canary whichever way it rounds.
+        let end = base as usize + page + inner;
+        let offset = ((end - layout.size()) & !(layout.align() - 1)) - base as usize;
+        // SAFETY: `offset` is within the mapping, and at least `CANARY_SIZE` bytes past the leading guard page
+        unsafe {
+            let data = base.add(offset);
+            ptr::copy_nonoverlapping(canary().as_ptr(), data.sub(CANARY_SIZE), CANARY_SIZE);
+            Region {
+                base: NonNull::new_unchecked(base),
+                total,
+                data: NonNull::new_unchecked(data),
+            }
+        }
+    }
+
+    /// Check the canary, aborting the process if it has been overwritten, and unmap the region.
+    fn unmap(&mut self) {
+        // SAFETY: the canary was written before the data when the region was mapped, and the region is still mapped
+        let intact = unsafe {
+            let start = self.data.as_ptr().sub(CANARY_SIZE);
+            core::slice::from_raw_parts(start, CANARY_SIZE) == canary()
+        };
+        if !intact {
+            abort();
+        }
+        // SAFETY: the region was mapped by `map` with this base and length
+        unsafe {
+            libc::munmap(self.base.as_ptr().cast(), self.total);
+        }
+    }
+}
+
+/// An owned value stored between guard pages. It dereferences to the value, like a `Box`.
+pub struct GuardedBox<T: ?Sized> {
+    value: NonNull<T>,
+    region: Region,
+    marker: PhantomData<T>,
+}
+
+// SAFETY: the box owns its value exclusively, as a `Box` does
+unsafe impl<T: ?Sized + Send> Send for GuardedBox<T> {}
+// SAFETY: shared access only hands out shared references to the value
+unsafe impl<T: ?Sized + Sync> Sync for GuardedBox<T> {}
+
+impl<T> GuardedBox<T> {
+    /// Move `value` into new guarded storage.
+    pub fn new(value: T) -> Self {
+        let region = Region::map(Layout::new::<T>());
+        let data = region.data.cast::<T>();
+        // SAFETY: the data is suitably sized and aligned for `T`
+        unsafe { data.as_ptr().write(value) };
+        Self {
+            value: data,
+            region,
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<T> GuardedBox<[T]> {
+    /// Create guarded storage for a slice of `len` items, setting each item to `f(index)`. If `f` panics, the items
+    /// created so far and the storage are leaked.
+    pub fn from_fn<F>(len: usize, mut f: F) -> Self
+    where F: FnMut(usize) -> T {
+        let layout = Layout::array::<T>(len).unwrap_or_else(|_| handle_alloc_error(Layout::new::<T>()));
+        let region = Region::map(layout);
+        let data = region.data.cast::<T>();
+        for i in 0..len {
+            // SAFETY: the data has room for `len` suitably aligned items
+            unsafe { data.as_ptr().add(i).write(f(i)) };
+        }
+        Self {
+            value: NonNull::slice_from_raw_parts(data, len),
+            region,
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<T: ?Sized> Deref for GuardedBox<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        // SAFETY: the value is initialized and owned by this box
+        unsafe { self.value.as_ref() }
+    }
+}
+
+impl<T: ?Sized> DerefMut for GuardedBox<T> {
+    fn deref_mut(&mut self) -> &mut T {
+        // SAFETY: the value is initialized and owned by this box, which is borrowed mutably
+        unsafe { self.value.as_mut() }
+    }
+}
+
+impl<T: Clone> Clone for GuardedBox<T> {
+    fn clone(&self) -> Self {
+        Self::new(self.deref().clone())
+    }
+}
+
+impl<T: Clone> Clone for GuardedBox<[T]> {
+    fn clone(&self) -> Self {
+        Self::from_fn(self.len(), |i| self[i].clone())
+    }
+}
+
+impl<T: ?Sized + fmt::Debug> fmt::Debug for GuardedBox<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.deref().fmt(f)
+    }
+}
+
+/// Drop the value, check the canary, and unmap the storage
+impl<T: ?Sized> Drop for GuardedBox<T> {
+    fn drop(&mut self) {
+        // SAFETY: the value is initialized, and is not used again
+        unsafe { ptr::drop_in_place(self.value.as_ptr()) };
+        self.region.unmap();
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use core::mem::{align_of, size_of};
+    use std::rc::Rc;
+
+    use super::*;
+
+    #[test]
+    fn values() {
+        let mut key = GuardedBox::new([7u8; 32]);
+        key[31] = 8;
+        assert_eq!(key[30..], [7, 8]);
+        assert_eq!(key.clone()[..], key[..]);
+        assert_eq!(format!("{:?}", GuardedBox::new(3u8)), "3");
+
+        let wide = GuardedBox::new([1u64; 3]);
+        assert!((wide.as_ptr() as usize).is_multiple_of(align_of::<u64>()));
+
+        let empty = GuardedBox::<[u8]>::from_fn(0, |_| 0);
+        assert!(empty.is_empty());
+        let _unit = GuardedBox::new(());
+    }
+
+    #[test]
+    fn ends_at_guard_page() {
+        let page = page_size();
+        let seed = GuardedBox::<[u16]>::from_fn(5, |i| i as u16);
+        assert_eq!(seed[..], [0, 1, 2, 3, 4]);
+        let end = seed.as_ptr() as usize + 5 * size_of::<u16>();
+        assert!(end.is_multiple_of(page));
+    }
+
+    #[test]
+    fn over_aligned() {
+        // Larger than the page size on every supported platform
+        #[repr(align(131072))]
+        struct Aligned([u8; 4]);
+
+        let value = GuardedBox::new(Aligned([1, 2, 3, 4]));
+        assert!((value.deref() as *const Aligned as usize).is_multiple_of(align_of::<Aligned>()));
+        assert_eq!(value.0, [1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn drops_value() {
+        let shared = Rc::new(());
+        let boxed = GuardedBox::new(Rc::clone(&shared));
+        let slice = GuardedBox::<[Rc<()>]>::from_fn(3, |_| Rc::clone(&shared));
+        assert_eq!(Rc::strong_count(&shared), 5);
+        drop(boxed);
+        drop(slice);
+        assert_eq!(Rc::strong_count(&shared), 1);
+    }
+}
@@ -20,11 +20,120 @@
 // WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
 // USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
 
-//! A simple `Hashable` trait which is used to describe how an object should be hashed.
+//! A simple `Hashable` trait which is used to describe how an object should be hashed, and, with the `digest`
+//! feature, a [HashingWriter] that hashes data as it is written.
 use alloc::vec::Vec;
+#[cfg(feature = "digest")]
+use std::io::{self, Write};
+
+#[cfg(feature = "digest")]
+use digest::{Digest, Output};
 
 /// Simple Hashable trait with single hash function.
 pub trait Hashable {
     /// Create a hash from this object
     fn hash(&self) -> Vec<u8>;
 }
+
+/// A writer that hashes everything written to it as it passes it on to an inner writer, so that data can be written
+/// and hashed in a single pass.
+///
+/// ```edition2018
+/// # use std::io::Write;
+/// # use sha2::{Digest, Sha256};
+/// # use tari_utilities::hash::HashingWriter;
+/// let mut writer = HashingWriter::<Sha256, _>::new(Vec::new());
+/// writer.write_all(b"hello").unwrap();
+/// let (written, hash) = writer.finalize();
+/// assert_eq!(written, b"hello");
+/// assert_eq!(hash, Sha256::digest(b"hello"));
+/// ```
+#[cfg(feature = "digest")]
+#[derive(Debug)]
+pub struct HashingWriter<D, W> {
+    digest: D,
+    inner: W,
+}
+
+#[cfg(feature = "digest")]
+impl<D: Digest, W: Write> HashingWriter<D, W> {
+    /// Create a writer that hashes with a new `D` and writes to `inner`.
+    pub fn new(inner: W) -> Self {
+        Self::with_digest(D::new(), inner)
+    }
+
+    /// Create a writer that continues hashing with `digest` and writes to `inner`. Use this to hash with a keyed or
+    /// domain-separated hasher.
+    pub fn with_digest(digest: D, inner: W) -> Self {
+        Self { digest, inner }
+    }
+
+    /// Returns a reference to the inner writer.
+    pub fn get_ref(&self) -> &W {
+        &self.inner
+    }
+
+    /// Returns the inner writer and the hash of everything written to it.
+    pub fn finalize(self) -> (W, Output<D>) {
+        (self.inner, self.digest.finalize())
+    }
+}
+
+#[cfg(feature = "digest")]
+impl<D: Digest, W: Write> Write for HashingWriter<D, W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        // Only hash what the inner writer accepted, so the hash always matches the output
+        let written = self.inner.write(buf)?;
+        self.digest.update(&buf[..written]);
+        Ok(written)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+#[cfg(all(test, feature = "digest"))]
+mod test {
+    use sha2::Sha256;
+
+    use super::*;
+
+    /// A writer that accepts at most 3 bytes per call
+    struct ShortWriter(Vec<u8>);
+
+    impl Write for ShortWriter {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            let len = buf.len().min(3);
+            self.0.extend_from_slice(&buf[..len]);
+            Ok(len)
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn hashes_only_what_is_written() {
+        let data = (0..100u8).collect::<Vec<_>>();
+        let mut writer = HashingWriter::<Sha256, _>::new(ShortWriter(Vec::new()));
+        assert_eq!(writer.write(&data).unwrap(), 3);
+        writer.write_all(&data[3..]).unwrap();
+        assert_eq!(writer.get_ref().0.len(), 100);
+        let (inner, hash) = writer.finalize();
+        assert_eq!(inner.0, data);
+        assert_eq!(hash, Sha256::digest(&data));
+    }
+
+    #[test]
+    fn continues_digest() {
+        let mut digest = Sha256::new();
+        digest.update(b"prefix");
+        let mut writer = HashingWriter::with_digest(digest, Vec::new());
+        writer.write_all(b"data").unwrap();
+        let (inner, hash) = writer.finalize();
+        assert_eq!(inner, b"data");
+        assert_eq!(hash, Sha256::digest(b"prefixdata"));
+    }
+}
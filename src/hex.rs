@@ -29,14 +29,12 @@ use core::fmt::LowerHex;
 use serde::Serializer;
 use snafu::prelude::*;
 
-use crate::alloc::string::ToString;
+use crate::{alloc::string::ToString, encoding::DecodeMode};
 
 /// Maximum bytes allowed for parsing to hex.
 const MAX_BYTES_SIZE: usize = 262_144; // 256kb
 
 /// Any object implementing this trait has the ability to represent itself as a hexadecimal string and convert from it.
-
-/// The max len of the hex
 pub trait Hex {
     /// Try to convert the given hexadecimal string to the type.
     ///
@@ -60,6 +58,8 @@ pub enum HexError {
     LengthError {},
     #[snafu(display("Invalid hex representation for the target type"))]
     HexConversionError {},
+    #[snafu(display("Hex output needs {required} bytes but the buffer has {available}"))]
+    OutputTooSmall { required: usize, available: usize },
 }
 
 /// Encode the provided bytes into a hex string. This will function will not fail, but will print out if it fails
@@ -76,6 +76,39 @@ where T: LowerHex {
     s
 }
 
+/// Encode bytes as lower case hex, appending to `out`. Reusing `out` across calls avoids allocating for each
+/// encoding. Unlike [to_hex], there is no limit on the length of `bytes`.
+pub fn to_hex_into(bytes: &[u8], out: &mut String) {
+    const DIGITS: &[u8; 16] = b"0123456789abcdef";
+    out.reserve(bytes.len() * 2);
+    for byte in bytes {
+        out.push(char::from(DIGITS[usize::from(byte >> 4)]));
+        out.push(char::from(DIGITS[usize::from(byte & 0x0f)]));
+    }
+}
+
+/// Encode a byte array as lower case hex digits in an array of twice its length, without allocating. The output length
+/// `M` is usually inferred, and must be `2 * N`; any other length fails to compile. This is a `const fn`, so it can
+/// also produce hex at compile time.
+///
+/// ```edition2018
+/// # use tari_utilities::hex::hex_encode_array;
+/// const DIGITS: [u8; 4] = hex_encode_array(&[0xca, 0xfe]);
+/// assert_eq!(core::str::from_utf8(&DIGITS).unwrap(), "cafe");
+/// ```
+pub const fn hex_encode_array<const N: usize, const M: usize>(bytes: &[u8; N]) -> [u8; M] {
+    const { assert!(M == 2 * N, "the hex output must be twice the length of the input") };
+    const DIGITS: &[u8; 16] = b"0123456789abcdef";
+    let mut out = [0u8; M];
+    let mut i = 0;
+    while i < N {
+        out[2 * i] = DIGITS[(bytes[i] >> 4) as usize];
+        out[2 * i + 1] = DIGITS[(bytes[i] & 0x0f) as usize];
+        i += 1;
+    }
+    out
+}
+
 /// Encode the provided vector of bytes into a hex string.
 pub fn to_hex_multiple(bytearray: &[Vec<u8>]) -> Vec<String> {
     let mut result = Vec::new();
@@ -85,26 +118,148 @@ pub fn to_hex_multiple(bytearray: &[Vec<u8>]) -> Vec<String> {
     result
 }
 
-/// Decode a hex string into bytes.
+/// Decode a hex string into bytes, in [DecodeMode::Strict] mode. Use [from_hex_with_mode] to accept whitespace, upper
+/// case digits or a `0x` prefix.
 pub fn from_hex(hex_str: &str) -> Result<Vec<u8>, HexError> {
-    let hex_trim = hex_str.trim();
-    if hex_trim.len() % 2 == 1 {
-        return Err(HexError::LengthError {});
+    from_hex_with_mode(hex_str, DecodeMode::Strict)
+}
+
+/// Decode a hex string into bytes.
+///
+/// In [DecodeMode::Strict] mode only lower case hex digits are accepted, exactly as produced by [to_hex]. In
+/// [DecodeMode::Lenient] mode, ASCII whitespace is ignored, upper case digits are accepted and the string may start
+/// with `0x`.
+pub fn from_hex_with_mode(hex_str: &str, mode: DecodeMode) -> Result<Vec<u8>, HexError> {
+    let lenient;
+    let (hex, table) = match mode {
+        DecodeMode::Strict => (hex_str, &STRICT_DIGITS),
+        DecodeMode::Lenient => {
+            // Only allocate when there is whitespace to remove
+            let compact = if hex_str.bytes().any(|c| c.is_ascii_whitespace()) {
+                lenient = hex_str.split_ascii_whitespace().collect::<String>();
+                lenient.as_str()
+            } else {
+                hex_str
+            };
+            (strip_hex_prefix(compact), &LENIENT_DIGITS)
+        },
+    };
+    ensure!(hex.len() % 2 == 0, LengthSnafu);
+    ensure!(hex.is_ascii(), HexConversionSnafu);
+    let mut out = vec![0u8; hex.len() / 2];
+    decode_digits(hex.as_bytes(), table, &mut out)?;
+    Ok(out)
+}
+
+/// Decode a hex string into `out` in [DecodeMode::Strict] mode, returning the number of bytes written.
+pub fn from_hex_into(hex_str: &str, out: &mut [u8]) -> Result<usize, HexError> {
+    from_hex_into_with_mode(hex_str, out, DecodeMode::Strict)
+}
+
+/// Decode a hex string into `out`, returning the number of bytes written. The modes are as for [from_hex_with_mode].
+/// This does not allocate, so it suits hot loops that decode into fixed-size arrays.
+pub fn from_hex_into_with_mode(hex_str: &str, out: &mut [u8], mode: DecodeMode) -> Result<usize, HexError> {
+    ensure!(hex_str.is_ascii(), HexConversionSnafu);
+    if mode == DecodeMode::Lenient && hex_str.bytes().any(|c| c.is_ascii_whitespace()) {
+        return decode_sparse_digits(hex_str, out);
     }
-    if !hex_str.is_ascii() {
-        return Err(HexError::HexConversionError {});
+    let (hex, table) = match mode {
+        DecodeMode::Strict => (hex_str, &STRICT_DIGITS),
+        DecodeMode::Lenient => (strip_hex_prefix(hex_str), &LENIENT_DIGITS),
+    };
+    ensure!(hex.len() % 2 == 0, LengthSnafu);
+    let len = hex.len() / 2;
+    let available = out.len();
+    let out = out.get_mut(..len).context(OutputTooSmallSnafu {
+        required: len,
+        available,
+    })?;
+    decode_digits(hex.as_bytes(), table, out)?;
+    Ok(len)
+}
+
+fn strip_hex_prefix(hex: &str) -> &str {
+    hex.strip_prefix("0x").or_else(|| hex.strip_prefix("0X")).unwrap_or(hex)
+}
+
+/// Marks a byte that is not a hex digit in a digit table. Valid digits are below 16, so a chunk of digits is valid
+/// exactly when the bitwise OR of their table entries is below 16.
+const INVALID_DIGIT: u8 = 0xff;
+
+const fn digit_table(upper_case: bool) -> [u8; 256] {
+    let mut table = [INVALID_DIGIT; 256];
+    let mut i = 0;
+    while i < 10 {
+        table[b'0' as usize + i] = i as u8;
+        i += 1;
     }
-    let hex_trim = if (hex_trim.len() >= 2) && (&hex_trim[..2] == "0x") {
-        &hex_trim[2..]
+    let mut i = 0;
+    while i < 6 {
+        table[b'a' as usize + i] = 10 + i as u8;
+        if upper_case {
+            table[b'A' as usize + i] = 10 + i as u8;
+        }
+        i += 1;
+    }
+    table
+}
+
+/// Digit values for lower case hex
+static STRICT_DIGITS: [u8; 256] = digit_table(false);
+/// Digit values for hex in either case
+static LENIENT_DIGITS: [u8; 256] = digit_table(true);
+
+/// Decode an even number of hex digits into `out`, which holds exactly half as many bytes, using a digit table, 8
+/// digits at a time.
+fn decode_digits(hex: &[u8], table: &[u8; 256], out: &mut [u8]) -> Result<(), HexError> {
+    let mut input = hex.chunks_exact(8);
+    let mut output = out.chunks_exact_mut(4);
+    for (digits, bytes) in input.by_ref().zip(output.by_ref()) {
+        let mut nibbles = [0u8; 8];
+        let mut invalid = 0;
+        for (nibble, digit) in nibbles.iter_mut().zip(digits) {
+            *nibble = table[usize::from(*digit)];
+            invalid |= *nibble;
+        }
+        ensure!(invalid < 16, InvalidCharacterSnafu);
+        for (byte, pair) in bytes.iter_mut().zip(nibbles.chunks_exact(2)) {
+            *byte = (pair[0] << 4) | pair[1];
+        }
+    }
+    for (digits, byte) in input.remainder().chunks_exact(2).zip(output.into_remainder()) {
+        let (high, low) = (table[usize::from(digits[0])], table[usize::from(digits[1])]);
+        ensure!((high | low) < 16, InvalidCharacterSnafu);
+        *byte = (high << 4) | low;
+    }
+    Ok(())
+}
+
+/// Decode lenient hex that contains ASCII whitespace into `out` one digit at a time, without removing the whitespace
+/// first.
+fn decode_sparse_digits(hex_str: &str, out: &mut [u8]) -> Result<usize, HexError> {
+    let digits = || hex_str.bytes().filter(|c| !c.is_ascii_whitespace());
+    let mut prefix = digits();
+    let skip = if prefix.next() == Some(b'0') && matches!(prefix.next(), Some(b'x' | b'X')) {
+        2
     } else {
-        hex_trim
+        0
     };
-    let num_bytes = hex_trim.len() / 2;
-    let mut result = vec![0u8; num_bytes];
-    for i in 0..num_bytes {
-        result[i] = u8::from_str_radix(&hex_trim[2 * i..2 * (i + 1)], 16).map_err(|_| HexError::InvalidCharacter {})?;
+    let count = digits().count() - skip;
+    ensure!(count % 2 == 0, LengthSnafu);
+    let len = count / 2;
+    let available = out.len();
+    let out = out.get_mut(..len).context(OutputTooSmallSnafu {
+        required: len,
+        available,
+    })?;
+    let mut nibbles = digits().skip(skip).map(|c| LENIENT_DIGITS[usize::from(c)]);
+    for byte in out.iter_mut() {
+        let high = nibbles.next().unwrap_or(INVALID_DIGIT);
+        let low = nibbles.next().unwrap_or(INVALID_DIGIT);
+        ensure!((high | low) < 16, InvalidCharacterSnafu);
+        *byte = (high << 4) | low;
     }
-    Ok(result)
+    Ok(len)
 }
 
 /// Use a serde serializer to serialize the hex string of the given object.
@@ -129,19 +284,124 @@ mod test {
         assert_eq!(to_hex(&[0, 0, 0, 255]), "000000ff");
     }
 
+    #[test]
+    fn test_to_hex_into() {
+        let bytes = (0..=255u8).collect::<Vec<_>>();
+        let mut out = String::from("0x");
+        to_hex_into(&bytes, &mut out);
+        assert_eq!(out[2..], to_hex(&bytes));
+        out.clear();
+        let capacity = out.capacity();
+        to_hex_into(&[0xde, 0xad], &mut out);
+        assert_eq!(out, "dead");
+        assert_eq!(out.capacity(), capacity);
+    }
+
+    #[test]
+    fn test_hex_encode_array() {
+        let bytes: [u8; 32] = core::array::from_fn(|i| (i * 9) as u8);
+        let hex: [u8; 64] = hex_encode_array(&bytes);
+        assert_eq!(core::str::from_utf8(&hex).unwrap(), to_hex(&bytes));
+        assert_eq!(hex_encode_array::<0, 0>(&[]), [0u8; 0]);
+    }
+
     #[test]
     fn test_from_hex() {
         assert_eq!(from_hex("00000000").unwrap(), vec![0, 0, 0, 0]);
         assert_eq!(from_hex("0a0b0c0d").unwrap(), vec![10, 11, 12, 13]);
-        assert_eq!(from_hex(" 0a0b0c0d  ").unwrap(), vec![10, 11, 12, 13]);
+        assert!(from_hex(" 0a0b0c0d  ").is_err());
         assert_eq!(from_hex("000000ff").unwrap(), vec![0, 0, 0, 255]);
-        assert_eq!(from_hex("0x800000ff").unwrap(), vec![128, 0, 0, 255]);
+        assert!(from_hex("0x800000ff").is_err());
+        assert!(from_hex("800000FF").is_err());
         assert!(from_hex("800").is_err()); // Odd number of bytes
         assert!(from_hex("8080gf").is_err()); // Invalid hex character g
                                               // unicode strings have odd lengths and can cause panics
         assert!(from_hex("🖖🥴").is_err());
     }
 
+    #[test]
+    fn decode_modes() {
+        assert_eq!(from_hex_with_mode("0a0bff", DecodeMode::Strict).unwrap(), vec![
+            10, 11, 255
+        ]);
+        assert_eq!(from_hex_with_mode(" 0x0A 0b\nFF ", DecodeMode::Lenient).unwrap(), vec![
+            10, 11, 255
+        ]);
+        for malformed in [" 0a0bff", "0x0a0bff", "0A0BFF", "0a 0b ff", "+f"] {
+            assert!(
+                from_hex_with_mode(malformed, DecodeMode::Strict).is_err(),
+                "{}",
+                malformed
+            );
+        }
+        assert!(from_hex_with_mode("+f", DecodeMode::Lenient).is_err());
+        assert!(from_hex_with_mode("0x0x", DecodeMode::Lenient).is_err());
+    }
+
+    #[test]
+    fn decode_into_slice() {
+        let mut out = [0u8; 4];
+        assert_eq!(from_hex_into("0a0bff", &mut out).unwrap(), 3);
+        assert_eq!(out[..3], [10, 11, 255]);
+        assert!(from_hex_into(" 0X 0A0b\nff01 ", &mut out).is_err());
+        assert_eq!(
+            from_hex_into_with_mode(" 0X 0A0b\nff01 ", &mut out, DecodeMode::Lenient).unwrap(),
+            4
+        );
+        assert_eq!(out, [10, 11, 255, 1]);
+        assert_eq!(
+            from_hex_into_with_mode("0a0bff", &mut out, DecodeMode::Strict).unwrap(),
+            3
+        );
+        assert!(from_hex_into_with_mode("0A0BFF", &mut out, DecodeMode::Strict).is_err());
+        assert!(matches!(
+            from_hex_into("0a0b0c0d0e", &mut out),
+            Err(HexError::OutputTooSmall {
+                required: 5,
+                available: 4
+            })
+        ));
+        assert!(matches!(
+            from_hex_into_with_mode("0a 0b 0c 0d 0e", &mut out, DecodeMode::Lenient),
+            Err(HexError::OutputTooSmall {
+                required: 5,
+                available: 4
+            })
+        ));
+        assert!(matches!(
+            from_hex_into_with_mode("0a 0", &mut out, DecodeMode::Lenient),
+            Err(HexError::LengthError {})
+        ));
+        assert!(matches!(
+            from_hex_into_with_mode("0a 0g", &mut out, DecodeMode::Lenient),
+            Err(HexError::InvalidCharacter {})
+        ));
+        assert!(from_hex_into("🖖🥴", &mut out).is_err());
+    }
+
+    #[test]
+    fn chunked_decoding() {
+        // Cover every length up to a few chunks, with an invalid digit at every position
+        let bytes = (0..40u8).map(|i| i.wrapping_mul(37)).collect::<Vec<_>>();
+        for len in 0..bytes.len() {
+            let hex = to_hex(&bytes[..len]);
+            assert_eq!(from_hex_with_mode(&hex, DecodeMode::Strict).unwrap(), &bytes[..len]);
+            assert_eq!(
+                from_hex_with_mode(&hex.to_uppercase(), DecodeMode::Lenient).unwrap(),
+                &bytes[..len]
+            );
+            for i in 0..hex.len() {
+                let mut invalid = hex.clone().into_bytes();
+                invalid[i] = b'g';
+                let invalid = String::from_utf8(invalid).unwrap();
+                assert!(matches!(
+                    from_hex_with_mode(&invalid, DecodeMode::Lenient),
+                    Err(HexError::InvalidCharacter {})
+                ));
+            }
+        }
+    }
+
     #[test]
     fn test_to_hex_multiple() {
         let ba = [vec![16u8, 32], vec![48, 64]];
@@ -0,0 +1,87 @@
+// Copyright 2026, The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+//! Serde support for [Hidden] fields that can be loaded but never written out.
+//!
+//! `Hidden` can be deserialized but does not implement `Serialize`, so a struct holding one cannot derive
+//! `Serialize`. Annotating the field with `#[serde(with = "tari_utilities::hidden::deserialize_only")]` lets the
+//! struct derive both: the secret is deserialized as usual, but serializing the struct always fails, so that a secret
+//! loaded from a provisioning file can never be written back out. To write the rest of the struct with a marker in
+//! place of the secret instead, use [redacted](super::redacted).
+//!
+//! ```edition2018
+//! # use serde::{Deserialize, Serialize};
+//! # use tari_utilities::Hidden;
+//! #[derive(Deserialize, Serialize)]
+//! struct Provisioning {
+//!     node: String,
+//!     #[serde(with = "tari_utilities::hidden::deserialize_only")]
+//!     key: Hidden<[u8; 4]>,
+//! }
+//!
+//! let config: Provisioning = serde_json::from_str(r#"{"node":"a","key":[1,2,3,4]}"#).unwrap();
+//! assert_eq!(config.key.reveal(), &[1, 2, 3, 4]);
+//! assert!(serde_json::to_string(&config).is_err());
+//! ```
+
+use serde::{ser::Error, Deserialize, Deserializer, Serializer};
+use zeroize::Zeroize;
+
+use super::Hidden;
+
+/// Always fails, so that hidden data is never serialized.
+pub fn serialize<S, T>(_data: &Hidden<T>, _ser: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+    T: Zeroize,
+{
+    Err(S::Error::custom("hidden data cannot be serialized"))
+}
+
+/// Deserializes hidden data transparently as the underlying type.
+pub fn deserialize<'de, D, T>(de: D) -> Result<Hidden<T>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Zeroize + Deserialize<'de>,
+{
+    Hidden::deserialize(de)
+}
+
+#[cfg(test)]
+mod test {
+    use alloc::string::ToString;
+
+    use serde::{Deserialize, Serialize};
+
+    use super::*;
+
+    #[derive(Deserialize, Serialize)]
+    struct Config(#[serde(with = "super")] Hidden<u32>);
+
+    #[test]
+    fn deserialize_only() {
+        let config: Config = serde_json::from_str("42").unwrap();
+        assert_eq!(config.0.reveal(), &42);
+        let err = serde_json::to_string(&config).unwrap_err();
+        assert_eq!(err.to_string(), "hidden data cannot be serialized");
+        assert!(bincode::serialize(&config).is_err());
+    }
+}
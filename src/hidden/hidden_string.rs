@@ -0,0 +1,172 @@
+// Copyright 2026, The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+//! A secret string that can be built up piece by piece without leaving stale copies behind.
+//!
+//! Building a secret such as a seed phrase by concatenating `String`s leaves a copy of the partial secret in every
+//! allocation the string outgrows. A [HiddenString] is backed by a [ZeroVec], so each old allocation is zeroized as the
+//! string grows, and the final one is zeroized when it is dropped.
+//!
+//! ```edition2018
+//! # use tari_utilities::hidden::hidden_string::HiddenString;
+//! let words = ["abandon", "ability", "able"];
+//! let mut phrase = HiddenString::new();
+//! for word in words.iter() {
+//!     if !phrase.is_empty() {
+//!         phrase.push(' ');
+//!     }
+//!     phrase.push_str(word);
+//! }
+//! assert_eq!(phrase.reveal(), "abandon ability able");
+//! assert_eq!(format!("{:?}", phrase), "HiddenString");
+//! ```
+
+use core::{fmt, str};
+
+use zeroize::Zeroize;
+
+use super::zero_vec::ZeroVec;
+
+/// A string that zeroizes its contents when dropped, and zeroizes its old allocation whenever it grows. Its contents
+/// are masked in `Debug` and `Display` output and can only be accessed by reference.
+#[derive(Clone, Default)]
+pub struct HiddenString {
+    bytes: ZeroVec<u8>,
+}
+
+impl HiddenString {
+    /// Create an empty string, which does not allocate until text is added.
+    pub fn new() -> Self {
+        Self { bytes: ZeroVec::new() }
+    }
+
+    /// Create an empty string with room for at least `capacity` bytes before it needs to reallocate.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            bytes: ZeroVec::with_capacity(capacity),
+        }
+    }
+
+    /// Make room for at least `additional` more bytes, zeroizing the old allocation if the string has to grow.
+    pub fn reserve(&mut self, additional: usize) {
+        self.bytes.reserve(additional);
+    }
+
+    /// Append a string slice.
+    pub fn push_str(&mut self, s: &str) {
+        self.bytes.extend_from_slice(s.as_bytes());
+    }
+
+    /// Append a character.
+    pub fn push(&mut self, c: char) {
+        self.push_str(c.encode_utf8(&mut [0; 4]));
+    }
+
+    /// Zeroize and remove the contents, keeping the allocation for reuse.
+    pub fn clear(&mut self) {
+        self.bytes.clear();
+    }
+
+    /// Return the length of the string in bytes.
+    pub fn len(&self) -> usize {
+        self.bytes.len()
+    }
+
+    /// Return true if the string is empty.
+    pub fn is_empty(&self) -> bool {
+        self.bytes.is_empty()
+    }
+
+    /// Return the number of bytes the string can hold without reallocating.
+    pub fn capacity(&self) -> usize {
+        self.bytes.capacity()
+    }
+
+    /// Reveal the string as a string slice.
+    pub fn reveal(&self) -> &str {
+        // Only whole strings and characters are ever appended, so the bytes are always valid UTF-8
+        str::from_utf8(self.bytes.reveal()).unwrap_or_default()
+    }
+}
+
+/// Copy a string slice into a new hidden string with just enough capacity for it
+impl From<&str> for HiddenString {
+    fn from(s: &str) -> Self {
+        let mut hidden = Self::with_capacity(s.len());
+        hidden.push_str(s);
+        hidden
+    }
+}
+
+/// Only output masked data for debugging, keeping the string hidden
+impl fmt::Debug for HiddenString {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("HiddenString")
+    }
+}
+
+/// Only display masked data, keeping the string hidden
+impl fmt::Display for HiddenString {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("HiddenString")
+    }
+}
+
+/// Zeroize the string and its whole allocation, leaving it empty
+impl Zeroize for HiddenString {
+    fn zeroize(&mut self) {
+        self.bytes.zeroize();
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use alloc::string::ToString;
+
+    use super::*;
+
+    #[test]
+    fn mutation() {
+        let mut s = HiddenString::from("seed");
+        assert_eq!(s.len(), 4);
+        s.push(' ');
+        s.push('é');
+        s.push_str(" phrase");
+        assert_eq!(s.reveal(), "seed é phrase");
+        assert_eq!(s.len(), "seed é phrase".len());
+        assert!(s.capacity() >= s.len());
+
+        s.clear();
+        assert!(s.is_empty());
+        assert_eq!(s.reveal(), "");
+        s.push_str("again");
+        assert_eq!(s.clone().reveal(), "again");
+        s.zeroize();
+        assert!(s.is_empty());
+    }
+
+    #[test]
+    fn masking() {
+        let s = HiddenString::from("secret");
+        assert_eq!(s.to_string(), "HiddenString");
+        assert_eq!(format!("{:?}", s), "HiddenString");
+    }
+}
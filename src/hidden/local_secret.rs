@@ -0,0 +1,149 @@
+// Copyright 2026, The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+//! Fixed-size secrets on the stack.
+//!
+//! Short-lived values like nonces and challenges do not justify the heap allocation of [Hidden](super::Hidden), but
+//! should still be wiped. A [LocalSecret] is a fixed array that lives on the stack, is zeroized when dropped, and
+//! can only be accessed inside a closure. The [hidden_local!](crate::hidden_local) macro declares one in the current
+//! scope and binds it by mutable reference, so that it cannot be moved, which would leave a stale copy behind.
+//!
+//! ```edition2018
+//! # #[macro_use] extern crate tari_utilities;
+//! # fn main() {
+//! hidden_local!(nonce: [u8; 24]);
+//! nonce.with_mut(|bytes| bytes[0] = 1);
+//! let first = nonce.with(|bytes| bytes[0]);
+//! assert_eq!(first, 1);
+//! # }
+//! ```
+
+use core::{any::type_name, fmt};
+
+use zeroize::{DefaultIsZeroes, Zeroize};
+
+/// A fixed array on the stack that is zeroized when dropped, and is only accessible inside a closure. Its contents are
+/// masked in `Debug` output.
+///
+/// Moving a `LocalSecret` copies its bytes and leaves the old ones behind, so declare it with
+/// [hidden_local!](crate::hidden_local), which prevents this.
+pub struct LocalSecret<T: DefaultIsZeroes, const N: usize> {
+    data: [T; N],
+}
+
+impl<T: DefaultIsZeroes, const N: usize> LocalSecret<T, N> {
+    /// Create a secret filled with the default (zero) value. This is only for [hidden_local!](crate::hidden_local),
+    /// which binds the secret so that it cannot be moved.
+    #[doc(hidden)]
+    pub fn new() -> Self {
+        Self {
+            data: [T::default(); N],
+        }
+    }
+
+    /// Call `f` with an immutable reference to the data, returning its result.
+    pub fn with<R, F>(&self, f: F) -> R
+    where F: FnOnce(&[T; N]) -> R {
+        f(&self.data)
+    }
+
+    /// Call `f` with a mutable reference to the data, returning its result.
+    pub fn with_mut<R, F>(&mut self, f: F) -> R
+    where F: FnOnce(&mut [T; N]) -> R {
+        f(&mut self.data)
+    }
+}
+
+/// Only output masked data for debugging, keeping the secret hidden
+impl<T: DefaultIsZeroes, const N: usize> fmt::Debug for LocalSecret<T, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "LocalSecret<[{}; {}]>", type_name::<T>(), N)
+    }
+}
+
+/// Zeroize the data
+impl<T: DefaultIsZeroes, const N: usize> Zeroize for LocalSecret<T, N> {
+    fn zeroize(&mut self) {
+        self.data.zeroize();
+    }
+}
+
+/// Zeroize the data when dropped
+impl<T: DefaultIsZeroes, const N: usize> Drop for LocalSecret<T, N> {
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
+
+/// Declare a zeroed [LocalSecret](crate::hidden::local_secret::LocalSecret) array on the stack, zeroized at the end of
+/// the enclosing scope. `hidden_local!(name: [T; N])` binds `name` to a mutable reference to it, so the data can only
+/// be accessed through [with](crate::hidden::local_secret::LocalSecret::with) and
+/// [with_mut](crate::hidden::local_secret::LocalSecret::with_mut), and cannot be moved out of the scope.
+///
+/// ```edition2018
+/// # #[macro_use] extern crate tari_utilities;
+/// # fn main() {
+/// hidden_local!(challenge: [u32; 4]);
+/// challenge.with_mut(|words| words.copy_from_slice(&[1, 2, 3, 4]));
+/// assert_eq!(challenge.with(|words| words.iter().sum::<u32>()), 10);
+/// # }
+/// ```
+#[macro_export]
+macro_rules! hidden_local {
+    ($name:ident: [$type:ty; $len:expr]) => {
+        let mut secret = $crate::hidden::local_secret::LocalSecret::<$type, { $len }>::new();
+        let $name = &mut secret;
+    };
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn access() {
+        hidden_local!(nonce: [u8; 8]);
+        assert_eq!(nonce.with(|bytes| *bytes), [0u8; 8]);
+        nonce.with_mut(|bytes| bytes.copy_from_slice(&[1, 2, 3, 4, 5, 6, 7, 8]));
+        assert_eq!(nonce.with(|bytes| bytes[7]), 8);
+        assert_eq!(format!("{:?}", nonce), "LocalSecret<[u8; 8]>");
+
+        nonce.zeroize();
+        assert_eq!(nonce.with(|bytes| *bytes), [0u8; 8]);
+    }
+
+    #[test]
+    fn zeroized_on_drop() {
+        use core::{mem::ManuallyDrop, ptr};
+
+        let mut secret = ManuallyDrop::new(LocalSecret::<u8, 4>::new());
+        secret.with_mut(|bytes| bytes.copy_from_slice(&[1, 2, 3, 4]));
+        // `ManuallyDrop` is transparent, so this points at the secret itself
+        let secret = ptr::addr_of_mut!(secret).cast::<LocalSecret<u8, 4>>();
+        // SAFETY: the secret is dropped in place and not used again. Its memory stays allocated until the end of the
+        // test, and is only read as plain bytes through the raw pointer.
+        let data = unsafe {
+            ptr::drop_in_place(secret);
+            ptr::read_volatile(ptr::addr_of!((*secret).data))
+        };
+        assert_eq!(data, [0u8; 4]);
+    }
+}
@@ -0,0 +1,687 @@
+// Copyright 2022. The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+//! Sometimes you need to handle sensitive data, like a passphrase or key material.
+//! There are pitfalls here that you want to avoid: the data should be zeroized when it goes out of scope, shouldn't be
+//! displayed or output to debug logs, shouldn't be unintentionally copied or moved, and often should have strict type
+//! differentiation to avoid it being misused in an unintended context. This library provides a generic type and macro
+//! that can help.
+
+#[cfg(not(all(feature = "guarded", unix)))]
+use alloc::boxed::Box;
+#[cfg(feature = "track-secrets")]
+use core::mem::size_of;
+#[cfg(feature = "debug-reveal-count")]
+use core::sync::atomic::{AtomicUsize, Ordering};
+use core::{
+    any::type_name,
+    fmt,
+    ops::{Deref, DerefMut},
+};
+
+use zeroize::Zeroize;
+
+use crate::{
+    hex::{from_hex_into, HexError},
+    ByteArrayError,
+};
+
+#[cfg(feature = "serde")]
+pub mod deserialize_only;
+pub mod hidden_string;
+pub mod local_secret;
+#[cfg(feature = "serde")]
+pub mod redacted;
+pub mod scoped_secret;
+#[cfg(feature = "std")]
+pub mod wipe_on_clone;
+pub mod zero_vec;
+
+/// This is a macro that produces a hidden type from an underlying data type.
+///
+/// It is a thin wrapper around `Hidden` and retains its useful properties:
+/// - The data is not subject to `Debug` or `Display` output, which are masked.
+/// - The data can only be accessed by (immutable or mutable) reference.
+/// - The data zeroizes when dropped, and can also be manually zeroized.
+/// - Cloning is safe.
+///
+/// The macro is a useful way to generate a hidden type that is subject to the compiler's type guarantees.
+/// This can be useful if you need multiple hidden types that use the same underlying data type, but shouldn't be
+/// confused for each other.
+///
+/// Note that it may not be safe to dereference the hidden data if its type implements `Copy`.
+/// If the type does not implement `Copy`, you should be fine.
+/// If it does, avoid dereferencing.
+///
+/// ```edition2018
+/// # #[macro_use] extern crate tari_utilities;
+/// # use tari_utilities::Hidden;
+/// # use zeroize::Zeroize;
+/// # fn main() {
+/// // Define a hidden type with a `[u8; 32]` data type
+/// hidden_type!(MyHiddenType, [u8; 32]);
+///
+/// // Instantiate using existing data
+/// let mut example = MyHiddenType::from([1u8; 32]);
+///
+/// // Access the data by immutable reference
+/// assert_eq!(example.reveal(), &[1u8; 32]);
+///
+/// // Access the data by mutable reference
+/// let example_mut_ref = example.reveal_mut();
+/// *example_mut_ref = [42u8; 32];
+/// assert_eq!(example.reveal(), &[42u8; 32]);
+///
+/// // Clone the data safely
+/// let mut example_clone = example.clone();
+///
+/// // Zeroize the data manually
+/// example_clone.zeroize();
+/// assert_eq!(example_clone.reveal(), &[0u8; 32]);
+///
+/// // Mask the data with a custom placeholder instead of the type name
+/// hidden_type!(MyApiKey, [u8; 32], redact = "<api key>");
+/// let key = MyApiKey::from([1u8; 32]);
+/// assert_eq!(format!("{:?}", key), "MyApiKey { data: <api key> }");
+/// # }
+/// ```
+///
+/// Outer attributes, including doc comments, are forwarded to the generated struct. `cfg` attributes are also applied
+/// to its impls, so a hidden type can be compiled conditionally. Serde support is opt-in: `Hidden` can be
+/// deserialized, so forwarding `#[derive(serde::Deserialize)]` and `#[serde(transparent)]` deserializes the type
+/// as its underlying data. A transparent type is deserialized with the default [Redaction::TypeName], whatever its
+/// `redact` or `fingerprint` option; use `#[serde(from = "...")]` with the underlying type instead to apply the option.
+///
+/// ```edition2018
+/// # #[macro_use] extern crate tari_utilities;
+/// # use tari_utilities::Hidden;
+/// # use zeroize::Zeroize;
+/// # fn main() {
+/// hidden_type!(
+///     /// The key used to encrypt the wallet database
+///     #[derive(serde::Deserialize)]
+///     #[serde(transparent)]
+///     DatabaseKey,
+///     [u8; 32]
+/// );
+///
+/// let key: DatabaseKey = serde_json::from_str(&serde_json::to_string(&[7u8; 32]).unwrap()).unwrap();
+/// assert_eq!(key.reveal(), &[7u8; 32]);
+/// # }
+/// ```
+///
+/// With the `fingerprint` feature, `hidden_type!(MyKey, [u8; 32], fingerprint)` masks the data with a short
+/// fingerprint of its hash instead; see [Redaction::Fingerprint].
+///
+/// The `from_bytes` and `from_hex` options generate `try_from_bytes` and `from_hex` constructors for byte buffer
+/// types, such as small arrays and [SafeArray](crate::safe_array::SafeArray). These decode straight into the hidden
+/// storage, so the secret is never held in the clear on the stack; see [Hidden::try_from_bytes]. Options can be
+/// combined, in any order.
+///
+/// ```edition2018
+/// # #[macro_use] extern crate tari_utilities;
+/// # use tari_utilities::Hidden;
+/// # use zeroize::Zeroize;
+/// # fn main() {
+/// hidden_type!(SeedKey, [u8; 4], from_bytes, from_hex, redact = "<seed>");
+///
+/// assert_eq!(SeedKey::try_from_bytes(&[1, 2, 3, 4]).unwrap().reveal(), &[
+///     1, 2, 3, 4
+/// ]);
+/// assert_eq!(SeedKey::from_hex("01020304").unwrap().reveal(), &[
+///     1, 2, 3, 4
+/// ]);
+/// assert!(SeedKey::try_from_bytes(&[1, 2, 3]).is_err());
+/// # }
+/// ```
+#[macro_export]
+macro_rules! hidden_type {
+    ($(#[$($attr:tt)*])* $name:ident, $type:ty $(, $($option:tt)+)?) => {
+        $crate::hidden_type!(@options [$(#[$($attr)*])*] $name, $type, [|data| data] [] [$($($option)+)?]);
+    };
+    // Collect the options into a redaction and a list of constructors
+    (@options $attrs:tt $name:ident, $type:ty, $redact:tt $ctors:tt [redact = $placeholder:expr $(, $($rest:tt)*)?]) => {
+        $crate::hidden_type!(@options $attrs $name, $type, [|data: Hidden<$type>| {
+            data.with_redaction($crate::hidden::Redaction::Placeholder($placeholder))
+        }] $ctors [$($($rest)*)?]);
+    };
+    (@options $attrs:tt $name:ident, $type:ty, $redact:tt $ctors:tt [fingerprint $(, $($rest:tt)*)?]) => {
+        $crate::hidden_type!(@options $attrs $name, $type, [|data: Hidden<$type>| data.with_fingerprint()] $ctors [$($($rest)*)?]);
+    };
+    (@options $attrs:tt $name:ident, $type:ty, $redact:tt [$($ctor:ident)*] [from_bytes $(, $($rest:tt)*)?]) => {
+        $crate::hidden_type!(@options $attrs $name, $type, $redact [$($ctor)* from_bytes] [$($($rest)*)?]);
+    };
+    (@options $attrs:tt $name:ident, $type:ty, $redact:tt [$($ctor:ident)*] [from_hex $(, $($rest:tt)*)?]) => {
+        $crate::hidden_type!(@options $attrs $name, $type, $redact [$($ctor)* from_hex] [$($($rest)*)?]);
+    };
+    (@options $attrs:tt $name:ident, $type:ty, $redact:tt $ctors:tt []) => {
+        $crate::hidden_type!(@attrs [] [] $attrs $name, $type, $redact $ctors);
+    };
+    // Separate `cfg` attributes, which apply to the struct and its impls, from the rest, which apply to the struct
+    (@attrs [$($cfg:tt)*] [$($other:tt)*] [#[cfg $args:tt] $($rest:tt)*] $($tail:tt)*) => {
+        $crate::hidden_type!(@attrs [$($cfg)* #[cfg $args]] [$($other)*] [$($rest)*] $($tail)*);
+    };
+    (@attrs [$($cfg:tt)*] [$($other:tt)*] [#[$($attr:tt)*] $($rest:tt)*] $($tail:tt)*) => {
+        $crate::hidden_type!(@attrs [$($cfg)*] [$($other)* #[$($attr)*]] [$($rest)*] $($tail)*);
+    };
+    (@attrs [$($cfg:tt)*] [] [] $($tail:tt)*) => {
+        $crate::hidden_type!(@define [$($cfg)*] [#[doc = "A hidden type"]] $($tail)*);
+    };
+    (@attrs [$($cfg:tt)*] [$($other:tt)+] [] $($tail:tt)*) => {
+        $crate::hidden_type!(@define [$($cfg)*] [$($other)+] $($tail)*);
+    };
+    (@define [$($cfg:tt)*] [$($attr:tt)*] $name:ident, $type:ty, [$redact:expr] [$($ctor:ident)*]) => {
+        $($cfg)*
+        $($attr)*
+        #[derive(Clone, Debug, Zeroize)]
+        pub struct $name {
+            data: Hidden<$type>,
+        }
+
+        $($cfg)*
+        impl $name {
+            /// Get an immutable reference to the data
+            #[allow(dead_code)]
+            pub fn reveal(&self) -> &$type {
+                self.data.reveal()
+            }
+
+            /// Get a mutable reference to the data
+            #[allow(dead_code)]
+            pub fn reveal_mut(&mut self) -> &mut $type {
+                self.data.reveal_mut()
+            }
+        }
+
+        $($cfg)*
+        impl From<$type> for $name {
+            /// Hide existing data
+            fn from(t: $type) -> Self {
+                Self {
+                    data: ($redact)(Hidden::hide(t)),
+                }
+            }
+        }
+
+        $crate::hidden_type!(@constructors [$($cfg)*] $name, $type, [$redact] [$($ctor)*]);
+    };
+    (@constructors $cfg:tt $name:ident, $type:ty, $redact:tt []) => {};
+    (@constructors [$($cfg:tt)*] $name:ident, $type:ty, [$redact:expr] [from_bytes $($rest:ident)*]) => {
+        $($cfg)*
+        impl $name {
+            /// Copy bytes directly into new hidden data, failing if their length does not match
+            #[allow(dead_code)]
+            pub fn try_from_bytes(bytes: &[u8]) -> Result<Self, $crate::ByteArrayError> {
+                Ok(Self {
+                    data: ($redact)(Hidden::<$type>::try_from_bytes(bytes)?),
+                })
+            }
+        }
+
+        $crate::hidden_type!(@constructors [$($cfg)*] $name, $type, [$redact] [$($rest)*]);
+    };
+    (@constructors [$($cfg:tt)*] $name:ident, $type:ty, [$redact:expr] [from_hex $($rest:ident)*]) => {
+        $($cfg)*
+        impl $name {
+            /// Decode a hex string directly into new hidden data, failing if its length does not match
+            #[allow(dead_code)]
+            pub fn from_hex(hex: &str) -> Result<Self, $crate::hex::HexError> {
+                Ok(Self {
+                    data: ($redact)(Hidden::<$type>::from_hex(hex)?),
+                })
+            }
+        }
+
+        $crate::hidden_type!(@constructors [$($cfg)*] $name, $type, [$redact] [$($rest)*]);
+    };
+}
+
+/// The heap storage for hidden data, which has guard pages with the `guarded` feature
+#[cfg(not(all(feature = "guarded", unix)))]
+type Storage<T> = Box<T>;
+#[cfg(all(feature = "guarded", unix))]
+type Storage<T> = crate::guarded::GuardedBox<T>;
+
+/// A random key for fingerprints, chosen once per process
+#[cfg(feature = "fingerprint")]
+fn fingerprint_key() -> &'static [u8; 32] {
+    use std::{
+        collections::hash_map::RandomState,
+        hash::{BuildHasher, Hasher},
+        sync::OnceLock,
+    };
+
+    static KEY: OnceLock<[u8; 32]> = OnceLock::new();
+    KEY.get_or_init(|| {
+        let state = RandomState::new();
+        let mut key = [0u8; 32];
+        for (i, chunk) in key.chunks_mut(8).enumerate() {
+            let mut hasher = state.build_hasher();
+            hasher.write_usize(i);
+            chunk.copy_from_slice(&hasher.finish().to_le_bytes());
+        }
+        key
+    })
+}
+
+/// How hidden data is masked in `Debug` and `Display` output.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Redaction {
+    /// `Hidden<T>`, where `T` is the name of the hidden type. This is the default.
+    #[default]
+    TypeName,
+    /// A fixed placeholder.
+    Placeholder(&'static str),
+    /// `Hidden<T>#abcd`, where `abcd` is the last four hex digits of the SHA-256 hash of the data, keyed with a random
+    /// key chosen once per process. This distinguishes different values in logs without revealing them, so that events
+    /// involving the same value can be correlated within a process. The key means a fingerprint cannot be checked
+    /// against guesses of the data outside the process. Set it with [Hidden::with_fingerprint].
+    Fingerprint(u16),
+}
+
+/// A generic type for data that needs to be kept hidden and zeroized when out of scope, and is accessible only by
+/// reference.
+///
+/// You can define a hidden type using any underlying data type that implements `Zeroize`.
+/// This is the case for most basic types that you probably care about.
+///
+/// Hidden data has useful properties:
+/// - The data is not subject to `Debug` or `Display` output, which are masked.
+/// - The data can only be accessed by (immutable or mutable) reference.
+/// - The data zeroizes when dropped, and can also be manually zeroized.
+/// - Cloning is safe.
+///
+/// Each clone is an independent copy that is zeroized only when that copy is dropped, so a clone can outlive the
+/// original. If every copy should be wiped when the original is dropped, use
+/// [WipeOnClone](wipe_on_clone::WipeOnClone) instead.
+///
+/// Note that it may not be safe to dereference the hidden data if its type implements `Copy`.
+/// If the type does not implement `Copy`, you should be fine.
+/// If it does, avoid dereferencing.
+///
+/// Hidden data supports transparent deserialization, but you'll need to implement serialization yourself if you need
+/// it. To derive `Serialize` for a struct with hidden fields, annotate them with
+/// [deserialize_only](deserialize_only), which refuses to serialize them, or [redacted](redacted), which writes a
+/// redaction marker.
+///
+/// ```edition2018
+/// # use tari_utilities::hidden::Hidden;
+/// # use zeroize::Zeroize;
+///
+/// // In this example, we need to handle secret data of type `[u8; 32]`.
+///
+/// // We can create hidden data from existing data; in this case, it's the caller's responsibility to make sure the existing data is handled securely
+/// let hidden_from_data = Hidden::<[u8; 32]>::hide([1u8; 32]);
+///
+/// // We can access the hidden data as a reference, but not take ownership of it
+/// assert_eq!(hidden_from_data.reveal(), &[1u8; 32]);
+///
+/// // We can create default hidden data and then modify it as a mutable reference; this is common for functions that act on data in place
+/// let mut hidden_in_place = Hidden::<[u8; 32]>::hide([0u8; 32]);
+/// let hidden_in_place_mut_ref = hidden_in_place.reveal_mut();
+/// *hidden_in_place_mut_ref = [42u8; 32];
+/// assert_eq!(hidden_in_place.reveal(), &[42u8; 32]);
+///
+/// // Cloning is safe to do
+/// let mut clone = hidden_in_place.clone();
+/// assert_eq!(hidden_in_place.reveal(), clone.reveal());
+///
+/// // You can manually zeroize the data if you need to
+/// clone.zeroize();
+/// assert_eq!(clone.reveal(), &[0u8; 32]);
+/// ```
+pub struct Hidden<T>
+where T: Zeroize
+{
+    inner: Storage<T>,
+    redaction: Redaction,
+    #[cfg(feature = "debug-reveal-count")]
+    reveals: AtomicUsize,
+}
+
+impl<T> Hidden<T>
+where T: Zeroize
+{
+    /// Create new hidden data from the underlying type
+    pub fn hide(inner: T) -> Self {
+        #[cfg(feature = "track-secrets")]
+        crate::secret_stats::hidden_created(size_of::<T>());
+        Self {
+            inner: Storage::new(inner),
+            redaction: Redaction::TypeName,
+            #[cfg(feature = "debug-reveal-count")]
+            reveals: AtomicUsize::new(0),
+        }
+    }
+
+    /// Create new hidden data by copying `bytes` into a zeroed buffer that is already hidden, so that the data is never
+    /// held in the clear outside of hidden storage. Fails if the length of `bytes` does not match the buffer.
+    pub fn try_from_bytes(bytes: &[u8]) -> Result<Self, ByteArrayError>
+    where T: Default + AsMut<[u8]> {
+        let mut hidden = Self::hide(T::default());
+        let buffer = AsMut::<[u8]>::as_mut(hidden.inner.deref_mut());
+        if buffer.len() != bytes.len() {
+            return Err(ByteArrayError::IncorrectLength {});
+        }
+        buffer.copy_from_slice(bytes);
+        Ok(hidden)
+    }
+
+    /// Create new hidden data by decoding a hex string directly into a zeroed buffer that is already hidden, as for
+    /// [try_from_bytes](Hidden::try_from_bytes). Fails if the decoded length does not match the buffer.
+    pub fn from_hex(hex: &str) -> Result<Self, HexError>
+    where T: Default + AsMut<[u8]> {
+        let mut hidden = Self::hide(T::default());
+        let buffer = AsMut::<[u8]>::as_mut(hidden.inner.deref_mut());
+        let expected = buffer.len();
+        match from_hex_into(hex, buffer) {
+            Ok(written) if written == expected => Ok(hidden),
+            Ok(_) | Err(HexError::OutputTooSmall { .. }) => Err(HexError::HexConversionError {}),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Mask the hidden data with `redaction` in `Debug` and `Display` output.
+    #[must_use]
+    pub fn with_redaction(mut self, redaction: Redaction) -> Self {
+        self.redaction = redaction;
+        self
+    }
+
+    /// Mask the hidden data with a [fingerprint](Redaction::Fingerprint) of its current value. The fingerprint is
+    /// computed once, here, and is not updated if the data is changed later.
+    #[cfg(feature = "fingerprint")]
+    #[must_use]
+    pub fn with_fingerprint(self) -> Self
+    where T: AsRef<[u8]> {
+        use sha2::{Digest, Sha256};
+
+        let hash = Sha256::new()
+            .chain_update(fingerprint_key())
+            .chain_update(self.inner.deref().as_ref())
+            .finalize();
+        let fingerprint = u16::from_be_bytes([hash[30], hash[31]]);
+        self.with_redaction(Redaction::Fingerprint(fingerprint))
+    }
+
+    /// Reveal the hidden data as an immutable reference
+    pub fn reveal(&self) -> &T {
+        #[cfg(feature = "debug-reveal-count")]
+        self.reveals.fetch_add(1, Ordering::Relaxed);
+        self.inner.deref()
+    }
+
+    /// Reveal the hidden data as a mutable reference
+    pub fn reveal_mut(&mut self) -> &mut T {
+        #[cfg(feature = "debug-reveal-count")]
+        self.reveals.fetch_add(1, Ordering::Relaxed);
+        self.inner.deref_mut()
+    }
+
+    /// Return the number of times this value has been revealed by [reveal](Hidden::reveal) or
+    /// [reveal_mut](Hidden::reveal_mut). A clone starts with a count of zero, and the count is not reset by zeroizing.
+    /// Intended for tests that check how often a secret is accessed.
+    #[cfg(feature = "debug-reveal-count")]
+    pub fn reveal_count(&self) -> usize {
+        self.reveals.load(Ordering::Relaxed)
+    }
+}
+
+/// Clone the hidden data into a new, independent hidden value
+impl<T> Clone for Hidden<T>
+where T: Zeroize + Clone
+{
+    fn clone(&self) -> Self {
+        Self::hide(self.reveal().clone()).with_redaction(self.redaction)
+    }
+}
+
+/// Deserialize transparently as the underlying type
+#[cfg(feature = "serde")]
+impl<'de, T> serde::Deserialize<'de> for Hidden<T>
+where T: Zeroize + serde::Deserialize<'de>
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where D: serde::Deserializer<'de> {
+        T::deserialize(deserializer).map(Self::hide)
+    }
+}
+
+/// Only output masked data for debugging, keeping the hidden data hidden
+impl<T> fmt::Debug for Hidden<T>
+where T: Zeroize
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+/// Only display masked data, keeping the hidden data hidden
+impl<T> fmt::Display for Hidden<T>
+where T: Zeroize
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.redaction {
+            Redaction::TypeName => write!(f, "Hidden<{}>", type_name::<T>()),
+            Redaction::Placeholder(placeholder) => f.write_str(placeholder),
+            Redaction::Fingerprint(fingerprint) => write!(f, "Hidden<{}>#{:04x}", type_name::<T>(), fingerprint),
+        }
+    }
+}
+
+/// Zeroize the hidden data
+impl<T> Zeroize for Hidden<T>
+where T: Zeroize
+{
+    fn zeroize(&mut self) {
+        self.inner.deref_mut().zeroize();
+    }
+}
+
+/// Zeroize the hidden data when dropped
+impl<T> Drop for Hidden<T>
+where T: Zeroize
+{
+    fn drop(&mut self) {
+        self.zeroize();
+        #[cfg(feature = "track-secrets")]
+        crate::secret_stats::hidden_dropped(size_of::<T>());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::ToString;
+
+    use super::*;
+    #[test]
+    fn references() {
+        // Check immutable reference
+        assert_eq!(Hidden::hide(0u8).reveal(), &0u8);
+
+        // Check mutable reference
+        let mut hidden = Hidden::hide([0u8; 32]);
+        let hidden_mut_ref = hidden.reveal_mut();
+
+        *hidden_mut_ref = [42u8; 32];
+        assert_eq!(hidden.reveal(), &[42u8; 32]);
+    }
+
+    #[test]
+    fn deserialize() {
+        let value = 1u8;
+        let hidden = Hidden::<u8>::hide(value);
+
+        let ser = value.to_string();
+
+        let deser: Hidden<u8> = serde_json::from_str(&ser).unwrap();
+        assert_eq!(hidden.reveal(), deser.reveal());
+    }
+
+    #[test]
+    fn masking() {
+        let hidden = Hidden::<u8>::hide(1u8);
+        let formatted = format!("{}", hidden);
+        let expected = format!("Hidden<{}>", type_name::<u8>());
+        assert_eq!(formatted, expected);
+    }
+
+    #[test]
+    #[cfg(feature = "debug-reveal-count")]
+    fn reveal_count() {
+        let mut hidden = Hidden::hide([0u8; 32]);
+        assert_eq!(hidden.reveal_count(), 0);
+        let _ = hidden.reveal();
+        hidden.reveal_mut()[0] = 1;
+        assert_eq!(hidden.reveal_count(), 2);
+
+        let clone = hidden.clone();
+        assert_eq!(clone.reveal_count(), 0);
+        assert_eq!(hidden.reveal_count(), 3);
+    }
+
+    #[test]
+    fn redaction() {
+        let hidden = Hidden::hide(1u8).with_redaction(Redaction::Placeholder("<secret>"));
+        assert_eq!(format!("{}", hidden), "<secret>");
+        assert_eq!(format!("{:?}", hidden.clone()), "<secret>");
+        let hidden = Hidden::hide(1u8).with_redaction(Redaction::Fingerprint(0xab));
+        assert_eq!(format!("{}", hidden), "Hidden<u8>#00ab");
+
+        hidden_type!(ApiKey, [u8; 4], redact = "<api key>");
+        let key = ApiKey::from([1u8; 4]);
+        assert_eq!(format!("{:?}", key), "ApiKey { data: <api key> }");
+        assert_eq!(key.reveal(), &[1u8; 4]);
+    }
+
+    #[test]
+    #[cfg(feature = "fingerprint")]
+    fn fingerprint() {
+        use sha2::{Digest, Sha256};
+
+        let a = Hidden::hide([1u8; 32]).with_fingerprint();
+        let b = Hidden::hide([2u8; 32]).with_fingerprint();
+        let hash = Sha256::new()
+            .chain_update(fingerprint_key())
+            .chain_update([1u8; 32])
+            .finalize();
+        let expected = format!("Hidden<[u8; 32]>#{:02x}{:02x}", hash[30], hash[31]);
+        assert_eq!(a.to_string(), expected);
+        assert_eq!(a.clone().to_string(), expected);
+        assert_ne!(a.to_string(), b.to_string());
+        assert_eq!(Hidden::hide([1u8; 32]).with_fingerprint().to_string(), expected);
+
+        hidden_type!(Key, [u8; 32], fingerprint);
+        assert_eq!(
+            format!("{:?}", Key::from([1u8; 32])),
+            format!("Key {{ data: {} }}", expected)
+        );
+    }
+
+    #[test]
+    fn macro_attributes() {
+        hidden_type!(
+            /// A documented key
+            #[cfg(test)]
+            #[derive(serde::Deserialize)]
+            #[serde(transparent)]
+            Documented,
+            [u8; 2]
+        );
+        let key: Documented = serde_json::from_str("[1,2]").unwrap();
+        assert_eq!(key.reveal(), &[1, 2]);
+
+        hidden_type!(
+            #[cfg(not(test))]
+            CompiledOut,
+            [u8; 2]
+        );
+        // The impls are compiled out along with the struct, so this is an independent type
+        #[allow(dead_code)]
+        struct CompiledOut;
+
+        hidden_type!(
+            #[allow(dead_code)]
+            Redacted,
+            u8,
+            redact = "<r>"
+        );
+        assert_eq!(format!("{:?}", Redacted::from(1)), "Redacted { data: <r> }");
+
+        hidden_type!(
+            #[derive(serde::Deserialize)]
+            #[serde(from = "u8")]
+            DeserializedRedacted,
+            u8,
+            redact = "<r>"
+        );
+        let key: DeserializedRedacted = serde_json::from_str("1").unwrap();
+        assert_eq!(format!("{:?}", key), "DeserializedRedacted { data: <r> }");
+    }
+
+    #[test]
+    fn from_bytes() {
+        let hidden = Hidden::<[u8; 4]>::try_from_bytes(&[1, 2, 3, 4]).unwrap();
+        assert_eq!(hidden.reveal(), &[1, 2, 3, 4]);
+        assert_eq!(
+            Hidden::<[u8; 4]>::try_from_bytes(&[1, 2, 3]).unwrap_err(),
+            ByteArrayError::IncorrectLength {}
+        );
+
+        let hidden = Hidden::<[u8; 4]>::from_hex("01020304").unwrap();
+        assert_eq!(hidden.reveal(), &[1, 2, 3, 4]);
+        for bad in ["010203", "0102030405", "0102030g", "0x01020304"] {
+            assert!(Hidden::<[u8; 4]>::from_hex(bad).is_err());
+        }
+        assert!(matches!(
+            Hidden::<[u8; 4]>::from_hex("0102030405"),
+            Err(HexError::HexConversionError {})
+        ));
+
+        hidden_type!(
+            #[cfg(feature = "std")]
+            LargeKey,
+            crate::safe_array::SafeArray<u8, 64>,
+            from_hex,
+            from_bytes,
+            redact = "<key>"
+        );
+        #[cfg(feature = "std")]
+        {
+            let key = LargeKey::from_hex(&"ab".repeat(64)).unwrap();
+            assert_eq!(key.reveal().as_ref(), &[0xab; 64][..]);
+            assert_eq!(format!("{:?}", key), "LargeKey { data: <key> }");
+            assert!(LargeKey::try_from_bytes(&[0; 63]).is_err());
+        }
+    }
+
+    #[test]
+    fn macro_types() {
+        hidden_type!(TypeA, [u8; 32]);
+        hidden_type!(TypeB, [u8; 32]);
+        let a = TypeA::from([1u8; 32]);
+        let b = TypeB::from([1u8; 32]);
+
+        assert_eq!(a.reveal(), &[1u8; 32]);
+        assert_eq!(b.reveal(), &[1u8; 32]);
+    }
+}
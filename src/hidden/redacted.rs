@@ -0,0 +1,92 @@
+// Copyright 2026, The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+//! Serde support for [Hidden] fields that are written out only as a redaction marker.
+//!
+//! Annotating a field with `#[serde(with = "tari_utilities::hidden::redacted")]` deserializes the secret as usual, but
+//! serializes it as its masked `Display` output, such as `Hidden<[u8; 32]>` or a placeholder set with
+//! [with_redaction](Hidden::with_redaction). The rest of the struct can then be written out, for example to show the
+//! effective configuration, without the secret. The marker is not valid input for most secret types, so a written
+//! file does not silently load with a placeholder key; beware of string secrets, for which it is. To fail instead, use
+//! [deserialize_only](super::deserialize_only).
+//!
+//! ```edition2018
+//! # use serde::{Deserialize, Serialize};
+//! # use tari_utilities::Hidden;
+//! #[derive(Deserialize, Serialize)]
+//! struct Provisioning {
+//!     node: String,
+//!     #[serde(with = "tari_utilities::hidden::redacted")]
+//!     key: Hidden<[u8; 4]>,
+//! }
+//!
+//! let config: Provisioning = serde_json::from_str(r#"{"node":"a","key":[1,2,3,4]}"#).unwrap();
+//! assert_eq!(
+//!     serde_json::to_string(&config).unwrap(),
+//!     r#"{"node":"a","key":"Hidden<[u8; 4]>"}"#
+//! );
+//! ```
+
+use serde::{Deserialize, Deserializer, Serializer};
+use zeroize::Zeroize;
+
+use super::Hidden;
+
+/// Serializes hidden data as its redaction marker, never the data itself.
+pub fn serialize<S, T>(data: &Hidden<T>, ser: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+    T: Zeroize,
+{
+    ser.collect_str(data)
+}
+
+/// Deserializes hidden data transparently as the underlying type.
+pub fn deserialize<'de, D, T>(de: D) -> Result<Hidden<T>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Zeroize + Deserialize<'de>,
+{
+    Hidden::deserialize(de)
+}
+
+#[cfg(test)]
+mod test {
+    use serde::{Deserialize, Serialize};
+
+    use super::*;
+    use crate::hidden::Redaction;
+
+    #[derive(Deserialize, Serialize)]
+    struct Config(#[serde(with = "super")] Hidden<[u8; 2]>);
+
+    #[test]
+    fn redacted() {
+        let config: Config = serde_json::from_str("[1,2]").unwrap();
+        assert_eq!(config.0.reveal(), &[1, 2]);
+        assert_eq!(serde_json::to_string(&config).unwrap(), r#""Hidden<[u8; 2]>""#);
+
+        let config = Config(Hidden::hide([1, 2]).with_redaction(Redaction::Placeholder("<key>")));
+        let written = serde_json::to_string(&config).unwrap();
+        assert_eq!(written, r#""<key>""#);
+        assert!(serde_json::from_str::<Config>(&written).is_err());
+    }
+}
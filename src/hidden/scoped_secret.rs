@@ -0,0 +1,160 @@
+// Copyright 2026, The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+//! Scratch buffers that are zeroized when they go out of scope.
+//!
+//! Key derivation and encryption often need an intermediate buffer, and it is easy to forget to wipe it, or to miss
+//! an early return or panic that skips the wipe. A [ScopedSecret] borrows a buffer and zeroizes it when the guard is
+//! dropped, which also happens while unwinding from a panic. The [zeroize_guard!](crate::zeroize_guard) macro either
+//! guards an existing buffer or declares a new one that can only be reached through the guard.
+//!
+//! ```edition2018
+//! # #[macro_use] extern crate tari_utilities;
+//! # fn main() {
+//! let mut derived = [0u8; 4];
+//! {
+//!     zeroize_guard!(scratch = [0u8; 32]);
+//!     scratch[..4].copy_from_slice(&[1, 2, 3, 4]);
+//!     derived.copy_from_slice(&scratch[..4]);
+//! } // `scratch` is zeroized here
+//! assert_eq!(derived, [1, 2, 3, 4]);
+//! # }
+//! ```
+
+use core::{
+    fmt,
+    ops::{Deref, DerefMut},
+};
+
+use zeroize::Zeroize;
+
+/// A borrowed byte buffer that is zeroized when the guard is dropped, including during a panic. The buffer is
+/// accessed through `Deref` and `DerefMut`, and is masked in `Debug` output.
+pub struct ScopedSecret<'a> {
+    buffer: &'a mut [u8],
+}
+
+impl<'a> ScopedSecret<'a> {
+    /// Guard `buffer`, which will be zeroized when the guard is dropped.
+    pub fn new(buffer: &'a mut [u8]) -> Self {
+        Self { buffer }
+    }
+}
+
+impl Deref for ScopedSecret<'_> {
+    type Target = [u8];
+
+    fn deref(&self) -> &[u8] {
+        self.buffer
+    }
+}
+
+impl DerefMut for ScopedSecret<'_> {
+    fn deref_mut(&mut self) -> &mut [u8] {
+        self.buffer
+    }
+}
+
+/// Only output the buffer length for debugging, keeping the contents hidden
+impl fmt::Debug for ScopedSecret<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "ScopedSecret<{} bytes>", self.buffer.len())
+    }
+}
+
+/// Zeroize the buffer when the guard goes out of scope
+impl Drop for ScopedSecret<'_> {
+    fn drop(&mut self) {
+        self.buffer.zeroize();
+    }
+}
+
+/// Guard a scratch buffer with a [ScopedSecret](crate::hidden::scoped_secret::ScopedSecret) that zeroizes it at the
+/// end of the enclosing scope, even if the scope is left early or by a panic.
+///
+/// - `zeroize_guard!(buffer)` shadows an existing mutable buffer variable with a guard over it.
+/// - `zeroize_guard!(name = init)` declares a new buffer, such as `[0u8; 32]` or `vec![0u8; n]`, that is only reachable
+///   through the guard `name`.
+///
+/// ```edition2018
+/// # #[macro_use] extern crate tari_utilities;
+/// # fn main() {
+/// let mut key = [7u8; 16];
+/// {
+///     zeroize_guard!(key);
+///     assert_eq!(key[0], 7);
+/// }
+/// assert_eq!(key, [0u8; 16]);
+/// # }
+/// ```
+#[macro_export]
+macro_rules! zeroize_guard {
+    ($name:ident = $init:expr) => {
+        let mut buffer = $init;
+        #[allow(unused_mut)]
+        let mut $name = $crate::hidden::scoped_secret::ScopedSecret::new(&mut buffer[..]);
+    };
+    ($name:ident) => {
+        #[allow(unused_mut)]
+        let mut $name = $crate::hidden::scoped_secret::ScopedSecret::new(&mut $name[..]);
+    };
+}
+
+#[cfg(test)]
+mod test {
+    use std::panic::{catch_unwind, AssertUnwindSafe};
+
+    use super::*;
+
+    #[test]
+    fn zeroized_on_drop() {
+        let mut buffer = [1u8; 8];
+        {
+            let mut guard = ScopedSecret::new(&mut buffer);
+            guard[0] = 2;
+            assert_eq!(&guard[..2], &[2, 1]);
+            assert_eq!(format!("{:?}", guard), "ScopedSecret<8 bytes>");
+        }
+        assert_eq!(buffer, [0u8; 8]);
+    }
+
+    #[test]
+    fn zeroized_on_panic() {
+        let mut buffer = vec![1u8; 8];
+        let result = catch_unwind(AssertUnwindSafe(|| {
+            zeroize_guard!(buffer);
+            buffer[0] = 2;
+            panic!("failed mid-derivation");
+        }));
+        assert!(result.is_err());
+        assert_eq!(buffer, vec![0u8; 8]);
+    }
+
+    #[test]
+    fn declared_buffer() {
+        let copied = {
+            zeroize_guard!(scratch = [0u8; 4]);
+            scratch.copy_from_slice(&[1, 2, 3, 4]);
+            scratch.to_vec()
+        };
+        assert_eq!(copied, [1, 2, 3, 4]);
+    }
+}
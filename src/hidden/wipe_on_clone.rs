@@ -0,0 +1,233 @@
+// Copyright 2026, The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+//! Secrets whose clones are wiped together with the original.
+//!
+//! Cloning a [Hidden](super::Hidden) value produces an independent copy that is only zeroized when that copy is
+//! dropped, so a clone handed to another component can outlive the original. A [WipeOnClone] instead registers every
+//! clone in a shared group: when the original is dropped, or [wipe_all](WipeOnClone::wipe_all) is called on any member
+//! of the group, every live copy is zeroized in place.
+//!
+//! ```edition2018
+//! # use tari_utilities::hidden::wipe_on_clone::WipeOnClone;
+//! let original = WipeOnClone::new(vec![1u8; 32]);
+//! let copy = original.clone();
+//! assert_eq!(copy.reveal(|key| key.clone()), vec![1u8; 32]);
+//!
+//! drop(original);
+//! assert!(copy.is_wiped());
+//! assert!(copy.reveal(|key| key.is_empty()));
+//! ```
+
+use alloc::{
+    sync::{Arc, Weak},
+    vec::Vec,
+};
+use core::{any::type_name, fmt};
+use std::sync::Mutex;
+
+use zeroize::Zeroize;
+
+use crate::{hidden::Hidden, locks::poison::recover_lock};
+
+struct TrackedCopy<T: Zeroize> {
+    value: Hidden<T>,
+    wiped: bool,
+}
+
+type Slot<T> = Arc<Mutex<TrackedCopy<T>>>;
+type Group<T> = Arc<Mutex<Vec<Weak<Mutex<TrackedCopy<T>>>>>>;
+
+/// A secret whose clones are all zeroized when the original is dropped. See the [module documentation](self) for
+/// details.
+pub struct WipeOnClone<T: Zeroize> {
+    slot: Slot<T>,
+    group: Group<T>,
+    is_original: bool,
+}
+
+impl<T: Zeroize> WipeOnClone<T> {
+    /// Hide a secret. The returned value is the original: dropping it wipes every clone.
+    pub fn new(value: T) -> Self {
+        let slot = Arc::new(Mutex::new(TrackedCopy {
+            value: Hidden::hide(value),
+            wiped: false,
+        }));
+        let group = Arc::new(Mutex::new(vec![Arc::downgrade(&slot)]));
+        Self {
+            slot,
+            group,
+            is_original: true,
+        }
+    }
+
+    /// Call `f` with a reference to this copy of the secret. A wiped secret has been zeroized.
+    pub fn reveal<R, F>(&self, f: F) -> R
+    where F: FnOnce(&T) -> R {
+        f(recover_lock(&self.slot).value.reveal())
+    }
+
+    /// Call `f` with a mutable reference to this copy of the secret. Changes are not propagated to other copies.
+    pub fn reveal_mut<R, F>(&mut self, f: F) -> R
+    where F: FnOnce(&mut T) -> R {
+        f(recover_lock(&self.slot).value.reveal_mut())
+    }
+
+    /// Returns true if this copy has been wiped
+    pub fn is_wiped(&self) -> bool {
+        recover_lock(&self.slot).wiped
+    }
+
+    /// Returns true if this is the original secret rather than a clone
+    pub fn is_original(&self) -> bool {
+        self.is_original
+    }
+
+    /// Zeroize every live copy of the secret, including this one
+    pub fn wipe_all(&self) {
+        let mut group = recover_lock(&self.group);
+        group.retain(|weak| match weak.upgrade() {
+            Some(slot) => {
+                let mut copy = recover_lock(&slot);
+                copy.value.zeroize();
+                copy.wiped = true;
+                true
+            },
+            None => false,
+        });
+    }
+
+    /// The number of live copies of the secret, including the original and this one. This is only available in debug
+    /// builds, to help track down clones that outlive the original.
+    #[cfg(debug_assertions)]
+    pub fn live_copies(&self) -> usize {
+        let mut group = recover_lock(&self.group);
+        group.retain(|weak| weak.strong_count() > 0);
+        group.len()
+    }
+}
+
+impl<T: Zeroize + Clone> Clone for WipeOnClone<T> {
+    /// Clone the secret, registering the clone so that it is wiped along with the original
+    fn clone(&self) -> Self {
+        let copy = recover_lock(&self.slot);
+        let slot = Arc::new(Mutex::new(TrackedCopy {
+            value: copy.value.clone(),
+            wiped: copy.wiped,
+        }));
+        drop(copy);
+        let mut group = recover_lock(&self.group);
+        group.retain(|weak| weak.strong_count() > 0);
+        group.push(Arc::downgrade(&slot));
+        drop(group);
+        Self {
+            slot,
+            group: self.group.clone(),
+            is_original: false,
+        }
+    }
+}
+
+impl<T: Zeroize> Zeroize for WipeOnClone<T> {
+    /// Zeroize this copy of the secret only. Use [wipe_all](WipeOnClone::wipe_all) to zeroize every copy.
+    fn zeroize(&mut self) {
+        let mut copy = recover_lock(&self.slot);
+        copy.value.zeroize();
+        copy.wiped = true;
+    }
+}
+
+impl<T: Zeroize> Drop for WipeOnClone<T> {
+    /// Dropping the original wipes every clone. Dropping a clone zeroizes only that clone.
+    fn drop(&mut self) {
+        if self.is_original {
+            self.wipe_all();
+        }
+    }
+}
+
+/// Only output masked data for debugging, keeping the hidden data hidden
+impl<T: Zeroize> fmt::Debug for WipeOnClone<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "WipeOnClone<{}>", type_name::<T>())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn clones_are_wiped_with_original() {
+        let original = WipeOnClone::new([7u8; 32]);
+        let a = original.clone();
+        let b = a.clone();
+        assert!(original.is_original());
+        assert!(!a.is_original());
+        assert_eq!(b.reveal(|v| *v), [7u8; 32]);
+        drop(original);
+        assert!(a.is_wiped() && b.is_wiped());
+        assert_eq!(a.reveal(|v| *v), [0u8; 32]);
+        assert_eq!(b.reveal(|v| *v), [0u8; 32]);
+    }
+
+    #[test]
+    fn dropping_a_clone_does_not_wipe_others() {
+        let original = WipeOnClone::new(42u64);
+        let mut a = original.clone();
+        let b = original.clone();
+        a.reveal_mut(|v| *v += 1);
+        assert_eq!(a.reveal(|v| *v), 43);
+        assert_eq!(original.reveal(|v| *v), 42);
+        drop(a);
+        assert!(!b.is_wiped());
+        assert_eq!(b.reveal(|v| *v), 42);
+    }
+
+    #[test]
+    fn wipe_all_from_clone() {
+        let original = WipeOnClone::new(1u32);
+        let mut a = original.clone();
+        a.wipe_all();
+        assert!(original.is_wiped());
+        assert_eq!(original.reveal(|v| *v), 0);
+
+        let b = original.clone();
+        assert!(b.is_wiped());
+        a.zeroize();
+        assert_eq!(format!("{:?}", a), "WipeOnClone<u32>");
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    fn live_copies() {
+        let original = WipeOnClone::new(1u8);
+        assert_eq!(original.live_copies(), 1);
+        let a = original.clone();
+        let b = a.clone();
+        assert_eq!(original.live_copies(), 3);
+        drop(a);
+        assert_eq!(b.live_copies(), 2);
+        drop(original);
+        assert_eq!(b.live_copies(), 1);
+    }
+}
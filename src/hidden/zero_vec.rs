@@ -0,0 +1,217 @@
+// Copyright 2026, The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+//! A growable vector of secret data that does not leave stale copies behind when it grows.
+//!
+//! When a `Vec` outgrows its capacity it moves its contents to a new allocation and frees the old one without clearing
+//! it, so wrapping a `Vec` in `Zeroizing` or [Hidden](super::Hidden) only wipes the final allocation. A [ZeroVec]
+//! instead manages its own growth: it moves its contents to the new allocation and zeroizes the old one before freeing
+//! it. Reserving the final capacity up front with [with_capacity](ZeroVec::with_capacity) or
+//! [reserve](ZeroVec::reserve) avoids reallocating at all.
+//!
+//! ```edition2018
+//! # use tari_utilities::hidden::zero_vec::ZeroVec;
+//! let mut seed = ZeroVec::with_capacity(32);
+//! seed.extend_from_slice(&[1u8; 16]);
+//! seed.extend_from_slice(&[2u8; 16]);
+//! assert_eq!(seed.len(), 32);
+//! assert_eq!(&seed.reveal()[15..17], &[1, 2]);
+//! ```
+
+use alloc::vec::Vec;
+use core::{any::type_name, fmt};
+
+use zeroize::Zeroize;
+
+/// A vector that zeroizes its contents when dropped, and zeroizes its old allocation whenever it grows. Like
+/// [Hidden](super::Hidden), its contents are masked in `Debug` output and can only be accessed by reference.
+pub struct ZeroVec<T: Zeroize> {
+    inner: Vec<T>,
+}
+
+impl<T: Zeroize> ZeroVec<T> {
+    /// Create an empty vector, which does not allocate until items are added.
+    pub fn new() -> Self {
+        Self { inner: Vec::new() }
+    }
+
+    /// Create an empty vector with room for at least `capacity` items before it needs to reallocate.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            inner: Vec::with_capacity(capacity),
+        }
+    }
+
+    /// Make room for at least `additional` more items. If the vector has to grow, its contents are moved to a new
+    /// allocation and the old allocation is zeroized.
+    pub fn reserve(&mut self, additional: usize) {
+        let required = self.inner.len().saturating_add(additional);
+        if required <= self.inner.capacity() {
+            return;
+        }
+        let capacity = required.max(self.inner.capacity().saturating_mul(2));
+        let mut grown = Vec::with_capacity(capacity);
+        grown.append(&mut self.inner);
+        // The old allocation still holds the moved bytes; with no items left, this zeroizes all of it
+        self.inner.zeroize();
+        self.inner = grown;
+    }
+
+    /// Append an item, growing the vector as described in [reserve](ZeroVec::reserve) if it is full.
+    pub fn push(&mut self, value: T) {
+        self.reserve(1);
+        self.inner.push(value);
+    }
+
+    /// Append clones of the items in `values`, growing the vector at most once.
+    pub fn extend_from_slice(&mut self, values: &[T])
+    where T: Clone {
+        self.reserve(values.len());
+        self.inner.extend_from_slice(values);
+    }
+
+    /// Zeroize and remove the items after the first `len`. Has no effect if the vector holds `len` items or fewer.
+    pub fn truncate(&mut self, len: usize) {
+        if let Some(tail) = self.inner.get_mut(len..) {
+            tail.iter_mut().zeroize();
+        }
+        self.inner.truncate(len);
+    }
+
+    /// Zeroize and remove all items, keeping the allocation for reuse.
+    pub fn clear(&mut self) {
+        self.truncate(0);
+    }
+
+    /// Return the number of items.
+    pub fn len(&self) -> usize {
+        self.inner.len()
+    }
+
+    /// Return true if the vector holds no items.
+    pub fn is_empty(&self) -> bool {
+        self.inner.is_empty()
+    }
+
+    /// Return the number of items the vector can hold without reallocating.
+    pub fn capacity(&self) -> usize {
+        self.inner.capacity()
+    }
+
+    /// Reveal the items as an immutable slice.
+    pub fn reveal(&self) -> &[T] {
+        &self.inner
+    }
+
+    /// Reveal the items as a mutable slice.
+    pub fn reveal_mut(&mut self) -> &mut [T] {
+        &mut self.inner
+    }
+}
+
+impl<T: Zeroize> Default for ZeroVec<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Take ownership of an existing vector. Any earlier allocations made by `value` as it grew are not zeroized.
+impl<T: Zeroize> From<Vec<T>> for ZeroVec<T> {
+    fn from(value: Vec<T>) -> Self {
+        Self { inner: value }
+    }
+}
+
+/// Clone the items into a new vector with just enough capacity for them
+impl<T: Zeroize + Clone> Clone for ZeroVec<T> {
+    fn clone(&self) -> Self {
+        let mut clone = Self::with_capacity(self.len());
+        clone.extend_from_slice(self.reveal());
+        clone
+    }
+}
+
+/// Only output masked data for debugging, keeping the items hidden
+impl<T: Zeroize> fmt::Debug for ZeroVec<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "ZeroVec<{}>", type_name::<T>())
+    }
+}
+
+/// Zeroize the items and the whole allocation, leaving the vector empty
+impl<T: Zeroize> Zeroize for ZeroVec<T> {
+    fn zeroize(&mut self) {
+        self.inner.zeroize();
+    }
+}
+
+/// Zeroize the items and the whole allocation when dropped
+impl<T: Zeroize> Drop for ZeroVec<T> {
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn growth_keeps_contents() {
+        let mut vec = ZeroVec::new();
+        for i in 0..100u8 {
+            vec.push(i);
+        }
+        assert_eq!(vec.reveal(), (0..100u8).collect::<Vec<_>>().as_slice());
+        assert!(vec.capacity() >= 100);
+    }
+
+    #[test]
+    fn reserve() {
+        let mut vec = ZeroVec::with_capacity(8);
+        let capacity = vec.capacity();
+        vec.extend_from_slice(&[1u8; 8]);
+        assert_eq!(vec.capacity(), capacity);
+        vec.reserve(100);
+        assert!(vec.capacity() >= 108);
+        assert_eq!(vec.reveal(), &[1u8; 8]);
+    }
+
+    #[test]
+    fn truncate_and_clear() {
+        let mut vec = ZeroVec::from(vec![1u8, 2, 3, 4]);
+        vec.truncate(10);
+        assert_eq!(vec.len(), 4);
+        vec.truncate(2);
+        assert_eq!(vec.reveal(), &[1, 2]);
+        vec.reveal_mut()[0] = 5;
+        assert_eq!(vec.clone().reveal(), &[5, 2]);
+        vec.clear();
+        assert!(vec.is_empty());
+        assert!(vec.capacity() >= 4);
+    }
+
+    #[test]
+    fn masking() {
+        let vec = ZeroVec::from(vec![1u8]);
+        assert_eq!(format!("{:?}", vec), "ZeroVec<u8>");
+    }
+}
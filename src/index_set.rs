@@ -0,0 +1,275 @@
+// Copyright 2026, The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+//! A compact set of small indices, stored as a bit array.
+
+use snafu::prelude::*;
+
+const WORD_BITS: usize = u64::BITS as usize;
+
+/// Errors that can occur when using an [IndexSet].
+#[derive(Debug, Snafu, PartialEq, Eq)]
+pub enum IndexSetError {
+    /// The index does not fit in the set.
+    #[snafu(display("Index {index} is out of range for a set of {bits} bits"))]
+    OutOfRange {
+        /// The index that was given
+        index: usize,
+        /// The number of indices the set can hold
+        bits: usize,
+    },
+}
+
+/// A set of indices in `0..BITS`, using one bit per index. Membership tests are constant time and iteration skips
+/// directly to the set bits.
+///
+/// The bits are stored inline in `WORDS` 64-bit words, so `WORDS` must be `BITS.div_ceil(64)`; a mismatch fails to
+/// compile. It defaults to one word, which covers sets of up to 64 indices.
+///
+/// ```edition2018
+/// # use tari_utilities::index_set::IndexSet;
+/// let mut signed = IndexSet::<100, 2>::new();
+/// signed.insert(3).unwrap();
+/// signed.insert(64).unwrap();
+/// assert!(signed.contains(64));
+/// assert!(signed.insert(100).is_err());
+/// assert_eq!(signed.iter().collect::<Vec<_>>(), vec![3, 64]);
+/// ```
+///
+/// ```compile_fail
+/// # use tari_utilities::index_set::IndexSet;
+/// // 100 bits need two words
+/// let set = IndexSet::<100>::new();
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct IndexSet<const BITS: usize, const WORDS: usize = 1> {
+    words: [u64; WORDS],
+}
+
+impl<const BITS: usize, const WORDS: usize> IndexSet<BITS, WORDS> {
+    const WORDS_MATCH_BITS: () = assert!(WORDS == BITS.div_ceil(WORD_BITS), "WORDS must be BITS.div_ceil(64)");
+
+    /// Creates an empty set.
+    pub fn new() -> Self {
+        #[allow(clippy::let_unit_value)]
+        let () = Self::WORDS_MATCH_BITS;
+        Self { words: [0; WORDS] }
+    }
+
+    /// Creates a set containing every index in `0..BITS`.
+    pub fn full() -> Self {
+        let mut set = Self::new();
+        set.words.iter_mut().for_each(|word| *word = u64::MAX);
+        set.clear_unused_bits();
+        set
+    }
+
+    /// Returns the number of indices the set can hold.
+    pub fn capacity(&self) -> usize {
+        BITS
+    }
+
+    /// Adds `index` to the set, returning true if it was not already present.
+    pub fn insert(&mut self, index: usize) -> Result<bool, IndexSetError> {
+        let (word, mask) = Self::locate(index)?;
+        let added = self.words[word] & mask == 0;
+        self.words[word] |= mask;
+        Ok(added)
+    }
+
+    /// Removes `index` from the set, returning true if it was present.
+    pub fn remove(&mut self, index: usize) -> Result<bool, IndexSetError> {
+        let (word, mask) = Self::locate(index)?;
+        let removed = self.words[word] & mask != 0;
+        self.words[word] &= !mask;
+        Ok(removed)
+    }
+
+    /// Returns true if `index` is in the set. Indices out of range are never present.
+    pub fn contains(&self, index: usize) -> bool {
+        Self::locate(index)
+            .map(|(word, mask)| self.words[word] & mask != 0)
+            .unwrap_or(false)
+    }
+
+    /// Returns the number of indices in the set.
+    pub fn len(&self) -> usize {
+        self.words.iter().map(|word| word.count_ones() as usize).sum()
+    }
+
+    /// Returns true if the set holds no indices.
+    pub fn is_empty(&self) -> bool {
+        self.words.iter().all(|word| *word == 0)
+    }
+
+    /// Removes every index from the set.
+    pub fn clear(&mut self) {
+        self.words.iter_mut().for_each(|word| *word = 0);
+    }
+
+    /// Adds every index in `other` to this set.
+    pub fn union_with(&mut self, other: &Self) {
+        self.words.iter_mut().zip(&other.words).for_each(|(a, b)| *a |= b);
+    }
+
+    /// Removes every index that is not also in `other` from this set.
+    pub fn intersect_with(&mut self, other: &Self) {
+        self.words.iter_mut().zip(&other.words).for_each(|(a, b)| *a &= b);
+    }
+
+    /// Removes every index in `other` from this set.
+    pub fn difference_with(&mut self, other: &Self) {
+        self.words.iter_mut().zip(&other.words).for_each(|(a, b)| *a &= !b);
+    }
+
+    /// Returns true if every index in this set is also in `other`.
+    pub fn is_subset(&self, other: &Self) -> bool {
+        self.words.iter().zip(&other.words).all(|(a, b)| a & !b == 0)
+    }
+
+    /// Returns an iterator over the indices in the set, in ascending order.
+    pub fn iter(&self) -> Iter<'_> {
+        Iter {
+            words: &self.words,
+            word_index: 0,
+            current: self.words.first().copied().unwrap_or(0),
+        }
+    }
+
+    fn locate(index: usize) -> Result<(usize, u64), IndexSetError> {
+        ensure!(index < BITS, OutOfRangeSnafu { index, bits: BITS });
+        Ok((index / WORD_BITS, 1 << (index % WORD_BITS)))
+    }
+
+    fn clear_unused_bits(&mut self) {
+        let used = BITS % WORD_BITS;
+        if let (Some(last), true) = (self.words.last_mut(), used != 0) {
+            *last &= (1 << used) - 1;
+        }
+    }
+}
+
+impl<const BITS: usize, const WORDS: usize> Default for IndexSet<BITS, WORDS> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a, const BITS: usize, const WORDS: usize> IntoIterator for &'a IndexSet<BITS, WORDS> {
+    type IntoIter = Iter<'a>;
+    type Item = usize;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// An iterator over the indices in an [IndexSet], in ascending order.
+#[derive(Clone, Debug)]
+pub struct Iter<'a> {
+    words: &'a [u64],
+    word_index: usize,
+    current: u64,
+}
+
+impl Iterator for Iter<'_> {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<usize> {
+        while self.current == 0 {
+            self.word_index += 1;
+            self.current = *self.words.get(self.word_index)?;
+        }
+        let bit = self.current.trailing_zeros() as usize;
+        // Clear the lowest set bit
+        self.current &= self.current - 1;
+        Some(self.word_index * WORD_BITS + bit)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use alloc::{vec, vec::Vec};
+
+    use super::*;
+
+    #[test]
+    fn insert_and_remove() {
+        let mut set = IndexSet::<130, 3>::default();
+        assert!(set.is_empty());
+        assert_eq!(set.insert(0), Ok(true));
+        assert_eq!(set.insert(0), Ok(false));
+        assert_eq!(set.insert(129), Ok(true));
+        assert_eq!(
+            set.insert(130),
+            Err(IndexSetError::OutOfRange { index: 130, bits: 130 })
+        );
+        assert!(set.contains(129));
+        assert!(!set.contains(130));
+        assert_eq!(set.len(), 2);
+        assert_eq!(set.remove(0), Ok(true));
+        assert_eq!(set.remove(0), Ok(false));
+        assert!(set.remove(1000).is_err());
+        set.clear();
+        assert!(set.is_empty());
+        assert_eq!(set.capacity(), 130);
+    }
+
+    #[test]
+    fn iteration() {
+        let mut set = IndexSet::<200, 4>::new();
+        let indices = [1usize, 63, 64, 65, 127, 128, 199];
+        for i in indices {
+            set.insert(i).unwrap();
+        }
+        assert_eq!(set.iter().collect::<Vec<_>>(), indices.to_vec());
+        assert_eq!((&set).into_iter().count(), indices.len());
+        assert_eq!(IndexSet::<0, 0>::new().iter().next(), None);
+        assert_eq!(
+            IndexSet::<70, 2>::full().iter().collect::<Vec<_>>(),
+            (0..70).collect::<Vec<_>>()
+        );
+        assert_eq!(IndexSet::<64>::full().len(), 64);
+    }
+
+    #[test]
+    fn set_operations() {
+        let mut a = IndexSet::<16>::new();
+        let mut b = IndexSet::<16>::new();
+        for i in [1, 2, 3] {
+            a.insert(i).unwrap();
+        }
+        for i in [3, 4] {
+            b.insert(i).unwrap();
+        }
+        let mut union = a.clone();
+        union.union_with(&b);
+        assert_eq!(union.iter().collect::<Vec<_>>(), vec![1, 2, 3, 4]);
+        assert!(a.is_subset(&union));
+        assert!(!union.is_subset(&a));
+        let mut intersection = a.clone();
+        intersection.intersect_with(&b);
+        assert_eq!(intersection.iter().collect::<Vec<_>>(), vec![3]);
+        a.difference_with(&b);
+        assert_eq!(a.iter().collect::<Vec<_>>(), vec![1, 2]);
+    }
+}
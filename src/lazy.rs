@@ -0,0 +1,319 @@
+// Copyright 2026, The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+//! One-time initialization primitives that work under `no_std` + `alloc`.
+//!
+//! [OnceInit] is a cell that can be written once, and [LazyInit] is a value that is computed on first access. Both are
+//! `Sync` and can be used in `static`s. Threads that race to initialize a value wait by spinning (yielding to the
+//! scheduler when `std` is available) until the winning thread finishes, so initializers should be short. If an
+//! initializer panics, the cell is left uninitialized and a later access will retry.
+//!
+//! ```edition2018
+//! # use tari_utilities::lazy::LazyInit;
+//! static SQUARES: LazyInit<Vec<u32>> = LazyInit::new(|| (0..16).map(|i| i * i).collect());
+//!
+//! assert_eq!(SQUARES[4], 16);
+//! ```
+
+use core::{
+    cell::UnsafeCell,
+    fmt,
+    mem::MaybeUninit,
+    ops::Deref,
+    sync::atomic::{AtomicU8, Ordering},
+};
+
+const UNINIT: u8 = 0;
+const RUNNING: u8 = 1;
+const COMPLETE: u8 = 2;
+
+/// A thread-safe cell that can be written to only once. See the [module documentation](self) for details.
+pub struct OnceInit<T> {
+    state: AtomicU8,
+    value: UnsafeCell<MaybeUninit<T>>,
+}
+
+// SAFETY: the value is only written by the single thread that moves the state from `UNINIT` to `RUNNING`, and is only
+// read once the state is `COMPLETE`, after which it is never written again. Sending the cell to another thread may
+// send the value, and sharing the cell shares `&T`.
+unsafe impl<T: Send + Sync> Sync for OnceInit<T> {}
+unsafe impl<T: Send> Send for OnceInit<T> {}
+
+/// Resets the state to `UNINIT` if an initializer panics, so that a later access can retry.
+struct ResetOnUnwind<'a> {
+    state: &'a AtomicU8,
+}
+
+impl Drop for ResetOnUnwind<'_> {
+    fn drop(&mut self) {
+        self.state.store(UNINIT, Ordering::Release);
+    }
+}
+
+impl<T> OnceInit<T> {
+    /// Create a new, uninitialized cell
+    pub const fn new() -> Self {
+        Self {
+            state: AtomicU8::new(UNINIT),
+            value: UnsafeCell::new(MaybeUninit::uninit()),
+        }
+    }
+
+    /// Return a reference to the value, or `None` if the cell has not been initialized yet
+    pub fn get(&self) -> Option<&T> {
+        if self.state.load(Ordering::Acquire) == COMPLETE {
+            // SAFETY: the state is `COMPLETE`, so the value has been written and will not change
+            Some(unsafe { (*self.value.get()).assume_init_ref() })
+        } else {
+            None
+        }
+    }
+
+    /// Return a mutable reference to the value, or `None` if the cell has not been initialized yet
+    pub fn get_mut(&mut self) -> Option<&mut T> {
+        if *self.state.get_mut() == COMPLETE {
+            // SAFETY: the state is `COMPLETE`, and we have exclusive access
+            Some(unsafe { self.value.get_mut().assume_init_mut() })
+        } else {
+            None
+        }
+    }
+
+    /// Initialize the cell with `value`. If the cell was already initialized (or is being initialized by another
+    /// thread), `value` is returned as an error.
+    pub fn set(&self, value: T) -> Result<(), T> {
+        let mut value = Some(value);
+        // `try_init` calls the initializer at most once, so the value is still there when it does
+        self.try_init(|| value.take().unwrap_or_else(|| unreachable!()));
+        match value {
+            None => Ok(()),
+            Some(value) => Err(value),
+        }
+    }
+
+    /// Return a reference to the value, initializing it with `f` if the cell is empty. If several threads call this
+    /// concurrently, only one of the initializers runs and the others wait for it to finish.
+    pub fn get_or_init<F>(&self, f: F) -> &T
+    where F: FnOnce() -> T {
+        let mut f = Some(f);
+        loop {
+            if let Some(value) = self.get() {
+                return value;
+            }
+            // Running the initializer either completes the cell, so the next `get` returns, or unwinds; `f` is only
+            // taken once
+            if !self.try_init(|| (f.take().unwrap_or_else(|| unreachable!()))()) {
+                self.wait();
+            }
+        }
+    }
+
+    /// Consume the cell and return the value, if it was initialized
+    pub fn into_inner(mut self) -> Option<T> {
+        if *self.state.get_mut() == COMPLETE {
+            *self.state.get_mut() = UNINIT;
+            // SAFETY: the state was `COMPLETE`, and resetting it means `Drop` will not read the value again
+            Some(unsafe { self.value.get().read().assume_init() })
+        } else {
+            None
+        }
+    }
+
+    /// Run `f` and store its result if this thread wins the race to initialize the cell. Returns false without calling
+    /// `f` if the cell is already initialized or being initialized.
+    fn try_init<F>(&self, f: F) -> bool
+    where F: FnOnce() -> T {
+        if self
+            .state
+            .compare_exchange(UNINIT, RUNNING, Ordering::Acquire, Ordering::Acquire)
+            .is_err()
+        {
+            return false;
+        }
+        let reset = ResetOnUnwind { state: &self.state };
+        let value = f();
+        // SAFETY: we hold the `RUNNING` state, so no other thread reads or writes the value
+        unsafe { (*self.value.get()).write(value) };
+        core::mem::forget(reset);
+        self.state.store(COMPLETE, Ordering::Release);
+        true
+    }
+
+    /// Wait until the cell is no longer being initialized by another thread
+    fn wait(&self) {
+        while self.state.load(Ordering::Acquire) == RUNNING {
+            #[cfg(feature = "std")]
+            std::thread::yield_now();
+            #[cfg(not(feature = "std"))]
+            core::hint::spin_loop();
+        }
+    }
+}
+
+impl<T> Default for OnceInit<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Drop for OnceInit<T> {
+    fn drop(&mut self) {
+        if *self.state.get_mut() == COMPLETE {
+            // SAFETY: the state is `COMPLETE`, so the value has been written, and it is dropped only once
+            unsafe { self.value.get_mut().assume_init_drop() };
+        }
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for OnceInit<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.get() {
+            Some(value) => f.debug_tuple("OnceInit").field(value).finish(),
+            None => f.write_str("OnceInit(<uninit>)"),
+        }
+    }
+}
+
+/// A value that is computed by the given function on first access. See the [module documentation](self) for details.
+pub struct LazyInit<T, F = fn() -> T> {
+    cell: OnceInit<T>,
+    init: F,
+}
+
+impl<T, F> LazyInit<T, F>
+where F: Fn() -> T
+{
+    /// Create a new lazy value that is computed by `init` on first access
+    pub const fn new(init: F) -> Self {
+        Self {
+            cell: OnceInit::new(),
+            init,
+        }
+    }
+
+    /// Force the value to be computed, and return a reference to it
+    pub fn force(this: &Self) -> &T {
+        this.cell.get_or_init(&this.init)
+    }
+
+    /// Return a reference to the value if it has already been computed
+    pub fn get(this: &Self) -> Option<&T> {
+        this.cell.get()
+    }
+}
+
+impl<T, F> Deref for LazyInit<T, F>
+where F: Fn() -> T
+{
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        LazyInit::force(self)
+    }
+}
+
+impl<T: fmt::Debug, F> fmt::Debug for LazyInit<T, F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.cell.get() {
+            Some(value) => f.debug_tuple("LazyInit").field(value).finish(),
+            None => f.write_str("LazyInit(<uninit>)"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use alloc::{string::String, sync::Arc, vec::Vec};
+    use core::sync::atomic::AtomicUsize;
+    use std::{panic, thread};
+
+    use super::*;
+
+    #[test]
+    fn once_init() {
+        let mut cell = OnceInit::new();
+        assert!(cell.get().is_none());
+        assert_eq!(cell.set(1u8), Ok(()));
+        assert_eq!(cell.set(2u8), Err(2));
+        assert_eq!(cell.get_or_init(|| 3), &1);
+        *cell.get_mut().unwrap() += 1;
+        assert_eq!(format!("{:?}", cell), "OnceInit(2)");
+        assert_eq!(cell.into_inner(), Some(2));
+        assert_eq!(OnceInit::<u8>::default().into_inner(), None);
+    }
+
+    #[test]
+    fn lazy_static() {
+        static CALLS: AtomicUsize = AtomicUsize::new(0);
+        static TABLE: LazyInit<Vec<u8>> = LazyInit::new(|| {
+            CALLS.fetch_add(1, Ordering::SeqCst);
+            (0..=255).collect()
+        });
+        assert!(LazyInit::get(&TABLE).is_none());
+        assert_eq!(TABLE[255], 255);
+        assert_eq!(TABLE.len(), 256);
+        assert_eq!(CALLS.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn concurrent_initialization() {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let cell = Arc::new(OnceInit::new());
+        let handles = (0..8)
+            .map(|i| {
+                let calls = calls.clone();
+                let cell = cell.clone();
+                thread::spawn(move || {
+                    *cell.get_or_init(|| {
+                        calls.fetch_add(1, Ordering::SeqCst);
+                        thread::sleep(core::time::Duration::from_millis(5));
+                        i
+                    })
+                })
+            })
+            .collect::<Vec<_>>();
+        let results = handles.into_iter().map(|h| h.join().unwrap()).collect::<Vec<_>>();
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+        assert!(results.iter().all(|&r| r == results[0]));
+    }
+
+    #[test]
+    fn panicking_initializer() {
+        let cell = OnceInit::<String>::new();
+        let result = panic::catch_unwind(panic::AssertUnwindSafe(|| {
+            cell.get_or_init(|| panic!("initializer failed"));
+        }));
+        assert!(result.is_err());
+        assert!(cell.get().is_none());
+        assert_eq!(cell.get_or_init(|| "retried".into()), "retried");
+    }
+
+    #[test]
+    fn drops_value() {
+        let value = Arc::new(());
+        let cell = OnceInit::new();
+        cell.set(value.clone()).unwrap();
+        assert_eq!(Arc::strong_count(&value), 2);
+        drop(cell);
+        assert_eq!(Arc::strong_count(&value), 1);
+    }
+}
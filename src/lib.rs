@@ -28,34 +28,93 @@
 #[macro_use]
 extern crate alloc;
 
-#[cfg(any(feature = "std", feature = "test"))]
+#[cfg(any(feature = "std", test))]
 #[macro_use]
 extern crate std;
 
+pub mod base32;
+pub mod base45;
+#[cfg(feature = "base58")]
+pub mod base58;
+pub mod base64;
+pub mod bech32;
 pub mod bit;
+pub mod bounded_queue;
+#[cfg(feature = "std")]
+pub mod buffer_pool;
 pub mod byte_array;
+pub mod byte_size;
+#[cfg(feature = "codec")]
+pub mod codec;
+#[cfg(feature = "serde")]
+pub mod content_type;
 pub mod convert;
+pub mod crc;
+#[cfg(feature = "subtle")]
+pub mod ct;
+pub mod dammsum;
 #[cfg(feature = "std")]
+pub mod debounce;
+pub mod diff;
+pub mod emoji;
 pub mod encoding;
 #[cfg(feature = "std")]
 pub mod epoch_time;
+pub mod error_code;
+#[cfg(feature = "ffi")]
+pub mod ffi;
 pub mod fixed_set;
+pub mod format;
+#[cfg(all(feature = "guarded", unix))]
+pub mod guarded;
 pub mod hash;
 pub mod hex;
 #[cfg(feature = "zeroize")]
 pub mod hidden;
+pub mod index_set;
+pub mod lazy;
 pub mod locks;
+pub mod lru;
 #[cfg(feature = "serde")]
 pub mod message_format;
+pub mod multibase;
 #[cfg(feature = "zeroize")]
 pub mod password;
+#[cfg(feature = "prost")]
+pub mod proto;
+#[cfg(feature = "pyo3")]
+pub mod python;
+#[cfg(feature = "std")]
+pub mod rate_limit;
+pub mod ratio;
+#[cfg(feature = "std")]
+pub mod retry;
+pub mod ring_buffer;
 #[cfg(feature = "std")]
 pub mod safe_array;
+#[cfg(all(feature = "std", feature = "zeroize"))]
+pub mod secret_cache;
+#[cfg(feature = "track-secrets")]
+pub mod secret_stats;
 #[cfg(feature = "serde")]
 pub mod serde;
+pub mod sorted_vec;
+#[cfg(feature = "sqlx")]
+pub mod sql;
+#[cfg(feature = "uniffi")]
+pub mod uniffi_bindings;
+pub mod uri;
+pub mod validate;
+pub mod varint;
+#[cfg(feature = "wasm")]
+pub mod wasm;
+pub mod z85;
 pub use self::{
     byte_array::{ByteArray, ByteArrayError},
     hash::Hashable,
 };
 #[cfg(feature = "zeroize")]
 pub use self::{hidden::Hidden, password::SafePassword};
+
+#[cfg(feature = "uniffi")]
+uniffi::setup_scaffolding!("tari_utilities");
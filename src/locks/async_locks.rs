@@ -0,0 +1,145 @@
+// Copyright 2026, The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+//! Macros for acquiring `tokio::sync::Mutex` and `tokio::sync::RwLock` guards in async code.
+//!
+//! Tokio locks are never poisoned, so the blocking forms simply await the guard. The `try_` and `_timeout` forms map
+//! the tokio errors into a [LockError](super::LockError) that carries a description of the lock being acquired.
+
+// Re-exported so that the macros work in crates that don't depend on tokio directly, or that rename it
+#[doc(hidden)]
+pub use tokio;
+
+/// Await a tokio `Mutex` or `RwLock` guard. Use `acquire_async_lock!(lock)` for a `Mutex`, or pass the method name
+/// explicitly, e.g. `acquire_async_lock!(lock, read)`.
+#[macro_export]
+macro_rules! acquire_async_lock {
+    ($e:expr, $m:ident) => {
+        $e.$m().await
+    };
+    ($e:expr) => {
+        $crate::acquire_async_lock!($e, lock)
+    };
+}
+
+/// Await a read guard on a tokio `RwLock`
+#[macro_export]
+macro_rules! acquire_async_read_lock {
+    ($e:expr) => {
+        $crate::acquire_async_lock!($e, read)
+    };
+}
+
+/// Await a write guard on a tokio `RwLock`
+#[macro_export]
+macro_rules! acquire_async_write_lock {
+    ($e:expr) => {
+        $crate::acquire_async_lock!($e, write)
+    };
+}
+
+/// Try to acquire a tokio `Mutex` or `RwLock` guard without waiting, returning a
+/// [LockError::WouldBlock](crate::locks::LockError::WouldBlock) with the given context if the lock is held elsewhere.
+/// The method defaults to `try_lock`; pass `try_read` or `try_write` for a `RwLock`.
+#[macro_export]
+macro_rules! try_acquire_async_lock {
+    ($e:expr, $m:ident, $ctx:expr) => {
+        $e.$m().map_err(|_| $crate::locks::LockError::WouldBlock {
+            context: ::std::string::ToString::to_string(&$ctx),
+        })
+    };
+    ($e:expr, $ctx:expr) => {
+        $crate::try_acquire_async_lock!($e, try_lock, $ctx)
+    };
+}
+
+/// Await a tokio `Mutex` or `RwLock` guard for at most the given `Duration`, returning a
+/// [LockError::Timeout](crate::locks::LockError::Timeout) with the given context if the deadline expires.
+/// The method defaults to `lock`; pass `read` or `write` for a `RwLock`. This must be called within a tokio runtime
+/// with the timer enabled.
+#[macro_export]
+macro_rules! acquire_async_lock_timeout {
+    ($e:expr, $m:ident, $timeout:expr, $ctx:expr) => {
+        $crate::locks::async_locks::tokio::time::timeout($timeout, $e.$m())
+            .await
+            .map_err(|_| $crate::locks::LockError::Timeout {
+                context: ::std::string::ToString::to_string(&$ctx),
+            })
+    };
+    ($e:expr, $timeout:expr, $ctx:expr) => {
+        $crate::acquire_async_lock_timeout!($e, lock, $timeout, $ctx)
+    };
+}
+
+#[cfg(test)]
+mod test {
+    use alloc::string::ToString;
+    use std::time::Duration;
+
+    use tokio::sync::{Mutex, RwLock};
+
+    use crate::locks::LockError;
+
+    #[tokio::test]
+    async fn acquire() {
+        let mutex = Mutex::new(1u8);
+        *acquire_async_lock!(mutex) += 1;
+        assert_eq!(*acquire_async_lock!(mutex), 2);
+
+        let rw_lock = RwLock::new(1u8);
+        *acquire_async_write_lock!(rw_lock) += 1;
+        assert_eq!(*acquire_async_read_lock!(rw_lock), 2);
+    }
+
+    #[tokio::test]
+    async fn try_acquire() {
+        let mutex = Mutex::new(1u8);
+        let guard = try_acquire_async_lock!(mutex, "mutex").unwrap();
+        let err = try_acquire_async_lock!(mutex, "mutex").unwrap_err();
+        assert_eq!(err, LockError::WouldBlock {
+            context: "mutex".into()
+        });
+        drop(guard);
+        assert!(try_acquire_async_lock!(mutex, "mutex").is_ok());
+
+        let rw_lock = RwLock::new(1u8);
+        let read_guard = try_acquire_async_lock!(rw_lock, try_read, "rw_lock").unwrap();
+        assert!(try_acquire_async_lock!(rw_lock, try_read, "rw_lock").is_ok());
+        assert!(try_acquire_async_lock!(rw_lock, try_write, "rw_lock").is_err());
+        drop(read_guard);
+        assert!(try_acquire_async_lock!(rw_lock, try_write, "rw_lock").is_ok());
+    }
+
+    #[tokio::test]
+    async fn timeout() {
+        let mutex = Mutex::new(1u8);
+        assert!(acquire_async_lock_timeout!(mutex, Duration::from_millis(10), "mutex").is_ok());
+        let _guard = acquire_async_lock!(mutex);
+        let err = acquire_async_lock_timeout!(mutex, Duration::from_millis(10), "mutex").unwrap_err();
+        assert_eq!(err.to_string(), "Timed out waiting to acquire lock `mutex'");
+
+        let rw_lock = RwLock::new(1u8);
+        let _read_guard = acquire_async_read_lock!(rw_lock);
+        assert!(acquire_async_lock_timeout!(rw_lock, read, Duration::from_millis(10), "rw_lock").is_ok());
+        assert!(acquire_async_lock_timeout!(rw_lock, write, Duration::from_millis(10), "rw_lock").is_err());
+    }
+}
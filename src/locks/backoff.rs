@@ -0,0 +1,276 @@
+// Copyright 2026, The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+//! Non-parking lock acquisition that retries `try_lock` with jittered exponential backoff until a deadline.
+//!
+//! This suits hot paths where contention is expected to be rare and short-lived: the calling thread spins (and yields
+//! once the delay grows) instead of parking, and gives up with a [LockError::Timeout] if the lock is not acquired in
+//! time.
+//!
+//! ```edition2018
+//! # use std::{sync::Mutex, time::Duration};
+//! # use tari_utilities::locks::backoff::{spin_try_lock, SpinBackoff};
+//! let mutex = Mutex::new(1u8);
+//! let backoff = SpinBackoff::new(Duration::from_millis(5));
+//! *spin_try_lock(&mutex, &backoff, "counter").unwrap() += 1;
+//! assert_eq!(*mutex.lock().unwrap(), 2);
+//! ```
+
+use alloc::string::ToString;
+use core::{
+    convert::TryFrom,
+    hint,
+    sync::atomic::{AtomicU64, Ordering},
+    time::Duration,
+};
+use std::{
+    sync::{Mutex, MutexGuard, RwLock, RwLockReadGuard, RwLockWriteGuard, TryLockError},
+    thread,
+    time::Instant,
+};
+
+use crate::locks::LockError;
+
+/// Delays at or above this threshold yield the thread between checks rather than busy-spinning.
+const YIELD_THRESHOLD: Duration = Duration::from_micros(50);
+
+/// The backoff schedule used by [spin_try_lock] and friends.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SpinBackoff {
+    initial_delay: Duration,
+    max_delay: Duration,
+    timeout: Duration,
+}
+
+impl SpinBackoff {
+    /// Create a backoff schedule that gives up after `timeout`, starting with a 1µs delay that doubles up to a maximum
+    /// of 1ms.
+    pub fn new(timeout: Duration) -> Self {
+        Self {
+            initial_delay: Duration::from_micros(1),
+            max_delay: Duration::from_millis(1),
+            timeout,
+        }
+    }
+
+    /// Set the delay before the first retry
+    pub fn with_initial_delay(mut self, delay: Duration) -> Self {
+        self.initial_delay = delay;
+        self
+    }
+
+    /// Set the upper bound on the delay between retries
+    pub fn with_max_delay(mut self, delay: Duration) -> Self {
+        self.max_delay = delay;
+        self
+    }
+
+    /// The total time to keep retrying before giving up
+    pub fn timeout(&self) -> Duration {
+        self.timeout
+    }
+}
+
+/// Repeatedly call `try_acquire` with jittered exponential backoff until it succeeds or the backoff deadline expires.
+/// Poisoned locks are recovered, but `spin_try` cannot reach the lock to clear its poison flag, so it stays set; the
+/// lock-specific functions below clear it. The `context` describes the lock in logs and errors.
+pub fn spin_try<G, F>(mut try_acquire: F, backoff: &SpinBackoff, context: &str) -> Result<G, LockError>
+where F: FnMut() -> Result<G, TryLockError<G>> {
+    // A timeout too large to represent as a deadline means waiting indefinitely
+    let deadline = Instant::now().checked_add(backoff.timeout);
+    let mut delay = backoff.initial_delay;
+    loop {
+        match try_acquire() {
+            Ok(guard) => return Ok(guard),
+            Err(TryLockError::Poisoned(poisoned)) => {
+                log::warn!(target: "tari_util", "Lock `{}' has been POISONED and will be recovered", context);
+                return Ok(poisoned.into_inner());
+            },
+            Err(TryLockError::WouldBlock) => {},
+        }
+        let now = Instant::now();
+        let wait = match deadline {
+            Some(deadline) if now >= deadline => {
+                return Err(LockError::Timeout {
+                    context: context.to_string(),
+                })
+            },
+            Some(deadline) => jitter(delay).min(deadline - now),
+            None => jitter(delay),
+        };
+        wait_until(now.checked_add(wait).unwrap_or(now));
+        delay = delay.saturating_mul(2).min(backoff.max_delay);
+    }
+}
+
+/// Lock the mutex, retrying with backoff rather than parking the thread. See [spin_try]. A recovered
+/// lock has its poison flag cleared.
+pub fn spin_try_lock<'a, T>(
+    mutex: &'a Mutex<T>,
+    backoff: &SpinBackoff,
+    context: &str,
+) -> Result<MutexGuard<'a, T>, LockError> {
+    let guard = spin_try(|| mutex.try_lock(), backoff, context)?;
+    mutex.clear_poison();
+    Ok(guard)
+}
+
+/// Acquire a read lock on the `RwLock`, retrying with backoff rather than parking the thread. See [spin_try]. A
+/// recovered lock has its poison flag cleared.
+pub fn spin_try_read<'a, T>(
+    lock: &'a RwLock<T>,
+    backoff: &SpinBackoff,
+    context: &str,
+) -> Result<RwLockReadGuard<'a, T>, LockError> {
+    let guard = spin_try(|| lock.try_read(), backoff, context)?;
+    lock.clear_poison();
+    Ok(guard)
+}
+
+/// Acquire a write lock on the `RwLock`, retrying with backoff rather than parking the thread. See [spin_try]. A
+/// recovered lock has its poison flag cleared.
+pub fn spin_try_write<'a, T>(
+    lock: &'a RwLock<T>,
+    backoff: &SpinBackoff,
+    context: &str,
+) -> Result<RwLockWriteGuard<'a, T>, LockError> {
+    let guard = spin_try(|| lock.try_write(), backoff, context)?;
+    lock.clear_poison();
+    Ok(guard)
+}
+
+fn wait_until(until: Instant) {
+    let spin = until.saturating_duration_since(Instant::now()) < YIELD_THRESHOLD;
+    while Instant::now() < until {
+        if spin {
+            hint::spin_loop();
+        } else {
+            thread::yield_now();
+        }
+    }
+}
+
+/// Scale `delay` by a pseudo-random factor in [0.5, 1.0] so that contending threads do not retry in lockstep.
+fn jitter(delay: Duration) -> Duration {
+    let nanos = u64::try_from(delay.as_nanos()).unwrap_or(u64::MAX);
+    Duration::from_nanos(nanos / 2 + (pseudo_random() % (nanos / 2 + 1)))
+}
+
+/// A cheap pseudo-random number for jitter. It is not suitable for anything that needs to be unpredictable.
+pub(crate) fn pseudo_random() -> u64 {
+    static STATE: AtomicU64 = AtomicU64::new(0x9e37_79b9_7f4a_7c15);
+    // xorshift64; the exact sequence doesn't matter, only that it varies between calls
+    let mut x = STATE.load(Ordering::Relaxed);
+    x ^= x << 13;
+    x ^= x >> 7;
+    x ^= x << 17;
+    STATE.store(x, Ordering::Relaxed);
+    x
+}
+
+#[cfg(test)]
+mod test {
+    use alloc::vec::Vec;
+    use std::sync::Arc;
+
+    use super::*;
+
+    #[test]
+    fn uncontended() {
+        let mutex = Mutex::new(1u8);
+        let backoff = SpinBackoff::new(Duration::from_millis(1));
+        assert_eq!(*spin_try_lock(&mutex, &backoff, "mutex").unwrap(), 1);
+
+        let lock = RwLock::new(1u8);
+        let _read_guard = spin_try_read(&lock, &backoff, "lock").unwrap();
+        assert_eq!(*spin_try_read(&lock, &backoff, "lock").unwrap(), 1);
+    }
+
+    #[test]
+    fn timeout() {
+        let mutex = Mutex::new(1u8);
+        let backoff = SpinBackoff::new(Duration::from_millis(5)).with_max_delay(Duration::from_micros(100));
+        let _guard = mutex.lock().unwrap();
+        let start = Instant::now();
+        let err = spin_try_lock(&mutex, &backoff, "mutex").unwrap_err();
+        assert!(start.elapsed() >= backoff.timeout());
+        assert_eq!(err, LockError::Timeout {
+            context: "mutex".into()
+        });
+
+        let lock = RwLock::new(1u8);
+        let _read_guard = lock.read().unwrap();
+        assert!(spin_try_write(&lock, &backoff, "lock").is_err());
+    }
+
+    #[test]
+    fn contended() {
+        let mutex = Arc::new(Mutex::new(0u32));
+        let backoff = SpinBackoff::new(Duration::from_secs(10)).with_initial_delay(Duration::from_nanos(100));
+        let handles = (0..4)
+            .map(|_| {
+                let mutex = mutex.clone();
+                thread::spawn(move || {
+                    for _ in 0..100 {
+                        *spin_try_lock(&mutex, &backoff, "mutex").unwrap() += 1;
+                    }
+                })
+            })
+            .collect::<Vec<_>>();
+        for handle in handles {
+            handle.join().unwrap();
+        }
+        assert_eq!(*mutex.lock().unwrap(), 400);
+    }
+
+    #[test]
+    fn unbounded_timeout() {
+        let mutex = Mutex::new(1u8);
+        let backoff = SpinBackoff::new(Duration::MAX).with_max_delay(Duration::MAX);
+        assert_eq!(*spin_try_lock(&mutex, &backoff, "mutex").unwrap(), 1);
+    }
+
+    #[test]
+    fn clears_poison() {
+        let mutex = Arc::new(Mutex::new(1u8));
+        let poisoner = mutex.clone();
+        let _ = thread::spawn(move || {
+            let _guard = poisoner.lock().unwrap();
+            panic!("poison the lock");
+        })
+        .join();
+        assert!(mutex.is_poisoned());
+        let backoff = SpinBackoff::new(Duration::from_millis(1));
+        assert_eq!(*spin_try_lock(&mutex, &backoff, "mutex").unwrap(), 1);
+        assert!(!mutex.is_poisoned());
+    }
+
+    #[test]
+    fn jitter_bounds() {
+        let delay = Duration::from_micros(10);
+        for _ in 0..100 {
+            let jittered = jitter(delay);
+            assert!(jittered >= delay / 2 && jittered <= delay);
+        }
+        assert_eq!(jitter(Duration::ZERO), Duration::ZERO);
+    }
+}
@@ -0,0 +1,342 @@
+// Copyright 2026, The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+//! Lock-order tracking that detects potential deadlocks as soon as two locks are taken in inconsistent orders.
+//!
+//! Every time a tracked lock is acquired while other tracked locks are held by the same thread, an edge is recorded in
+//! a global lock-order graph. If acquiring a lock would close a cycle in that graph (e.g. one thread takes `A` then
+//! `B`, and another later takes `B` then `A`), the inversion is reported *before* blocking on the lock, using the
+//! configured [InversionPolicy]. Re-acquiring a lock that the current thread already holds is reported in the same way.
+//!
+//! Wrap a lock in a [TrackedLock] to track it, and acquire it with the [acquire_tracked](crate::acquire_tracked) macro,
+//! which returns a [TrackedGuard] that dereferences to the locked value. Each tracked lock has a unique id, and its
+//! ordering history is removed when it is dropped. The bookkeeping takes a global lock on every acquisition, so the
+//! feature is intended for debug and test builds only.
+//!
+//! ```edition2018
+//! # use std::sync::Mutex;
+//! # use tari_utilities::{acquire_tracked, locks::deadlock::TrackedLock};
+//! let counter = TrackedLock::new(Mutex::new(1u8));
+//! *acquire_tracked!(counter) += 1;
+//! assert_eq!(*acquire_tracked!(counter), 2);
+//! ```
+
+use alloc::collections::{BTreeMap, BTreeSet};
+use core::{
+    cell::RefCell,
+    ops::{Deref, DerefMut},
+    sync::atomic::{AtomicU64, AtomicU8, Ordering},
+};
+use std::{sync::Mutex, vec::Vec};
+
+use crate::locks::poison::recover_lock;
+
+/// What to do when a lock-order inversion is detected
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InversionPolicy {
+    /// Panic on the thread that attempted the inverted acquisition. This is the default.
+    Panic,
+    /// Log an error and continue with the acquisition
+    Log,
+}
+
+static POLICY: AtomicU8 = AtomicU8::new(0);
+static NEXT_ID: AtomicU64 = AtomicU64::new(0);
+static LOCK_ORDER: Mutex<BTreeMap<u64, BTreeSet<u64>>> = Mutex::new(BTreeMap::new());
+
+std::thread_local! {
+    static HELD_LOCKS: RefCell<Vec<u64>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Set the global policy applied when a lock-order inversion is detected.
+pub fn set_inversion_policy(policy: InversionPolicy) {
+    let value = match policy {
+        InversionPolicy::Panic => 0,
+        InversionPolicy::Log => 1,
+    };
+    POLICY.store(value, Ordering::SeqCst);
+}
+
+/// Return the global policy applied when a lock-order inversion is detected.
+pub fn inversion_policy() -> InversionPolicy {
+    match POLICY.load(Ordering::SeqCst) {
+        0 => InversionPolicy::Panic,
+        _ => InversionPolicy::Log,
+    }
+}
+
+/// A lock whose acquisitions are tracked in the lock-order graph. Acquire it with
+/// [acquire_tracked](crate::acquire_tracked).
+#[derive(Debug)]
+pub struct TrackedLock<L> {
+    id: u64,
+    lock: L,
+}
+
+impl<L> TrackedLock<L> {
+    /// Track `lock` under a new unique id.
+    pub fn new(lock: L) -> Self {
+        Self {
+            id: NEXT_ID.fetch_add(1, Ordering::Relaxed),
+            lock,
+        }
+    }
+
+    /// The id that identifies this lock in the lock-order graph and in inversion reports
+    pub fn id(&self) -> u64 {
+        self.id
+    }
+
+    /// Acquire the lock with `f`, first recording the acquisition and reporting any inversion. The `name` describes
+    /// the lock in reports.
+    pub fn acquire<'a, G, F>(&'a self, name: &str, f: F) -> TrackedGuard<G>
+    where F: FnOnce(&'a L) -> G {
+        acquire(self.id, name);
+        TrackedGuard {
+            id: self.id,
+            guard: f(&self.lock),
+        }
+    }
+
+    /// Stop tracking the lock and return it.
+    pub fn into_inner(self) -> L {
+        let this = core::mem::ManuallyDrop::new(self);
+        forget(this.id);
+        // SAFETY: `this` is never used or dropped again, so the lock is moved out exactly once
+        unsafe { core::ptr::read(&this.lock) }
+    }
+}
+
+/// Remove the lock's ordering history, so that the graph does not grow without bound
+impl<L> Drop for TrackedLock<L> {
+    fn drop(&mut self) {
+        forget(self.id);
+    }
+}
+
+fn forget(id: u64) {
+    let mut graph = recover_lock(&LOCK_ORDER);
+    graph.remove(&id);
+    for successors in graph.values_mut() {
+        successors.remove(&id);
+    }
+}
+
+enum Inversion {
+    Recursive,
+    Order(u64),
+}
+
+/// Record that the current thread is about to acquire the lock with the given id, reporting any inversion according to
+/// the current [InversionPolicy]. This must be called before blocking on the lock.
+fn acquire(id: u64, name: &str) {
+    let inversion = HELD_LOCKS.with(|held| {
+        let held = held.borrow();
+        if held.contains(&id) {
+            return Some(Inversion::Recursive);
+        }
+        if held.is_empty() {
+            // Nothing is held, so there is no ordering to record
+            return None;
+        }
+        let mut graph = recover_lock(&LOCK_ORDER);
+        if let Some(&other) = held.iter().find(|&&h| is_reachable(&graph, id, h)) {
+            return Some(Inversion::Order(other));
+        }
+        for &h in held.iter() {
+            graph.entry(h).or_default().insert(id);
+        }
+        None
+    });
+    match inversion {
+        Some(Inversion::Recursive) => report(format_args!("Lock `{}' is already held by this thread", name)),
+        Some(Inversion::Order(other)) => report(format_args!(
+            "Lock-order inversion: `{}' is being acquired while holding lock {}, but has previously been held while \
+             acquiring it",
+            name, other
+        )),
+        None => {},
+    }
+    HELD_LOCKS.with(|held| held.borrow_mut().push(id));
+}
+
+/// Record that the current thread has released the lock with the given id.
+fn release(id: u64) {
+    HELD_LOCKS.with(|held| {
+        let mut held = held.borrow_mut();
+        if let Some(pos) = held.iter().rposition(|&h| h == id) {
+            held.remove(pos);
+        }
+    });
+}
+
+fn is_reachable(graph: &BTreeMap<u64, BTreeSet<u64>>, from: u64, to: u64) -> bool {
+    let mut visited = BTreeSet::new();
+    let mut stack = vec![from];
+    while let Some(node) = stack.pop() {
+        if node == to {
+            return true;
+        }
+        if visited.insert(node) {
+            if let Some(successors) = graph.get(&node) {
+                stack.extend(successors.iter().copied());
+            }
+        }
+    }
+    false
+}
+
+fn report(msg: core::fmt::Arguments<'_>) {
+    match inversion_policy() {
+        // Panicking is the point of this policy: it stops the thread before it can deadlock
+        InversionPolicy::Panic => panic!("{}", msg),
+        InversionPolicy::Log => log::error!(target: "tari_util", "{}", msg),
+    }
+}
+
+/// A guard for a [TrackedLock] that records its release when dropped. It dereferences to the locked value, like the
+/// guard it wraps.
+pub struct TrackedGuard<G> {
+    id: u64,
+    guard: G,
+}
+
+impl<G: Deref> Deref for TrackedGuard<G> {
+    type Target = G::Target;
+
+    fn deref(&self) -> &Self::Target {
+        &self.guard
+    }
+}
+
+impl<G: DerefMut> DerefMut for TrackedGuard<G> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.guard
+    }
+}
+
+impl<G> Drop for TrackedGuard<G> {
+    fn drop(&mut self) {
+        release(self.id);
+    }
+}
+
+/// Acquire a [TrackedLock](crate::locks::deadlock::TrackedLock) wrapping a `Mutex` or `RwLock`, recovering it if it is
+/// poisoned and reporting lock-order inversions before blocking. Use `acquire_tracked!(lock)` for a `Mutex`, or pass
+/// the method name explicitly, e.g. `acquire_tracked!(lock, read)`.
+#[macro_export]
+macro_rules! acquire_tracked {
+    ($e:expr, $m:ident) => {
+        $e.acquire(stringify!($e), |lock| $crate::recover_lock!(lock.$m()))
+    };
+    ($e:expr) => {
+        $crate::acquire_tracked!($e, lock)
+    };
+}
+
+#[cfg(test)]
+mod test {
+    use std::{
+        panic,
+        sync::{Mutex, RwLock},
+    };
+
+    use super::*;
+
+    #[test]
+    fn consistent_order() {
+        let a = TrackedLock::new(Mutex::new(1));
+        let b = TrackedLock::new(RwLock::new(2));
+        for _ in 0..2 {
+            let mut guard_a = acquire_tracked!(a);
+            let guard_b = acquire_tracked!(b, read);
+            *guard_a += 1;
+            assert!(*guard_a + *guard_b >= 4);
+        }
+        assert_eq!(a.into_inner().into_inner().unwrap(), 3);
+    }
+
+    #[test]
+    fn inversion() {
+        let a = TrackedLock::new(Mutex::new(1));
+        let b = TrackedLock::new(Mutex::new(2));
+        {
+            let _guard_a = acquire_tracked!(a);
+            let _guard_b = acquire_tracked!(b);
+        }
+        let result = panic::catch_unwind(|| {
+            let _guard_b = acquire_tracked!(b);
+            let _guard_a = acquire_tracked!(a);
+        });
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn transitive_inversion() {
+        let a = TrackedLock::new(Mutex::new(1));
+        let b = TrackedLock::new(Mutex::new(2));
+        let c = TrackedLock::new(Mutex::new(3));
+        {
+            let _guard_a = acquire_tracked!(a);
+            let _guard_b = acquire_tracked!(b);
+        }
+        {
+            let _guard_b = acquire_tracked!(b);
+            let _guard_c = acquire_tracked!(c);
+        }
+        let result = panic::catch_unwind(|| {
+            let _guard_c = acquire_tracked!(c);
+            let _guard_a = acquire_tracked!(a);
+        });
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn recursive_acquisition() {
+        let a = TrackedLock::new(RwLock::new(1));
+        let result = panic::catch_unwind(|| {
+            let _guard_1 = acquire_tracked!(a, read);
+            let _guard_2 = acquire_tracked!(a, read);
+        });
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn dropped_locks_are_forgotten() {
+        for _ in 0..100 {
+            // New locks may reuse the addresses of dropped ones, but never their ids
+            let a = TrackedLock::new(Mutex::new(1));
+            let b = TrackedLock::new(Mutex::new(2));
+            let (first, second) = if a.id().is_multiple_of(2) { (&a, &b) } else { (&b, &a) };
+            let _guard_1 = acquire_tracked!(first);
+            let _guard_2 = acquire_tracked!(second);
+        }
+        let id = {
+            let a = TrackedLock::new(Mutex::new(1));
+            let b = TrackedLock::new(Mutex::new(2));
+            let _guard_a = acquire_tracked!(a);
+            let _guard_b = acquire_tracked!(b);
+            a.id()
+        };
+        assert!(!recover_lock(&LOCK_ORDER).contains_key(&id));
+    }
+}
@@ -0,0 +1,286 @@
+// Copyright 2026, The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+//! Guard projections that narrow a lock guard to a single field (or any other part) of the locked data.
+//!
+//! The mapped guards keep the original guard alive, so the lock stays held until the mapped guard is dropped, but only
+//! expose the projected part of the data. This lets an API hand out access to part of a locked structure without
+//! cloning it or exposing the rest of the structure.
+//!
+//! ```edition2018
+//! # use std::sync::{Mutex, RwLock};
+//! # use tari_utilities::locks::guard::{map_guard, map_read_guard, MappedMutexGuard};
+//! struct State {
+//!     height: u64,
+//!     peers: Vec<String>,
+//! }
+//!
+//! fn peers(state: &Mutex<State>) -> MappedMutexGuard<'_, State, Vec<String>> {
+//!     map_guard(state.lock().unwrap(), |s| &mut s.peers)
+//! }
+//!
+//! let state = Mutex::new(State {
+//!     height: 1,
+//!     peers: vec![],
+//! });
+//! peers(&state).push("peer".to_string());
+//! assert_eq!(state.lock().unwrap().peers.len(), 1);
+//!
+//! let state = RwLock::new(state.into_inner().unwrap());
+//! let height = map_read_guard(state.read().unwrap(), |s| &s.height);
+//! assert_eq!(*height, 1);
+//! ```
+
+use core::{
+    fmt,
+    marker::PhantomData,
+    ops::{Deref, DerefMut},
+    ptr::NonNull,
+};
+use std::sync::{MutexGuard, RwLockReadGuard, RwLockWriteGuard};
+
+/// A `MutexGuard` narrowed to a part of the locked data. Created by [map_guard].
+pub struct MappedMutexGuard<'a, T: ?Sized, U: ?Sized> {
+    // Never read, but must be kept alive so that the lock stays held while `value` is in use
+    _guard: MutexGuard<'a, T>,
+    value: NonNull<U>,
+    _marker: PhantomData<&'a mut U>,
+}
+
+/// A `RwLockReadGuard` narrowed to a part of the locked data. Created by [map_read_guard].
+pub struct MappedReadGuard<'a, T: ?Sized, U: ?Sized> {
+    // Never read, but must be kept alive so that the lock stays held while `value` is in use
+    _guard: RwLockReadGuard<'a, T>,
+    value: NonNull<U>,
+    _marker: PhantomData<&'a U>,
+}
+
+/// A `RwLockWriteGuard` narrowed to a part of the locked data. Created by [map_write_guard].
+pub struct MappedWriteGuard<'a, T: ?Sized, U: ?Sized> {
+    // Never read, but must be kept alive so that the lock stays held while `value` is in use
+    _guard: RwLockWriteGuard<'a, T>,
+    value: NonNull<U>,
+    _marker: PhantomData<&'a mut U>,
+}
+
+/// Narrow a `MutexGuard` to the part of the locked data returned by `f`.
+pub fn map_guard<'a, T, U, F>(mut guard: MutexGuard<'a, T>, f: F) -> MappedMutexGuard<'a, T, U>
+where
+    T: ?Sized,
+    U: ?Sized,
+    F: FnOnce(&mut T) -> &mut U,
+{
+    let value = NonNull::from(f(&mut *guard));
+    MappedMutexGuard {
+        _guard: guard,
+        value,
+        _marker: PhantomData,
+    }
+}
+
+/// Narrow a `RwLockReadGuard` to the part of the locked data returned by `f`.
+pub fn map_read_guard<'a, T, U, F>(guard: RwLockReadGuard<'a, T>, f: F) -> MappedReadGuard<'a, T, U>
+where
+    T: ?Sized,
+    U: ?Sized,
+    F: FnOnce(&T) -> &U,
+{
+    let value = NonNull::from(f(&*guard));
+    MappedReadGuard {
+        _guard: guard,
+        value,
+        _marker: PhantomData,
+    }
+}
+
+/// Narrow a `RwLockWriteGuard` to the part of the locked data returned by `f`.
+pub fn map_write_guard<'a, T, U, F>(mut guard: RwLockWriteGuard<'a, T>, f: F) -> MappedWriteGuard<'a, T, U>
+where
+    T: ?Sized,
+    U: ?Sized,
+    F: FnOnce(&mut T) -> &mut U,
+{
+    let value = NonNull::from(f(&mut *guard));
+    MappedWriteGuard {
+        _guard: guard,
+        value,
+        _marker: PhantomData,
+    }
+}
+
+impl<'a, T: ?Sized, U: ?Sized> MappedMutexGuard<'a, T, U> {
+    /// Narrow the guard further to the part of the data returned by `f`.
+    pub fn map<V, F>(mut self, f: F) -> MappedMutexGuard<'a, T, V>
+    where
+        V: ?Sized,
+        F: FnOnce(&mut U) -> &mut V,
+    {
+        let value = NonNull::from(f(&mut *self));
+        MappedMutexGuard {
+            _guard: self._guard,
+            value,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<'a, T: ?Sized, U: ?Sized> MappedReadGuard<'a, T, U> {
+    /// Narrow the guard further to the part of the data returned by `f`.
+    pub fn map<V, F>(self, f: F) -> MappedReadGuard<'a, T, V>
+    where
+        V: ?Sized,
+        F: FnOnce(&U) -> &V,
+    {
+        let value = NonNull::from(f(&*self));
+        MappedReadGuard {
+            _guard: self._guard,
+            value,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<'a, T: ?Sized, U: ?Sized> MappedWriteGuard<'a, T, U> {
+    /// Narrow the guard further to the part of the data returned by `f`.
+    pub fn map<V, F>(mut self, f: F) -> MappedWriteGuard<'a, T, V>
+    where
+        V: ?Sized,
+        F: FnOnce(&mut U) -> &mut V,
+    {
+        let value = NonNull::from(f(&mut *self));
+        MappedWriteGuard {
+            _guard: self._guard,
+            value,
+            _marker: PhantomData,
+        }
+    }
+}
+
+// SAFETY (for all of the dereferences below): `value` points into the data protected by the lock, which lives in the
+// lock itself rather than in the guard, so it is not moved when the guard is moved. The guard is held for as long as
+// the mapped guard exists, so the lock is held and the pointer is valid for `'a`. Mutable access is only handed out
+// for exclusive guards, and only through `&mut self`.
+
+impl<T: ?Sized, U: ?Sized> Deref for MappedMutexGuard<'_, T, U> {
+    type Target = U;
+
+    fn deref(&self) -> &U {
+        unsafe { self.value.as_ref() }
+    }
+}
+
+impl<T: ?Sized, U: ?Sized> DerefMut for MappedMutexGuard<'_, T, U> {
+    fn deref_mut(&mut self) -> &mut U {
+        unsafe { self.value.as_mut() }
+    }
+}
+
+impl<T: ?Sized, U: ?Sized> Deref for MappedReadGuard<'_, T, U> {
+    type Target = U;
+
+    fn deref(&self) -> &U {
+        unsafe { self.value.as_ref() }
+    }
+}
+
+impl<T: ?Sized, U: ?Sized> Deref for MappedWriteGuard<'_, T, U> {
+    type Target = U;
+
+    fn deref(&self) -> &U {
+        unsafe { self.value.as_ref() }
+    }
+}
+
+impl<T: ?Sized, U: ?Sized> DerefMut for MappedWriteGuard<'_, T, U> {
+    fn deref_mut(&mut self) -> &mut U {
+        unsafe { self.value.as_mut() }
+    }
+}
+
+// SAFETY: sharing a mapped guard between threads only gives out `&U`, exactly like sharing the underlying guard.
+unsafe impl<T: ?Sized + Sync, U: ?Sized + Sync> Sync for MappedMutexGuard<'_, T, U> {}
+unsafe impl<T: ?Sized + Sync, U: ?Sized + Sync> Sync for MappedReadGuard<'_, T, U> {}
+unsafe impl<T: ?Sized + Sync, U: ?Sized + Sync> Sync for MappedWriteGuard<'_, T, U> {}
+
+impl<T: ?Sized, U: ?Sized + fmt::Debug> fmt::Debug for MappedMutexGuard<'_, T, U> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&**self, f)
+    }
+}
+
+impl<T: ?Sized, U: ?Sized + fmt::Debug> fmt::Debug for MappedReadGuard<'_, T, U> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&**self, f)
+    }
+}
+
+impl<T: ?Sized, U: ?Sized + fmt::Debug> fmt::Debug for MappedWriteGuard<'_, T, U> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&**self, f)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use alloc::{string::String, vec::Vec};
+    use std::sync::{Mutex, RwLock};
+
+    use super::*;
+
+    #[derive(Debug, Default)]
+    struct State {
+        name: String,
+        values: Vec<u32>,
+    }
+
+    #[test]
+    fn mutex_projection() {
+        let state = Mutex::new(State::default());
+        {
+            let mut values = map_guard(state.lock().unwrap(), |s| &mut s.values);
+            values.push(1);
+            // The lock is held while the mapped guard is alive
+            assert!(state.try_lock().is_err());
+        }
+        assert!(state.try_lock().is_ok());
+        let mut first = map_guard(state.lock().unwrap(), |s| &mut s.values).map(|v| &mut v[0]);
+        *first += 1;
+        assert_eq!(format!("{:?}", first), "2");
+        drop(first);
+        assert_eq!(state.lock().unwrap().values, [2]);
+    }
+
+    #[test]
+    fn rw_lock_projection() {
+        let state = RwLock::new(State::default());
+        {
+            let mut name = map_write_guard(state.write().unwrap(), |s| &mut s.name);
+            name.push_str("tari");
+            assert!(state.try_read().is_err());
+        }
+        let name = map_read_guard(state.read().unwrap(), |s| &s.name);
+        let name_str = map_read_guard(state.read().unwrap(), |s| &s.name).map(|n| n.as_str());
+        assert_eq!(*name, "tari");
+        assert_eq!(&*name_str, "tari");
+        assert!(state.try_write().is_err());
+    }
+}
@@ -0,0 +1,379 @@
+// Copyright 2026, The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+//! Lock contention instrumentation.
+//!
+//! [InstrumentedMutex] and [InstrumentedRwLock] record how long each acquisition waited for the lock and how long the
+//! guard was held, and report both to a [LockMetricsSink]. [LockCounters] is a simple sink that aggregates the
+//! measurements with atomic counters; implement the trait to forward them to any other metrics system instead.
+//!
+//! ```edition2018
+//! # use std::sync::Arc;
+//! # use tari_utilities::locks::metrics::{InstrumentedMutex, LockCounters};
+//! let counters = Arc::new(LockCounters::default());
+//! let mempool = InstrumentedMutex::new(Vec::<u64>::new(), "mempool", counters.clone());
+//! mempool.lock().push(1);
+//! assert_eq!(counters.stats().acquisitions, 1);
+//! ```
+
+use alloc::{borrow::Cow, sync::Arc};
+use core::{
+    convert::TryFrom,
+    fmt,
+    mem::ManuallyDrop,
+    ops::{Deref, DerefMut},
+    sync::atomic::{AtomicU64, Ordering},
+    time::Duration,
+};
+use std::{
+    sync::{Mutex, MutexGuard, RwLock, RwLockReadGuard, RwLockWriteGuard},
+    time::Instant,
+};
+
+use crate::locks::poison::{recover_lock, recover_read_lock, recover_write_lock};
+
+/// A destination for lock timing measurements. Implementations must be cheap, since they are called on every lock
+/// acquisition and release.
+pub trait LockMetricsSink: Send + Sync {
+    /// Record the time spent waiting to acquire the named lock
+    fn record_wait(&self, name: &str, wait: Duration);
+    /// Record the time the named lock was held before the guard was dropped
+    fn record_hold(&self, name: &str, hold: Duration);
+}
+
+/// A snapshot of the measurements aggregated by [LockCounters]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct LockStats {
+    /// The number of times the lock was acquired
+    pub acquisitions: u64,
+    /// The total time spent waiting to acquire the lock
+    pub total_wait: Duration,
+    /// The longest time spent waiting for a single acquisition
+    pub max_wait: Duration,
+    /// The total time the lock was held
+    pub total_hold: Duration,
+    /// The longest time the lock was held by a single guard
+    pub max_hold: Duration,
+}
+
+/// A [LockMetricsSink] that aggregates measurements using atomic counters, ignoring lock names.
+#[derive(Debug, Default)]
+pub struct LockCounters {
+    acquisitions: AtomicU64,
+    total_wait_ns: AtomicU64,
+    max_wait_ns: AtomicU64,
+    total_hold_ns: AtomicU64,
+    max_hold_ns: AtomicU64,
+}
+
+impl LockCounters {
+    /// Return a snapshot of the current counter values
+    pub fn stats(&self) -> LockStats {
+        LockStats {
+            acquisitions: self.acquisitions.load(Ordering::Relaxed),
+            total_wait: Duration::from_nanos(self.total_wait_ns.load(Ordering::Relaxed)),
+            max_wait: Duration::from_nanos(self.max_wait_ns.load(Ordering::Relaxed)),
+            total_hold: Duration::from_nanos(self.total_hold_ns.load(Ordering::Relaxed)),
+            max_hold: Duration::from_nanos(self.max_hold_ns.load(Ordering::Relaxed)),
+        }
+    }
+
+    /// Reset all counters to zero
+    pub fn reset(&self) {
+        self.acquisitions.store(0, Ordering::Relaxed);
+        self.total_wait_ns.store(0, Ordering::Relaxed);
+        self.max_wait_ns.store(0, Ordering::Relaxed);
+        self.total_hold_ns.store(0, Ordering::Relaxed);
+        self.max_hold_ns.store(0, Ordering::Relaxed);
+    }
+}
+
+fn as_nanos(duration: Duration) -> u64 {
+    u64::try_from(duration.as_nanos()).unwrap_or(u64::MAX)
+}
+
+impl LockMetricsSink for LockCounters {
+    fn record_wait(&self, _name: &str, wait: Duration) {
+        let nanos = as_nanos(wait);
+        self.acquisitions.fetch_add(1, Ordering::Relaxed);
+        self.total_wait_ns.fetch_add(nanos, Ordering::Relaxed);
+        self.max_wait_ns.fetch_max(nanos, Ordering::Relaxed);
+    }
+
+    fn record_hold(&self, _name: &str, hold: Duration) {
+        let nanos = as_nanos(hold);
+        self.total_hold_ns.fetch_add(nanos, Ordering::Relaxed);
+        self.max_hold_ns.fetch_max(nanos, Ordering::Relaxed);
+    }
+}
+
+/// A lock guard that reports how long it was held to a [LockMetricsSink] when dropped.
+pub struct InstrumentedGuard<'a, G> {
+    // Dropped by hand, so that the lock is released before the hold time is reported
+    guard: ManuallyDrop<G>,
+    name: &'a str,
+    sink: &'a dyn LockMetricsSink,
+    acquired_at: Instant,
+}
+
+/// Acquire a lock using `acquire` and report the wait time to `sink`. The returned guard reports the hold time when it
+/// is dropped. This can be used to instrument existing locks without changing their type.
+pub fn instrument<'a, G, F>(name: &'a str, sink: &'a dyn LockMetricsSink, acquire: F) -> InstrumentedGuard<'a, G>
+where F: FnOnce() -> G {
+    let start = Instant::now();
+    let guard = acquire();
+    let acquired_at = Instant::now();
+    sink.record_wait(name, acquired_at - start);
+    InstrumentedGuard {
+        guard: ManuallyDrop::new(guard),
+        name,
+        sink,
+        acquired_at,
+    }
+}
+
+impl<G: Deref> Deref for InstrumentedGuard<'_, G> {
+    type Target = G::Target;
+
+    fn deref(&self) -> &Self::Target {
+        self.guard.deref()
+    }
+}
+
+impl<G: DerefMut> DerefMut for InstrumentedGuard<'_, G> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.guard.deref_mut()
+    }
+}
+
+impl<G> Drop for InstrumentedGuard<'_, G> {
+    fn drop(&mut self) {
+        let hold = self.acquired_at.elapsed();
+        // SAFETY: `guard` is dropped exactly once, here, and is not used afterwards
+        unsafe { ManuallyDrop::drop(&mut self.guard) };
+        self.sink.record_hold(self.name, hold);
+    }
+}
+
+impl<G: Deref> fmt::Debug for InstrumentedGuard<'_, G>
+where G::Target: fmt::Debug
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self.deref(), f)
+    }
+}
+
+/// A `Mutex` that reports wait and hold times to a [LockMetricsSink]. Poisoned locks are recovered.
+pub struct InstrumentedMutex<T> {
+    inner: Mutex<T>,
+    name: Cow<'static, str>,
+    sink: Arc<dyn LockMetricsSink>,
+}
+
+impl<T> InstrumentedMutex<T> {
+    /// Create a new instrumented mutex that reports to `sink` under the given name
+    pub fn new<N: Into<Cow<'static, str>>>(value: T, name: N, sink: Arc<dyn LockMetricsSink>) -> Self {
+        Self {
+            inner: Mutex::new(value),
+            name: name.into(),
+            sink,
+        }
+    }
+
+    /// Lock the mutex, recording the time spent waiting
+    pub fn lock(&self) -> InstrumentedGuard<'_, MutexGuard<'_, T>> {
+        instrument(&self.name, self.sink.as_ref(), || recover_lock(&self.inner))
+    }
+
+    /// The name this lock reports metrics under
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Consume the mutex and return the data
+    pub fn into_inner(self) -> T {
+        self.inner.into_inner().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+/// A `RwLock` that reports wait and hold times to a [LockMetricsSink]. Poisoned locks are recovered.
+pub struct InstrumentedRwLock<T> {
+    inner: RwLock<T>,
+    name: Cow<'static, str>,
+    sink: Arc<dyn LockMetricsSink>,
+}
+
+impl<T> InstrumentedRwLock<T> {
+    /// Create a new instrumented lock that reports to `sink` under the given name
+    pub fn new<N: Into<Cow<'static, str>>>(value: T, name: N, sink: Arc<dyn LockMetricsSink>) -> Self {
+        Self {
+            inner: RwLock::new(value),
+            name: name.into(),
+            sink,
+        }
+    }
+
+    /// Acquire a read lock, recording the time spent waiting
+    pub fn read(&self) -> InstrumentedGuard<'_, RwLockReadGuard<'_, T>> {
+        instrument(&self.name, self.sink.as_ref(), || recover_read_lock(&self.inner))
+    }
+
+    /// Acquire a write lock, recording the time spent waiting
+    pub fn write(&self) -> InstrumentedGuard<'_, RwLockWriteGuard<'_, T>> {
+        instrument(&self.name, self.sink.as_ref(), || recover_write_lock(&self.inner))
+    }
+
+    /// The name this lock reports metrics under
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Consume the lock and return the data
+    pub fn into_inner(self) -> T {
+        self.inner.into_inner().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for InstrumentedMutex<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("InstrumentedMutex")
+            .field("name", &self.name)
+            .field("inner", &self.inner)
+            .finish()
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for InstrumentedRwLock<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("InstrumentedRwLock")
+            .field("name", &self.name)
+            .field("inner", &self.inner)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use alloc::{string::String, vec::Vec};
+    use std::{sync::Mutex as StdMutex, thread};
+
+    use super::*;
+
+    #[derive(Default)]
+    struct RecordingSink {
+        events: StdMutex<Vec<(String, &'static str)>>,
+    }
+
+    impl LockMetricsSink for RecordingSink {
+        fn record_wait(&self, name: &str, _wait: Duration) {
+            self.events.lock().unwrap().push((name.into(), "wait"));
+        }
+
+        fn record_hold(&self, name: &str, _hold: Duration) {
+            self.events.lock().unwrap().push((name.into(), "hold"));
+        }
+    }
+
+    #[test]
+    fn counters() {
+        let counters = Arc::new(LockCounters::default());
+        let mutex = InstrumentedMutex::new(0u32, "mutex", counters.clone());
+        {
+            let mut guard = mutex.lock();
+            *guard += 1;
+            thread::sleep(Duration::from_millis(2));
+        }
+        *mutex.lock() += 1;
+        let stats = counters.stats();
+        assert_eq!(stats.acquisitions, 2);
+        assert!(stats.max_hold >= Duration::from_millis(2));
+        assert!(stats.total_hold >= stats.max_hold);
+        assert!(stats.total_wait >= stats.max_wait);
+        assert_eq!(mutex.into_inner(), 2);
+
+        counters.reset();
+        assert_eq!(counters.stats(), LockStats::default());
+    }
+
+    #[test]
+    fn contention_is_measured() {
+        let counters = Arc::new(LockCounters::default());
+        let mutex = Arc::new(InstrumentedMutex::new(0u32, "mutex", counters.clone()));
+        let guard = mutex.lock();
+        let mutex_clone = mutex.clone();
+        let handle = thread::spawn(move || *mutex_clone.lock() += 1);
+        thread::sleep(Duration::from_millis(5));
+        drop(guard);
+        handle.join().unwrap();
+        assert!(counters.stats().max_wait >= Duration::from_millis(1));
+    }
+
+    struct ReleaseCheckingSink {
+        lock: Arc<StdMutex<u8>>,
+        released: StdMutex<Vec<bool>>,
+    }
+
+    impl LockMetricsSink for ReleaseCheckingSink {
+        fn record_wait(&self, _name: &str, _wait: Duration) {}
+
+        fn record_hold(&self, _name: &str, _hold: Duration) {
+            let released = self.lock.try_lock().is_ok();
+            self.released.lock().unwrap().push(released);
+        }
+    }
+
+    #[test]
+    fn hold_is_recorded_after_release() {
+        let lock = Arc::new(StdMutex::new(0u8));
+        let sink = ReleaseCheckingSink {
+            lock: lock.clone(),
+            released: StdMutex::new(Vec::new()),
+        };
+        *instrument("existing", &sink, || lock.lock().unwrap()) += 1;
+        assert_eq!(*sink.released.lock().unwrap(), [true]);
+        assert_eq!(*lock.lock().unwrap(), 1);
+    }
+
+    #[test]
+    fn custom_sink() {
+        let sink = Arc::new(RecordingSink::default());
+        let lock = InstrumentedRwLock::new(1u8, "rw_lock", sink.clone());
+        assert_eq!(*lock.read(), 1);
+        *lock.write() += 1;
+        let existing = StdMutex::new(0u8);
+        *instrument("existing", sink.as_ref(), || existing.lock().unwrap()) += 1;
+        let events = sink.events.lock().unwrap();
+        let expected = [
+            ("rw_lock", "wait"),
+            ("rw_lock", "hold"),
+            ("rw_lock", "wait"),
+            ("rw_lock", "hold"),
+            ("existing", "wait"),
+            ("existing", "hold"),
+        ];
+        assert_eq!(events.len(), expected.len());
+        for ((name, kind), (expected_name, expected_kind)) in events.iter().zip(expected.iter()) {
+            assert_eq!(name, expected_name);
+            assert_eq!(kind, expected_kind);
+        }
+    }
+}
@@ -20,7 +20,57 @@
 // WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
 // USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
 
-//! Macros for RwLock.
+//! Macros and utilities for working with `Mutex` and `RwLock` types.
+
+use alloc::string::String;
+
+use snafu::prelude::*;
+
+#[cfg(feature = "async")]
+pub mod async_locks;
+#[cfg(feature = "std")]
+pub mod backoff;
+#[cfg(feature = "deadlock-detect")]
+pub mod deadlock;
+#[cfg(feature = "std")]
+pub mod guard;
+#[cfg(feature = "metrics")]
+pub mod metrics;
+#[cfg(feature = "std")]
+pub mod ordered;
+#[cfg(feature = "std")]
+pub mod poison;
+
+/// Errors for lock acquisition helpers that can fail rather than block.
+#[derive(Debug, Snafu, PartialEq, Eq)]
+pub enum LockError {
+    /// The lock is currently held elsewhere and could not be acquired without waiting.
+    #[snafu(display("Lock `{context}' is held elsewhere and could not be acquired"))]
+    WouldBlock {
+        /// A description of the lock that was being acquired
+        context: String,
+    },
+    /// The lock was poisoned by a thread that panicked while holding it.
+    #[snafu(display("Lock `{context}' has been poisoned"))]
+    Poisoned {
+        /// A description of the lock that was being acquired
+        context: String,
+    },
+    /// Acquiring the lock would violate the lock hierarchy.
+    #[snafu(display("Lock level {level} cannot be acquired while holding a lock at level {held}"))]
+    OrderViolation {
+        /// The level of the lock being acquired
+        level: u8,
+        /// The highest level currently held by this thread
+        held: u8,
+    },
+    /// The lock could not be acquired before the deadline expired.
+    #[snafu(display("Timed out waiting to acquire lock `{context}'"))]
+    Timeout {
+        /// A description of the lock that was being acquired
+        context: String,
+    },
+}
 
 /// Recovers a poisoned lock by returning the value before the lock was poisoned
 #[macro_export]
@@ -0,0 +1,224 @@
+// Copyright 2026, The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+//! A `Mutex` with a fixed level in a lock hierarchy.
+//!
+//! A thread may only acquire an [OrderedMutex] whose level is strictly greater than the level of every ordered mutex
+//! it already holds. If all shared locks are ordered this way, lock-order deadlocks are impossible. Violations panic in
+//! debug builds when using [OrderedMutex::lock], and are always reported as an error by
+//! [OrderedMutex::try_lock_ordered].
+//!
+//! ```edition2018
+//! # use tari_utilities::locks::ordered::OrderedMutex;
+//! let accounts: OrderedMutex<Vec<u64>, 1> = OrderedMutex::new(vec![]);
+//! let balances: OrderedMutex<u64, 2> = OrderedMutex::new(0);
+//!
+//! let mut accounts_guard = accounts.lock();
+//! let mut balances_guard = balances.lock();
+//! accounts_guard.push(100);
+//! *balances_guard += 100;
+//! drop(balances_guard);
+//!
+//! // Taking a lower level while holding a higher one is an error
+//! let _balances_guard = balances.lock();
+//! drop(accounts_guard);
+//! assert!(accounts.try_lock_ordered().is_err());
+//! ```
+
+use alloc::vec::Vec;
+use core::{
+    cell::RefCell,
+    fmt,
+    ops::{Deref, DerefMut},
+};
+use std::sync::{Mutex, MutexGuard};
+
+use crate::locks::{poison::recover_lock, LockError};
+
+std::thread_local! {
+    static HELD_LEVELS: RefCell<Vec<u8>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Return the highest lock level currently held by this thread, if any
+pub fn highest_held_level() -> Option<u8> {
+    HELD_LEVELS.with(|held| held.borrow().iter().copied().max())
+}
+
+fn check_level(level: u8) -> Result<(), LockError> {
+    match highest_held_level() {
+        Some(held) if held >= level => Err(LockError::OrderViolation { level, held }),
+        _ => Ok(()),
+    }
+}
+
+/// A `Mutex` at level `LEVEL` of the lock hierarchy. See the [module documentation](self) for details.
+#[derive(Default)]
+pub struct OrderedMutex<T, const LEVEL: u8> {
+    inner: Mutex<T>,
+}
+
+impl<T, const LEVEL: u8> OrderedMutex<T, LEVEL> {
+    /// The level of this mutex in the lock hierarchy
+    pub const LEVEL: u8 = LEVEL;
+
+    /// Create a new ordered mutex
+    pub fn new(value: T) -> Self {
+        Self {
+            inner: Mutex::new(value),
+        }
+    }
+
+    /// Lock the mutex, recovering it if it has been poisoned.
+    ///
+    /// # Panics
+    /// In debug builds, panics if the current thread already holds an ordered mutex at the same or a higher level.
+    pub fn lock(&self) -> OrderedMutexGuard<'_, T, LEVEL> {
+        if let Err(e) = check_level(LEVEL) {
+            debug_assert!(false, "{}", e);
+        }
+        self.acquire()
+    }
+
+    /// Lock the mutex, recovering it if it has been poisoned. Returns a [LockError::OrderViolation] without blocking if
+    /// the current thread already holds an ordered mutex at the same or a higher level.
+    pub fn try_lock_ordered(&self) -> Result<OrderedMutexGuard<'_, T, LEVEL>, LockError> {
+        check_level(LEVEL)?;
+        Ok(self.acquire())
+    }
+
+    /// Get a mutable reference to the data. No locking is needed since this requires exclusive access.
+    pub fn get_mut(&mut self) -> &mut T {
+        self.inner.get_mut().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Consume the mutex and return the data
+    pub fn into_inner(self) -> T {
+        self.inner.into_inner().unwrap_or_else(|e| e.into_inner())
+    }
+
+    fn acquire(&self) -> OrderedMutexGuard<'_, T, LEVEL> {
+        let guard = recover_lock(&self.inner);
+        HELD_LEVELS.with(|held| held.borrow_mut().push(LEVEL));
+        OrderedMutexGuard { guard }
+    }
+}
+
+impl<T, const LEVEL: u8> fmt::Debug for OrderedMutex<T, LEVEL>
+where T: fmt::Debug
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("OrderedMutex")
+            .field("level", &LEVEL)
+            .field("inner", &self.inner)
+            .finish()
+    }
+}
+
+/// The guard returned by [OrderedMutex]. Releases its level in the lock hierarchy when dropped.
+pub struct OrderedMutexGuard<'a, T, const LEVEL: u8> {
+    guard: MutexGuard<'a, T>,
+}
+
+impl<T, const LEVEL: u8> Deref for OrderedMutexGuard<'_, T, LEVEL> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        self.guard.deref()
+    }
+}
+
+impl<T, const LEVEL: u8> DerefMut for OrderedMutexGuard<'_, T, LEVEL> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.guard.deref_mut()
+    }
+}
+
+impl<T, const LEVEL: u8> fmt::Debug for OrderedMutexGuard<'_, T, LEVEL>
+where T: fmt::Debug
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self.deref(), f)
+    }
+}
+
+impl<T, const LEVEL: u8> Drop for OrderedMutexGuard<'_, T, LEVEL> {
+    fn drop(&mut self) {
+        HELD_LEVELS.with(|held| {
+            let mut held = held.borrow_mut();
+            if let Some(pos) = held.iter().rposition(|&l| l == LEVEL) {
+                held.remove(pos);
+            }
+        });
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn increasing_order() {
+        let a = OrderedMutex::<u8, 1>::new(1);
+        let b = OrderedMutex::<u8, 2>::new(2);
+        let c = OrderedMutex::<u8, 3>::new(3);
+        let guard_a = a.lock();
+        let guard_c = c.lock();
+        assert_eq!(highest_held_level(), Some(3));
+        drop(guard_c);
+        let guard_b = b.lock();
+        assert_eq!(*guard_a + *guard_b, 3);
+        drop(guard_a);
+        drop(guard_b);
+        assert_eq!(highest_held_level(), None);
+    }
+
+    #[test]
+    fn order_violation() {
+        let a = OrderedMutex::<u8, 1>::new(1);
+        let b = OrderedMutex::<u8, 2>::new(2);
+        let b2 = OrderedMutex::<u8, 2>::new(2);
+        let _guard_b = b.lock();
+        assert_eq!(a.try_lock_ordered().unwrap_err(), LockError::OrderViolation {
+            level: 1,
+            held: 2
+        });
+        assert_eq!(b2.try_lock_ordered().unwrap_err(), LockError::OrderViolation {
+            level: 2,
+            held: 2
+        });
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    fn order_violation_panics() {
+        use std::panic;
+
+        let a = OrderedMutex::<u8, 1>::new(1);
+        let b = OrderedMutex::<u8, 2>::new(2);
+        let result = panic::catch_unwind(|| {
+            let _guard_b = b.lock();
+            let _guard_a = a.lock();
+        });
+        assert!(result.is_err());
+        assert_eq!(highest_held_level(), None);
+    }
+}
@@ -0,0 +1,246 @@
+// Copyright 2026, The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+//! Helpers for recovering `std` locks that have been poisoned by a panicking thread.
+//!
+//! By default a panic while a `Mutex` or `RwLock` guard is held poisons the lock, and every later acquisition returns
+//! an error. These helpers let callers choose explicitly what happens instead: recover the data, log a warning and
+//! clear the poison flag so that other users are unaffected, or surface a [LockError::Poisoned].
+
+use alloc::string::{String, ToString};
+use std::sync::{Mutex, MutexGuard, PoisonError, RwLock, RwLockReadGuard, RwLockWriteGuard};
+
+use crate::locks::LockError;
+
+/// What to do when a lock is found to be poisoned
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PoisonPolicy {
+    /// Log a warning, clear the poison flag and continue with the data as it was left by the panicking thread. This is
+    /// the default.
+    #[default]
+    LogAndContinue,
+    /// Return a [LockError::Poisoned] and leave the lock poisoned
+    Error,
+}
+
+fn handle_poison<G, C>(
+    result: Result<G, PoisonError<G>>,
+    policy: PoisonPolicy,
+    context: &str,
+    clear: C,
+) -> Result<G, LockError>
+where
+    C: FnOnce(),
+{
+    match result {
+        Ok(guard) => Ok(guard),
+        Err(poisoned) => match policy {
+            PoisonPolicy::LogAndContinue => {
+                log::warn!(target: "tari_util", "Lock `{}' has been POISONED and will be recovered", context);
+                clear();
+                Ok(poisoned.into_inner())
+            },
+            PoisonPolicy::Error => Err(LockError::Poisoned {
+                context: context.to_string(),
+            }),
+        },
+    }
+}
+
+/// Lock the mutex, recovering it if it has been poisoned. A warning is logged and the poison flag is cleared.
+pub fn recover_lock<T>(mutex: &Mutex<T>) -> MutexGuard<'_, T> {
+    // `LogAndContinue` recovers a poisoned lock, so no error is returned
+    lock_with_policy(mutex, PoisonPolicy::LogAndContinue, "Mutex").unwrap_or_else(|_| unreachable!())
+}
+
+/// Acquire a read lock on the `RwLock`, recovering it if it has been poisoned. A warning is logged and the poison flag
+/// is cleared.
+pub fn recover_read_lock<T>(lock: &RwLock<T>) -> RwLockReadGuard<'_, T> {
+    // `LogAndContinue` recovers a poisoned lock, so no error is returned
+    read_with_policy(lock, PoisonPolicy::LogAndContinue, "RwLock").unwrap_or_else(|_| unreachable!())
+}
+
+/// Acquire a write lock on the `RwLock`, recovering it if it has been poisoned. A warning is logged and the poison flag
+/// is cleared.
+pub fn recover_write_lock<T>(lock: &RwLock<T>) -> RwLockWriteGuard<'_, T> {
+    // `LogAndContinue` recovers a poisoned lock, so no error is returned
+    write_with_policy(lock, PoisonPolicy::LogAndContinue, "RwLock").unwrap_or_else(|_| unreachable!())
+}
+
+/// Lock the mutex, handling poisoning according to `policy`. The `context` describes the lock in logs and errors.
+pub fn lock_with_policy<'a, T>(
+    mutex: &'a Mutex<T>,
+    policy: PoisonPolicy,
+    context: &str,
+) -> Result<MutexGuard<'a, T>, LockError> {
+    handle_poison(mutex.lock(), policy, context, || mutex.clear_poison())
+}
+
+/// Acquire a read lock on the `RwLock`, handling poisoning according to `policy`. The `context` describes the lock in
+/// logs and errors.
+pub fn read_with_policy<'a, T>(
+    lock: &'a RwLock<T>,
+    policy: PoisonPolicy,
+    context: &str,
+) -> Result<RwLockReadGuard<'a, T>, LockError> {
+    handle_poison(lock.read(), policy, context, || lock.clear_poison())
+}
+
+/// Acquire a write lock on the `RwLock`, handling poisoning according to `policy`. The `context` describes the lock in
+/// logs and errors.
+pub fn write_with_policy<'a, T>(
+    lock: &'a RwLock<T>,
+    policy: PoisonPolicy,
+    context: &str,
+) -> Result<RwLockWriteGuard<'a, T>, LockError> {
+    handle_poison(lock.write(), policy, context, || lock.clear_poison())
+}
+
+/// A `Mutex` that applies a fixed [PoisonPolicy] every time it is locked.
+///
+/// ```edition2018
+/// # use tari_utilities::locks::poison::{PoisonPolicy, RecoverableMutex};
+/// let mutex = RecoverableMutex::new(1u32, PoisonPolicy::LogAndContinue, "counter");
+/// *mutex.lock().unwrap() += 1;
+/// assert_eq!(*mutex.lock().unwrap(), 2);
+/// ```
+#[derive(Debug, Default)]
+pub struct RecoverableMutex<T> {
+    inner: Mutex<T>,
+    policy: PoisonPolicy,
+    context: String,
+}
+
+impl<T> RecoverableMutex<T> {
+    /// Create a new mutex with the given poison policy. The `context` describes the lock in logs and errors.
+    pub fn new<S: Into<String>>(value: T, policy: PoisonPolicy, context: S) -> Self {
+        Self {
+            inner: Mutex::new(value),
+            policy,
+            context: context.into(),
+        }
+    }
+
+    /// Lock the mutex, handling poisoning according to the policy of this mutex. With
+    /// [PoisonPolicy::LogAndContinue], this never returns an error.
+    pub fn lock(&self) -> Result<MutexGuard<'_, T>, LockError> {
+        lock_with_policy(&self.inner, self.policy, &self.context)
+    }
+
+    /// Return the poison policy of this mutex
+    pub fn policy(&self) -> PoisonPolicy {
+        self.policy
+    }
+
+    /// Returns true if the mutex is currently poisoned
+    pub fn is_poisoned(&self) -> bool {
+        self.inner.is_poisoned()
+    }
+
+    /// Clear the poisoned state of the mutex
+    pub fn clear_poison(&self) {
+        self.inner.clear_poison();
+    }
+
+    /// Get a mutable reference to the data. No locking is needed since this requires exclusive access.
+    pub fn get_mut(&mut self) -> &mut T {
+        self.inner.get_mut().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Consume the mutex and return the data, regardless of whether it was poisoned
+    pub fn into_inner(self) -> T {
+        self.inner.into_inner().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::{panic, sync::Arc, thread};
+
+    use super::*;
+
+    fn poison<T: Send + 'static>(mutex: &Arc<Mutex<T>>) {
+        let mutex = mutex.clone();
+        let _result = thread::spawn(move || {
+            let _guard = mutex.lock().unwrap();
+            panic!("poisoning the mutex");
+        })
+        .join();
+    }
+
+    #[test]
+    fn recover() {
+        let mutex = Arc::new(Mutex::new(1u8));
+        poison(&mutex);
+        assert!(mutex.is_poisoned());
+        *recover_lock(&mutex) += 1;
+        assert!(!mutex.is_poisoned());
+        assert_eq!(*mutex.lock().unwrap(), 2);
+    }
+
+    #[test]
+    fn recover_rw_lock() {
+        let lock = Arc::new(RwLock::new(1u8));
+        let lock_clone = lock.clone();
+        let _result = thread::spawn(move || {
+            let _guard = lock_clone.write().unwrap();
+            panic!("poisoning the lock");
+        })
+        .join();
+        assert!(lock.is_poisoned());
+        assert_eq!(*recover_read_lock(&lock), 1);
+        assert!(!lock.is_poisoned());
+        *recover_write_lock(&lock) += 1;
+        assert_eq!(*lock.read().unwrap(), 2);
+    }
+
+    #[test]
+    fn error_policy() {
+        let mutex = Arc::new(Mutex::new(1u8));
+        assert!(lock_with_policy(&mutex, PoisonPolicy::Error, "mutex").is_ok());
+        poison(&mutex);
+        let err = lock_with_policy(&mutex, PoisonPolicy::Error, "mutex").unwrap_err();
+        assert_eq!(err, LockError::Poisoned {
+            context: "mutex".into()
+        });
+        assert!(mutex.is_poisoned());
+    }
+
+    #[test]
+    fn recoverable_mutex() {
+        let mutex = Arc::new(RecoverableMutex::new(1u8, PoisonPolicy::Error, "mutex"));
+        let mutex_clone = mutex.clone();
+        let result = panic::catch_unwind(move || {
+            let _guard = mutex_clone.lock().unwrap();
+            panic!("poisoning the mutex");
+        });
+        assert!(result.is_err());
+        assert!(mutex.is_poisoned());
+        assert!(mutex.lock().is_err());
+        mutex.clear_poison();
+        assert_eq!(*mutex.lock().unwrap(), 1);
+
+        let mutex = RecoverableMutex::new(1u8, PoisonPolicy::default(), "mutex");
+        assert_eq!(mutex.policy(), PoisonPolicy::LogAndContinue);
+        assert_eq!(mutex.into_inner(), 1);
+    }
+}
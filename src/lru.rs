@@ -0,0 +1,304 @@
+// Copyright 2026, The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+//! A small fixed-capacity least-recently-used cache.
+
+use core::array;
+
+#[cfg(feature = "std")]
+use crate::epoch_time::EpochTime;
+
+#[derive(Clone, Debug)]
+struct Entry<K, V> {
+    key: K,
+    value: V,
+    last_used: u64,
+    expires_at: Option<u64>,
+}
+
+/// A cache holding at most `N` entries. When full, inserting a new key evicts the least recently used entry.
+///
+/// Entries are kept in a fixed array and found by linear search, so the cache is intended for small `N`. With the
+/// `std` feature, entries can be given a time-to-live after which they are treated as absent.
+///
+/// ```edition2018
+/// # use tari_utilities::lru::LruCache;
+/// let mut cache = LruCache::<&str, u32, 2>::new();
+/// cache.put("a", 1);
+/// cache.put("b", 2);
+/// assert_eq!(cache.get(&"a"), Some(&1));
+/// // "b" is now the least recently used entry
+/// assert_eq!(cache.put("c", 3), Some(("b", 2)));
+/// assert_eq!(cache.peek(&"b"), None);
+/// ```
+#[derive(Clone, Debug)]
+pub struct LruCache<K, V, const N: usize> {
+    entries: [Option<Entry<K, V>>; N],
+    clock: u64,
+}
+
+impl<K: PartialEq, V, const N: usize> LruCache<K, V, N> {
+    /// Creates an empty cache.
+    pub fn new() -> Self {
+        Self {
+            entries: array::from_fn(|_| None),
+            clock: 0,
+        }
+    }
+
+    /// Returns the maximum number of entries the cache can hold.
+    pub fn capacity(&self) -> usize {
+        N
+    }
+
+    /// Returns the number of entries in the cache, including any that have expired but not yet been removed.
+    pub fn len(&self) -> usize {
+        self.entries.iter().filter(|entry| entry.is_some()).count()
+    }
+
+    /// Returns true if the cache holds no entries.
+    pub fn is_empty(&self) -> bool {
+        self.entries.iter().all(Option::is_none)
+    }
+
+    /// Returns the value for `key` and marks it as the most recently used entry. An expired entry is removed.
+    pub fn get(&mut self, key: &K) -> Option<&V> {
+        let index = self.live_position(key)?;
+        self.clock += 1;
+        let entry = self.entries[index].as_mut()?;
+        entry.last_used = self.clock;
+        Some(&entry.value)
+    }
+
+    /// Returns a mutable reference to the value for `key` and marks it as the most recently used entry.
+    pub fn get_mut(&mut self, key: &K) -> Option<&mut V> {
+        let index = self.live_position(key)?;
+        self.clock += 1;
+        let entry = self.entries[index].as_mut()?;
+        entry.last_used = self.clock;
+        Some(&mut entry.value)
+    }
+
+    /// Returns the value for `key` without changing how recently it was used.
+    pub fn peek(&self, key: &K) -> Option<&V> {
+        let now = now_secs();
+        self.entries
+            .iter()
+            .flatten()
+            .find(|entry| entry.key == *key && !is_expired(entry, now))
+            .map(|entry| &entry.value)
+    }
+
+    /// Returns true if the cache holds an unexpired entry for `key`.
+    pub fn contains_key(&self, key: &K) -> bool {
+        self.peek(key).is_some()
+    }
+
+    /// Inserts a value that never expires. Returns the entry that was replaced or evicted to make room, if any.
+    pub fn put(&mut self, key: K, value: V) -> Option<(K, V)> {
+        self.insert(key, value, None)
+    }
+
+    /// Inserts a value that expires `ttl` after now. Returns the entry that was replaced or evicted to make room, if
+    /// any.
+    #[cfg(feature = "std")]
+    pub fn put_with_ttl(&mut self, key: K, value: V, ttl: EpochTime) -> Option<(K, V)> {
+        let expires_at = EpochTime::now().checked_add(ttl).map(EpochTime::as_u64);
+        self.insert(key, value, Some(expires_at.unwrap_or(u64::MAX)))
+    }
+
+    /// Inserts a value that expires at `expires_at`. Returns the entry that was replaced or evicted to make room, if
+    /// any.
+    #[cfg(feature = "std")]
+    pub fn put_expiring_at(&mut self, key: K, value: V, expires_at: EpochTime) -> Option<(K, V)> {
+        self.insert(key, value, Some(expires_at.as_u64()))
+    }
+
+    /// Removes the entry for `key`, returning its value if it had not expired.
+    pub fn remove(&mut self, key: &K) -> Option<V> {
+        let now = now_secs();
+        let index = self.position(key)?;
+        self.entries[index]
+            .take()
+            .filter(|entry| !is_expired(entry, now))
+            .map(|entry| entry.value)
+    }
+
+    /// Removes every expired entry, returning the number removed.
+    pub fn purge_expired(&mut self) -> usize {
+        let now = now_secs();
+        let mut removed = 0;
+        for slot in &mut self.entries {
+            if slot.as_ref().map(|entry| is_expired(entry, now)).unwrap_or(false) {
+                *slot = None;
+                removed += 1;
+            }
+        }
+        removed
+    }
+
+    /// Removes every entry.
+    pub fn clear(&mut self) {
+        self.entries.iter_mut().for_each(|slot| *slot = None);
+    }
+
+    fn insert(&mut self, key: K, value: V, expires_at: Option<u64>) -> Option<(K, V)> {
+        if N == 0 {
+            return Some((key, value));
+        }
+        self.clock += 1;
+        let entry = Entry {
+            key,
+            value,
+            last_used: self.clock,
+            expires_at,
+        };
+        let index = self
+            .position(&entry.key)
+            .or_else(|| self.entries.iter().position(Option::is_none))
+            .or_else(|| self.expired_position())
+            .unwrap_or_else(|| self.least_recently_used());
+        self.entries[index].replace(entry).map(|old| (old.key, old.value))
+    }
+
+    fn position(&self, key: &K) -> Option<usize> {
+        self.entries
+            .iter()
+            .position(|slot| slot.as_ref().map(|entry| entry.key == *key).unwrap_or(false))
+    }
+
+    fn live_position(&mut self, key: &K) -> Option<usize> {
+        let index = self.position(key)?;
+        if self.entries[index]
+            .as_ref()
+            .map(|entry| is_expired(entry, now_secs()))
+            .unwrap_or(true)
+        {
+            self.entries[index] = None;
+            return None;
+        }
+        Some(index)
+    }
+
+    fn expired_position(&self) -> Option<usize> {
+        let now = now_secs();
+        self.entries
+            .iter()
+            .position(|slot| slot.as_ref().map(|entry| is_expired(entry, now)).unwrap_or(false))
+    }
+
+    fn least_recently_used(&self) -> usize {
+        self.entries
+            .iter()
+            .enumerate()
+            .min_by_key(|(_, slot)| slot.as_ref().map(|entry| entry.last_used).unwrap_or(0))
+            .map(|(index, _)| index)
+            .unwrap_or(0)
+    }
+}
+
+impl<K: PartialEq, V, const N: usize> Default for LruCache<K, V, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn is_expired<K, V>(entry: &Entry<K, V>, now: Option<u64>) -> bool {
+    match (entry.expires_at, now) {
+        (Some(expires_at), Some(now)) => now >= expires_at,
+        _ => false,
+    }
+}
+
+#[cfg(feature = "std")]
+fn now_secs() -> Option<u64> {
+    Some(EpochTime::now().as_u64())
+}
+
+#[cfg(not(feature = "std"))]
+fn now_secs() -> Option<u64> {
+    None
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn evicts_least_recently_used() {
+        let mut cache = LruCache::<u8, u8, 3>::new();
+        assert!(cache.is_empty());
+        assert_eq!(cache.put(1, 10), None);
+        assert_eq!(cache.put(2, 20), None);
+        assert_eq!(cache.put(3, 30), None);
+        assert_eq!(cache.len(), 3);
+        assert_eq!(cache.get(&1), Some(&10));
+        // Peeking does not count as a use
+        assert_eq!(cache.peek(&2), Some(&20));
+        assert_eq!(cache.put(4, 40), Some((2, 20)));
+        assert!(!cache.contains_key(&2));
+        *cache.get_mut(&3).unwrap() += 1;
+        assert_eq!(cache.put(5, 50), Some((1, 10)));
+        assert_eq!(cache.peek(&3), Some(&31));
+    }
+
+    #[test]
+    fn replace_and_remove() {
+        let mut cache = LruCache::<u8, u8, 2>::default();
+        cache.put(1, 10);
+        assert_eq!(cache.put(1, 11), Some((1, 10)));
+        assert_eq!(cache.len(), 1);
+        assert_eq!(cache.remove(&1), Some(11));
+        assert_eq!(cache.remove(&1), None);
+        cache.put(2, 20);
+        cache.clear();
+        assert!(cache.is_empty());
+        assert_eq!(cache.capacity(), 2);
+    }
+
+    #[test]
+    fn zero_capacity() {
+        let mut cache = LruCache::<u8, u8, 0>::new();
+        assert_eq!(cache.put(1, 10), Some((1, 10)));
+        assert_eq!(cache.get(&1), None);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn ttl() {
+        let mut cache = LruCache::<u8, u8, 2>::new();
+        cache.put_with_ttl(1, 10, EpochTime::from(3600));
+        cache.put_expiring_at(2, 20, EpochTime::from(1));
+        assert_eq!(cache.peek(&1), Some(&10));
+        assert_eq!(cache.peek(&2), None);
+        assert_eq!(cache.len(), 2);
+        // Expired entries are replaced before live ones are evicted
+        assert_eq!(cache.put(3, 30), Some((2, 20)));
+        assert_eq!(cache.get(&1), Some(&10));
+
+        cache.put_expiring_at(4, 40, EpochTime::from(1));
+        assert_eq!(cache.get(&4), None);
+        cache.put_with_ttl(5, 50, EpochTime::from(0));
+        assert_eq!(cache.purge_expired(), 1);
+        assert_eq!(cache.len(), 1);
+    }
+}
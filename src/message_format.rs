@@ -21,14 +21,39 @@
 // USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
 
 //! A `MessageFormat` trait that handles conversion from and to binary, json, or base64.
-
-use alloc::{string::String, vec::Vec};
+//!
+//! Byte payload fields are serialized element by element unless they are annotated with
+//! `#[serde(with = "tari_utilities::serde::bytes")]`, which writes them to binary as a single raw byte string. See
+//! [crate::serde::bytes].
+
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+#[cfg(feature = "digest")]
+use std::io::Write;
 
 use base64;
+use bincode::Options;
+#[cfg(feature = "bytes")]
+use bytes::{Buf, BufMut};
+#[cfg(feature = "digest")]
+use digest::{Digest, Output};
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
 use serde_json;
 use snafu::prelude::*;
 
+#[cfg(feature = "digest")]
+use crate::hash::HashingWriter;
+use crate::validate::{Validated, ValidationError};
+#[cfg(feature = "std")]
+use crate::{
+    base64::{encoded_len, Config, EncoderWriter},
+    buffer_pool::{BufferPool, PooledBuffer},
+};
+
 /// Errors for [MessageFormat] trait.
 #[derive(Debug, Snafu)]
 #[allow(missing_docs)]
@@ -41,23 +66,175 @@ pub enum MessageFormatError {
     JSONError {},
     #[snafu(display("An error occurred deserialising an object from Base64"))]
     Base64DeserializeError {},
+    #[snafu(display("Unknown JSON field `{field}'"))]
+    UnknownJSONFieldError { field: String },
+    #[snafu(display("The message is invalid: {error}"))]
+    ValidationFailedError { error: ValidationError },
+    #[snafu(display("JSON is nested more than {max_depth} levels deep"))]
+    JSONDepthError { max_depth: usize },
+}
+
+/// The maximum nesting depth of arrays and objects accepted by `from_json` and `from_json_strict`.
+pub const DEFAULT_MAX_JSON_DEPTH: usize = 128;
+
+/// The bincode configuration used by `to_canonical_binary` and `from_canonical_binary`.
+///
+/// It is set explicitly rather than taken from bincode's defaults, so that the layout does not change if those
+/// defaults do:
+/// - integers are fixed-width and little-endian, and lengths and enum tags are encoded as `u64` and `u32`;
+/// - there is no size limit;
+/// - decoding fails if any bytes remain after the value.
+///
+/// Data written with this configuration must stay readable, so it must not be changed.
+pub fn canonical_options() -> impl bincode::Options + Copy {
+    bincode::DefaultOptions::new()
+        .with_no_limit()
+        .with_fixint_encoding()
+        .with_little_endian()
+        .reject_trailing_bytes()
 }
 
 /// Trait for converting to/from binary/json/base64.
 pub trait MessageFormat: Sized {
     /// Convert to binary.
     fn to_binary(&self) -> Result<Vec<u8>, MessageFormatError>;
+    /// Convert to the canonical binary form described by [canonical_options], which is suitable for storage. The
+    /// default implementation is the same as `to_binary`.
+    fn to_canonical_binary(&self) -> Result<Vec<u8>, MessageFormatError> {
+        self.to_binary()
+    }
     /// Convert to json.
     fn to_json(&self) -> Result<String, MessageFormatError>;
+    /// Convert to json with the keys of every object, including those of maps such as `HashMap`, in sorted order, so
+    /// that equal values always produce the same json. Numbers that do not fit in an `i64`, `u64` or `f64` cannot be
+    /// converted. The default implementation sorts the output of `to_json`.
+    fn to_json_sorted(&self) -> Result<String, MessageFormatError> {
+        let mut value: serde_json::Value =
+            serde_json::from_str(&self.to_json()?).map_err(|_| MessageFormatError::JSONError {})?;
+        value.sort_all_objects();
+        Ok(value.to_string())
+    }
     /// Convert to base64.
     fn to_base64(&self) -> Result<String, MessageFormatError>;
+    /// Convert to binary, writing into a buffer drawn from `pool`. The buffer is returned to the pool when the guard is
+    /// dropped.
+    #[cfg(feature = "std")]
+    fn to_binary_pooled<'a>(&self, pool: &'a BufferPool) -> Result<PooledBuffer<'a>, MessageFormatError> {
+        let mut buf = pool.get();
+        buf.extend_from_slice(&self.to_binary()?);
+        Ok(buf)
+    }
+
+    /// Convert to binary and write it to `writer`, hashing the binary with `D` as it is written. Returns the writer
+    /// and the hash.
+    #[cfg(feature = "digest")]
+    fn to_writer_hashed<D, W>(&self, writer: W) -> Result<(W, Output<D>), MessageFormatError>
+    where
+        D: Digest,
+        W: Write,
+    {
+        let mut writer = HashingWriter::<D, W>::new(writer);
+        writer
+            .write_all(&self.to_binary()?)
+            .map_err(|_| MessageFormatError::BinarySerializeError {})?;
+        Ok(writer.finalize())
+    }
+
+    /// Convert to binary, appending it to `buf`.
+    #[cfg(feature = "bytes")]
+    fn to_buf(&self, buf: &mut impl BufMut) -> Result<(), MessageFormatError> {
+        buf.put_slice(&self.to_binary()?);
+        Ok(())
+    }
 
     /// Convert from binary.
     fn from_binary(msg: &[u8]) -> Result<Self, MessageFormatError>;
-    /// Convert from json.
+    /// Convert from the canonical binary form described by [canonical_options], rejecting trailing bytes. The default
+    /// implementation is the same as `from_binary`.
+    fn from_canonical_binary(msg: &[u8]) -> Result<Self, MessageFormatError> {
+        Self::from_binary(msg)
+    }
+    /// Convert from json. Fails with `JSONDepthError` if arrays and objects are nested more than
+    /// [DEFAULT_MAX_JSON_DEPTH] levels deep.
     fn from_json(msg: &str) -> Result<Self, MessageFormatError>;
+    /// Convert from json, failing with `JSONDepthError` if arrays and objects are nested more than `max_depth` levels
+    /// deep. The depth is checked before decoding starts, so untrusted input can be given a lower limit than the
+    /// default. Decoding recurses once per level, so a limit much higher than the default needs a correspondingly
+    /// large stack. The default implementation accepts at most [DEFAULT_MAX_JSON_DEPTH] levels, however high
+    /// `max_depth` is.
+    fn from_json_with_max_depth(msg: &str, max_depth: usize) -> Result<Self, MessageFormatError> {
+        check_json_depth(msg, max_depth)?;
+        Self::from_json(msg)
+    }
+    /// Convert from json, rejecting fields that the type does not declare. The default implementation is the same as
+    /// `from_json`; the implementation for serde types fails with `UnknownJSONFieldError`, naming the path of the
+    /// first unknown field (such as `config.retries`), where `from_json` would silently ignore it. Types that are
+    /// themselves annotated with `#[serde(deny_unknown_fields)]` fail with a `JSONError` instead.
+    fn from_json_strict(msg: &str) -> Result<Self, MessageFormatError> {
+        Self::from_json(msg)
+    }
     /// Convert from base64.
     fn from_base64(msg: &str) -> Result<Self, MessageFormatError>;
+    /// Convert from binary, then check the result with [Validated::validate].
+    fn from_binary_validated(msg: &[u8]) -> Result<Self, MessageFormatError>
+    where Self: Validated {
+        validated(Self::from_binary(msg)?)
+    }
+    /// Convert from json, then check the result with [Validated::validate].
+    fn from_json_validated(msg: &str) -> Result<Self, MessageFormatError>
+    where Self: Validated {
+        validated(Self::from_json(msg)?)
+    }
+    /// Convert from base64, then check the result with [Validated::validate].
+    fn from_base64_validated(msg: &str) -> Result<Self, MessageFormatError>
+    where Self: Validated {
+        validated(Self::from_base64(msg)?)
+    }
+    /// Convert from binary read from `buf`. The default implementation consumes all remaining bytes; the
+    /// implementation for serde types consumes only the bytes of one message, leaving any that follow in `buf`.
+    #[cfg(feature = "bytes")]
+    fn from_buf(buf: &mut impl Buf) -> Result<Self, MessageFormatError> {
+        let msg = buf.copy_to_bytes(buf.remaining());
+        Self::from_binary(&msg)
+    }
+}
+
+/// Fails if arrays and objects in `json` are nested more than `max_depth` levels deep. Brackets inside strings are
+/// ignored, and malformed JSON is left for the decoder to reject.
+fn check_json_depth(json: &str, max_depth: usize) -> Result<(), MessageFormatError> {
+    let mut depth = 0usize;
+    let mut in_string = false;
+    let mut escaped = false;
+    for &byte in json.as_bytes() {
+        if in_string {
+            match byte {
+                _ if escaped => escaped = false,
+                b'\\' => escaped = true,
+                b'"' => in_string = false,
+                _ => {},
+            }
+            continue;
+        }
+        match byte {
+            b'"' => in_string = true,
+            b'[' | b'{' => {
+                depth += 1;
+                if depth > max_depth {
+                    return Err(MessageFormatError::JSONDepthError { max_depth });
+                }
+            },
+            b']' | b'}' => depth = depth.saturating_sub(1),
+            _ => {},
+        }
+    }
+    Ok(())
+}
+
+fn validated<T: Validated>(value: T) -> Result<T, MessageFormatError> {
+    value
+        .validate()
+        .map_err(|error| MessageFormatError::ValidationFailedError { error })?;
+    Ok(value)
 }
 
 impl<T> MessageFormat for T
@@ -67,22 +244,109 @@ where T: DeserializeOwned + Serialize
         bincode::serialize(self).map_err(|_| MessageFormatError::BinarySerializeError {})
     }
 
+    fn to_canonical_binary(&self) -> Result<Vec<u8>, MessageFormatError> {
+        canonical_options()
+            .serialize(self)
+            .map_err(|_| MessageFormatError::BinarySerializeError {})
+    }
+
     fn to_json(&self) -> Result<String, MessageFormatError> {
         serde_json::to_string(self).map_err(|_| MessageFormatError::JSONError {})
     }
 
+    /// Goes through a `serde_json::Value`, sorting it explicitly in case serde_json's `preserve_order` feature is
+    /// enabled.
+    fn to_json_sorted(&self) -> Result<String, MessageFormatError> {
+        let mut value = serde_json::to_value(self).map_err(|_| MessageFormatError::JSONError {})?;
+        value.sort_all_objects();
+        Ok(value.to_string())
+    }
+
+    #[cfg(not(feature = "std"))]
     fn to_base64(&self) -> Result<String, MessageFormatError> {
         let val = self.to_binary()?;
         Ok(base64::encode(val))
     }
 
+    /// Serializes straight into a base64 encoder, so the binary form is never held in memory in full.
+    #[cfg(feature = "std")]
+    fn to_base64(&self) -> Result<String, MessageFormatError> {
+        let size = bincode::serialized_size(self).map_err(|_| MessageFormatError::BinarySerializeError {})?;
+        let mut encoded = Vec::with_capacity(encoded_len(size as usize, Config::STANDARD));
+        let mut encoder = EncoderWriter::new(&mut encoded, Config::STANDARD);
+        bincode::serialize_into(&mut encoder, self).map_err(|_| MessageFormatError::BinarySerializeError {})?;
+        encoder
+            .finish()
+            .map_err(|_| MessageFormatError::BinarySerializeError {})?;
+        String::from_utf8(encoded).map_err(|_| MessageFormatError::BinarySerializeError {})
+    }
+
+    #[cfg(feature = "std")]
+    fn to_binary_pooled<'a>(&self, pool: &'a BufferPool) -> Result<PooledBuffer<'a>, MessageFormatError> {
+        let mut buf = pool.get();
+        bincode::serialize_into(&mut *buf, self).map_err(|_| MessageFormatError::BinarySerializeError {})?;
+        Ok(buf)
+    }
+
+    #[cfg(feature = "digest")]
+    fn to_writer_hashed<D, W>(&self, writer: W) -> Result<(W, Output<D>), MessageFormatError>
+    where
+        D: Digest,
+        W: Write,
+    {
+        let mut writer = HashingWriter::<D, W>::new(writer);
+        bincode::serialize_into(&mut writer, self).map_err(|_| MessageFormatError::BinarySerializeError {})?;
+        Ok(writer.finalize())
+    }
+
     fn from_binary(msg: &[u8]) -> Result<Self, MessageFormatError> {
         bincode::deserialize(msg).map_err(|_| MessageFormatError::BinaryDeserializeError {})
     }
 
+    fn from_canonical_binary(msg: &[u8]) -> Result<Self, MessageFormatError> {
+        canonical_options()
+            .deserialize(msg)
+            .map_err(|_| MessageFormatError::BinaryDeserializeError {})
+    }
+
+    #[cfg(feature = "bytes")]
+    fn to_buf(&self, buf: &mut impl BufMut) -> Result<(), MessageFormatError> {
+        bincode::serialize_into(buf.writer(), self).map_err(|_| MessageFormatError::BinarySerializeError {})
+    }
+
+    #[cfg(feature = "bytes")]
+    fn from_buf(buf: &mut impl Buf) -> Result<Self, MessageFormatError> {
+        bincode::deserialize_from(buf.reader()).map_err(|_| MessageFormatError::BinaryDeserializeError {})
+    }
+
     fn from_json(msg: &str) -> Result<Self, MessageFormatError> {
-        let mut de = serde_json::Deserializer::from_reader(msg.as_bytes());
-        Deserialize::deserialize(&mut de).map_err(|_| MessageFormatError::JSONError {})
+        Self::from_json_with_max_depth(msg, DEFAULT_MAX_JSON_DEPTH)
+    }
+
+    fn from_json_with_max_depth(msg: &str, max_depth: usize) -> Result<Self, MessageFormatError> {
+        check_json_depth(msg, max_depth)?;
+        let mut de = serde_json::Deserializer::from_str(msg);
+        // The depth has been bounded above, so serde_json's own fixed limit is not needed
+        de.disable_recursion_limit();
+        let value = Deserialize::deserialize(&mut de).map_err(|_| MessageFormatError::JSONError {})?;
+        de.end().map_err(|_| MessageFormatError::JSONError {})?;
+        Ok(value)
+    }
+
+    fn from_json_strict(msg: &str) -> Result<Self, MessageFormatError> {
+        check_json_depth(msg, DEFAULT_MAX_JSON_DEPTH)?;
+        let mut unknown = None;
+        let mut de = serde_json::Deserializer::from_str(msg);
+        de.disable_recursion_limit();
+        let value = serde_ignored::deserialize(&mut de, |path| {
+            unknown.get_or_insert_with(|| path.to_string());
+        })
+        .map_err(|_| MessageFormatError::JSONError {})?;
+        de.end().map_err(|_| MessageFormatError::JSONError {})?;
+        match unknown {
+            Some(field) => Err(MessageFormatError::UnknownJSONFieldError { field }),
+            None => Ok(value),
+        }
     }
 
     fn from_base64(msg: &str) -> Result<Self, MessageFormatError> {
@@ -91,6 +355,25 @@ where T: DeserializeOwned + Serialize
     }
 }
 
+/// Converts each value to binary in parallel on the rayon thread pool. The output is in the same order as `values`,
+/// and the first error encountered is returned.
+#[cfg(feature = "rayon")]
+pub fn encode_batch_parallel<T>(values: &[T]) -> Result<Vec<Vec<u8>>, MessageFormatError>
+where T: MessageFormat + Sync {
+    values.par_iter().map(MessageFormat::to_binary).collect()
+}
+
+/// Converts each binary message to a value in parallel on the rayon thread pool. The output is in the same order as
+/// `messages`, and the first error encountered is returned.
+#[cfg(feature = "rayon")]
+pub fn decode_batch_parallel<T, B>(messages: &[B]) -> Result<Vec<T>, MessageFormatError>
+where
+    T: MessageFormat + Send,
+    B: AsRef<[u8]> + Sync,
+{
+    messages.par_iter().map(|msg| T::from_binary(msg.as_ref())).collect()
+}
+
 #[cfg(test)]
 mod test {
     use alloc::{boxed::Box, string::ToString};
@@ -185,12 +468,224 @@ mod test {
         assert_eq!(val, val2);
     }
 
+    #[test]
+    #[cfg(feature = "std")]
+    fn binary_pooled() {
+        let pool = BufferPool::new();
+        let val = TestMessage::new("twenty", 20);
+        {
+            let msg = val.to_binary_pooled(&pool).unwrap();
+            assert_eq!(&msg[..], &val.to_binary().unwrap()[..]);
+            assert_eq!(TestMessage::from_binary(&msg).unwrap(), val);
+        }
+        assert_eq!(pool.available(), 1);
+        let msg = TestMessage::new("one", 1).to_binary_pooled(&pool).unwrap();
+        assert_eq!(TestMessage::from_binary(&msg).unwrap(), TestMessage::new("one", 1));
+        assert_eq!(pool.available(), 0);
+    }
+
+    #[test]
+    #[cfg(feature = "rayon")]
+    fn batch_parallel() {
+        let values = (0..1000u64)
+            .map(|i| TestMessage::new(&i.to_string(), i))
+            .collect::<Vec<_>>();
+        let encoded = encode_batch_parallel(&values).unwrap();
+        assert_eq!(encoded.len(), values.len());
+        assert_eq!(encoded[42], values[42].to_binary().unwrap());
+        let decoded: Vec<TestMessage> = decode_batch_parallel(&encoded).unwrap();
+        assert_eq!(decoded, values);
+
+        let mut corrupted = encoded;
+        corrupted[500].truncate(3);
+        let err = decode_batch_parallel::<TestMessage, _>(&corrupted).unwrap_err();
+        assert!(matches!(err, MessageFormatError::BinaryDeserializeError {}));
+    }
+
+    #[test]
+    fn base64_matches_binary() {
+        for len in 0..64 {
+            let val = TestMessage::new(&"x".repeat(len), len as u64);
+            assert_eq!(val.to_base64().unwrap(), base64::encode(val.to_binary().unwrap()));
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "digest")]
+    fn writer_hashed() {
+        use sha2::Sha256;
+
+        let val = TestMessage::new("twenty", 20);
+        let (written, hash) = val.to_writer_hashed::<Sha256, _>(Vec::new()).unwrap();
+        let binary = val.to_binary().unwrap();
+        assert_eq!(written, binary);
+        assert_eq!(hash, Sha256::digest(&binary));
+    }
+
+    #[test]
+    #[cfg(feature = "bytes")]
+    fn bufs() {
+        use bytes::{Buf, BytesMut};
+
+        let first = TestMessage::new("one", 1);
+        let second = TestMessage::new("two", 2);
+        let mut buf = BytesMut::new();
+        first.to_buf(&mut buf).unwrap();
+        second.to_buf(&mut buf).unwrap();
+        assert_eq!(buf.len(), first.to_binary().unwrap().len() * 2);
+
+        let mut buf = buf.freeze();
+        assert_eq!(TestMessage::from_buf(&mut buf).unwrap(), first);
+        assert_eq!(TestMessage::from_buf(&mut buf).unwrap(), second);
+        assert!(!buf.has_remaining());
+        let err = TestMessage::from_buf(&mut buf).unwrap_err();
+        assert!(matches!(err, MessageFormatError::BinaryDeserializeError {}));
+    }
+
     #[test]
     fn fail_json() {
         let err = TestMessage::from_json("{\"key\":5}").unwrap_err();
         assert!(matches!(err, MessageFormatError::JSONError {}));
     }
 
+    #[test]
+    fn fail_json_trailing_data() {
+        let json = TestMessage::new("a", 1).to_json().unwrap();
+        assert!(TestMessage::from_json(&json).is_ok());
+        let err = TestMessage::from_json(&format!("{}xyz", json)).unwrap_err();
+        assert!(matches!(err, MessageFormatError::JSONError {}));
+        let err = TestMessage::from_json(&format!("{}{}", json, json)).unwrap_err();
+        assert!(matches!(err, MessageFormatError::JSONError {}));
+        assert!(TestMessage::from_json(&format!(" {}\n", json)).is_ok());
+    }
+
+    #[test]
+    fn canonical_binary() {
+        let msg = TestMessage::new("ab", 1);
+        let binary = msg.to_canonical_binary().unwrap();
+        assert_eq!(binary, [2, 0, 0, 0, 0, 0, 0, 0, b'a', b'b', 1, 0, 0, 0, 0, 0, 0, 0, 0]);
+        assert_eq!(binary, msg.to_binary().unwrap());
+        assert_eq!(TestMessage::from_canonical_binary(&binary).unwrap(), msg);
+
+        let mut trailing = binary;
+        trailing.push(0);
+        assert_eq!(TestMessage::from_binary(&trailing).unwrap(), msg);
+        let err = TestMessage::from_canonical_binary(&trailing).unwrap_err();
+        assert!(matches!(err, MessageFormatError::BinaryDeserializeError {}));
+    }
+
+    impl Validated for TestMessage {
+        fn validate(&self) -> Result<(), ValidationError> {
+            if self.key.is_empty() {
+                return Err(ValidationError::invalid_field("key", "must not be empty"));
+            }
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn validated() {
+        let valid = TestMessage::new("valid", 1);
+        let invalid = TestMessage::new("", 1);
+        assert_eq!(
+            TestMessage::from_binary_validated(&valid.to_binary().unwrap()).unwrap(),
+            valid
+        );
+        assert_eq!(
+            TestMessage::from_json_validated(&valid.to_json().unwrap()).unwrap(),
+            valid
+        );
+        assert_eq!(
+            TestMessage::from_base64_validated(&valid.to_base64().unwrap()).unwrap(),
+            valid
+        );
+
+        let err = TestMessage::from_binary_validated(&invalid.to_binary().unwrap()).unwrap_err();
+        assert!(matches!(err, MessageFormatError::ValidationFailedError {
+            error: ValidationError::InvalidField { field: "key", .. }
+        }));
+        assert_eq!(
+            err.to_string(),
+            "The message is invalid: Invalid value for `key': `must not be empty'"
+        );
+        let err = TestMessage::from_json_validated(&invalid.to_json().unwrap()).unwrap_err();
+        assert!(matches!(err, MessageFormatError::ValidationFailedError { .. }));
+        let err = TestMessage::from_json_validated("{").unwrap_err();
+        assert!(matches!(err, MessageFormatError::JSONError {}));
+    }
+
+    #[test]
+    fn json_sorted() {
+        let mut map = std::collections::HashMap::new();
+        for (i, key) in ["delta", "alpha", "charlie", "bravo", "echo"].iter().enumerate() {
+            map.insert(key.to_string(), i);
+        }
+        assert_eq!(
+            map.to_json_sorted().unwrap(),
+            r#"{"alpha":1,"bravo":3,"charlie":2,"delta":0,"echo":4}"#
+        );
+
+        let mut msg = TestMessage::new("outer", 1);
+        msg.set_sub_message(TestMessage::new("inner", 2));
+        let sorted = msg.to_json_sorted().unwrap();
+        assert_eq!(
+            sorted,
+            r#"{"key":"outer","sub_message":{"key":"inner","sub_message":null,"value":2},"value":1}"#
+        );
+        assert_eq!(TestMessage::from_json(&sorted).unwrap(), msg);
+    }
+
+    #[test]
+    fn json_depth() {
+        let nested = |depth: usize| format!("{}{}", "[".repeat(depth), "]".repeat(depth));
+        assert!(Vec::<u8>::from_json_with_max_depth("[1,2]", 1).is_ok());
+        assert!(matches!(
+            Vec::<Vec<u8>>::from_json_with_max_depth("[[1],[2]]", 1),
+            Err(MessageFormatError::JSONDepthError { max_depth: 1 })
+        ));
+        // Brackets in strings do not count
+        assert_eq!(
+            Vec::<String>::from_json_with_max_depth(r#"["[[", "\"{{"]"#, 1).unwrap(),
+            vec!["[[".to_string(), "\"{{".to_string()]
+        );
+
+        let err = serde_json::Value::from_json(&nested(DEFAULT_MAX_JSON_DEPTH + 1)).unwrap_err();
+        assert!(matches!(err, MessageFormatError::JSONDepthError {
+            max_depth: DEFAULT_MAX_JSON_DEPTH
+        }));
+        assert_eq!(err.to_string(), "JSON is nested more than 128 levels deep");
+        assert!(serde_json::Value::from_json_strict(&nested(DEFAULT_MAX_JSON_DEPTH + 1)).is_err());
+        assert!(serde_json::Value::from_json(&nested(DEFAULT_MAX_JSON_DEPTH)).is_ok());
+        assert!(serde_json::Value::from_json_with_max_depth(&nested(200), 200).is_ok());
+    }
+
+    #[test]
+    fn json_strict() {
+        let json = TestMessage::new("strict", 1).to_json().unwrap();
+        assert_eq!(TestMessage::from_json_strict(&json).unwrap().key, "strict");
+
+        let json = json.replacen('{', "{\"retries\":3,", 1);
+        assert_eq!(TestMessage::from_json(&json).unwrap().key, "strict");
+        let err = TestMessage::from_json_strict(&json).unwrap_err();
+        assert!(matches!(err, MessageFormatError::UnknownJSONFieldError { ref field } if field == "retries"));
+        assert_eq!(err.to_string(), "Unknown JSON field `retries'");
+
+        let mut msg = TestMessage::new("outer", 1);
+        msg.set_sub_message(TestMessage::new("inner", 2));
+        let json = msg
+            .to_json()
+            .unwrap()
+            .replacen("\"key\":\"inner\"", "\"key\":\"inner\",\"typo\":0", 1);
+        let err = TestMessage::from_json_strict(&json).unwrap_err();
+        assert!(
+            matches!(err, MessageFormatError::UnknownJSONFieldError { ref field } if field == "sub_message.?.typo")
+        );
+
+        let err =
+            TestMessage::from_json_strict(&format!("{} {{", TestMessage::new("a", 1).to_json().unwrap())).unwrap_err();
+        assert!(matches!(err, MessageFormatError::JSONError {}));
+    }
+
     #[test]
     fn fail_base64() {
         let err = TestMessage::from_base64("aaaaa$aaaaa").unwrap_err();
@@ -0,0 +1,277 @@
+// Copyright 2026, The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+//! [Multibase](https://github.com/multiformats/multibase) encoding, optionally with a
+//! [multicodec](https://github.com/multiformats/multicodec) prefix.
+//!
+//! A multibase string is a single character identifying the base, followed by the payload in that base. A multicodec
+//! prefix is a varint code identifying the payload type, placed in front of the payload before it is encoded.
+//!
+//! ```edition2018
+//! # use tari_utilities::multibase::{self, codec, Base};
+//! let key = [0x11u8; 32];
+//! let encoded = multibase::encode_with_codec(Base::Base32, codec::ED25519_PUB, &key);
+//! assert!(encoded.starts_with("b5u"));
+//! let (base, code, payload) = multibase::decode_with_codec(&encoded).unwrap();
+//! assert_eq!(
+//!     (base, code, payload),
+//!     (Base::Base32, codec::ED25519_PUB, key.to_vec())
+//! );
+//! ```
+
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+
+use snafu::prelude::*;
+
+use crate::{
+    base32,
+    base64::{self, Config},
+    hex,
+    varint,
+};
+
+/// Common multicodec codes.
+pub mod codec {
+    /// Raw identity: the payload is the data itself
+    pub const IDENTITY: u64 = 0x00;
+    /// SHA2-256 multihash
+    pub const SHA2_256: u64 = 0x12;
+    /// Raw binary
+    pub const RAW: u64 = 0x55;
+    /// Ed25519 public key
+    pub const ED25519_PUB: u64 = 0xed;
+    /// Ristretto255 public key, as used by Tari
+    pub const RISTRETTO255_PUB: u64 = 0x1206;
+}
+
+/// The supported multibase encodings.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Base {
+    /// Lower case hexadecimal, prefix `f`
+    Base16,
+    /// Upper case hexadecimal, prefix `F`
+    Base16Upper,
+    /// Lower case RFC 4648 Base32 without padding, prefix `b`
+    Base32,
+    /// Upper case RFC 4648 Base32 without padding, prefix `B`
+    Base32Upper,
+    /// Bitcoin Base58, prefix `z`
+    #[cfg(feature = "base58")]
+    Base58Btc,
+    /// RFC 4648 Base64 without padding, prefix `m`
+    Base64,
+    /// RFC 4648 URL-safe Base64 without padding, prefix `u`
+    Base64Url,
+}
+
+impl Base {
+    /// Returns the prefix character for this base.
+    pub fn code(self) -> char {
+        match self {
+            Base::Base16 => 'f',
+            Base::Base16Upper => 'F',
+            Base::Base32 => 'b',
+            Base::Base32Upper => 'B',
+            #[cfg(feature = "base58")]
+            Base::Base58Btc => 'z',
+            Base::Base64 => 'm',
+            Base::Base64Url => 'u',
+        }
+    }
+
+    /// Returns the base for a prefix character, if it is supported.
+    pub fn from_code(code: char) -> Option<Base> {
+        match code {
+            'f' => Some(Base::Base16),
+            'F' => Some(Base::Base16Upper),
+            'b' => Some(Base::Base32),
+            'B' => Some(Base::Base32Upper),
+            #[cfg(feature = "base58")]
+            'z' => Some(Base::Base58Btc),
+            'm' => Some(Base::Base64),
+            'u' => Some(Base::Base64Url),
+            _ => None,
+        }
+    }
+
+    fn encode(self, data: &[u8]) -> String {
+        match self {
+            Base::Base16 => {
+                let mut s = String::new();
+                hex::to_hex_into(data, &mut s);
+                s
+            },
+            Base::Base16Upper => Base::Base16.encode(data).to_ascii_uppercase(),
+            Base::Base32 => base32::encode(data),
+            Base::Base32Upper => base32::encode(data).to_ascii_uppercase(),
+            #[cfg(feature = "base58")]
+            Base::Base58Btc => crate::base58::encode(data),
+            Base::Base64 => base64::encode(data, Config::STANDARD_NO_PAD),
+            Base::Base64Url => base64::encode(data, Config::URL_SAFE_NO_PAD),
+        }
+    }
+
+    fn decode(self, s: &str) -> Result<Vec<u8>, MultibaseError> {
+        let invalid = |reason: String| MultibaseError::InvalidData { base: self, reason };
+        // The codecs only decode lower case strictly, so check upper case input here and decode its lower case form
+        let lower_case = || {
+            ensure!(!s.bytes().any(|c| c.is_ascii_lowercase()), InvalidDataSnafu {
+                base: self,
+                reason: "lower case character in an upper case base"
+            });
+            Ok(s.to_ascii_lowercase())
+        };
+        match self {
+            Base::Base16 => hex::from_hex(s).map_err(|e| invalid(e.to_string())),
+            Base::Base16Upper => hex::from_hex(&lower_case()?).map_err(|e| invalid(e.to_string())),
+            Base::Base32 => base32::decode(s).map_err(|e| invalid(e.to_string())),
+            Base::Base32Upper => base32::decode(&lower_case()?).map_err(|e| invalid(e.to_string())),
+            #[cfg(feature = "base58")]
+            Base::Base58Btc => crate::base58::decode(s).map_err(|e| invalid(e.to_string())),
+            Base::Base64 => base64::decode(s, Config::STANDARD_NO_PAD).map_err(|e| invalid(e.to_string())),
+            Base::Base64Url => base64::decode(s, Config::URL_SAFE_NO_PAD).map_err(|e| invalid(e.to_string())),
+        }
+    }
+}
+
+/// Errors for multibase decoding.
+#[derive(Debug, Snafu, PartialEq, Eq)]
+pub enum MultibaseError {
+    /// The input is empty, so has no base prefix.
+    #[snafu(display("Multibase string is empty"))]
+    Empty {},
+    /// The base prefix is not supported.
+    #[snafu(display("Unsupported multibase prefix `{code}'"))]
+    UnknownBase {
+        /// The prefix character
+        code: char,
+    },
+    /// The payload is not valid in its base.
+    #[snafu(display("Invalid {base:?} payload: `{reason}'"))]
+    InvalidData {
+        /// The base given by the prefix
+        base: Base,
+        /// Why the payload is invalid
+        reason: String,
+    },
+    /// The multicodec prefix is not a valid varint.
+    #[snafu(display("Invalid multicodec prefix: `{reason}'"))]
+    InvalidCodec {
+        /// Why the codec is invalid
+        reason: String,
+    },
+}
+
+/// Encode `data` in the given base, with its prefix character.
+pub fn encode(base: Base, data: &[u8]) -> String {
+    let mut s = String::new();
+    s.push(base.code());
+    s.push_str(&base.encode(data));
+    s
+}
+
+/// Decode a multibase string, returning the base and the payload.
+pub fn decode(s: &str) -> Result<(Base, Vec<u8>), MultibaseError> {
+    let mut chars = s.chars();
+    let code = chars.next().context(EmptySnafu)?;
+    let base = Base::from_code(code).context(UnknownBaseSnafu { code })?;
+    Ok((base, base.decode(chars.as_str())?))
+}
+
+/// Encode `data` prefixed with the multicodec `codec` in the given base.
+pub fn encode_with_codec(base: Base, codec: u64, data: &[u8]) -> String {
+    let mut payload = Vec::with_capacity(varint::MAX_LEN_U64 + data.len());
+    varint::write_u64(codec, &mut payload);
+    payload.extend_from_slice(data);
+    encode(base, &payload)
+}
+
+/// Decode a multibase string whose payload starts with a multicodec prefix, returning the base, the codec and the data.
+pub fn decode_with_codec(s: &str) -> Result<(Base, u64, Vec<u8>), MultibaseError> {
+    let (base, mut payload) = decode(s)?;
+    let (codec, len) =
+        varint::decode_u64(&payload).map_err(|e| MultibaseError::InvalidCodec { reason: e.to_string() })?;
+    payload.drain(..len);
+    Ok((base, codec, payload))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn bases() {
+        let data = b"yes mani !";
+        let mut cases = vec![
+            (Base::Base16, "f796573206d616e692021"),
+            (Base::Base16Upper, "F796573206D616E692021"),
+            (Base::Base32, "bpfsxgidnmfxgsibb"),
+            (Base::Base32Upper, "BPFSXGIDNMFXGSIBB"),
+        ];
+        #[cfg(feature = "base58")]
+        cases.push((Base::Base58Btc, "z7paNL19xttacUY"));
+        cases.extend([(Base::Base64, "meWVzIG1hbmkgIQ"), (Base::Base64Url, "ueWVzIG1hbmkgIQ")]);
+        for (base, encoded) in cases {
+            assert_eq!(encode(base, data), encoded);
+            assert_eq!(decode(encoded).unwrap(), (base, data.to_vec()));
+            assert_eq!(Base::from_code(base.code()), Some(base));
+        }
+    }
+
+    #[test]
+    fn errors() {
+        assert_eq!(decode(""), Err(MultibaseError::Empty {}));
+        assert_eq!(decode("Q1234"), Err(MultibaseError::UnknownBase { code: 'Q' }));
+        for bad in ["f7g", "ff+f", "f7A", "F7a", "f 7f"] {
+            assert!(
+                matches!(decode(bad), Err(MultibaseError::InvalidData { .. })),
+                "{}",
+                bad
+            );
+        }
+        assert!(matches!(
+            decode("b1"),
+            Err(MultibaseError::InvalidData { base: Base::Base32, .. })
+        ));
+        assert!(matches!(
+            decode_with_codec("f80"),
+            Err(MultibaseError::InvalidCodec { .. })
+        ));
+    }
+
+    #[test]
+    fn codecs() {
+        let encoded = encode_with_codec(Base::Base16, codec::RISTRETTO255_PUB, &[0xab, 0xcd]);
+        assert_eq!(encoded, "f8624abcd");
+        assert_eq!(
+            decode_with_codec(&encoded).unwrap(),
+            (Base::Base16, codec::RISTRETTO255_PUB, vec![0xab, 0xcd])
+        );
+        assert_eq!(
+            decode_with_codec("f00").unwrap(),
+            (Base::Base16, codec::IDENTITY, vec![])
+        );
+    }
+}
@@ -0,0 +1,195 @@
+// Copyright 2026, The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+//! Diceware passphrase generation.
+//!
+//! Passphrases are built from words chosen uniformly at random from the
+//! [EFF large wordlist](https://www.eff.org/deeplinks/2016/07/new-wordlists-random-passphrases), which is bundled with
+//! this crate under the [CC BY 3.0 US](https://creativecommons.org/licenses/by/3.0/us/) license. Each word adds about
+//! 12.9 bits of entropy, so the default of 6 words gives about 77 bits.
+//!
+//! ```edition2018
+//! # use rand::rngs::OsRng;
+//! # use tari_utilities::password::diceware::Diceware;
+//! let generator = Diceware::new().with_words(8).with_separator(" ");
+//! let passphrase = generator.generate(&mut OsRng);
+//! assert_eq!(
+//!     passphrase.reveal().iter().filter(|&&b| b == b' ').count(),
+//!     7
+//! );
+//! assert_eq!(generator.entropy_bits(), 103);
+//! ```
+
+use alloc::{borrow::Cow, vec::Vec};
+use core::convert::TryFrom;
+
+use rand_core::{CryptoRng, RngCore};
+use zeroize::Zeroize;
+
+use crate::{hidden::Hidden, lazy::LazyInit, password::SafePassword};
+
+const WORDLIST_SOURCE: &str = include_str!("eff_large_wordlist.txt");
+
+/// The number of words in the bundled wordlist
+pub const WORDLIST_LEN: usize = 7776;
+
+/// log2(7776) in 16.16 fixed point
+const BITS_PER_WORD_FIXED: u64 = 847_011;
+
+static WORDLIST: LazyInit<Vec<&'static str>> = LazyInit::new(|| WORDLIST_SOURCE.lines().collect());
+
+/// Return the bundled wordlist
+pub fn wordlist() -> &'static [&'static str] {
+    &WORDLIST
+}
+
+/// A configurable diceware passphrase generator. See the [module documentation](self) for details.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Diceware {
+    words: usize,
+    separator: Cow<'static, str>,
+}
+
+impl Default for Diceware {
+    fn default() -> Self {
+        Self {
+            words: 6,
+            separator: Cow::Borrowed(" "),
+        }
+    }
+}
+
+impl Diceware {
+    /// Create a generator for 6-word passphrases separated by spaces
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the number of words in each passphrase
+    pub fn with_words(mut self, words: usize) -> Self {
+        self.words = words;
+        self
+    }
+
+    /// Set the separator placed between words. Avoid `-`, which appears inside a few words of the list (such as
+    /// `t-shirt`), so that the words of a passphrase can always be told apart.
+    pub fn with_separator<S: Into<Cow<'static, str>>>(mut self, separator: S) -> Self {
+        self.separator = separator.into();
+        self
+    }
+
+    /// The number of words in each passphrase
+    pub fn words(&self) -> usize {
+        self.words
+    }
+
+    /// The entropy of each generated passphrase, in whole bits
+    pub fn entropy_bits(&self) -> u32 {
+        let bits = (self.words as u64).saturating_mul(BITS_PER_WORD_FIXED) >> 16;
+        u32::try_from(bits).unwrap_or(u32::MAX)
+    }
+
+    /// Generate a new passphrase using the given cryptographically secure RNG
+    pub fn generate<R>(&self, rng: &mut R) -> SafePassword
+    where R: RngCore + CryptoRng {
+        let list = wordlist();
+        let mut indices: Vec<usize> = (0..self.words).map(|_| uniform_index(rng, list.len())).collect();
+
+        // Size the buffer exactly up front so that it is never reallocated
+        let len =
+            indices.iter().map(|&i| list[i].len()).sum::<usize>() + self.separator.len() * self.words.saturating_sub(1);
+        let mut passphrase = Vec::with_capacity(len);
+        for (n, &i) in indices.iter().enumerate() {
+            if n > 0 {
+                passphrase.extend_from_slice(self.separator.as_bytes());
+            }
+            passphrase.extend_from_slice(list[i].as_bytes());
+        }
+        indices.zeroize();
+
+        SafePassword {
+            passphrase: Hidden::hide(passphrase),
+        }
+    }
+}
+
+/// Choose an index in `0..n` uniformly at random, using rejection sampling to avoid modulo bias
+fn uniform_index<R: RngCore>(rng: &mut R, n: usize) -> usize {
+    let n = n as u32;
+    let zone = u32::MAX - (u32::MAX % n);
+    loop {
+        let x = rng.next_u32();
+        if x < zone {
+            return (x % n) as usize;
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use alloc::string::String;
+
+    use rand::{rngs::OsRng, SeedableRng};
+
+    use super::*;
+
+    #[test]
+    fn bundled_wordlist() {
+        let list = wordlist();
+        assert_eq!(list.len(), WORDLIST_LEN);
+        assert_eq!(list[0], "abacus");
+        assert_eq!(list[WORDLIST_LEN - 1], "zoom");
+        assert!(list.windows(2).all(|w| w[0] < w[1]));
+        // The default separator never appears inside a word, so passphrases split back into their words
+        assert!(list.iter().all(|w| !w.contains(' ')));
+    }
+
+    #[test]
+    fn generation() {
+        let generator = Diceware::new();
+        assert_eq!(generator.words(), 6);
+        assert_eq!(generator.entropy_bits(), 77);
+        let passphrase = generator.generate(&mut OsRng);
+        let s = String::from_utf8(passphrase.reveal().clone()).unwrap();
+        let words = s.split(' ').collect::<Vec<_>>();
+        assert_eq!(words.len(), 6);
+        assert!(words.iter().all(|w| wordlist().binary_search(w).is_ok()));
+        assert_eq!(passphrase.reveal().len(), passphrase.reveal().capacity());
+    }
+
+    #[test]
+    fn deterministic_with_seeded_rng() {
+        let generator = Diceware::new().with_words(4).with_separator(".");
+        let a = generator.generate(&mut rand::rngs::StdRng::seed_from_u64(42));
+        let b = generator.generate(&mut rand::rngs::StdRng::seed_from_u64(42));
+        let c = generator.generate(&mut rand::rngs::StdRng::seed_from_u64(43));
+        assert_eq!(a.reveal(), b.reveal());
+        assert_ne!(a.reveal(), c.reveal());
+    }
+
+    #[test]
+    fn empty() {
+        let passphrase = Diceware::new().with_words(0).generate(&mut OsRng);
+        assert!(passphrase.reveal().is_empty());
+        assert_eq!(Diceware::new().with_words(0).entropy_bits(), 0);
+    }
+}
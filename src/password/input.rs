@@ -0,0 +1,232 @@
+// Copyright 2026, The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+//! Reading passphrases from the environment and from an interactive terminal.
+//!
+//! Both sources write the passphrase straight into the buffer that ends up inside the [SafePassword], and any
+//! temporary buffers are zeroized, so no unprotected copies of the passphrase are left in memory.
+
+use alloc::{string::String, vec::Vec};
+use std::{env, ffi::OsString};
+
+use snafu::prelude::*;
+use zeroize::Zeroize;
+
+use crate::{
+    hidden::Hidden,
+    password::{push_zeroizing, SafePassword},
+};
+
+/// Errors for reading a [SafePassword] from the environment or a terminal.
+#[derive(Debug, Snafu, PartialEq, Eq)]
+pub enum PasswordInputError {
+    /// The environment variable is not set
+    #[snafu(display("Environment variable `{var}' is not set"))]
+    EnvVarNotPresent {
+        /// The name of the environment variable
+        var: String,
+    },
+    /// The environment variable is not valid unicode
+    #[snafu(display("Environment variable `{var}' is not valid unicode"))]
+    EnvVarNotUnicode {
+        /// The name of the environment variable
+        var: String,
+    },
+    /// Reading from the terminal failed
+    #[snafu(display("Could not read a passphrase from the terminal: `{reason}'"))]
+    TerminalError {
+        /// The reason for the error
+        reason: String,
+    },
+    /// Prompting for a passphrase is not supported on this platform
+    #[snafu(display("Prompting for a passphrase is not supported on this platform"))]
+    Unsupported {},
+}
+
+impl SafePassword {
+    /// Read the passphrase from the environment variable `var`.
+    ///
+    /// The variable's value is moved into the passphrase without being copied. The process environment itself still
+    /// holds the value; remove it with `std::env::remove_var` if it is no longer needed.
+    pub fn from_env(var: &str) -> Result<SafePassword, PasswordInputError> {
+        let value = env::var_os(var).ok_or_else(|| PasswordInputError::EnvVarNotPresent { var: var.into() })?;
+        match value.into_string() {
+            Ok(s) => Ok(SafePassword {
+                passphrase: Hidden::hide(s.into_bytes()),
+            }),
+            Err(value) => {
+                zeroize_os_string(value);
+                Err(PasswordInputError::EnvVarNotUnicode { var: var.into() })
+            },
+        }
+    }
+
+    /// Display `msg` on the controlling terminal and read a passphrase from it without echoing the input. The
+    /// trailing newline is not included in the passphrase.
+    ///
+    /// This is currently only supported on Unix platforms; other platforms return
+    /// [PasswordInputError::Unsupported].
+    pub fn prompt(msg: &str) -> Result<SafePassword, PasswordInputError> {
+        let bytes = tty::read_without_echo(msg)?;
+        Ok(SafePassword {
+            passphrase: Hidden::hide(bytes),
+        })
+    }
+}
+
+#[cfg(unix)]
+fn zeroize_os_string(value: OsString) {
+    use std::os::unix::ffi::OsStringExt;
+    value.into_vec().zeroize();
+}
+
+#[cfg(not(unix))]
+fn zeroize_os_string(value: OsString) {
+    // There is no way to access the buffer of an `OsString` on this platform, so the best we can do is drop it
+    drop(value);
+}
+
+/// Read bytes from `reader` up to (but not including) the first newline or the end of input.
+fn read_line_zeroizing<R: std::io::Read>(mut reader: R) -> std::io::Result<Vec<u8>> {
+    let mut buf = Vec::with_capacity(64);
+    let mut byte = [0u8; 1];
+    loop {
+        match reader.read(&mut byte) {
+            Ok(0) => break,
+            Ok(_) if byte[0] == b'\n' => break,
+            Ok(_) => push_zeroizing(&mut buf, byte[0]),
+            Err(e) if e.kind() == std::io::ErrorKind::Interrupted => continue,
+            Err(e) => {
+                buf.zeroize();
+                byte.zeroize();
+                return Err(e);
+            },
+        }
+    }
+    byte.zeroize();
+    if buf.last() == Some(&b'\r') {
+        buf.pop();
+        // `pop` only shortens the vector, so clear the removed byte
+        buf.spare_capacity_mut()[0].write(0);
+    }
+    Ok(buf)
+}
+
+#[cfg(unix)]
+mod tty {
+    use alloc::{string::ToString, vec::Vec};
+    use std::{
+        fs::OpenOptions,
+        io::Write,
+        mem::MaybeUninit,
+        os::unix::io::{AsRawFd, RawFd},
+    };
+
+    use super::{read_line_zeroizing, PasswordInputError};
+
+    /// Restores the original terminal settings when dropped, including on early return
+    struct RestoreTermios {
+        fd: RawFd,
+        original: libc::termios,
+    }
+
+    impl Drop for RestoreTermios {
+        fn drop(&mut self) {
+            // SAFETY: `fd` is a valid open file descriptor for the lifetime of this guard
+            unsafe {
+                libc::tcsetattr(self.fd, libc::TCSANOW, &self.original);
+            }
+        }
+    }
+
+    fn terminal_error<E: ToString>(e: E) -> PasswordInputError {
+        PasswordInputError::TerminalError { reason: e.to_string() }
+    }
+
+    pub(super) fn read_without_echo(msg: &str) -> Result<Vec<u8>, PasswordInputError> {
+        let mut tty = OpenOptions::new()
+            .read(true)
+            .write(true)
+            .open("/dev/tty")
+            .map_err(terminal_error)?;
+        let fd = tty.as_raw_fd();
+
+        let mut termios = MaybeUninit::<libc::termios>::uninit();
+        // SAFETY: `fd` is a valid open file descriptor and `termios` is a valid pointer to write the settings to
+        if unsafe { libc::tcgetattr(fd, termios.as_mut_ptr()) } != 0 {
+            return Err(terminal_error(std::io::Error::last_os_error()));
+        }
+        // SAFETY: `tcgetattr` succeeded, so `termios` has been initialized
+        let original = unsafe { termios.assume_init() };
+        let mut silent = original;
+        silent.c_lflag &= !libc::ECHO;
+        silent.c_lflag |= libc::ECHONL;
+
+        tty.write_all(msg.as_bytes()).map_err(terminal_error)?;
+        tty.flush().map_err(terminal_error)?;
+        // SAFETY: `fd` is a valid open file descriptor and `silent` is a valid termios value
+        if unsafe { libc::tcsetattr(fd, libc::TCSANOW, &silent) } != 0 {
+            return Err(terminal_error(std::io::Error::last_os_error()));
+        }
+        let _restore = RestoreTermios { fd, original };
+        read_line_zeroizing(&tty).map_err(terminal_error)
+    }
+}
+
+#[cfg(not(unix))]
+mod tty {
+    use alloc::vec::Vec;
+
+    use super::PasswordInputError;
+
+    pub(super) fn read_without_echo(_msg: &str) -> Result<Vec<u8>, PasswordInputError> {
+        Err(PasswordInputError::Unsupported {})
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn from_env() {
+        env::set_var("TARI_UTILITIES_TEST_PASSWORD", "correct horse");
+        let password = SafePassword::from_env("TARI_UTILITIES_TEST_PASSWORD").unwrap();
+        assert_eq!(password.reveal(), b"correct horse");
+        env::remove_var("TARI_UTILITIES_TEST_PASSWORD");
+
+        let err = SafePassword::from_env("TARI_UTILITIES_TEST_PASSWORD").unwrap_err();
+        assert_eq!(err, PasswordInputError::EnvVarNotPresent {
+            var: "TARI_UTILITIES_TEST_PASSWORD".into()
+        });
+    }
+
+    #[test]
+    fn read_line() {
+        let input = b"secret passphrase\nnext line".as_ref();
+        assert_eq!(read_line_zeroizing(input).unwrap(), b"secret passphrase");
+        let input = b"windows\r\n".as_ref();
+        assert_eq!(read_line_zeroizing(input).unwrap(), b"windows");
+        let input = b"no newline".as_ref();
+        assert_eq!(read_line_zeroizing(input).unwrap(), b"no newline");
+    }
+}
@@ -0,0 +1,196 @@
+// Copyright 2026, The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+//! Key stretching: turning a [SafePassword] into a cryptographic key.
+//!
+//! Any password-based key derivation function can be used by implementing the [Kdf] trait. With the `argon2` feature,
+//! [Argon2Kdf] provides Argon2id. The derived key is returned as a [SafeArray], which compares in constant time and can
+//! be zeroized, and is zeroized before being dropped if the derivation fails.
+//!
+//! ```edition2018
+//! # use tari_utilities::{
+//! #     password::kdf::{Kdf, KdfError},
+//! #     safe_array::SafeArray,
+//! #     SafePassword,
+//! # };
+//! // A toy KDF for the example; use a real password hashing function in practice
+//! struct XorKdf;
+//!
+//! impl Kdf for XorKdf {
+//!     fn derive_into(
+//!         &self,
+//!         password: &[u8],
+//!         salt: &[u8],
+//!         output: &mut [u8],
+//!     ) -> Result<(), KdfError> {
+//!         for (i, out) in output.iter_mut().enumerate() {
+//!             *out = password[i % password.len()] ^ salt[i % salt.len()];
+//!         }
+//!         Ok(())
+//!     }
+//! }
+//!
+//! let password = SafePassword::from("passphrase");
+//! let key: SafeArray<u8, 32> = password.derive_key(&XorKdf, b"salt").unwrap();
+//! assert_eq!(key, password.derive_key(&XorKdf, b"salt").unwrap());
+//! ```
+
+use alloc::string::String;
+
+use snafu::prelude::*;
+use zeroize::Zeroize;
+
+use crate::{password::SafePassword, safe_array::SafeArray};
+
+/// Errors for [Kdf] implementations
+#[derive(Debug, Snafu, Clone, PartialEq, Eq)]
+pub enum KdfError {
+    /// The KDF parameters, salt or output length are not valid for this KDF
+    #[snafu(display("Invalid key derivation parameters: `{reason}'"))]
+    InvalidParameters {
+        /// The reason for the error
+        reason: String,
+    },
+    /// The key derivation failed
+    #[snafu(display("Key derivation failed: `{reason}'"))]
+    DerivationFailed {
+        /// The reason for the error
+        reason: String,
+    },
+}
+
+/// A password-based key derivation function
+pub trait Kdf {
+    /// Derive key material from `password` and `salt`, filling `output`. Implementations must zeroize any intermediate
+    /// copies of the password or key material that they make.
+    fn derive_into(&self, password: &[u8], salt: &[u8], output: &mut [u8]) -> Result<(), KdfError>;
+}
+
+impl SafePassword {
+    /// Derive an `N`-byte key from the passphrase and `salt` using the given KDF.
+    pub fn derive_key<K, const N: usize>(&self, kdf: &K, salt: &[u8]) -> Result<SafeArray<u8, N>, KdfError>
+    where K: Kdf + ?Sized {
+        let mut key = SafeArray::<u8, N>::default();
+        match kdf.derive_into(self.reveal(), salt, &mut key) {
+            Ok(()) => Ok(key),
+            Err(e) => {
+                key.zeroize();
+                Err(e)
+            },
+        }
+    }
+}
+
+#[cfg(feature = "argon2")]
+pub use self::argon2_kdf::Argon2Kdf;
+
+#[cfg(feature = "argon2")]
+mod argon2_kdf {
+    use alloc::string::ToString;
+
+    use argon2::{Algorithm, Argon2, Params, Version};
+
+    use super::{Kdf, KdfError};
+
+    /// Argon2id key derivation
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub struct Argon2Kdf {
+        params: Params,
+    }
+
+    impl Argon2Kdf {
+        /// Create an Argon2id KDF with the given memory cost (in KiB), number of iterations and degree of parallelism
+        pub fn new(memory_kib: u32, iterations: u32, parallelism: u32) -> Result<Self, KdfError> {
+            let params = Params::new(memory_kib, iterations, parallelism, None)
+                .map_err(|e| KdfError::InvalidParameters { reason: e.to_string() })?;
+            Ok(Self { params })
+        }
+    }
+
+    impl Default for Argon2Kdf {
+        /// Argon2id with the parameters recommended by OWASP: 19 MiB of memory, 2 iterations and 1 degree of
+        /// parallelism
+        fn default() -> Self {
+            Self {
+                params: Params::default(),
+            }
+        }
+    }
+
+    impl Kdf for Argon2Kdf {
+        fn derive_into(&self, password: &[u8], salt: &[u8], output: &mut [u8]) -> Result<(), KdfError> {
+            Argon2::new(Algorithm::Argon2id, Version::V0x13, self.params.clone())
+                .hash_password_into(password, salt, output)
+                .map_err(|e| match e {
+                    argon2::Error::SaltTooShort |
+                    argon2::Error::SaltTooLong |
+                    argon2::Error::OutputTooShort |
+                    argon2::Error::OutputTooLong => KdfError::InvalidParameters { reason: e.to_string() },
+                    _ => KdfError::DerivationFailed { reason: e.to_string() },
+                })
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    struct FailingKdf;
+
+    impl Kdf for FailingKdf {
+        fn derive_into(&self, _password: &[u8], _salt: &[u8], output: &mut [u8]) -> Result<(), KdfError> {
+            output[0] = 1;
+            Err(KdfError::DerivationFailed {
+                reason: "failed".into(),
+            })
+        }
+    }
+
+    #[test]
+    fn failing_kdf() {
+        let password = SafePassword::from("password");
+        let err = password.derive_key::<_, 32>(&FailingKdf, b"salt").unwrap_err();
+        assert_eq!(err, KdfError::DerivationFailed {
+            reason: "failed".into()
+        });
+    }
+
+    #[test]
+    #[cfg(feature = "argon2")]
+    fn argon2() {
+        let kdf = Argon2Kdf::new(64, 1, 1).unwrap();
+        let password = SafePassword::from("password");
+        let key: SafeArray<u8, 32> = password.derive_key(&kdf, b"somesalt").unwrap();
+        assert_eq!(key, password.derive_key(&kdf, b"somesalt").unwrap());
+        assert_ne!(key, password.derive_key(&kdf, b"othersalt").unwrap());
+        assert_ne!(
+            key,
+            SafePassword::from("passwore").derive_key(&kdf, b"somesalt").unwrap()
+        );
+
+        let err = password.derive_key::<_, 32>(&kdf, b"salt").unwrap_err();
+        assert!(matches!(err, KdfError::InvalidParameters { .. }));
+        assert!(Argon2Kdf::new(0, 0, 0).is_err());
+        assert_eq!(Argon2Kdf::default(), Argon2Kdf::new(19 * 1024, 2, 1).unwrap());
+    }
+}
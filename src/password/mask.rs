@@ -0,0 +1,141 @@
+// Copyright 2026, The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+//! Masked display of passphrases for confirmation screens and logs.
+//!
+//! ```edition2018
+//! # use tari_utilities::{password::mask::MaskStyle, SafePassword};
+//! let password = SafePassword::from("password");
+//! assert_eq!(password.masked().to_string(), "••••••••");
+//! assert_eq!(
+//!     password.masked_with(MaskStyle::FirstAndLast).to_string(),
+//!     "p••••••d"
+//! );
+//! ```
+
+use core::fmt;
+
+use crate::password::SafePassword;
+
+/// The character used to mask each hidden character of the passphrase
+pub const MASK_CHAR: char = '•';
+
+/// The minimum number of characters a passphrase must have before [MaskStyle::FirstAndLast] reveals any of them
+pub const MIN_LENGTH_TO_REVEAL: usize = 6;
+
+/// How much of the passphrase a [MaskedPassword] reveals
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum MaskStyle {
+    /// Mask every character, revealing only the length
+    #[default]
+    Full,
+    /// Reveal the first and last characters and mask the rest. Passphrases shorter than [MIN_LENGTH_TO_REVEAL]
+    /// characters are masked in full.
+    FirstAndLast,
+}
+
+/// A `Display` implementation that shows a masked version of a passphrase. Created by [SafePassword::masked].
+pub struct MaskedPassword<'a> {
+    password: &'a SafePassword,
+    style: MaskStyle,
+}
+
+impl SafePassword {
+    /// Return a displayable mask of the passphrase that shows only its length, e.g. `••••••••`
+    pub fn masked(&self) -> MaskedPassword<'_> {
+        self.masked_with(MaskStyle::Full)
+    }
+
+    /// Return a displayable mask of the passphrase using the given style
+    pub fn masked_with(&self, style: MaskStyle) -> MaskedPassword<'_> {
+        MaskedPassword { password: self, style }
+    }
+}
+
+impl fmt::Display for MaskedPassword<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        use fmt::Write;
+
+        // Passphrases that are not valid UTF-8 are masked byte by byte and never revealed
+        let s = core::str::from_utf8(self.password.reveal()).ok();
+        let len = s
+            .map(|s| s.chars().count())
+            .unwrap_or_else(|| self.password.reveal().len());
+        match (self.style, s) {
+            (MaskStyle::FirstAndLast, Some(s)) if len >= MIN_LENGTH_TO_REVEAL => {
+                let mut chars = s.chars();
+                let first = chars.next().unwrap_or(MASK_CHAR);
+                let last = chars.next_back().unwrap_or(MASK_CHAR);
+                f.write_char(first)?;
+                for _ in 0..len - 2 {
+                    f.write_char(MASK_CHAR)?;
+                }
+                f.write_char(last)
+            },
+            _ => {
+                for _ in 0..len {
+                    f.write_char(MASK_CHAR)?;
+                }
+                Ok(())
+            },
+        }
+    }
+}
+
+impl fmt::Debug for MaskedPassword<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use alloc::string::ToString;
+
+    use super::*;
+
+    #[test]
+    fn full() {
+        assert_eq!(SafePassword::from("").masked().to_string(), "");
+        assert_eq!(SafePassword::from("abc").masked().to_string(), "•••");
+        assert_eq!(SafePassword::from("pässwörd").masked().to_string(), "••••••••");
+        assert_eq!(format!("{:?}", SafePassword::from("ab").masked()), "••");
+    }
+
+    #[test]
+    fn first_and_last() {
+        let style = MaskStyle::FirstAndLast;
+        assert_eq!(
+            SafePassword::from("pässwörd").masked_with(style).to_string(),
+            "p••••••d"
+        );
+        assert_eq!(SafePassword::from("ünicode").masked_with(style).to_string(), "ü•••••e");
+        assert_eq!(SafePassword::from("short").masked_with(style).to_string(), "•••••");
+    }
+
+    #[test]
+    fn invalid_utf8() {
+        let mut password = SafePassword::from("password");
+        password.reveal_mut()[0] = 0xff;
+        assert_eq!(password.masked_with(MaskStyle::FirstAndLast).to_string(), "••••••••");
+    }
+}
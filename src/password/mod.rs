@@ -24,17 +24,33 @@
 use alloc::{string::String, vec::Vec};
 use core::str::FromStr;
 
-#[cfg(feature = "serde")]
-use serde::{ser::SerializeSeq, Serialize, Serializer};
+#[cfg(feature = "subtle")]
+use subtle::{Choice, ConstantTimeEq};
 
 use crate::hidden::Hidden;
 
+#[cfg(feature = "diceware")]
+pub mod diceware;
+#[cfg(feature = "std")]
+pub mod input;
+#[cfg(feature = "std")]
+pub mod kdf;
+pub mod mask;
+#[cfg(feature = "nfkc")]
+pub mod normalize;
+pub mod policy;
+#[cfg(feature = "serde")]
+pub mod serializable;
+#[cfg(feature = "password-strength")]
+pub mod strength;
+
 /// A representation of a passphrase that zeroizes on drop, prevents display and debug output, and limits access to
 /// references
 ///
 /// The passphrase can be instantiated from a string or any type that can become a string.
 /// It is converted to a byte array, which can be accessed as a mutable or immutable reference.
-/// You can serialize and deserialize it transparently.
+/// It cannot be serialized or deserialized directly; wrap it in a
+/// [SerializablePassword](serializable::SerializablePassword) if it really needs to be.
 ///
 /// ```edition2018
 /// # use tari_utilities::SafePassword;
@@ -49,8 +65,7 @@ use crate::hidden::Hidden;
 /// );
 /// ```
 #[derive(Clone, Debug)]
-#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
-#[cfg_attr(feature = "serde", serde(transparent))]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Object))]
 pub struct SafePassword {
     passphrase: Hidden<Vec<u8>>,
 }
@@ -65,6 +80,28 @@ impl SafePassword {
     pub fn reveal_mut(&mut self) -> &mut Vec<u8> {
         self.passphrase.reveal_mut()
     }
+
+    /// Compare this passphrase to a candidate in constant time. The comparison takes the same time for any candidate of
+    /// the same length, although the lengths themselves are not hidden.
+    #[cfg(feature = "subtle")]
+    pub fn verify_ct(&self, candidate: &SafePassword) -> Choice {
+        self.reveal().as_slice().ct_eq(candidate.reveal().as_slice())
+    }
+}
+
+/// Compare key material derived from a passphrase (e.g. a password hash or a decryption key) to the expected bytes in
+/// constant time. Use this rather than `==` on revealed bytes, which returns as soon as a byte differs. The lengths
+/// are not hidden.
+#[cfg(feature = "subtle")]
+pub fn verify_key_ct(derived: &[u8], expected: &[u8]) -> Choice {
+    derived.ct_eq(expected)
+}
+
+#[cfg(feature = "subtle")]
+impl ConstantTimeEq for SafePassword {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.verify_ct(other)
+    }
 }
 
 impl FromStr for SafePassword {
@@ -85,16 +122,19 @@ impl<S: Into<String>> From<S> for SafePassword {
     }
 }
 
-#[cfg(feature = "serde")]
-impl Serialize for SafePassword {
-    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
-    where S: Serializer {
-        let mut seq = serializer.serialize_seq(Some(self.passphrase.reveal().len()))?;
-        for e in self.passphrase.reveal() {
-            seq.serialize_element(e)?;
-        }
-        seq.end()
+/// Append `byte` to `buf`, moving the contents into a larger buffer and zeroizing the old one when `buf` is full, so
+/// that reallocation does not leave copies of the contents behind.
+#[cfg(any(feature = "std", feature = "serde"))]
+pub(crate) fn push_zeroizing(buf: &mut Vec<u8>, byte: u8) {
+    use zeroize::Zeroize;
+
+    if buf.len() == buf.capacity() {
+        let mut larger = Vec::with_capacity((buf.capacity() * 2).max(64));
+        larger.extend_from_slice(buf);
+        buf.zeroize();
+        *buf = larger;
     }
+    buf.push(byte);
 }
 
 #[cfg(test)]
@@ -102,7 +142,7 @@ mod tests {
     use alloc::string::ToString;
     use std::str::FromStr;
 
-    use super::SafePassword;
+    use super::*;
 
     #[test]
     fn from_strings() {
@@ -117,12 +157,28 @@ mod tests {
     }
 
     #[test]
-    fn serialization() {
-        let safe_password = SafePassword::from("password");
-        let ser = serde_json::to_string(&safe_password).unwrap();
-        let deser: SafePassword = serde_json::from_str(&ser).unwrap();
+    #[cfg(any(feature = "std", feature = "serde"))]
+    fn zeroizing_growth() {
+        let mut buf = Vec::with_capacity(2);
+        for b in 0..200u8 {
+            push_zeroizing(&mut buf, b);
+        }
+        assert_eq!(buf, (0..200u8).collect::<Vec<_>>());
+    }
 
-        assert_eq!(safe_password.reveal(), deser.reveal());
+    #[test]
+    #[cfg(feature = "subtle")]
+    fn verify_ct() {
+        use super::verify_key_ct;
+
+        let password = SafePassword::from("password");
+        assert!(bool::from(password.verify_ct(&SafePassword::from("password"))));
+        assert!(!bool::from(password.verify_ct(&SafePassword::from("passwork"))));
+        assert!(!bool::from(password.verify_ct(&SafePassword::from("password1"))));
+
+        assert!(bool::from(verify_key_ct(&[1, 2, 3], &[1, 2, 3])));
+        assert!(!bool::from(verify_key_ct(&[1, 2, 3], &[1, 2, 4])));
+        assert!(!bool::from(verify_key_ct(&[1, 2, 3], &[1, 2])));
     }
 
     #[test]
@@ -131,7 +187,7 @@ mod tests {
         use alloc::string::String;
 
         use clap::Parser;
-        use serde::{Deserialize, Serialize};
+        use serde::Deserialize;
 
         fn deserialize_safe_password_option<'de, D>(deserializer: D) -> Result<Option<SafePassword>, D::Error>
         where D: serde::Deserializer<'de> {
@@ -140,7 +196,7 @@ mod tests {
             Ok(password.map(SafePassword::from))
         }
 
-        #[derive(Parser, Serialize, Deserialize, Debug)]
+        #[derive(Parser, Deserialize, Debug)]
         #[clap(author, version, about, long_about = None)]
         #[clap(propagate_version = true)]
         struct Cli {
@@ -0,0 +1,115 @@
+// Copyright 2026, The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+//! Unicode normalization of passphrases.
+//!
+//! The same passphrase can be encoded as different byte sequences depending on the keyboard, input method or platform
+//! it was typed on (e.g. a precomposed `é` versus `e` followed by a combining accent, or a full-width `Ａ` versus `A`).
+//! Normalizing to [NFKC](https://unicode.org/reports/tr15/) before deriving keys makes these equivalent.
+//!
+//! ```edition2018
+//! # use tari_utilities::SafePassword;
+//! let composed = SafePassword::from("caf\u{e9}");
+//! let decomposed = SafePassword::from("cafe\u{301}");
+//! assert_ne!(composed.reveal(), decomposed.reveal());
+//! assert_eq!(
+//!     composed.normalized().reveal(),
+//!     decomposed.normalized().reveal()
+//! );
+//! ```
+
+use alloc::vec::Vec;
+
+use unicode_normalization::{is_nfkc_quick, IsNormalized, UnicodeNormalization};
+use zeroize::Zeroize;
+
+use crate::{hidden::Hidden, password::SafePassword};
+
+impl SafePassword {
+    /// Return a copy of the passphrase normalized to Unicode NFKC form. A passphrase that is not valid UTF-8 is
+    /// returned unchanged.
+    ///
+    /// The normalized bytes are written directly into an exactly-sized buffer, so no partial copies of the passphrase
+    /// are left behind by reallocation.
+    pub fn normalized(&self) -> SafePassword {
+        let s = match core::str::from_utf8(self.reveal()) {
+            Ok(s) => s,
+            Err(_) => return self.clone(),
+        };
+        let len = s.nfkc().map(char::len_utf8).sum();
+        let mut bytes = Vec::with_capacity(len);
+        let mut buf = [0u8; 4];
+        for c in s.nfkc() {
+            bytes.extend_from_slice(c.encode_utf8(&mut buf).as_bytes());
+        }
+        buf.zeroize();
+        SafePassword {
+            passphrase: Hidden::hide(bytes),
+        }
+    }
+
+    /// Returns true if the passphrase is valid UTF-8 and already in Unicode NFKC form
+    pub fn is_normalized(&self) -> bool {
+        match core::str::from_utf8(self.reveal()) {
+            Ok(s) => match is_nfkc_quick(s.chars()) {
+                IsNormalized::Yes => true,
+                IsNormalized::No => false,
+                IsNormalized::Maybe => s.chars().eq(s.nfkc()),
+            },
+            Err(_) => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn compatibility_forms() {
+        let full_width = SafePassword::from("\u{ff30}\u{ff41}\u{ff53}\u{ff53}");
+        let ascii = SafePassword::from("Pass");
+        assert!(!full_width.is_normalized());
+        assert!(ascii.is_normalized());
+        assert_eq!(full_width.normalized().reveal(), ascii.reveal());
+
+        let ligature = SafePassword::from("\u{fb01}ne");
+        assert_eq!(ligature.normalized().reveal(), b"fine");
+    }
+
+    #[test]
+    fn composition() {
+        let decomposed = SafePassword::from("cafe\u{301}");
+        let normalized = decomposed.normalized();
+        assert_eq!(normalized.reveal(), "caf\u{e9}".as_bytes());
+        assert_eq!(normalized.reveal().capacity(), normalized.reveal().len());
+        assert!(normalized.is_normalized());
+    }
+
+    #[test]
+    fn invalid_utf8() {
+        let mut password = SafePassword::from("password");
+        password.reveal_mut()[0] = 0xff;
+        assert!(!password.is_normalized());
+        assert_eq!(password.normalized().reveal(), password.reveal());
+    }
+}
@@ -0,0 +1,265 @@
+// Copyright 2026, The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+//! Configurable passphrase policies.
+//!
+//! A [PasswordPolicy] checks a [SafePassword] in place, by reference, so that applications do not need to reveal the
+//! passphrase as a `&str` (or copy it) to enforce their rules.
+//!
+//! ```edition2018
+//! # use tari_utilities::{password::policy::{CharacterClass, PasswordPolicy, PolicyViolation}, SafePassword};
+//! let policy = PasswordPolicy::new()
+//!     .with_min_length(10)
+//!     .with_required_class(CharacterClass::Digit)
+//!     .with_denied("password123456");
+//!
+//! assert!(policy.validate(&SafePassword::from("hunter2 is 1337")).is_ok());
+//! assert_eq!(
+//!     policy.validate(&SafePassword::from("hunter2")),
+//!     Err(PolicyViolation::TooShort { min: 10 })
+//! );
+//! assert_eq!(
+//!     policy.validate(&SafePassword::from("PASSWORD123456")),
+//!     Err(PolicyViolation::DenyListed {})
+//! );
+//! ```
+
+use alloc::{borrow::Cow, vec::Vec};
+use core::fmt;
+
+use snafu::prelude::*;
+
+use crate::password::SafePassword;
+
+/// A class of characters that a policy can require
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CharacterClass {
+    /// ASCII lowercase letters
+    Lowercase,
+    /// ASCII uppercase letters
+    Uppercase,
+    /// ASCII digits
+    Digit,
+    /// Any character that is not an ASCII letter or digit, including whitespace and non-ASCII characters
+    Symbol,
+}
+
+impl CharacterClass {
+    fn matches(self, c: char) -> bool {
+        match self {
+            CharacterClass::Lowercase => c.is_ascii_lowercase(),
+            CharacterClass::Uppercase => c.is_ascii_uppercase(),
+            CharacterClass::Digit => c.is_ascii_digit(),
+            CharacterClass::Symbol => !c.is_ascii_alphanumeric(),
+        }
+    }
+}
+
+impl fmt::Display for CharacterClass {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            CharacterClass::Lowercase => "lowercase letter",
+            CharacterClass::Uppercase => "uppercase letter",
+            CharacterClass::Digit => "digit",
+            CharacterClass::Symbol => "symbol",
+        };
+        f.write_str(name)
+    }
+}
+
+/// The ways in which a passphrase can fail a [PasswordPolicy]
+#[derive(Debug, Snafu, Clone, PartialEq, Eq)]
+pub enum PolicyViolation {
+    /// The passphrase has fewer characters than the minimum
+    #[snafu(display("The passphrase must be at least {min} characters long"))]
+    TooShort {
+        /// The minimum number of characters
+        min: usize,
+    },
+    /// The passphrase has more characters than the maximum
+    #[snafu(display("The passphrase must be at most {max} characters long"))]
+    TooLong {
+        /// The maximum number of characters
+        max: usize,
+    },
+    /// The passphrase does not contain a character from a required class
+    #[snafu(display("The passphrase must contain at least one {class}"))]
+    MissingCharacterClass {
+        /// The class that is missing
+        class: CharacterClass,
+    },
+    /// The passphrase is on the deny-list
+    #[snafu(display("The passphrase is not allowed"))]
+    DenyListed {},
+    /// The passphrase is not valid UTF-8
+    #[snafu(display("The passphrase is not valid UTF-8"))]
+    InvalidUtf8 {},
+}
+
+/// A set of rules that a passphrase must satisfy. See the [module documentation](self) for an example.
+///
+/// The default policy accepts any valid UTF-8 passphrase.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PasswordPolicy {
+    min_length: usize,
+    max_length: Option<usize>,
+    required_classes: Vec<CharacterClass>,
+    deny_list: Vec<Cow<'static, str>>,
+}
+
+impl PasswordPolicy {
+    /// Create a policy that accepts any valid UTF-8 passphrase
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Require at least `min` characters
+    pub fn with_min_length(mut self, min: usize) -> Self {
+        self.min_length = min;
+        self
+    }
+
+    /// Allow at most `max` characters
+    pub fn with_max_length(mut self, max: usize) -> Self {
+        self.max_length = Some(max);
+        self
+    }
+
+    /// Require at least one character from `class`
+    pub fn with_required_class(mut self, class: CharacterClass) -> Self {
+        if !self.required_classes.contains(&class) {
+            self.required_classes.push(class);
+        }
+        self
+    }
+
+    /// Reject a specific passphrase. The deny-list is matched ignoring ASCII case.
+    pub fn with_denied<S: Into<Cow<'static, str>>>(mut self, passphrase: S) -> Self {
+        self.deny_list.push(passphrase.into());
+        self
+    }
+
+    /// Reject each of the given passphrases. See [with_denied](Self::with_denied).
+    pub fn with_deny_list<I, S>(mut self, passphrases: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<Cow<'static, str>>,
+    {
+        self.deny_list.extend(passphrases.into_iter().map(Into::into));
+        self
+    }
+
+    /// Check the passphrase against the policy, returning the first rule it violates. Length limits are checked first,
+    /// then required character classes, then the deny-list.
+    pub fn validate(&self, password: &SafePassword) -> Result<(), PolicyViolation> {
+        let s = core::str::from_utf8(password.reveal()).map_err(|_| PolicyViolation::InvalidUtf8 {})?;
+        let length = s.chars().count();
+        if length < self.min_length {
+            return Err(PolicyViolation::TooShort { min: self.min_length });
+        }
+        if let Some(max) = self.max_length {
+            if length > max {
+                return Err(PolicyViolation::TooLong { max });
+            }
+        }
+        if let Some(&class) = self
+            .required_classes
+            .iter()
+            .find(|class| !s.chars().any(|c| class.matches(c)))
+        {
+            return Err(PolicyViolation::MissingCharacterClass { class });
+        }
+        if self.deny_list.iter().any(|denied| denied.eq_ignore_ascii_case(s)) {
+            return Err(PolicyViolation::DenyListed {});
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use alloc::string::ToString;
+
+    use super::*;
+
+    #[test]
+    fn default_policy() {
+        let policy = PasswordPolicy::new();
+        assert!(policy.validate(&SafePassword::from("")).is_ok());
+        let mut invalid = SafePassword::from("a");
+        invalid.reveal_mut()[0] = 0xff;
+        assert_eq!(policy.validate(&invalid), Err(PolicyViolation::InvalidUtf8 {}));
+    }
+
+    #[test]
+    fn lengths_count_characters() {
+        let policy = PasswordPolicy::new().with_min_length(4).with_max_length(6);
+        assert_eq!(
+            policy.validate(&SafePassword::from("abc")),
+            Err(PolicyViolation::TooShort { min: 4 })
+        );
+        assert!(policy.validate(&SafePassword::from("ünïc")).is_ok());
+        assert!(policy.validate(&SafePassword::from("abcdef")).is_ok());
+        assert_eq!(
+            policy.validate(&SafePassword::from("abcdefg")),
+            Err(PolicyViolation::TooLong { max: 6 })
+        );
+    }
+
+    #[test]
+    fn character_classes() {
+        let policy = PasswordPolicy::new()
+            .with_required_class(CharacterClass::Lowercase)
+            .with_required_class(CharacterClass::Uppercase)
+            .with_required_class(CharacterClass::Digit)
+            .with_required_class(CharacterClass::Symbol);
+        assert!(policy.validate(&SafePassword::from("aB3 ")).is_ok());
+        assert!(policy.validate(&SafePassword::from("aB3€")).is_ok());
+        let err = policy.validate(&SafePassword::from("aB3")).unwrap_err();
+        assert_eq!(err, PolicyViolation::MissingCharacterClass {
+            class: CharacterClass::Symbol
+        });
+        assert_eq!(err.to_string(), "The passphrase must contain at least one symbol");
+        assert_eq!(
+            policy.validate(&SafePassword::from("ab3!")),
+            Err(PolicyViolation::MissingCharacterClass {
+                class: CharacterClass::Uppercase
+            })
+        );
+    }
+
+    #[test]
+    fn deny_list() {
+        let policy = PasswordPolicy::new()
+            .with_deny_list(["letmein", "changeme"])
+            .with_denied("Hunter2".to_string());
+        assert_eq!(
+            policy.validate(&SafePassword::from("LetMeIn")),
+            Err(PolicyViolation::DenyListed {})
+        );
+        assert_eq!(
+            policy.validate(&SafePassword::from("hunter2")),
+            Err(PolicyViolation::DenyListed {})
+        );
+        assert!(policy.validate(&SafePassword::from("let me in")).is_ok());
+    }
+}
@@ -0,0 +1,214 @@
+// Copyright 2026, The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+//! Explicit, opt-in serialization for passphrases.
+//!
+//! [SafePassword] deliberately does not implement `Serialize` or `Deserialize`, so that a passphrase cannot end up in a
+//! serialized struct by accident. When a passphrase really does need to be stored, e.g. in a configuration file for an
+//! unattended deployment, wrap it in a [SerializablePassword] to make that choice visible in the type.
+//!
+//! The passphrase is serialized as a sequence of bytes, and can be deserialized from either a sequence of bytes or a
+//! string.
+//!
+//! ```edition2018
+//! # use serde::{Deserialize, Serialize};
+//! # use tari_utilities::password::serializable::SerializablePassword;
+//! #[derive(Serialize, Deserialize)]
+//! struct Config {
+//!     wallet_password: Option<SerializablePassword>,
+//! }
+//!
+//! let config: Config = serde_json::from_str(r#"{"wallet_password": "hunter2"}"#).unwrap();
+//! let password = config.wallet_password.unwrap().into_inner();
+//! assert_eq!(password.reveal(), b"hunter2");
+//! ```
+
+use alloc::{string::String, vec::Vec};
+use core::{fmt, str::FromStr};
+
+use serde::{
+    de::{Error, SeqAccess, Visitor},
+    ser::SerializeSeq,
+    Deserialize,
+    Deserializer,
+    Serialize,
+    Serializer,
+};
+use zeroize::Zeroize;
+
+use crate::{
+    hidden::Hidden,
+    password::{push_zeroizing, SafePassword},
+};
+
+/// A [SafePassword] that can be serialized and deserialized. See the [module documentation](self) for details.
+#[derive(Clone, Debug)]
+pub struct SerializablePassword(SafePassword);
+
+impl SerializablePassword {
+    /// Get a reference to the wrapped passphrase
+    pub fn password(&self) -> &SafePassword {
+        &self.0
+    }
+
+    /// Unwrap the passphrase, dropping the ability to serialize it
+    pub fn into_inner(self) -> SafePassword {
+        self.0
+    }
+}
+
+impl From<SafePassword> for SerializablePassword {
+    fn from(password: SafePassword) -> Self {
+        Self(password)
+    }
+}
+
+impl From<SerializablePassword> for SafePassword {
+    fn from(password: SerializablePassword) -> Self {
+        password.0
+    }
+}
+
+impl AsRef<SafePassword> for SerializablePassword {
+    fn as_ref(&self) -> &SafePassword {
+        &self.0
+    }
+}
+
+impl FromStr for SerializablePassword {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        SafePassword::from_str(s).map(Self)
+    }
+}
+
+impl Serialize for SerializablePassword {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where S: Serializer {
+        let bytes = self.0.reveal();
+        let mut seq = serializer.serialize_seq(Some(bytes.len()))?;
+        for e in bytes {
+            seq.serialize_element(e)?;
+        }
+        seq.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for SerializablePassword {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where D: Deserializer<'de> {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_any(PasswordVisitor)
+        } else {
+            deserializer.deserialize_seq(PasswordVisitor)
+        }
+    }
+}
+
+struct PasswordVisitor;
+
+impl PasswordVisitor {
+    fn hide(bytes: Vec<u8>) -> SerializablePassword {
+        SerializablePassword(SafePassword {
+            passphrase: Hidden::hide(bytes),
+        })
+    }
+}
+
+impl<'de> Visitor<'de> for PasswordVisitor {
+    type Value = SerializablePassword;
+
+    fn expecting(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.write_str("a passphrase as a string or a sequence of bytes")
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+    where E: Error {
+        Ok(Self::hide(v.as_bytes().to_vec()))
+    }
+
+    fn visit_string<E>(self, v: String) -> Result<Self::Value, E>
+    where E: Error {
+        Ok(Self::hide(v.into_bytes()))
+    }
+
+    fn visit_bytes<E>(self, v: &[u8]) -> Result<Self::Value, E>
+    where E: Error {
+        Ok(Self::hide(v.to_vec()))
+    }
+
+    fn visit_byte_buf<E>(self, v: Vec<u8>) -> Result<Self::Value, E>
+    where E: Error {
+        Ok(Self::hide(v))
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where A: SeqAccess<'de> {
+        let mut bytes = Vec::with_capacity(seq.size_hint().unwrap_or(0).min(1024));
+        loop {
+            match seq.next_element::<u8>() {
+                Ok(Some(b)) => push_zeroizing(&mut bytes, b),
+                Ok(None) => return Ok(Self::hide(bytes)),
+                Err(e) => {
+                    bytes.zeroize();
+                    return Err(e);
+                },
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use serde::{Deserialize, Serialize};
+
+    use super::*;
+
+    #[derive(Serialize, Deserialize)]
+    struct Config {
+        password: SerializablePassword,
+    }
+
+    #[test]
+    fn round_trip() {
+        let config = Config {
+            password: SafePassword::from("password").into(),
+        };
+        let json = serde_json::to_string(&config).unwrap();
+        assert_eq!(json, r#"{"password":[112,97,115,115,119,111,114,100]}"#);
+        let restored: Config = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored.password.password().reveal(), b"password");
+
+        let binary = bincode::serialize(&config).unwrap();
+        let restored: Config = bincode::deserialize(&binary).unwrap();
+        assert_eq!(SafePassword::from(restored.password).reveal(), b"password");
+    }
+
+    #[test]
+    fn from_string() {
+        let restored: Config = serde_json::from_str(r#"{"password":"pass word"}"#).unwrap();
+        assert_eq!(restored.password.as_ref().reveal(), b"pass word");
+        assert!(serde_json::from_str::<Config>(r#"{"password":[256]}"#).is_err());
+        assert!(serde_json::from_str::<Config>(r#"{"password":7}"#).is_err());
+    }
+}
@@ -0,0 +1,289 @@
+// Copyright 2026, The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+//! A lightweight password strength estimate.
+//!
+//! The estimate is deliberately simple: it multiplies the length of the passphrase by the size of the character pool
+//! it draws from, gives little credit to repeated and sequential characters, and treats passphrases from a small list
+//! of very common passwords as having almost no entropy. It is intended for warning users about obviously weak
+//! passphrases, not as a guarantee of strength.
+
+use core::convert::TryFrom;
+
+use crate::password::SafePassword;
+
+/// Passphrases with fewer bits of estimated entropy than this are considered weak
+pub const FAIR_THRESHOLD_BITS: u32 = 40;
+/// Passphrases with at least this many bits of estimated entropy are considered strong
+pub const STRONG_THRESHOLD_BITS: u32 = 64;
+
+/// A small list of very common passwords, compared case-insensitively
+const COMMON_PASSWORDS: &[&str] = &[
+    "123456",
+    "123456789",
+    "12345678",
+    "1234567890",
+    "12345",
+    "1234567",
+    "111111",
+    "000000",
+    "123123",
+    "654321",
+    "666666",
+    "121212",
+    "password",
+    "password1",
+    "password123",
+    "passw0rd",
+    "qwerty",
+    "qwerty123",
+    "qwertyuiop",
+    "asdfgh",
+    "asdfghjkl",
+    "zxcvbnm",
+    "1q2w3e4r",
+    "1qaz2wsx",
+    "abc123",
+    "iloveyou",
+    "admin",
+    "welcome",
+    "letmein",
+    "monkey",
+    "dragon",
+    "football",
+    "baseball",
+    "sunshine",
+    "princess",
+    "master",
+    "shadow",
+    "superman",
+    "trustno1",
+    "starwars",
+    "whatever",
+    "login",
+    "secret",
+    "changeme",
+    "hello123",
+    "wallet",
+    "bitcoin",
+    "crypto",
+    "satoshi",
+    "tari",
+];
+
+/// A coarse rating of passphrase strength
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum StrengthScore {
+    /// Fewer than [FAIR_THRESHOLD_BITS] bits of estimated entropy, or a very common password
+    Weak,
+    /// At least [FAIR_THRESHOLD_BITS] but fewer than [STRONG_THRESHOLD_BITS] bits of estimated entropy
+    Fair,
+    /// At least [STRONG_THRESHOLD_BITS] bits of estimated entropy
+    Strong,
+}
+
+/// The result of estimating the strength of a passphrase
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PasswordStrength {
+    /// The estimated entropy, in bits
+    pub entropy_bits: u32,
+    /// The coarse rating corresponding to the estimated entropy
+    pub score: StrengthScore,
+    /// True if the passphrase appears in the built-in list of very common passwords
+    pub is_common: bool,
+}
+
+impl PasswordStrength {
+    fn from_bits(entropy_bits: u32, is_common: bool) -> Self {
+        let score = if is_common || entropy_bits < FAIR_THRESHOLD_BITS {
+            StrengthScore::Weak
+        } else if entropy_bits < STRONG_THRESHOLD_BITS {
+            StrengthScore::Fair
+        } else {
+            StrengthScore::Strong
+        };
+        Self {
+            entropy_bits,
+            score,
+            is_common,
+        }
+    }
+}
+
+impl SafePassword {
+    /// Estimate the strength of the passphrase. See the [module documentation](self) for how the estimate is made.
+    pub fn strength(&self) -> PasswordStrength {
+        estimate(self.reveal())
+    }
+}
+
+/// Estimate the strength of a passphrase given as UTF-8 bytes. Invalid UTF-8 is treated as arbitrary bytes.
+pub fn estimate(passphrase: &[u8]) -> PasswordStrength {
+    if let Some(index) = COMMON_PASSWORDS
+        .iter()
+        .position(|common| common.as_bytes().eq_ignore_ascii_case(passphrase))
+    {
+        // An attacker trying the list in order needs about `index` guesses
+        let bits = log2_fixed(u32::try_from(index + 1).unwrap_or(u32::MAX)) >> 16;
+        return PasswordStrength::from_bits(bits, true);
+    }
+
+    let mut classes = CharClasses::default();
+    match core::str::from_utf8(passphrase) {
+        Ok(s) => s.chars().for_each(|c| classes.add(c)),
+        Err(_) => classes.other = true,
+    }
+    let bits_per_char = log2_fixed(classes.pool_size());
+
+    // Repeated and sequential characters (e.g. "aaa" or "abc") only contribute one bit each
+    let mut total: u64 = 0;
+    let mut previous: Option<u32> = None;
+    let mut add = |code: u32| {
+        let predictable = previous.map(|p| p.abs_diff(code) <= 1).unwrap_or(false);
+        total += if predictable { 1 << 16 } else { u64::from(bits_per_char) };
+        previous = Some(code);
+    };
+    match core::str::from_utf8(passphrase) {
+        Ok(s) => s.chars().for_each(|c| add(u32::from(c))),
+        Err(_) => passphrase.iter().for_each(|&b| add(u32::from(b))),
+    }
+    PasswordStrength::from_bits(u32::try_from(total >> 16).unwrap_or(u32::MAX), false)
+}
+
+#[derive(Default)]
+struct CharClasses {
+    lower: bool,
+    upper: bool,
+    digit: bool,
+    symbol: bool,
+    other: bool,
+}
+
+impl CharClasses {
+    fn add(&mut self, c: char) {
+        if c.is_ascii_lowercase() {
+            self.lower = true;
+        } else if c.is_ascii_uppercase() {
+            self.upper = true;
+        } else if c.is_ascii_digit() {
+            self.digit = true;
+        } else if c.is_ascii() {
+            self.symbol = true;
+        } else {
+            self.other = true;
+        }
+    }
+
+    fn pool_size(&self) -> u32 {
+        let mut size = 0;
+        if self.lower {
+            size += 26;
+        }
+        if self.upper {
+            size += 26;
+        }
+        if self.digit {
+            size += 10;
+        }
+        if self.symbol {
+            size += 33;
+        }
+        if self.other {
+            size += 100;
+        }
+        size
+    }
+}
+
+/// Compute log2(n) as a 16.16 fixed-point number, without floating point. Returns 0 for n <= 1.
+fn log2_fixed(n: u32) -> u32 {
+    if n <= 1 {
+        return 0;
+    }
+    let int = 31 - n.leading_zeros();
+    // Normalize n to the range [1, 2) in 16.16 fixed point, then extract the fractional bits by repeated squaring
+    let mut x = (u64::from(n) << 16) >> int;
+    let mut frac = 0u32;
+    for bit in (0..16).rev() {
+        x = (x * x) >> 16;
+        if x >= 2 << 16 {
+            x >>= 1;
+            frac |= 1 << bit;
+        }
+    }
+    (int << 16) | frac
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn fixed_point_log() {
+        assert_eq!(log2_fixed(1), 0);
+        assert_eq!(log2_fixed(2), 1 << 16);
+        assert_eq!(log2_fixed(1024), 10 << 16);
+        // log2(26) = 4.7004
+        assert_eq!((log2_fixed(26) * 10_000) >> 16, 47_004);
+    }
+
+    #[test]
+    fn common_passwords() {
+        let strength = SafePassword::from("Password").strength();
+        assert!(strength.is_common);
+        assert_eq!(strength.score, StrengthScore::Weak);
+        assert!(strength.entropy_bits < 8);
+    }
+
+    #[test]
+    fn scores() {
+        assert_eq!(SafePassword::from("").strength().entropy_bits, 0);
+        assert_eq!(
+            SafePassword::from("aaaaaaaaaaaaaaaa").strength().score,
+            StrengthScore::Weak
+        );
+        assert_eq!(
+            SafePassword::from("abcdefghijklmnop").strength().score,
+            StrengthScore::Weak
+        );
+        assert_eq!(SafePassword::from("kqzmtwrpvy").strength().score, StrengthScore::Fair);
+        assert_eq!(
+            SafePassword::from("correct horse battery staple").strength().score,
+            StrengthScore::Strong
+        );
+        assert_eq!(
+            SafePassword::from("Tr0ub4dor&3xQz!").strength().score,
+            StrengthScore::Strong
+        );
+    }
+
+    #[test]
+    fn character_classes_increase_entropy() {
+        let lower = SafePassword::from("kqzmtwrp").strength().entropy_bits;
+        let mixed = SafePassword::from("kQzMtWrP").strength().entropy_bits;
+        let symbols = SafePassword::from("kQ#M7W!P").strength().entropy_bits;
+        assert!(lower < mixed);
+        assert!(mixed < symbols);
+        let unicode = SafePassword::from("kqzmtwrpü").strength().entropy_bits;
+        assert!(unicode > lower);
+    }
+}
@@ -0,0 +1,234 @@
+// Copyright 2026, The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+//! Conversion between domain types and their protobuf messages.
+//!
+//! Implement [ProtoConvert] for a domain type to describe how it maps to and from its `prost` message. The
+//! [ProtoFormat] methods, implemented for every [ProtoConvert] type, then encode and decode the type as protobuf
+//! binary or Base64, in the same way that [MessageFormat](crate::message_format::MessageFormat) does for serde types.
+//! Conversion failures are reported as [ProtoError]s, and [required] handles the common case of a message field that
+//! must be present.
+//!
+//! ```edition2018
+//! # use tari_utilities::proto::{required, ProtoConvert, ProtoError, ProtoFormat};
+//! #[derive(Clone, PartialEq, prost::Message)]
+//! struct AmountProto {
+//!     #[prost(uint64, optional, tag = "1")]
+//!     value: Option<u64>,
+//! }
+//!
+//! #[derive(Debug, PartialEq)]
+//! struct Amount(u64);
+//!
+//! impl ProtoConvert for Amount {
+//!     type Proto = AmountProto;
+//!
+//!     fn to_proto(&self) -> AmountProto {
+//!         AmountProto {
+//!             value: Some(self.0),
+//!         }
+//!     }
+//!
+//!     fn from_proto(proto: AmountProto) -> Result<Self, ProtoError> {
+//!         Ok(Amount(required(proto.value, "value")?))
+//!     }
+//! }
+//!
+//! let binary = Amount(42).to_proto_binary();
+//! assert_eq!(Amount::from_proto_binary(&binary).unwrap(), Amount(42));
+//! assert_eq!(
+//!     Amount::from_proto_binary(&[]),
+//!     Err(ProtoError::MissingField { field: "value" })
+//! );
+//! ```
+
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+
+use prost::Message;
+use snafu::prelude::*;
+
+use crate::base64::{self, Config};
+
+/// Errors for converting protobuf messages to domain types.
+#[derive(Debug, Snafu, PartialEq, Eq)]
+pub enum ProtoError {
+    /// The binary data is not a valid encoding of the message.
+    #[snafu(display("Invalid protobuf encoding: `{reason}'"))]
+    Decode {
+        /// Why the data could not be decoded
+        reason: String,
+    },
+    /// The Base64 text could not be decoded.
+    #[snafu(display("Invalid Base64: `{reason}'"))]
+    Base64 {
+        /// Why the text could not be decoded
+        reason: String,
+    },
+    /// A field that the domain type requires is missing.
+    #[snafu(display("Missing field `{field}'"))]
+    MissingField {
+        /// The name of the field
+        field: &'static str,
+    },
+    /// A field has a value that the domain type does not accept.
+    #[snafu(display("Invalid field `{field}': {reason}"))]
+    InvalidField {
+        /// The name of the field
+        field: &'static str,
+        /// Why the value is not accepted
+        reason: String,
+    },
+}
+
+impl ProtoError {
+    /// Creates an [ProtoError::InvalidField] error from the error returned by a field conversion.
+    pub fn invalid_field<E: ToString>(field: &'static str, error: E) -> Self {
+        ProtoError::InvalidField {
+            field,
+            reason: error.to_string(),
+        }
+    }
+}
+
+/// Returns the value of a field that must be present, or a [ProtoError::MissingField] error naming it.
+pub fn required<T>(value: Option<T>, field: &'static str) -> Result<T, ProtoError> {
+    value.context(MissingFieldSnafu { field })
+}
+
+/// A domain type with a protobuf representation.
+pub trait ProtoConvert: Sized {
+    /// The protobuf message for this type.
+    type Proto: Message + Default;
+
+    /// Convert to the protobuf message.
+    fn to_proto(&self) -> Self::Proto;
+    /// Convert from the protobuf message, validating it.
+    fn from_proto(proto: Self::Proto) -> Result<Self, ProtoError>;
+}
+
+/// Conversion of [ProtoConvert] types to and from protobuf binary and Base64.
+pub trait ProtoFormat: Sized {
+    /// Convert to protobuf binary.
+    fn to_proto_binary(&self) -> Vec<u8>;
+    /// Convert to protobuf binary encoded as standard padded Base64.
+    fn to_proto_base64(&self) -> String;
+    /// Convert from protobuf binary.
+    fn from_proto_binary(msg: &[u8]) -> Result<Self, ProtoError>;
+    /// Convert from protobuf binary encoded as standard padded Base64.
+    fn from_proto_base64(msg: &str) -> Result<Self, ProtoError>;
+}
+
+impl<T> ProtoFormat for T
+where T: ProtoConvert
+{
+    fn to_proto_binary(&self) -> Vec<u8> {
+        self.to_proto().encode_to_vec()
+    }
+
+    fn to_proto_base64(&self) -> String {
+        base64::encode(&self.to_proto_binary(), Config::STANDARD)
+    }
+
+    fn from_proto_binary(msg: &[u8]) -> Result<Self, ProtoError> {
+        let proto = T::Proto::decode(msg).map_err(|e| ProtoError::Decode { reason: e.to_string() })?;
+        T::from_proto(proto)
+    }
+
+    fn from_proto_base64(msg: &str) -> Result<Self, ProtoError> {
+        let binary = base64::decode(msg, Config::STANDARD).map_err(|e| ProtoError::Base64 { reason: e.to_string() })?;
+        Self::from_proto_binary(&binary)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use core::convert::TryFrom;
+
+    use super::*;
+
+    #[derive(Clone, PartialEq, prost::Message)]
+    struct PeerProto {
+        #[prost(bytes = "vec", tag = "1")]
+        public_key: Vec<u8>,
+        #[prost(uint32, tag = "2")]
+        port: u32,
+    }
+
+    #[derive(Debug, PartialEq)]
+    struct Peer {
+        public_key: [u8; 4],
+        port: u16,
+    }
+
+    impl ProtoConvert for Peer {
+        type Proto = PeerProto;
+
+        fn to_proto(&self) -> PeerProto {
+            PeerProto {
+                public_key: self.public_key.to_vec(),
+                port: u32::from(self.port),
+            }
+        }
+
+        fn from_proto(proto: PeerProto) -> Result<Self, ProtoError> {
+            Ok(Peer {
+                public_key: <[u8; 4]>::try_from(proto.public_key.as_slice())
+                    .map_err(|e| ProtoError::invalid_field("public_key", e))?,
+                port: u16::try_from(proto.port).map_err(|e| ProtoError::invalid_field("port", e))?,
+            })
+        }
+    }
+
+    #[test]
+    fn round_trip() {
+        let peer = Peer {
+            public_key: [1, 2, 3, 4],
+            port: 18141,
+        };
+        assert_eq!(Peer::from_proto_binary(&peer.to_proto_binary()).unwrap(), peer);
+        assert_eq!(Peer::from_proto_base64(&peer.to_proto_base64()).unwrap(), peer);
+    }
+
+    #[test]
+    fn errors() {
+        let bad_port = PeerProto {
+            public_key: vec![1, 2, 3, 4],
+            port: 70000,
+        };
+        assert!(matches!(
+            Peer::from_proto_binary(&bad_port.encode_to_vec()),
+            Err(ProtoError::InvalidField { field: "port", .. })
+        ));
+        assert!(matches!(
+            Peer::from_proto_binary(&[0xff]),
+            Err(ProtoError::Decode { .. })
+        ));
+        assert!(matches!(Peer::from_proto_base64("!"), Err(ProtoError::Base64 { .. })));
+        assert_eq!(
+            required(None::<u8>, "port"),
+            Err(ProtoError::MissingField { field: "port" })
+        );
+    }
+}
@@ -0,0 +1,193 @@
+// Copyright 2026, The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+//! Python bindings for the codecs and [MessageFormat], so scripts can decode payloads with the same rules as the Rust
+//! code.
+//!
+//! [register] adds `hex_encode`, `hex_decode`, `hex_decode_lenient`, `base64_encode`, `base64_decode`,
+//! `base58_monero_encode`, `base58_monero_decode`, `base58_btc_encode` and `base58_btc_decode` to a Python module.
+//! Decoders are strict, as their Rust counterparts are, and `base58_monero_encode` matches
+//! [Base58::to_base58](crate::encoding::Base58::to_base58). Bytes cross the boundary as `bytes`, and decoding errors
+//! are raised as `ValueError`. Message types are specific to each application, so [message_to_json] and
+//! [message_from_json] are plain Rust helpers for use in an application's own `#[pyfunction]`s.
+
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::fmt::Display;
+
+use pyo3::{exceptions::PyValueError, prelude::*, types::PyBytes};
+
+use crate::{
+    base58,
+    base64::{self, Config},
+    encoding::{Base58MoneroEncoding, DecodeMode, Encoding},
+    hex,
+    message_format::MessageFormat,
+};
+
+fn value_error<E: Display>(error: E) -> PyErr {
+    PyValueError::new_err(error.to_string())
+}
+
+fn into_bytes(py: Python<'_>, result: PyResult<Vec<u8>>) -> PyResult<Bound<'_, PyBytes>> {
+    result.map(|bytes| PyBytes::new(py, &bytes))
+}
+
+/// Adds the codec functions to `module`.
+pub fn register(module: &Bound<'_, PyModule>) -> PyResult<()> {
+    module.add_function(wrap_pyfunction!(hex_encode, module)?)?;
+    module.add_function(wrap_pyfunction!(hex_decode, module)?)?;
+    module.add_function(wrap_pyfunction!(hex_decode_lenient, module)?)?;
+    module.add_function(wrap_pyfunction!(base64_encode, module)?)?;
+    module.add_function(wrap_pyfunction!(base64_decode, module)?)?;
+    module.add_function(wrap_pyfunction!(base58_monero_encode, module)?)?;
+    module.add_function(wrap_pyfunction!(base58_monero_decode, module)?)?;
+    module.add_function(wrap_pyfunction!(base58_btc_encode, module)?)?;
+    module.add_function(wrap_pyfunction!(base58_btc_decode, module)?)?;
+    Ok(())
+}
+
+/// Encode bytes as lower case hex.
+#[pyfunction]
+pub fn hex_encode(data: &[u8]) -> String {
+    let mut out = String::new();
+    hex::to_hex_into(data, &mut out);
+    out
+}
+
+/// Decode lower case hex, as [hex::from_hex] does.
+#[pyfunction]
+pub fn hex_decode<'py>(py: Python<'py>, s: &str) -> PyResult<Bound<'py, PyBytes>> {
+    into_bytes(py, hex::from_hex(s).map_err(value_error))
+}
+
+/// Decode hex, ignoring whitespace, case and any `0x` prefix.
+#[pyfunction]
+pub fn hex_decode_lenient<'py>(py: Python<'py>, s: &str) -> PyResult<Bound<'py, PyBytes>> {
+    into_bytes(py, hex::from_hex_with_mode(s, DecodeMode::Lenient).map_err(value_error))
+}
+
+/// Encode bytes as padded standard Base64, as used by [MessageFormat::to_base64].
+#[pyfunction]
+pub fn base64_encode(data: &[u8]) -> String {
+    base64::encode(data, Config::STANDARD)
+}
+
+/// Decode padded standard Base64.
+#[pyfunction]
+pub fn base64_decode<'py>(py: Python<'py>, s: &str) -> PyResult<Bound<'py, PyBytes>> {
+    into_bytes(py, base64::decode(s, Config::STANDARD).map_err(value_error))
+}
+
+/// Encode bytes as Monero block-based Base58, as [Base58::to_base58](crate::encoding::Base58::to_base58) does.
+#[pyfunction]
+pub fn base58_monero_encode(data: &[u8]) -> String {
+    Base58MoneroEncoding.encode(data)
+}
+
+/// Decode Monero block-based Base58.
+#[pyfunction]
+pub fn base58_monero_decode<'py>(py: Python<'py>, s: &str) -> PyResult<Bound<'py, PyBytes>> {
+    into_bytes(py, Base58MoneroEncoding.decode(s).map_err(value_error))
+}
+
+/// Encode bytes as Base58 with the Bitcoin alphabet.
+#[pyfunction]
+pub fn base58_btc_encode(data: &[u8]) -> String {
+    base58::encode(data)
+}
+
+/// Decode Base58 with the Bitcoin alphabet.
+#[pyfunction]
+pub fn base58_btc_decode<'py>(py: Python<'py>, s: &str) -> PyResult<Bound<'py, PyBytes>> {
+    into_bytes(py, base58::decode(s).map_err(value_error))
+}
+
+/// Convert a message to its JSON form, raising `ValueError` on failure.
+pub fn message_to_json<T: MessageFormat>(value: &T) -> PyResult<String> {
+    value.to_json().map_err(value_error)
+}
+
+/// Convert the JSON form of a message back to the message, raising `ValueError` on failure.
+pub fn message_from_json<T: MessageFormat>(json: &str) -> PyResult<T> {
+    T::from_json(json).map_err(value_error)
+}
+
+#[cfg(test)]
+mod test {
+    use std::ffi::CString;
+
+    use pyo3::types::PyDict;
+
+    use super::*;
+
+    #[test]
+    fn codecs_from_python() {
+        pyo3::prepare_freethreaded_python();
+        Python::with_gil(|py| {
+            let module = PyModule::new(py, "tari_utilities").unwrap();
+            register(&module).unwrap();
+            let locals = PyDict::new(py);
+            locals.set_item("tu", module).unwrap();
+            let script = CString::new(
+                r#"
+assert tu.hex_encode(b"\xca\xfe") == "cafe"
+assert tu.hex_decode("cafe") == b"\xca\xfe"
+assert tu.hex_decode_lenient("0xCAFE") == b"\xca\xfe"
+assert tu.base58_btc_encode(b"hello world") == "StV1DL6CwTryKyV"
+assert tu.base58_btc_decode("StV1DL6CwTryKyV") == b"hello world"
+assert tu.base58_monero_decode(tu.base58_monero_encode(b"foob")) == b"foob"
+assert tu.base64_decode(tu.base64_encode(b"foob")) == b"foob"
+try:
+    tu.hex_decode("xyz")
+    raise AssertionError("expected a ValueError")
+except ValueError:
+    pass
+try:
+    tu.hex_decode("0xCAFE")
+    raise AssertionError("expected a ValueError")
+except ValueError:
+    pass
+"#,
+            )
+            .unwrap();
+            py.run(&script, None, Some(&locals)).unwrap();
+        });
+    }
+
+    #[test]
+    fn base58_monero_matches_trait() {
+        use crate::encoding::Base58;
+
+        let key = [7u8; 32];
+        assert_eq!(base58_monero_encode(&key), key.to_base58());
+    }
+
+    #[test]
+    fn message_json() {
+        let json = message_to_json(&vec![1u8, 2]).unwrap();
+        assert_eq!(json, "[1,2]");
+        assert_eq!(message_from_json::<Vec<u8>>(&json).unwrap(), vec![1, 2]);
+    }
+}
@@ -0,0 +1,200 @@
+// Copyright 2026, The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+//! Token-bucket rate limiting.
+//!
+//! A [RateLimiter] holds up to `capacity` tokens and refills at a steady rate. Each request takes some tokens, so
+//! bursts of up to `capacity` are allowed while the long-run rate is bounded by the refill rate.
+//! [try_acquire](RateLimiter::try_acquire) takes tokens without waiting, and with the `async` feature
+//! [acquire](RateLimiter::acquire) waits until enough tokens are available. The limiter can be shared between threads
+//! and tasks by reference.
+//!
+//! ```edition2018
+//! # use tari_utilities::rate_limit::RateLimiter;
+//! // Allow bursts of 10 requests, refilling at 5 requests per second
+//! let limiter = RateLimiter::new(10, 5.0);
+//! assert!(limiter.try_acquire(8));
+//! assert!(!limiter.try_acquire(3));
+//! assert!(limiter.try_acquire(2));
+//! ```
+
+use core::time::Duration;
+use std::{sync::Mutex, time::Instant};
+
+use snafu::prelude::*;
+
+use crate::locks::poison::recover_lock;
+
+/// Errors for [RateLimiter].
+#[derive(Debug, Snafu, PartialEq, Eq)]
+pub enum RateLimitError {
+    /// More tokens were requested than the limiter can ever hold, so the request can never be granted.
+    #[snafu(display("Requested {requested} tokens but the capacity is {capacity}"))]
+    ExceedsCapacity {
+        /// The number of tokens requested
+        requested: u64,
+        /// The capacity of the limiter
+        capacity: u64,
+    },
+}
+
+#[derive(Debug)]
+struct Bucket {
+    tokens: f64,
+    updated: Instant,
+}
+
+/// A token-bucket rate limiter. See the [module](self) documentation.
+#[derive(Debug)]
+pub struct RateLimiter {
+    capacity: u64,
+    tokens_per_second: f64,
+    bucket: Mutex<Bucket>,
+}
+
+impl RateLimiter {
+    /// Create a full limiter holding up to `capacity` tokens, which refills at `tokens_per_second`. A rate that is not
+    /// positive never refills.
+    pub fn new(capacity: u64, tokens_per_second: f64) -> Self {
+        Self::new_at(capacity, tokens_per_second, Instant::now())
+    }
+
+    fn new_at(capacity: u64, tokens_per_second: f64, now: Instant) -> Self {
+        Self {
+            capacity,
+            tokens_per_second: if tokens_per_second > 0.0 {
+                tokens_per_second
+            } else {
+                0.0
+            },
+            bucket: Mutex::new(Bucket {
+                tokens: capacity as f64,
+                updated: now,
+            }),
+        }
+    }
+
+    /// The maximum number of tokens
+    pub fn capacity(&self) -> u64 {
+        self.capacity
+    }
+
+    /// The number of whole tokens available now
+    pub fn available(&self) -> u64 {
+        self.available_at(Instant::now())
+    }
+
+    /// Take `n` tokens if they are available, returning whether they were taken.
+    pub fn try_acquire(&self, n: u64) -> bool {
+        self.try_acquire_at(n, Instant::now()).is_ok()
+    }
+
+    /// Wait until `n` tokens are available and take them. Fails immediately if `n` is more than the capacity, which
+    /// could never be satisfied.
+    #[cfg(feature = "async")]
+    pub async fn acquire(&self, n: u64) -> Result<(), RateLimitError> {
+        ensure!(n <= self.capacity, ExceedsCapacitySnafu {
+            requested: n,
+            capacity: self.capacity,
+        });
+        while let Err(wait) = self.try_acquire_at(n, Instant::now()) {
+            tokio::time::sleep(wait).await;
+        }
+        Ok(())
+    }
+
+    fn available_at(&self, now: Instant) -> u64 {
+        let mut bucket = recover_lock(&self.bucket);
+        self.refill(&mut bucket, now);
+        bucket.tokens as u64
+    }
+
+    /// Take `n` tokens at `now`, or return how long to wait until they will be available.
+    fn try_acquire_at(&self, n: u64, now: Instant) -> Result<(), Duration> {
+        let mut bucket = recover_lock(&self.bucket);
+        self.refill(&mut bucket, now);
+        let n = n as f64;
+        if bucket.tokens >= n {
+            bucket.tokens -= n;
+            return Ok(());
+        }
+        let wait = (n - bucket.tokens) / self.tokens_per_second;
+        Err(Duration::try_from_secs_f64(wait).unwrap_or(Duration::MAX))
+    }
+
+    fn refill(&self, bucket: &mut Bucket, now: Instant) {
+        let elapsed = now.saturating_duration_since(bucket.updated);
+        bucket.tokens = elapsed
+            .as_secs_f64()
+            .mul_add(self.tokens_per_second, bucket.tokens)
+            .min(self.capacity as f64);
+        bucket.updated = bucket.updated.max(now);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn refills() {
+        let start = Instant::now();
+        let limiter = RateLimiter::new_at(10, 2.0, start);
+        assert_eq!(limiter.try_acquire_at(10, start), Ok(()));
+        assert_eq!(limiter.try_acquire_at(1, start), Err(Duration::from_millis(500)));
+
+        let later = start + Duration::from_secs(2);
+        assert_eq!(limiter.available_at(later), 4);
+        assert_eq!(limiter.try_acquire_at(3, later), Ok(()));
+        assert_eq!(limiter.try_acquire_at(2, later), Err(Duration::from_millis(500)));
+
+        // Refilling stops at the capacity
+        assert_eq!(limiter.available_at(later + Duration::from_secs(60)), 10);
+        assert_eq!(limiter.capacity(), 10);
+    }
+
+    #[test]
+    fn no_refill() {
+        let limiter = RateLimiter::new(1, 0.0);
+        assert!(limiter.try_acquire(1));
+        assert!(!limiter.try_acquire(1));
+        assert_eq!(limiter.try_acquire_at(1, Instant::now()), Err(Duration::MAX));
+        assert!(!RateLimiter::new(1, 1.0).try_acquire(2));
+    }
+
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn acquire() {
+        let limiter = RateLimiter::new(2, 100.0);
+        let start = Instant::now();
+        for _ in 0..4 {
+            limiter.acquire(1).await.unwrap();
+        }
+        assert!(start.elapsed() >= Duration::from_millis(15));
+        assert_eq!(
+            limiter.acquire(3).await,
+            Err(RateLimitError::ExceedsCapacity {
+                requested: 3,
+                capacity: 2,
+            })
+        );
+    }
+}
@@ -0,0 +1,341 @@
+// Copyright 2026, The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+//! A validated ratio between 0 and 1, which can equally be treated as a percentage between 0 and 100.
+//!
+//! [Ratio] is stored as a whole number of parts per billion, so it can be compared, hashed and applied to integer
+//! amounts exactly. Construction checks the range, and arithmetic saturates at the bounds rather than producing an
+//! invalid value.
+//!
+//! ```edition2018
+//! # use tari_utilities::ratio::Ratio;
+//! let fee = Ratio::from_percent(0.25).unwrap();
+//! assert_eq!(fee.apply(1_000_000), 2_500);
+//! assert_eq!(fee.to_string(), "0.25%");
+//! assert_eq!("0.25%".parse::<Ratio>().unwrap(), fee);
+//! assert!(Ratio::new(1.5).is_err());
+//! assert_eq!(
+//!     Ratio::from_percent(70.0)
+//!         .unwrap()
+//!         .saturating_add(Ratio::from_percent(50.0).unwrap()),
+//!     Ratio::ONE
+//! );
+//! ```
+//!
+//! With the `serde` feature, a ratio is serialized as a fraction between 0 and 1, and checked when deserialized.
+
+use alloc::string::{String, ToString};
+use core::{convert::TryFrom, fmt, ops::Mul, str::FromStr};
+
+use snafu::prelude::*;
+
+const SCALE: u32 = 1_000_000_000;
+
+/// Errors for constructing a [Ratio].
+#[derive(Debug, Snafu, PartialEq)]
+pub enum RatioError {
+    /// The value is outside the valid range, or is not a number.
+    #[snafu(display("Ratio {value} is outside the range {min} to {max}"))]
+    OutOfRange {
+        /// The offending value
+        value: f64,
+        /// The minimum value of the range
+        min: f64,
+        /// The maximum value of the range
+        max: f64,
+    },
+    /// A fraction has a zero denominator, or a numerator larger than its denominator.
+    #[snafu(display("Invalid fraction {numerator}/{denominator}"))]
+    InvalidFraction {
+        /// The numerator
+        numerator: u64,
+        /// The denominator
+        denominator: u64,
+    },
+    /// A string could not be parsed as a ratio.
+    #[snafu(display("Invalid ratio `{input}'"))]
+    InvalidFormat {
+        /// The string being parsed
+        input: String,
+    },
+}
+
+/// A ratio between 0 and 1 inclusive, with a resolution of one part per billion.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Ratio(u32);
+
+impl Ratio {
+    /// A ratio of 1, or 100%
+    pub const ONE: Ratio = Ratio(SCALE);
+    /// A ratio of 0, or 0%
+    pub const ZERO: Ratio = Ratio(0);
+
+    /// Create a ratio from a fraction between 0.0 and 1.0 inclusive, rounded to the nearest part per billion.
+    pub fn new(value: f64) -> Result<Self, RatioError> {
+        ensure!((0.0..=1.0).contains(&value), OutOfRangeSnafu {
+            value,
+            min: 0.0,
+            max: 1.0
+        });
+        Ok(Self::from_f64_unchecked(value))
+    }
+
+    /// Create a ratio from a percentage between 0.0 and 100.0 inclusive, rounded to the nearest part per billion.
+    pub fn from_percent(percent: f64) -> Result<Self, RatioError> {
+        ensure!((0.0..=100.0).contains(&percent), OutOfRangeSnafu {
+            value: percent,
+            min: 0.0,
+            max: 100.0
+        });
+        Ok(Self::from_f64_unchecked(percent / 100.0))
+    }
+
+    /// Create a ratio from a fraction of integers, rounded down to the nearest part per billion. The numerator must not
+    /// exceed the denominator, which must not be zero.
+    pub fn from_fraction(numerator: u64, denominator: u64) -> Result<Self, RatioError> {
+        ensure!(denominator != 0 && numerator <= denominator, InvalidFractionSnafu {
+            numerator,
+            denominator
+        });
+        let parts = u128::from(numerator) * u128::from(SCALE) / u128::from(denominator);
+        // parts <= SCALE since numerator <= denominator
+        Ok(Ratio(parts as u32))
+    }
+
+    /// Create a ratio from a number of parts per billion, which must not exceed one billion.
+    pub fn from_parts_per_billion(parts: u32) -> Result<Self, RatioError> {
+        ensure!(parts <= SCALE, InvalidFractionSnafu {
+            numerator: u64::from(parts),
+            denominator: u64::from(SCALE)
+        });
+        Ok(Ratio(parts))
+    }
+
+    fn from_f64_unchecked(value: f64) -> Self {
+        // value is in 0.0..=1.0, so the product is in range
+        let parts = (value * f64::from(SCALE) + 0.5) as u32;
+        Ratio(parts.min(SCALE))
+    }
+
+    /// Return the ratio as a fraction between 0.0 and 1.0.
+    pub fn as_f64(self) -> f64 {
+        f64::from(self.0) / f64::from(SCALE)
+    }
+
+    /// Return the ratio as a percentage between 0.0 and 100.0.
+    pub fn as_percent(self) -> f64 {
+        f64::from(self.0) / f64::from(SCALE / 100)
+    }
+
+    /// Return the ratio as a number of parts per billion.
+    pub fn parts_per_billion(self) -> u32 {
+        self.0
+    }
+
+    /// Returns `1 - self`.
+    #[must_use]
+    pub fn complement(self) -> Self {
+        Ratio(SCALE - self.0)
+    }
+
+    /// Add two ratios, saturating at 1.
+    #[must_use]
+    pub fn saturating_add(self, other: Ratio) -> Self {
+        Ratio((self.0 + other.0).min(SCALE))
+    }
+
+    /// Subtract a ratio, saturating at 0.
+    #[must_use]
+    pub fn saturating_sub(self, other: Ratio) -> Self {
+        Ratio(self.0.saturating_sub(other.0))
+    }
+
+    /// Return this proportion of `amount`, rounded down. The result never exceeds `amount`.
+    pub fn apply(self, amount: u64) -> u64 {
+        // The result is at most amount, since self.0 <= SCALE
+        (u128::from(amount) * u128::from(self.0) / u128::from(SCALE)) as u64
+    }
+}
+
+impl Mul for Ratio {
+    type Output = Ratio;
+
+    /// The product of two ratios, rounded down. This is always a valid ratio.
+    fn mul(self, rhs: Ratio) -> Ratio {
+        Ratio(rhs.apply(u64::from(self.0)) as u32)
+    }
+}
+
+impl TryFrom<f64> for Ratio {
+    type Error = RatioError;
+
+    fn try_from(value: f64) -> Result<Self, Self::Error> {
+        Ratio::new(value)
+    }
+}
+
+impl From<Ratio> for f64 {
+    fn from(ratio: Ratio) -> Self {
+        ratio.as_f64()
+    }
+}
+
+/// Displays the ratio as a percentage, e.g. `12.5%`. The formatter precision, if given, sets the number of decimal
+/// places.
+impl fmt::Display for Ratio {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match f.precision() {
+            Some(precision) => write!(f, "{:.*}%", precision, self.as_percent()),
+            None => write!(f, "{}%", self.as_percent()),
+        }
+    }
+}
+
+/// Parses a percentage with a `%` suffix, e.g. `12.5%`, or a fraction without one, e.g. `0.125`.
+impl FromStr for Ratio {
+    type Err = RatioError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let input = s.trim();
+        let invalid = || RatioError::InvalidFormat { input: s.to_string() };
+        match input.strip_suffix('%') {
+            Some(percent) => Ratio::from_percent(percent.trim_end().parse().map_err(|_| invalid())?),
+            None => Ratio::new(input.parse().map_err(|_| invalid())?),
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Ratio {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where S: serde::Serializer {
+        serializer.serialize_f64(self.as_f64())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Ratio {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where D: serde::Deserializer<'de> {
+        let value = f64::deserialize(deserializer)?;
+        Ratio::new(value).map_err(|e| serde::de::Error::custom(e.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn construction() {
+        assert_eq!(Ratio::new(0.0).unwrap(), Ratio::ZERO);
+        assert_eq!(Ratio::new(1.0).unwrap(), Ratio::ONE);
+        assert_eq!(Ratio::new(0.125).unwrap().parts_per_billion(), 125_000_000);
+        assert_eq!(Ratio::from_percent(12.5).unwrap(), Ratio::new(0.125).unwrap());
+        assert_eq!(Ratio::from_percent(100.0).unwrap(), Ratio::ONE);
+        assert_eq!(Ratio::from_fraction(1, 3).unwrap().parts_per_billion(), 333_333_333);
+        assert_eq!(Ratio::from_fraction(u64::MAX, u64::MAX).unwrap(), Ratio::ONE);
+        assert_eq!(Ratio::try_from(0.5).unwrap().as_f64(), 0.5);
+        assert_eq!(Ratio::from_parts_per_billion(SCALE).unwrap(), Ratio::ONE);
+        assert!((Ratio::from_percent(33.3).unwrap().as_percent() - 33.3).abs() < 1e-9);
+    }
+
+    #[test]
+    fn invalid_construction() {
+        assert_eq!(
+            Ratio::new(1.000_001),
+            Err(RatioError::OutOfRange {
+                value: 1.000_001,
+                min: 0.0,
+                max: 1.0
+            })
+        );
+        assert!(Ratio::new(-0.1).is_err());
+        assert!(Ratio::new(f64::NAN).is_err());
+        assert!(Ratio::new(f64::INFINITY).is_err());
+        assert!(Ratio::from_percent(100.1).is_err());
+        assert!(Ratio::from_percent(-1.0).is_err());
+        assert_eq!(
+            Ratio::from_fraction(2, 1),
+            Err(RatioError::InvalidFraction {
+                numerator: 2,
+                denominator: 1
+            })
+        );
+        assert!(Ratio::from_fraction(0, 0).is_err());
+        assert!(Ratio::from_parts_per_billion(SCALE + 1).is_err());
+    }
+
+    #[test]
+    fn arithmetic() {
+        let a = Ratio::from_percent(70.0).unwrap();
+        let b = Ratio::from_percent(50.0).unwrap();
+        assert_eq!(a.saturating_add(b), Ratio::ONE);
+        assert_eq!(b.saturating_add(b), Ratio::ONE);
+        assert_eq!(b.saturating_sub(a), Ratio::ZERO);
+        assert_eq!(a.saturating_sub(b), Ratio::from_percent(20.0).unwrap());
+        assert_eq!(a.complement(), Ratio::from_percent(30.0).unwrap());
+        assert_eq!(a * b, Ratio::from_percent(35.0).unwrap());
+        assert_eq!(Ratio::ONE * a, a);
+        assert_eq!(Ratio::ZERO * a, Ratio::ZERO);
+        assert!(a > b);
+    }
+
+    #[test]
+    fn apply() {
+        assert_eq!(Ratio::from_percent(0.25).unwrap().apply(1_000_000), 2_500);
+        assert_eq!(Ratio::ONE.apply(u64::MAX), u64::MAX);
+        assert_eq!(Ratio::ZERO.apply(u64::MAX), 0);
+        assert_eq!(Ratio::from_fraction(1, 3).unwrap().apply(10), 3);
+    }
+
+    #[test]
+    fn display_and_parse() {
+        assert_eq!(Ratio::from_percent(12.5).unwrap().to_string(), "12.5%");
+        assert_eq!(Ratio::ONE.to_string(), "100%");
+        assert_eq!(format!("{:.2}", Ratio::from_fraction(1, 3).unwrap()), "33.33%");
+        assert_eq!("12.5%".parse::<Ratio>().unwrap(), Ratio::new(0.125).unwrap());
+        assert_eq!(" 12.5 % ".parse::<Ratio>().unwrap(), Ratio::new(0.125).unwrap());
+        assert_eq!("0.125".parse::<Ratio>().unwrap(), Ratio::new(0.125).unwrap());
+        assert_eq!(
+            "abc%".parse::<Ratio>(),
+            Err(RatioError::InvalidFormat { input: "abc%".into() })
+        );
+        assert!("101%".parse::<Ratio>().is_err());
+        assert!("2".parse::<Ratio>().is_err());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde() {
+        let ratio = Ratio::from_percent(12.5).unwrap();
+        let json = serde_json::to_string(&ratio).unwrap();
+        assert_eq!(json, "0.125");
+        assert_eq!(serde_json::from_str::<Ratio>(&json).unwrap(), ratio);
+        assert_eq!(serde_json::from_str::<Ratio>("1").unwrap(), Ratio::ONE);
+        assert!(serde_json::from_str::<Ratio>("1.5").is_err());
+        assert!(serde_json::from_str::<Ratio>("-0.5").is_err());
+        let third = Ratio::from_fraction(1, 3).unwrap();
+        let bytes = bincode::serialize(&third).unwrap();
+        assert_eq!(bincode::deserialize::<Ratio>(&bytes).unwrap(), third);
+    }
+}
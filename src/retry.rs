@@ -0,0 +1,295 @@
+// Copyright 2026, The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+//! Retrying fallible operations with exponential backoff.
+//!
+//! A [Backoff] describes a retry schedule: a base delay that is multiplied by a factor after each attempt, up to an
+//! optional maximum, with optional jitter so that clients do not retry in lockstep, and a limit on the number of
+//! retries. Iterating over it yields the delays, and [retry] (or [retry_async] with the `async` feature) runs an
+//! operation until it succeeds or the retries run out.
+//!
+//! ```edition2018
+//! # use std::time::Duration;
+//! # use tari_utilities::retry::{retry, Backoff};
+//! let backoff = Backoff::new(Duration::from_millis(100))
+//!     .with_factor(3.0)
+//!     .with_max_delay(Duration::from_secs(1))
+//!     .with_max_retries(4);
+//! let delays = backoff.iter().map(|d| d.as_millis()).collect::<Vec<_>>();
+//! assert_eq!(delays, [100, 300, 900, 1000]);
+//!
+//! let mut attempts = 0;
+//! let result: Result<u32, &str> = retry(&Backoff::new(Duration::ZERO), || {
+//!     attempts += 1;
+//!     if attempts < 3 {
+//!         Err("not yet")
+//!     } else {
+//!         Ok(attempts)
+//!     }
+//! });
+//! assert_eq!(result, Ok(3));
+//! ```
+
+use core::{convert::TryFrom, time::Duration};
+#[cfg(feature = "async")]
+use std::future::Future;
+use std::thread;
+
+use crate::locks::backoff::pseudo_random;
+
+/// A retry schedule of exponentially growing delays. See the [module](self) documentation.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Backoff {
+    base: Duration,
+    factor: f64,
+    max_delay: Duration,
+    jitter: f64,
+    max_retries: usize,
+}
+
+impl Backoff {
+    /// Create a schedule that starts with a delay of `base` and doubles it for each retry, without jitter, for up to 5
+    /// retries.
+    pub fn new(base: Duration) -> Self {
+        Self {
+            base,
+            factor: 2.0,
+            max_delay: Duration::MAX,
+            jitter: 0.0,
+            max_retries: 5,
+        }
+    }
+
+    /// Set the factor that the delay is multiplied by after each retry. Factors below 1 are treated as 1.
+    #[must_use]
+    pub fn with_factor(mut self, factor: f64) -> Self {
+        self.factor = if factor >= 1.0 { factor } else { 1.0 };
+        self
+    }
+
+    /// Set the upper bound on the delay between retries.
+    #[must_use]
+    pub fn with_max_delay(mut self, delay: Duration) -> Self {
+        self.max_delay = delay;
+        self
+    }
+
+    /// Randomly shorten each delay by up to the fraction `jitter` of its length, clamped to between 0 and 1. A jitter
+    /// of 0.5 gives delays between half and all of the scheduled delay.
+    #[must_use]
+    pub fn with_jitter(mut self, jitter: f64) -> Self {
+        self.jitter = if jitter > 0.0 { jitter.min(1.0) } else { 0.0 };
+        self
+    }
+
+    /// Set the number of retries after the first attempt.
+    #[must_use]
+    pub fn with_max_retries(mut self, retries: usize) -> Self {
+        self.max_retries = retries;
+        self
+    }
+
+    /// The number of retries after the first attempt
+    pub fn max_retries(&self) -> usize {
+        self.max_retries
+    }
+
+    /// Iterate over the delays before each retry.
+    pub fn iter(&self) -> Delays {
+        Delays {
+            backoff: *self,
+            retry: 0,
+        }
+    }
+
+    /// The delay before retry number `retry`, counting from zero, before jitter
+    fn delay(&self, retry: usize) -> Duration {
+        // A zero base stays zero, rather than becoming `0 * inf = NaN` once the factor overflows
+        if self.base.is_zero() {
+            return Duration::ZERO;
+        }
+        let exponent = i32::try_from(retry).unwrap_or(i32::MAX);
+        let scaled = self.base.as_secs_f64() * self.factor.powi(exponent);
+        Duration::try_from_secs_f64(scaled)
+            .unwrap_or(Duration::MAX)
+            .min(self.max_delay)
+    }
+
+    fn jittered(&self, delay: Duration) -> Duration {
+        if self.jitter == 0.0 {
+            return delay;
+        }
+        let unit = (pseudo_random() >> 11) as f64 / (1u64 << 53) as f64;
+        delay.mul_f64(1.0 - self.jitter * unit)
+    }
+}
+
+impl IntoIterator for &Backoff {
+    type IntoIter = Delays;
+    type Item = Duration;
+
+    fn into_iter(self) -> Delays {
+        self.iter()
+    }
+}
+
+/// The delays of a [Backoff] schedule, one for each retry.
+#[derive(Debug, Clone)]
+pub struct Delays {
+    backoff: Backoff,
+    retry: usize,
+}
+
+impl Iterator for Delays {
+    type Item = Duration;
+
+    fn next(&mut self) -> Option<Duration> {
+        if self.retry >= self.backoff.max_retries {
+            return None;
+        }
+        let delay = self.backoff.delay(self.retry);
+        self.retry += 1;
+        Some(self.backoff.jittered(delay))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.backoff.max_retries - self.retry;
+        (remaining, Some(remaining))
+    }
+}
+
+impl ExactSizeIterator for Delays {}
+
+/// Call `op` until it succeeds, sleeping the thread for each delay of `backoff` between attempts. Returns the first
+/// success, or the last error once the retries run out.
+pub fn retry<T, E, F>(backoff: &Backoff, mut op: F) -> Result<T, E>
+where F: FnMut() -> Result<T, E> {
+    let mut delays = backoff.iter();
+    loop {
+        match op() {
+            Ok(value) => return Ok(value),
+            Err(e) => match delays.next() {
+                Some(delay) => thread::sleep(delay),
+                None => return Err(e),
+            },
+        }
+    }
+}
+
+/// Await `op` until it succeeds, sleeping the task for each delay of `backoff` between attempts, as for [retry].
+#[cfg(feature = "async")]
+pub async fn retry_async<T, E, F, Fut>(backoff: &Backoff, mut op: F) -> Result<T, E>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<T, E>>,
+{
+    let mut delays = backoff.iter();
+    loop {
+        match op().await {
+            Ok(value) => return Ok(value),
+            Err(e) => match delays.next() {
+                Some(delay) => tokio::time::sleep(delay).await,
+                None => return Err(e),
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use alloc::vec::Vec;
+
+    use super::*;
+
+    #[test]
+    fn delays() {
+        let backoff = Backoff::new(Duration::from_millis(10));
+        let delays = backoff.iter().map(|d| d.as_millis()).collect::<Vec<_>>();
+        assert_eq!(delays, [10, 20, 40, 80, 160]);
+        assert_eq!(backoff.iter().len(), backoff.max_retries());
+
+        let capped = Backoff::new(Duration::from_secs(1))
+            .with_factor(0.5)
+            .with_max_retries(3)
+            .with_max_delay(Duration::from_millis(500));
+        assert!((&capped).into_iter().all(|d| d == Duration::from_millis(500)));
+
+        let huge = Backoff::new(Duration::from_secs(u64::MAX)).with_max_retries(3);
+        assert!(huge.iter().all(|d| d == Duration::MAX));
+        assert_eq!(Backoff::new(Duration::ZERO).with_max_retries(0).iter().next(), None);
+
+        let overflow = Backoff::new(Duration::ZERO).with_factor(1e200);
+        assert!(overflow.iter().all(|d| d == Duration::ZERO));
+        let overflow = Backoff::new(Duration::from_millis(1))
+            .with_factor(1e200)
+            .with_max_delay(Duration::from_secs(1));
+        assert_eq!(overflow.iter().skip(1).collect::<Vec<_>>(), [Duration::from_secs(1); 4]);
+    }
+
+    #[test]
+    fn jitter() {
+        let backoff = Backoff::new(Duration::from_millis(100))
+            .with_factor(1.0)
+            .with_jitter(0.25)
+            .with_max_retries(100);
+        assert!(backoff
+            .iter()
+            .all(|d| d >= Duration::from_millis(75) && d <= Duration::from_millis(100)));
+        assert!(backoff.iter().any(|d| d != Duration::from_millis(100)));
+
+        let full = Backoff::new(Duration::from_millis(1)).with_jitter(7.0);
+        assert!(full.iter().all(|d| d <= Duration::from_millis(16)));
+    }
+
+    #[test]
+    fn retries() {
+        let backoff = Backoff::new(Duration::ZERO).with_max_retries(2);
+        let mut attempts = 0;
+        let result: Result<(), u32> = retry(&backoff, || {
+            attempts += 1;
+            Err(attempts)
+        });
+        assert_eq!(result, Err(3));
+
+        let result: Result<u32, ()> = retry(&backoff, || Ok(1));
+        assert_eq!(result, Ok(1));
+    }
+
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn retries_async() {
+        let backoff = Backoff::new(Duration::from_millis(1)).with_max_retries(3);
+        let mut attempts = 0;
+        let result: Result<u32, u32> = retry_async(&backoff, || {
+            attempts += 1;
+            let attempt = attempts;
+            async move {
+                if attempt < 3 {
+                    Err(attempt)
+                } else {
+                    Ok(attempt)
+                }
+            }
+        })
+        .await;
+        assert_eq!(result, Ok(3));
+    }
+}
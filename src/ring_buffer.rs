@@ -0,0 +1,231 @@
+// Copyright 2026, The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+//! A fixed-capacity ring buffer that evicts its oldest item when full.
+
+use alloc::boxed::Box;
+use core::{array, fmt};
+
+/// A callback that is given each item evicted from a full [RingBuffer]
+pub type OverwriteCallback<T> = Box<dyn FnMut(T) + Send>;
+
+/// A ring buffer holding at most `N` items. Pushing onto a full buffer evicts the oldest item. Items are iterated in
+/// insertion order, oldest first.
+///
+/// ```edition2018
+/// # use tari_utilities::ring_buffer::RingBuffer;
+/// let mut recent = RingBuffer::<u32, 3>::new();
+/// for height in 1..=4 {
+///     recent.push(height);
+/// }
+/// assert_eq!(recent.iter().copied().collect::<Vec<_>>(), vec![2, 3, 4]);
+/// ```
+pub struct RingBuffer<T, const N: usize> {
+    items: [Option<T>; N],
+    head: usize,
+    len: usize,
+    on_overwrite: Option<OverwriteCallback<T>>,
+}
+
+impl<T, const N: usize> RingBuffer<T, N> {
+    /// Creates an empty ring buffer.
+    pub fn new() -> Self {
+        Self {
+            items: array::from_fn(|_| None),
+            head: 0,
+            len: 0,
+            on_overwrite: None,
+        }
+    }
+
+    /// Calls `f` with every item that is evicted by a push onto a full buffer, instead of returning it from
+    /// [push](RingBuffer::push).
+    pub fn with_overwrite_callback<F: FnMut(T) + Send + 'static>(mut self, f: F) -> Self {
+        self.on_overwrite = Some(Box::new(f));
+        self
+    }
+
+    /// Returns the maximum number of items the buffer can hold.
+    pub fn capacity(&self) -> usize {
+        N
+    }
+
+    /// Returns the number of items in the buffer.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns true if the buffer holds no items.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns true if the next push will evict the oldest item.
+    pub fn is_full(&self) -> bool {
+        self.len == N
+    }
+
+    /// Appends an item. If the buffer is full, the oldest item is evicted and either passed to the overwrite callback
+    /// or returned. A zero-capacity buffer evicts `val` immediately.
+    pub fn push(&mut self, val: T) -> Option<T> {
+        let evicted = if N == 0 {
+            Some(val)
+        } else if self.is_full() {
+            let evicted = self.items[self.head].replace(val);
+            self.head = (self.head + 1) % N;
+            evicted
+        } else {
+            self.items[(self.head + self.len) % N] = Some(val);
+            self.len += 1;
+            None
+        };
+        match (evicted, self.on_overwrite.as_mut()) {
+            (Some(evicted), Some(f)) => {
+                f(evicted);
+                None
+            },
+            (evicted, _) => evicted,
+        }
+    }
+
+    /// Removes and returns the oldest item.
+    pub fn pop_front(&mut self) -> Option<T> {
+        if self.is_empty() {
+            return None;
+        }
+        let val = self.items[self.head].take();
+        self.head = (self.head + 1) % N;
+        self.len -= 1;
+        val
+    }
+
+    /// Returns the `index`-th oldest item.
+    pub fn get(&self, index: usize) -> Option<&T> {
+        if index >= self.len {
+            return None;
+        }
+        self.items[(self.head + index) % N].as_ref()
+    }
+
+    /// Returns the oldest item.
+    pub fn front(&self) -> Option<&T> {
+        self.get(0)
+    }
+
+    /// Returns the most recently pushed item.
+    pub fn back(&self) -> Option<&T> {
+        self.len.checked_sub(1).and_then(|index| self.get(index))
+    }
+
+    /// Returns an iterator over the items, oldest first.
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = &T> + '_ {
+        (0..self.len).filter_map(move |index| self.get(index))
+    }
+
+    /// Removes every item without calling the overwrite callback.
+    pub fn clear(&mut self) {
+        self.items.iter_mut().for_each(|item| *item = None);
+        self.head = 0;
+        self.len = 0;
+    }
+}
+
+impl<T, const N: usize> Default for RingBuffer<T, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: fmt::Debug, const N: usize> fmt::Debug for RingBuffer<T, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T, const N: usize> Extend<T> for RingBuffer<T, N> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for val in iter {
+            self.push(val);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use alloc::{sync::Arc, vec::Vec};
+    use core::sync::atomic::{AtomicUsize, Ordering};
+
+    use super::*;
+
+    #[test]
+    fn push_evicts_oldest() {
+        let mut buf = RingBuffer::<usize, 3>::new();
+        assert!(buf.is_empty());
+        assert_eq!(buf.push(1), None);
+        assert_eq!(buf.push(2), None);
+        assert_eq!(buf.push(3), None);
+        assert!(buf.is_full());
+        assert_eq!(buf.push(4), Some(1));
+        assert_eq!(buf.push(5), Some(2));
+        assert_eq!(buf.iter().copied().collect::<Vec<_>>(), vec![3, 4, 5]);
+        assert_eq!(buf.iter().rev().copied().collect::<Vec<_>>(), vec![5, 4, 3]);
+        assert_eq!(buf.front(), Some(&3));
+        assert_eq!(buf.back(), Some(&5));
+        assert_eq!(buf.get(3), None);
+        assert_eq!(format!("{:?}", buf), "[3, 4, 5]");
+    }
+
+    #[test]
+    fn pop_and_clear() {
+        let mut buf = RingBuffer::<usize, 2>::default();
+        buf.extend(0..5);
+        assert_eq!(buf.pop_front(), Some(3));
+        assert_eq!(buf.len(), 1);
+        buf.push(5);
+        assert_eq!(buf.iter().copied().collect::<Vec<_>>(), vec![4, 5]);
+        buf.clear();
+        assert!(buf.is_empty());
+        assert_eq!(buf.pop_front(), None);
+        assert_eq!(buf.back(), None);
+    }
+
+    #[test]
+    fn zero_capacity() {
+        let mut buf = RingBuffer::<usize, 0>::new();
+        assert_eq!(buf.push(1), Some(1));
+        assert!(buf.is_empty());
+        assert_eq!(buf.pop_front(), None);
+    }
+
+    #[test]
+    fn overwrite_callback() {
+        let evicted = Arc::new(AtomicUsize::new(0));
+        let counter = evicted.clone();
+        let mut buf = RingBuffer::<usize, 2>::new().with_overwrite_callback(move |val| {
+            counter.fetch_add(val, Ordering::SeqCst);
+        });
+        buf.extend(1..=4);
+        assert_eq!(evicted.load(Ordering::SeqCst), 3);
+        assert_eq!(buf.push(5), None);
+        assert_eq!(evicted.load(Ordering::SeqCst), 6);
+    }
+}
@@ -22,7 +22,10 @@
 
 //! An array-like type with safety features that make it suitable for cryptographic keys.
 
+#[cfg(not(all(feature = "guarded", unix)))]
 use alloc::vec::Vec;
+#[cfg(feature = "track-secrets")]
+use core::mem::size_of;
 use core::{
     fmt::Debug,
     ops::{Deref, DerefMut},
@@ -43,7 +46,8 @@ use zeroize::Zeroize;
 /// Further, you get `Default` for handy instantiation, as well as `Clone`.
 /// It automatically handles equality checking in constant time.
 ///
-/// Under the hood, it's just `Vec<T>`, but don't tell anybody.
+/// Under the hood, it's just `Vec<T>`, but don't tell anybody. With the `guarded` feature, it is a
+/// [GuardedBox](crate::guarded::GuardedBox) instead, which puts guard pages around the data.
 ///
 /// It's recommended that you use it as part of a `Hidden` type when you need a cryptographic key, like this:
 ///
@@ -68,14 +72,36 @@ use zeroize::Zeroize;
 /// rng.fill_bytes(key.reveal_mut());
 /// }
 /// ```
-#[derive(Clone, Debug)]
-pub struct SafeArray<T, const N: usize>(Vec<T>);
+#[derive(Debug)]
+pub struct SafeArray<T, const N: usize>(Storage<T>);
+
+#[cfg(not(all(feature = "guarded", unix)))]
+type Storage<T> = Vec<T>;
+#[cfg(all(feature = "guarded", unix))]
+type Storage<T> = crate::guarded::GuardedBox<[T]>;
 
 impl<T, const N: usize> SafeArray<T, N> {
     /// The fixed number of elements
     pub const LEN: usize = N;
 }
 
+impl<T, const N: usize> Clone for SafeArray<T, N>
+where T: Clone
+{
+    fn clone(&self) -> Self {
+        #[cfg(feature = "track-secrets")]
+        crate::secret_stats::safe_array_created(N * size_of::<T>());
+        Self(self.0.clone())
+    }
+}
+
+impl<T, const N: usize> Drop for SafeArray<T, N> {
+    fn drop(&mut self) {
+        #[cfg(feature = "track-secrets")]
+        crate::secret_stats::safe_array_dropped(N * size_of::<T>());
+    }
+}
+
 impl<T, const N: usize> AsRef<[T]> for SafeArray<T, N> {
     fn as_ref(&self) -> &[T] {
         &self.0
@@ -107,7 +133,8 @@ impl<T, const N: usize> Zeroize for SafeArray<T, N>
 where T: Zeroize
 {
     fn zeroize(&mut self) {
-        self.0.zeroize();
+        // Zeroize in place, keeping the fixed length
+        self.0.iter_mut().zeroize();
     }
 }
 
@@ -115,7 +142,13 @@ impl<T, const N: usize> Default for SafeArray<T, N>
 where T: Clone + Default
 {
     fn default() -> Self {
-        Self(vec![T::default(); N])
+        #[cfg(feature = "track-secrets")]
+        crate::secret_stats::safe_array_created(N * size_of::<T>());
+        #[cfg(not(all(feature = "guarded", unix)))]
+        let storage = vec![T::default(); N];
+        #[cfg(all(feature = "guarded", unix))]
+        let storage = Storage::from_fn(N, |_| T::default());
+        Self(storage)
     }
 }
 
@@ -170,4 +203,16 @@ mod tests {
         assert_eq!(SafeArray::<u8, N>::default().len(), N);
         assert_eq!(SafeArray::<u8, 64>::LEN, N);
     }
+
+    #[test]
+    #[cfg(feature = "zeroize")]
+    fn zeroize() {
+        let mut array = SafeArray::<u8, 4>::default();
+        array.copy_from_slice(&[1, 2, 3, 4]);
+        let mut clone = array.clone();
+        clone.zeroize();
+        assert_eq!(clone.as_ref(), &[0u8; 4]);
+        assert_eq!(clone.len(), SafeArray::<u8, 4>::LEN);
+        assert_eq!(array.as_ref(), &[1, 2, 3, 4]);
+    }
 }
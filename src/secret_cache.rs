@@ -0,0 +1,303 @@
+// Copyright 2026, The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+//! A bounded, thread-safe cache for secrets such as session keys.
+//!
+//! Values are stored as [Hidden] data, so they are zeroized whenever they leave the cache: when they are replaced,
+//! removed, evicted to make room for a new entry, purged after their time-to-live expires, or when the cache itself is
+//! dropped. Values can only be accessed by reference from within a closure, or by cloning them into a new [Hidden].
+//!
+//! ```edition2018
+//! # use std::time::Duration;
+//! # use tari_utilities::secret_cache::SecretCache;
+//! let cache = SecretCache::new(16, Some(Duration::from_secs(300)));
+//! cache.insert("session-1", vec![1u8; 32]);
+//!
+//! assert_eq!(cache.with("session-1", |key| key.len()), Some(32));
+//! assert!(cache.remove(&"session-1"));
+//! assert!(cache.with("session-1", |key| key.len()).is_none());
+//! ```
+
+use core::{borrow::Borrow, hash::Hash, time::Duration};
+use std::{collections::HashMap, sync::RwLock, time::Instant};
+
+use zeroize::Zeroize;
+
+use crate::{
+    hidden::Hidden,
+    locks::poison::{recover_read_lock, recover_write_lock},
+};
+
+struct Entry<V: Zeroize> {
+    value: Hidden<V>,
+    expires_at: Option<Instant>,
+    sequence: u64,
+}
+
+impl<V: Zeroize> Entry<V> {
+    fn is_expired(&self, now: Instant) -> bool {
+        self.expires_at.map(|t| t <= now).unwrap_or(false)
+    }
+}
+
+struct Inner<K, V: Zeroize> {
+    entries: HashMap<K, Entry<V>>,
+    next_sequence: u64,
+}
+
+/// A bounded concurrent map whose values are zeroized when they leave the cache. See the
+/// [module documentation](self) for details.
+///
+/// When the cache is full, inserting a new key first purges expired entries, and then evicts the least recently
+/// inserted entry. Eviction scans all entries, so the cache is intended for modest capacities.
+pub struct SecretCache<K, V: Zeroize> {
+    inner: RwLock<Inner<K, V>>,
+    capacity: usize,
+    ttl: Option<Duration>,
+}
+
+impl<K, V> SecretCache<K, V>
+where
+    K: Hash + Eq + Clone,
+    V: Zeroize,
+{
+    /// Create a cache that holds at most `capacity` entries, each of which expires `ttl` after it was inserted. A
+    /// `ttl` of `None` means entries never expire.
+    pub fn new(capacity: usize, ttl: Option<Duration>) -> Self {
+        Self {
+            inner: RwLock::new(Inner {
+                entries: HashMap::with_capacity(capacity),
+                next_sequence: 0,
+            }),
+            capacity,
+            ttl,
+        }
+    }
+
+    /// The maximum number of entries the cache holds
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// The time-to-live of new entries
+    pub fn ttl(&self) -> Option<Duration> {
+        self.ttl
+    }
+
+    /// Insert a value, replacing (and zeroizing) any existing value for the key. If the cache is full, expired entries
+    /// are purged and then the oldest entry is evicted to make room. A cache with zero capacity stores nothing.
+    pub fn insert(&self, key: K, value: V) {
+        self.insert_hidden(key, Hidden::hide(value));
+    }
+
+    /// Insert a value that is already hidden. See [insert](Self::insert).
+    pub fn insert_hidden(&self, key: K, value: Hidden<V>) {
+        if self.capacity == 0 {
+            return;
+        }
+        let now = Instant::now();
+        let mut inner = recover_write_lock(&self.inner);
+        if !inner.entries.contains_key(&key) && inner.entries.len() >= self.capacity {
+            inner.entries.retain(|_, entry| !entry.is_expired(now));
+            if inner.entries.len() >= self.capacity {
+                let oldest = inner
+                    .entries
+                    .iter()
+                    .min_by_key(|(_, entry)| entry.sequence)
+                    .map(|(k, _)| k.clone());
+                if let Some(oldest) = oldest {
+                    inner.entries.remove(&oldest);
+                }
+            }
+        }
+        let sequence = inner.next_sequence;
+        inner.next_sequence += 1;
+        inner.entries.insert(key, Entry {
+            value,
+            expires_at: self.ttl.and_then(|ttl| now.checked_add(ttl)),
+            sequence,
+        });
+    }
+
+    /// Call `f` with a reference to the value for `key`, if it is present and has not expired.
+    pub fn with<Q, R, F>(&self, key: &Q, f: F) -> Option<R>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+        F: FnOnce(&V) -> R,
+    {
+        let inner = recover_read_lock(&self.inner);
+        inner
+            .entries
+            .get(key)
+            .filter(|entry| !entry.is_expired(Instant::now()))
+            .map(|entry| f(entry.value.reveal()))
+    }
+
+    /// Return a hidden copy of the value for `key`, if it is present and has not expired.
+    pub fn get_cloned<Q>(&self, key: &Q) -> Option<Hidden<V>>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+        V: Clone,
+    {
+        let inner = recover_read_lock(&self.inner);
+        inner
+            .entries
+            .get(key)
+            .filter(|entry| !entry.is_expired(Instant::now()))
+            .map(|entry| entry.value.clone())
+    }
+
+    /// Returns true if the cache holds an unexpired value for `key`
+    pub fn contains_key<Q>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.with(key, |_| ()).is_some()
+    }
+
+    /// Remove and zeroize the value for `key`. Returns true if an unexpired value was present.
+    pub fn remove<Q>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let mut inner = recover_write_lock(&self.inner);
+        inner
+            .entries
+            .remove(key)
+            .map(|entry| !entry.is_expired(Instant::now()))
+            .unwrap_or(false)
+    }
+
+    /// Remove and zeroize all expired entries, returning the number of entries removed
+    pub fn purge_expired(&self) -> usize {
+        let now = Instant::now();
+        let mut inner = recover_write_lock(&self.inner);
+        let before = inner.entries.len();
+        inner.entries.retain(|_, entry| !entry.is_expired(now));
+        before - inner.entries.len()
+    }
+
+    /// Remove and zeroize all entries
+    pub fn clear(&self) {
+        recover_write_lock(&self.inner).entries.clear();
+    }
+
+    /// The number of entries in the cache, including any that have expired but not yet been purged
+    pub fn len(&self) -> usize {
+        recover_read_lock(&self.inner).entries.len()
+    }
+
+    /// Returns true if the cache has no entries
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use alloc::{string::String, vec::Vec};
+    use std::{sync::Arc, thread};
+
+    use super::*;
+
+    #[test]
+    fn insert_and_access() {
+        let cache = SecretCache::new(2, None);
+        cache.insert(String::from("a"), vec![1u8; 4]);
+        assert_eq!(cache.with("a", |v| v.clone()), Some(vec![1u8; 4]));
+        assert_eq!(cache.get_cloned("a").unwrap().reveal(), &vec![1u8; 4]);
+        cache.insert(String::from("a"), vec![2u8; 4]);
+        assert_eq!(cache.len(), 1);
+        assert_eq!(cache.with("a", |v| v[0]), Some(2));
+        assert!(cache.contains_key("a"));
+        assert!(cache.remove("a"));
+        assert!(!cache.remove("a"));
+        assert!(cache.is_empty());
+    }
+
+    #[test]
+    fn evicts_oldest() {
+        let cache = SecretCache::new(2, None);
+        cache.insert(1, [1u8; 32]);
+        cache.insert(2, [2u8; 32]);
+        cache.insert(1, [3u8; 32]);
+        cache.insert(3, [4u8; 32]);
+        assert_eq!(cache.len(), 2);
+        assert!(!cache.contains_key(&2));
+        assert!(cache.contains_key(&1));
+        assert!(cache.contains_key(&3));
+    }
+
+    #[test]
+    fn expiry() {
+        let cache = SecretCache::new(2, Some(Duration::from_millis(10)));
+        cache.insert(1, 1u64);
+        assert!(cache.contains_key(&1));
+        thread::sleep(Duration::from_millis(20));
+        assert!(!cache.contains_key(&1));
+        assert!(cache.get_cloned(&1).is_none());
+        assert_eq!(cache.len(), 1);
+        assert_eq!(cache.purge_expired(), 1);
+        assert!(cache.is_empty());
+
+        // Expired entries are purged before a live entry is evicted
+        cache.insert(1, 1u64);
+        thread::sleep(Duration::from_millis(20));
+        cache.insert(2, 2u64);
+        cache.insert(3, 3u64);
+        assert!(cache.contains_key(&2));
+        assert!(cache.contains_key(&3));
+    }
+
+    #[test]
+    fn zero_capacity() {
+        let cache = SecretCache::new(0, None);
+        cache.insert(1, 1u8);
+        assert!(cache.is_empty());
+    }
+
+    #[test]
+    fn concurrent_access() {
+        let cache = Arc::new(SecretCache::new(8, None));
+        let handles = (0..4u8)
+            .map(|i| {
+                let cache = cache.clone();
+                thread::spawn(move || {
+                    for j in 0..100u8 {
+                        cache.insert(i, vec![j; 8]);
+                        assert!(cache.with(&i, |v| v.len()).is_some());
+                    }
+                })
+            })
+            .collect::<Vec<_>>();
+        for handle in handles {
+            handle.join().unwrap();
+        }
+        assert_eq!(cache.len(), 4);
+        cache.clear();
+        assert!(cache.is_empty());
+    }
+}
@@ -0,0 +1,146 @@
+// Copyright 2026, The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+//! Counts of the secret values that are currently alive, for tracking down secrets that are held longer than
+//! intended.
+//!
+//! With the `track-secrets` feature, every [Hidden](crate::hidden::Hidden) and
+//! [SafeArray](crate::safe_array::SafeArray) records itself here when it is created and when it is dropped. The byte
+//! counts are the sizes of the secret values themselves, not including any heap data they own.
+//!
+//! ```edition2018
+//! # use tari_utilities::{safe_array::SafeArray, secret_stats, Hidden};
+//! let before = secret_stats::stats();
+//! let secret = Hidden::hide([0u8; 32]);
+//! let key = SafeArray::<u16, 8>::default();
+//! let copy = key.clone();
+//! let during = secret_stats::stats();
+//! assert_eq!(during.live_hidden - before.live_hidden, 1);
+//! assert_eq!(during.hidden_bytes - before.hidden_bytes, 32);
+//! assert_eq!(during.live_safe_arrays - before.live_safe_arrays, 2);
+//! assert_eq!(during.safe_array_bytes - before.safe_array_bytes, 32);
+//! drop((secret, key, copy));
+//! assert_eq!(secret_stats::stats(), before);
+//! ```
+
+use core::sync::atomic::{AtomicUsize, Ordering};
+
+static LIVE_HIDDEN: AtomicUsize = AtomicUsize::new(0);
+static HIDDEN_BYTES: AtomicUsize = AtomicUsize::new(0);
+static LIVE_SAFE_ARRAYS: AtomicUsize = AtomicUsize::new(0);
+static SAFE_ARRAY_BYTES: AtomicUsize = AtomicUsize::new(0);
+
+/// A snapshot of the secret values alive in the process.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct SecretStats {
+    /// The number of live `Hidden` values
+    pub live_hidden: usize,
+    /// The total size in bytes of the values held by live `Hidden` values
+    pub hidden_bytes: usize,
+    /// The number of live `SafeArray` values
+    pub live_safe_arrays: usize,
+    /// The total size in bytes of the elements of live `SafeArray` values
+    pub safe_array_bytes: usize,
+}
+
+impl SecretStats {
+    /// Returns the number of live secret values of all types.
+    pub fn live(&self) -> usize {
+        self.live_hidden + self.live_safe_arrays
+    }
+
+    /// Returns the total size in bytes of all live secret values.
+    pub fn total_bytes(&self) -> usize {
+        self.hidden_bytes + self.safe_array_bytes
+    }
+}
+
+/// Returns a snapshot of the secret values currently alive. The counters are updated independently, so a snapshot taken
+/// while other threads create or drop secrets may mix counts from slightly different moments.
+pub fn stats() -> SecretStats {
+    SecretStats {
+        live_hidden: LIVE_HIDDEN.load(Ordering::Relaxed),
+        hidden_bytes: HIDDEN_BYTES.load(Ordering::Relaxed),
+        live_safe_arrays: LIVE_SAFE_ARRAYS.load(Ordering::Relaxed),
+        safe_array_bytes: SAFE_ARRAY_BYTES.load(Ordering::Relaxed),
+    }
+}
+
+#[cfg(feature = "zeroize")]
+pub(crate) fn hidden_created(bytes: usize) {
+    LIVE_HIDDEN.fetch_add(1, Ordering::Relaxed);
+    HIDDEN_BYTES.fetch_add(bytes, Ordering::Relaxed);
+}
+
+#[cfg(feature = "zeroize")]
+pub(crate) fn hidden_dropped(bytes: usize) {
+    saturating_sub(&LIVE_HIDDEN, 1);
+    saturating_sub(&HIDDEN_BYTES, bytes);
+}
+
+#[cfg(feature = "std")]
+pub(crate) fn safe_array_created(bytes: usize) {
+    LIVE_SAFE_ARRAYS.fetch_add(1, Ordering::Relaxed);
+    SAFE_ARRAY_BYTES.fetch_add(bytes, Ordering::Relaxed);
+}
+
+#[cfg(feature = "std")]
+pub(crate) fn safe_array_dropped(bytes: usize) {
+    saturating_sub(&LIVE_SAFE_ARRAYS, 1);
+    saturating_sub(&SAFE_ARRAY_BYTES, bytes);
+}
+
+/// Decrements a counter without wrapping, so a miscounted drop shows up as a low count rather than a huge one.
+#[cfg(any(feature = "zeroize", feature = "std"))]
+fn saturating_sub(counter: &AtomicUsize, n: usize) {
+    // The closure always returns `Some`, so the update cannot fail
+    let _ = counter.fetch_update(Ordering::Relaxed, Ordering::Relaxed, |count| {
+        Some(count.saturating_sub(n))
+    });
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn totals() {
+        let stats = SecretStats {
+            live_hidden: 2,
+            hidden_bytes: 64,
+            live_safe_arrays: 1,
+            safe_array_bytes: 32,
+        };
+        assert_eq!(stats.live(), 3);
+        assert_eq!(stats.total_bytes(), 96);
+    }
+
+    #[test]
+    #[cfg(any(feature = "zeroize", feature = "std"))]
+    fn decrements_saturate() {
+        let counter = AtomicUsize::new(3);
+        saturating_sub(&counter, 2);
+        assert_eq!(counter.load(Ordering::Relaxed), 1);
+        saturating_sub(&counter, 2);
+        assert_eq!(counter.load(Ordering::Relaxed), 0);
+    }
+}
@@ -0,0 +1,142 @@
+// Copyright 2026, The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+//! A module for serializing byte payloads as raw bytes.
+//!
+//! By default serde treats a `Vec<u8>` as a sequence, so binary formats such as bincode encode and decode it one
+//! element at a time. Annotating a field with `#[serde(with = "tari_utilities::serde::bytes")]` serializes it as a
+//! single byte string instead, which bincode writes with one copy. The bincode encoding is unchanged, so existing
+//! messages remain readable, and human readable formats still see an array of numbers.
+//!
+//! ```edition2018
+//! # use serde::{Deserialize, Serialize};
+//! # use tari_utilities::message_format::MessageFormat;
+//! #[derive(Debug, Deserialize, Serialize, PartialEq)]
+//! struct Envelope {
+//!     #[serde(with = "tari_utilities::serde::bytes")]
+//!     payload: Vec<u8>,
+//! }
+//!
+//! let envelope = Envelope {
+//!     payload: vec![1, 2, 3],
+//! };
+//! let binary = envelope.to_binary().unwrap();
+//! assert_eq!(binary, bincode::serialize(&vec![1u8, 2, 3]).unwrap());
+//! assert_eq!(Envelope::from_binary(&binary).unwrap(), envelope);
+//! ```
+
+use alloc::vec::Vec;
+use core::{fmt, marker::PhantomData};
+
+use serde::{
+    de::{Error, SeqAccess, Visitor},
+    Deserializer,
+    Serializer,
+};
+
+/// Serializes anything that can be viewed as a byte slice as raw bytes.
+pub fn serialize<S, T>(data: &T, ser: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+    T: AsRef<[u8]> + ?Sized,
+{
+    ser.serialize_bytes(data.as_ref())
+}
+
+/// Deserializes raw bytes, or a sequence of bytes, into any type that can be built from a `Vec<u8>`.
+pub fn deserialize<'de, D, T>(de: D) -> Result<T, D::Error>
+where
+    D: Deserializer<'de>,
+    T: From<Vec<u8>>,
+{
+    de.deserialize_byte_buf(BytesVisitor::default())
+}
+
+struct BytesVisitor<T> {
+    _target: PhantomData<T>,
+}
+
+impl<T> Default for BytesVisitor<T> {
+    fn default() -> Self {
+        Self { _target: PhantomData }
+    }
+}
+
+impl<'de, T> Visitor<'de> for BytesVisitor<T>
+where T: From<Vec<u8>>
+{
+    type Value = T;
+
+    fn expecting(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.write_str("Expecting a byte array")
+    }
+
+    fn visit_bytes<E>(self, v: &[u8]) -> Result<Self::Value, E>
+    where E: Error {
+        Ok(T::from(v.to_vec()))
+    }
+
+    fn visit_byte_buf<E>(self, v: Vec<u8>) -> Result<Self::Value, E>
+    where E: Error {
+        Ok(T::from(v))
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where A: SeqAccess<'de> {
+        let mut bytes = Vec::with_capacity(seq.size_hint().unwrap_or(0).min(4096));
+        while let Some(byte) = seq.next_element()? {
+            bytes.push(byte);
+        }
+        Ok(T::from(bytes))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Debug, Deserialize, Serialize, PartialEq, Eq)]
+    struct Payload(#[serde(with = "super")] Vec<u8>);
+
+    #[derive(Debug, Deserialize, Serialize, PartialEq, Eq)]
+    struct PlainPayload(Vec<u8>);
+
+    #[test]
+    fn binary_matches_plain_vec() {
+        let data = (0..=255u8).collect::<Vec<_>>();
+        let encoded = bincode::serialize(&Payload(data.clone())).unwrap();
+        assert_eq!(encoded, bincode::serialize(&PlainPayload(data.clone())).unwrap());
+        let restored: Payload = bincode::deserialize(&encoded).unwrap();
+        assert_eq!(restored.0, data);
+    }
+
+    #[test]
+    fn human_readable_matches_plain_vec() {
+        let json = serde_json::to_string(&Payload(vec![1, 2, 255])).unwrap();
+        assert_eq!(json, "[1,2,255]");
+        let restored: Payload = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored, Payload(vec![1, 2, 255]));
+        assert!(serde_json::from_str::<Payload>("[256]").is_err());
+    }
+}
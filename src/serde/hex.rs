@@ -24,6 +24,9 @@
 //!
 //! It stores bytes as hex for human readable formats and
 //! uses bytes for binary formats.
+//!
+//! Hex strings are decoded in [DecodeMode::Strict] mode, as [Hex::from_hex] does. Use [lenient] to also accept
+//! surrounding whitespace, upper case digits and a `0x` prefix, for example in hand-edited config files.
 
 use alloc::string::{String, ToString};
 use core::{fmt, marker::PhantomData};
@@ -36,7 +39,8 @@ use serde::{
 
 use crate::{
     byte_array::ByteArray,
-    hex::{from_hex, Hex},
+    encoding::DecodeMode,
+    hex::{from_hex_with_mode, Hex},
 };
 
 /// Serializes a [`ByteArray`] to a hex string or a binary array.
@@ -58,7 +62,43 @@ where
     D: Deserializer<'de>,
     T: ByteArray,
 {
-    let visitor = HexVisitor::default();
+    deserialize_with_mode(de, DecodeMode::Strict)
+}
+
+/// Like the parent module, but decodes hex strings in [DecodeMode::Lenient] mode. Serialization is unchanged.
+pub mod lenient {
+    use serde::{Deserializer, Serializer};
+
+    use crate::{byte_array::ByteArray, encoding::DecodeMode};
+
+    /// Serializes a [`ByteArray`] to a hex string or a binary array.
+    pub fn serialize<S, T>(data: &T, ser: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+        T: ByteArray,
+    {
+        super::serialize(data, ser)
+    }
+
+    /// Deserializes a [`ByteArray`] from a hex string, decoded leniently, or a binary array.
+    pub fn deserialize<'de, D, T>(de: D) -> Result<T, D::Error>
+    where
+        D: Deserializer<'de>,
+        T: ByteArray,
+    {
+        super::deserialize_with_mode(de, DecodeMode::Lenient)
+    }
+}
+
+fn deserialize_with_mode<'de, D, T>(de: D, mode: DecodeMode) -> Result<T, D::Error>
+where
+    D: Deserializer<'de>,
+    T: ByteArray,
+{
+    let visitor = HexVisitor {
+        mode,
+        _target: PhantomData,
+    };
     if de.is_human_readable() {
         de.deserialize_string(visitor)
     } else {
@@ -67,15 +107,10 @@ where
 }
 
 struct HexVisitor<T> {
+    mode: DecodeMode,
     _target: PhantomData<T>,
 }
 
-impl<T> Default for HexVisitor<T> {
-    fn default() -> Self {
-        Self { _target: PhantomData }
-    }
-}
-
 impl<'de, T> Visitor<'de> for HexVisitor<T>
 where T: ByteArray
 {
@@ -87,7 +122,7 @@ where T: ByteArray
 
     fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
     where E: Error {
-        let bytes = from_hex(v).map_err(|e| E::custom(e.to_string()))?;
+        let bytes = from_hex_with_mode(v, self.mode).map_err(|e| E::custom(e.to_string()))?;
         self.visit_bytes(&bytes)
     }
 
@@ -117,6 +152,9 @@ mod tests {
     #[derive(Debug, Deserialize, Serialize, PartialEq, Eq)]
     struct HexOrBytes(#[serde(with = "super")] [u8; 4]);
 
+    #[derive(Debug, Deserialize, Serialize, PartialEq, Eq)]
+    struct LenientHex(#[serde(with = "super::lenient")] [u8; 4]);
+
     #[test]
     fn check_serde_hex_human_readable() {
         let hex_or_bytes = HexOrBytes([1, 2, 3, 255]);
@@ -136,4 +174,12 @@ mod tests {
         let restored: HexOrBytes = bincode::deserialize(&expected).unwrap();
         assert_eq!(hex_or_bytes, restored);
     }
+
+    #[test]
+    fn check_serde_hex_lenient() {
+        assert!(serde_json::from_str::<HexOrBytes>("\"0x010203FF\"").is_err());
+        let restored: LenientHex = serde_json::from_str("\" 0x010203FF \"").unwrap();
+        assert_eq!(restored, LenientHex([1, 2, 3, 255]));
+        assert_eq!(serde_json::to_string(&restored).unwrap(), "\"010203ff\"");
+    }
 }
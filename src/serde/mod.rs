@@ -22,4 +22,5 @@
 
 //! A module with serialization utilities.
 
+pub mod bytes;
 pub mod hex;
@@ -0,0 +1,282 @@
+// Copyright 2026, The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+//! A fixed-capacity vector that keeps its items sorted.
+
+use alloc::vec::Vec;
+
+use snafu::prelude::*;
+
+/// Errors that can occur when inserting into a [SortedVec].
+#[derive(Debug, Snafu, PartialEq, Eq)]
+pub enum SortedVecError {
+    /// The vector already holds its maximum number of items.
+    #[snafu(display("The sorted vector is full (capacity {capacity})"))]
+    Full {
+        /// The capacity of the vector
+        capacity: usize,
+    },
+    /// An equal item is already present and duplicates are rejected.
+    #[snafu(display("An equal item is already present"))]
+    Duplicate {},
+}
+
+/// How a [SortedVec] handles inserting an item equal to one it already holds.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Duplicates {
+    /// Keep both items. The new item is placed after any equal items.
+    #[default]
+    Allow,
+    /// Refuse the new item.
+    Reject,
+    /// Replace the existing item with the new one.
+    Replace,
+}
+
+/// A vector holding at most `N` items in ascending order. Lookups use binary search.
+///
+/// ```edition2018
+/// # use tari_utilities::sorted_vec::{Duplicates, SortedVec};
+/// let mut fees = SortedVec::<u64, 4>::new(Duplicates::Reject);
+/// fees.insert(30).unwrap();
+/// fees.insert(10).unwrap();
+/// fees.insert(20).unwrap();
+/// assert!(fees.insert(20).is_err());
+/// assert_eq!(fees.as_slice(), &[10, 20, 30]);
+/// assert!(fees.contains(&20));
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SortedVec<T, const N: usize> {
+    items: Vec<T>,
+    duplicates: Duplicates,
+}
+
+impl<T: Ord, const N: usize> SortedVec<T, N> {
+    /// Creates an empty vector with the given duplicate handling.
+    pub fn new(duplicates: Duplicates) -> Self {
+        Self {
+            items: Vec::with_capacity(N),
+            duplicates,
+        }
+    }
+
+    /// Returns the maximum number of items the vector can hold.
+    pub fn capacity(&self) -> usize {
+        N
+    }
+
+    /// Returns how the vector handles duplicate items.
+    pub fn duplicates(&self) -> Duplicates {
+        self.duplicates
+    }
+
+    /// Returns the number of items in the vector.
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    /// Returns true if the vector holds no items.
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    /// Returns true if no more items can be inserted.
+    pub fn is_full(&self) -> bool {
+        self.items.len() >= N
+    }
+
+    /// Inserts `val` in sorted position, returning that position. Replacing a duplicate succeeds even when the vector
+    /// is full.
+    pub fn insert(&mut self, val: T) -> Result<usize, SortedVecError> {
+        match self.items.binary_search(&val) {
+            Ok(index) => match self.duplicates {
+                Duplicates::Reject => DuplicateSnafu.fail(),
+                Duplicates::Replace => {
+                    self.items[index] = val;
+                    Ok(index)
+                },
+                Duplicates::Allow => {
+                    let index = self.items.partition_point(|item| item <= &val);
+                    self.insert_at(index, val)
+                },
+            },
+            Err(index) => self.insert_at(index, val),
+        }
+    }
+
+    /// Searches for `val`, returning its position, or the position at which it could be inserted.
+    pub fn binary_search(&self, val: &T) -> Result<usize, usize> {
+        self.items.binary_search(val)
+    }
+
+    /// Returns true if an item equal to `val` is present.
+    pub fn contains(&self, val: &T) -> bool {
+        self.binary_search(val).is_ok()
+    }
+
+    /// Removes an item equal to `val`, returning it.
+    pub fn remove(&mut self, val: &T) -> Option<T> {
+        let index = self.binary_search(val).ok()?;
+        Some(self.items.remove(index))
+    }
+
+    /// Removes and returns the item at `index`.
+    pub fn remove_at(&mut self, index: usize) -> Option<T> {
+        (index < self.items.len()).then(|| self.items.remove(index))
+    }
+
+    /// Returns the item at `index`.
+    pub fn get(&self, index: usize) -> Option<&T> {
+        self.items.get(index)
+    }
+
+    /// Returns the smallest item.
+    pub fn first(&self) -> Option<&T> {
+        self.items.first()
+    }
+
+    /// Returns the largest item.
+    pub fn last(&self) -> Option<&T> {
+        self.items.last()
+    }
+
+    /// Removes and returns the largest item.
+    pub fn pop_last(&mut self) -> Option<T> {
+        self.items.pop()
+    }
+
+    /// Returns the items in ascending order.
+    pub fn as_slice(&self) -> &[T] {
+        &self.items
+    }
+
+    /// Returns an iterator over the items in ascending order.
+    pub fn iter(&self) -> core::slice::Iter<'_, T> {
+        self.items.iter()
+    }
+
+    /// Keeps only the items for which `f` returns true.
+    pub fn retain<F: FnMut(&T) -> bool>(&mut self, f: F) {
+        self.items.retain(f);
+    }
+
+    /// Removes every item.
+    pub fn clear(&mut self) {
+        self.items.clear();
+    }
+
+    /// Returns the items as a sorted `Vec`.
+    pub fn into_vec(self) -> Vec<T> {
+        self.items
+    }
+
+    fn insert_at(&mut self, index: usize, val: T) -> Result<usize, SortedVecError> {
+        ensure!(!self.is_full(), FullSnafu { capacity: N });
+        self.items.insert(index, val);
+        Ok(index)
+    }
+}
+
+impl<T: Ord, const N: usize> Default for SortedVec<T, N> {
+    fn default() -> Self {
+        Self::new(Duplicates::default())
+    }
+}
+
+impl<'a, T, const N: usize> IntoIterator for &'a SortedVec<T, N> {
+    type IntoIter = core::slice::Iter<'a, T>;
+    type Item = &'a T;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.items.iter()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn keeps_order() {
+        let mut v = SortedVec::<u32, 5>::default();
+        assert_eq!(v.insert(5), Ok(0));
+        assert_eq!(v.insert(1), Ok(0));
+        assert_eq!(v.insert(3), Ok(1));
+        assert_eq!(v.insert(3), Ok(2));
+        assert_eq!(v.as_slice(), &[1, 3, 3, 5]);
+        assert_eq!(v.first(), Some(&1));
+        assert_eq!(v.last(), Some(&5));
+        assert_eq!(v.binary_search(&4), Err(3));
+        assert_eq!(v.insert(9), Ok(4));
+        assert!(v.is_full());
+        assert_eq!(v.insert(0), Err(SortedVecError::Full { capacity: 5 }));
+        assert_eq!(v.pop_last(), Some(9));
+        assert_eq!(v.remove(&3), Some(3));
+        assert_eq!(v.remove(&4), None);
+        assert_eq!(v.remove_at(5), None);
+        assert_eq!(v.remove_at(0), Some(1));
+        assert_eq!(v.iter().copied().collect::<Vec<_>>(), vec![3, 5]);
+        v.retain(|x| *x > 3);
+        assert_eq!(v.into_vec(), vec![5]);
+    }
+
+    #[test]
+    fn duplicate_policies() {
+        #[derive(Debug, Clone, Copy)]
+        struct Slot(u32, &'static str);
+        impl PartialEq for Slot {
+            fn eq(&self, other: &Self) -> bool {
+                self.0 == other.0
+            }
+        }
+        impl Eq for Slot {}
+        impl PartialOrd for Slot {
+            fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+        impl Ord for Slot {
+            fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+                self.0.cmp(&other.0)
+            }
+        }
+
+        let mut v = SortedVec::<Slot, 2>::new(Duplicates::Reject);
+        v.insert(Slot(1, "a")).unwrap();
+        assert_eq!(v.insert(Slot(1, "b")), Err(SortedVecError::Duplicate {}));
+
+        let mut v = SortedVec::<Slot, 1>::new(Duplicates::Replace);
+        assert_eq!(v.duplicates(), Duplicates::Replace);
+        v.insert(Slot(1, "a")).unwrap();
+        assert_eq!(v.insert(Slot(1, "b")), Ok(0));
+        assert_eq!(v.get(0).unwrap().1, "b");
+        assert_eq!(v.len(), 1);
+
+        let mut v = SortedVec::<Slot, 3>::new(Duplicates::Allow);
+        v.insert(Slot(1, "a")).unwrap();
+        v.insert(Slot(2, "c")).unwrap();
+        assert_eq!(v.insert(Slot(1, "b")), Ok(1));
+        assert_eq!(v.iter().map(|s| s.1).collect::<Vec<_>>(), vec!["a", "b", "c"]);
+        v.clear();
+        assert!(v.is_empty());
+    }
+}
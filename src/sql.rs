@@ -0,0 +1,212 @@
+// Copyright 2026, The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+//! Wrapper types for storing [ByteArray] values in SQL columns through `sqlx`.
+//!
+//! [HexText] stores a value as a hex-encoded `TEXT` column and [Blob] stores it as a raw binary column. Both are
+//! generic over the `sqlx` database, and are supported by any driver that can encode and decode strings and byte
+//! vectors respectively. Decoding validates the column contents, so a malformed hex string or a value of the wrong
+//! length is reported as a decode error rather than a panic.
+//!
+//! ```edition2018
+//! # use tari_utilities::sql::{Blob, HexText};
+//! // Bind `HexText(key)` or `Blob(key)` as a query parameter, or read either back with `query_scalar`
+//! let key = HexText([1u8; 32]);
+//! assert_eq!(key.into_inner(), [1u8; 32]);
+//! let key = Blob::from([2u8; 32]);
+//! assert_eq!(*key, [2u8; 32]);
+//! ```
+
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::ops::{Deref, DerefMut};
+
+use sqlx::{encode::IsNull, error::BoxDynError, Database, Decode, Encode, Type};
+
+use crate::{hex::Hex, ByteArray};
+
+/// A [ByteArray] value stored as a hex-encoded `TEXT` column.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct HexText<T>(pub T);
+
+/// A [ByteArray] value stored as a binary (`BLOB` or `BYTEA`) column.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Blob<T>(pub T);
+
+macro_rules! impl_wrapper {
+    ($name:ident) => {
+        impl<T> $name<T> {
+            /// Return the wrapped value.
+            pub fn into_inner(self) -> T {
+                self.0
+            }
+        }
+
+        impl<T> From<T> for $name<T> {
+            fn from(value: T) -> Self {
+                Self(value)
+            }
+        }
+
+        impl<T> Deref for $name<T> {
+            type Target = T;
+
+            fn deref(&self) -> &T {
+                &self.0
+            }
+        }
+
+        impl<T> DerefMut for $name<T> {
+            fn deref_mut(&mut self) -> &mut T {
+                &mut self.0
+            }
+        }
+    };
+}
+
+impl_wrapper!(HexText);
+impl_wrapper!(Blob);
+
+impl<DB, T> Type<DB> for HexText<T>
+where
+    DB: Database,
+    T: ByteArray,
+    String: Type<DB>,
+{
+    fn type_info() -> DB::TypeInfo {
+        <String as Type<DB>>::type_info()
+    }
+
+    fn compatible(ty: &DB::TypeInfo) -> bool {
+        <String as Type<DB>>::compatible(ty)
+    }
+}
+
+impl<'q, DB, T> Encode<'q, DB> for HexText<T>
+where
+    DB: Database,
+    T: ByteArray,
+    String: Encode<'q, DB>,
+{
+    fn encode_by_ref(&self, buf: &mut DB::ArgumentBuffer<'q>) -> Result<IsNull, BoxDynError> {
+        self.0.to_hex().encode(buf)
+    }
+}
+
+impl<'r, DB, T> Decode<'r, DB> for HexText<T>
+where
+    DB: Database,
+    T: ByteArray,
+    &'r str: Decode<'r, DB>,
+{
+    fn decode(value: DB::ValueRef<'r>) -> Result<Self, BoxDynError> {
+        decode_hex(<&str as Decode<DB>>::decode(value)?).map(Self)
+    }
+}
+
+impl<DB, T> Type<DB> for Blob<T>
+where
+    DB: Database,
+    T: ByteArray,
+    Vec<u8>: Type<DB>,
+{
+    fn type_info() -> DB::TypeInfo {
+        <Vec<u8> as Type<DB>>::type_info()
+    }
+
+    fn compatible(ty: &DB::TypeInfo) -> bool {
+        <Vec<u8> as Type<DB>>::compatible(ty)
+    }
+}
+
+impl<'q, DB, T> Encode<'q, DB> for Blob<T>
+where
+    DB: Database,
+    T: ByteArray,
+    Vec<u8>: Encode<'q, DB>,
+{
+    fn encode_by_ref(&self, buf: &mut DB::ArgumentBuffer<'q>) -> Result<IsNull, BoxDynError> {
+        self.0.to_vec().encode(buf)
+    }
+}
+
+impl<'r, DB, T> Decode<'r, DB> for Blob<T>
+where
+    DB: Database,
+    T: ByteArray,
+    &'r [u8]: Decode<'r, DB>,
+{
+    fn decode(value: DB::ValueRef<'r>) -> Result<Self, BoxDynError> {
+        decode_bytes(<&[u8] as Decode<DB>>::decode(value)?).map(Self)
+    }
+}
+
+/// Parse a hex column value, reporting invalid hex or an incorrect length as a decode error.
+fn decode_hex<T: ByteArray>(hex: &str) -> Result<T, BoxDynError> {
+    T::from_hex(hex).map_err(|e| e.to_string().into())
+}
+
+/// Parse a binary column value, reporting an incorrect length as a decode error.
+fn decode_bytes<T: ByteArray>(bytes: &[u8]) -> Result<T, BoxDynError> {
+    T::from_canonical_bytes(bytes).map_err(|e| e.to_string().into())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn hex_column_round_trip() {
+        let value = [0xabu8; 4];
+        let decoded: [u8; 4] = decode_hex(&value.to_hex()).unwrap();
+        assert_eq!(decoded, value);
+        let decoded: Vec<u8> = decode_hex("00ff").unwrap();
+        assert_eq!(decoded, vec![0x00, 0xff]);
+    }
+
+    #[test]
+    fn invalid_hex_column() {
+        assert!(decode_hex::<[u8; 2]>("zzzz").is_err());
+        assert!(decode_hex::<[u8; 2]>("00").is_err());
+        assert!(decode_hex::<[u8; 2]>("000000").is_err());
+    }
+
+    #[test]
+    fn blob_column() {
+        let decoded: [u8; 3] = decode_bytes(&[1, 2, 3]).unwrap();
+        assert_eq!(decoded, [1, 2, 3]);
+        let err = decode_bytes::<[u8; 3]>(&[1, 2]).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "The input data was the incorrect length to perform the desired conversion"
+        );
+    }
+
+    #[test]
+    fn wrappers() {
+        let mut text = HexText::from([1u8; 2]);
+        text[0] = 2;
+        assert_eq!(text.into_inner(), [2, 1]);
+        assert_eq!(*Blob([3u8; 1]), [3]);
+    }
+}
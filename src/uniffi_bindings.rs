@@ -0,0 +1,122 @@
+// Copyright 2026, The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+//! UniFFI exports of the core types, so Kotlin and Swift bindings can be generated rather than written by hand.
+//!
+//! [EpochTime](crate::epoch_time::EpochTime) is exported as a `u64` number of seconds, and
+//! [SafePassword] as an object that can be constructed from a string and compared in constant time. The hex helpers
+//! are exported as `hex_encode` and `hex_decode`. Generate bindings with `uniffi-bindgen` from a library built with the
+//! `uniffi` feature.
+
+use alloc::{
+    string::{String, ToString},
+    sync::Arc,
+    vec::Vec,
+};
+
+use snafu::prelude::*;
+
+use crate::{encoding::DecodeMode, epoch_time::EpochTime, hex, SafePassword};
+
+/// Errors returned to foreign callers.
+#[derive(Debug, Snafu, PartialEq, Eq, uniffi::Error)]
+#[uniffi(flat_error)]
+pub enum BindingError {
+    /// The input is not valid hex.
+    #[snafu(display("Invalid hex: {reason}"))]
+    InvalidHex {
+        /// Why the input is invalid
+        reason: String,
+    },
+}
+
+/// Returns the current time.
+#[uniffi::export]
+pub fn epoch_time_now() -> EpochTime {
+    EpochTime::now()
+}
+
+/// Returns the number of seconds from `earlier` to `later`, or `None` if `earlier` is after `later`.
+#[uniffi::export]
+pub fn epoch_time_seconds_between(earlier: EpochTime, later: EpochTime) -> Option<u64> {
+    later.checked_sub(earlier).map(EpochTime::as_u64)
+}
+
+/// Encodes bytes as lower case hex.
+#[uniffi::export]
+pub fn hex_encode(bytes: Vec<u8>) -> String {
+    let mut out = String::new();
+    hex::to_hex_into(&bytes, &mut out);
+    out
+}
+
+/// Decodes hex, ignoring whitespace, case and any `0x` prefix.
+#[uniffi::export]
+pub fn hex_decode(hex: String) -> Result<Vec<u8>, BindingError> {
+    hex::from_hex_with_mode(&hex, DecodeMode::Lenient).map_err(|e| BindingError::InvalidHex { reason: e.to_string() })
+}
+
+#[uniffi::export]
+impl SafePassword {
+    /// Creates a password from a string. The foreign copy of the string is outside our control, so callers should
+    /// keep it as short-lived as their platform allows.
+    #[uniffi::constructor]
+    pub fn from_text(password: String) -> Arc<Self> {
+        Arc::new(Self::from(password))
+    }
+
+    /// Compares this password to `candidate` in constant time.
+    pub fn matches(&self, candidate: Arc<Self>) -> bool {
+        bool::from(self.verify_ct(&candidate))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn epoch_time() {
+        let earlier = EpochTime::from(100);
+        let later = EpochTime::from(160);
+        assert_eq!(epoch_time_seconds_between(earlier, later), Some(60));
+        assert_eq!(epoch_time_seconds_between(later, earlier), None);
+        assert!(epoch_time_now() >= later);
+    }
+
+    #[test]
+    fn hex() {
+        assert_eq!(hex_encode(vec![0xca, 0xfe]), "cafe");
+        assert_eq!(hex_decode("0xCAFE".to_string()).unwrap(), vec![0xca, 0xfe]);
+        assert!(matches!(
+            hex_decode("xyz".to_string()),
+            Err(BindingError::InvalidHex { .. })
+        ));
+    }
+
+    #[test]
+    fn password() {
+        let password = SafePassword::from_text("correct horse".to_string());
+        assert!(password.matches(SafePassword::from_text("correct horse".to_string())));
+        assert!(!password.matches(SafePassword::from_text("battery staple".to_string())));
+    }
+}
@@ -0,0 +1,258 @@
+// Copyright 2026, The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+//! Binary payloads wrapped in URIs of the form `scheme:version:data?checksum=crc`, for deep links.
+//!
+//! The data is encoded as unpadded URL-safe Base64, and the checksum is the CRC-32 of everything before the `?`, as 8
+//! lower case hex digits. The scheme is chosen by the caller and is matched ignoring ASCII case, as RFC 3986 requires.
+//! Decoding is strict: anything that does not have exactly this form is rejected.
+//!
+//! ```edition2018
+//! # use tari_utilities::uri::{decode_uri, encode_uri};
+//! let uri = encode_uri("tari", 1, b"payment").unwrap();
+//! assert_eq!(uri, "tari:1:cGF5bWVudA?checksum=5bd7f571");
+//! let payload = decode_uri("tari", &uri).unwrap();
+//! assert_eq!((payload.version, payload.data), (1, b"payment".to_vec()));
+//! assert!(decode_uri("tari", "tari:1:cGF5bWVudB?checksum=5bd7f571").is_err());
+//! ```
+
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+
+use snafu::prelude::*;
+
+use crate::{
+    base64::{self, Config},
+    crc::crc32,
+};
+
+const CHECKSUM_PARAMETER: &str = "?checksum=";
+
+/// A payload decoded from a URI.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UriPayload {
+    /// The payload format version
+    pub version: u32,
+    /// The payload data
+    pub data: Vec<u8>,
+}
+
+/// Errors for URI payload encoding and decoding.
+#[derive(Debug, Snafu, PartialEq, Eq)]
+pub enum UriError {
+    /// The scheme is not a valid RFC 3986 scheme.
+    #[snafu(display("Invalid URI scheme `{scheme}'"))]
+    InvalidScheme {
+        /// The invalid scheme
+        scheme: String,
+    },
+    /// The URI has a different scheme to the one expected.
+    #[snafu(display("Expected URI scheme `{expected}', found `{found}'"))]
+    SchemeMismatch {
+        /// The expected scheme
+        expected: String,
+        /// The scheme of the URI
+        found: String,
+    },
+    /// The URI does not have the form `scheme:version:data?checksum=crc`.
+    #[snafu(display("Malformed URI: `{reason}'"))]
+    Malformed {
+        /// What is wrong with the URI
+        reason: String,
+    },
+    /// The version is not a decimal `u32`.
+    #[snafu(display("Invalid URI payload version `{version}'"))]
+    InvalidVersion {
+        /// The invalid version
+        version: String,
+    },
+    /// The data is not valid URL-safe Base64.
+    #[snafu(display("Invalid URI payload data: `{reason}'"))]
+    InvalidData {
+        /// Why the data is invalid
+        reason: String,
+    },
+    /// The checksum does not match the URI.
+    #[snafu(display("URI checksum mismatch: expected {expected:08x}, found {found:08x}"))]
+    ChecksumMismatch {
+        /// The checksum computed from the URI
+        expected: u32,
+        /// The checksum given in the URI
+        found: u32,
+    },
+}
+
+fn malformed(reason: &str) -> UriError {
+    UriError::Malformed {
+        reason: reason.to_string(),
+    }
+}
+
+fn validate_scheme(scheme: &str) -> Result<(), UriError> {
+    let mut chars = scheme.chars();
+    let valid = chars.next().is_some_and(|c| c.is_ascii_alphabetic()) &&
+        chars.all(|c| c.is_ascii_alphanumeric() || matches!(c, '+' | '-' | '.'));
+    ensure!(valid, InvalidSchemeSnafu { scheme });
+    Ok(())
+}
+
+/// Encode `data` as a `scheme:version:data?checksum=crc` URI.
+pub fn encode_uri(scheme: &str, version: u32, data: &[u8]) -> Result<String, UriError> {
+    validate_scheme(scheme)?;
+    let body = format!(
+        "{}:{}:{}",
+        scheme,
+        version,
+        base64::encode(data, Config::URL_SAFE_NO_PAD)
+    );
+    let checksum = crc32(body.as_bytes());
+    Ok(format!("{}{}{:08x}", body, CHECKSUM_PARAMETER, checksum))
+}
+
+/// Decode a `scheme:version:data?checksum=crc` URI, checking that it has the expected scheme and a valid checksum.
+pub fn decode_uri(scheme: &str, uri: &str) -> Result<UriPayload, UriError> {
+    validate_scheme(scheme)?;
+    let (body, checksum) = uri
+        .split_once(CHECKSUM_PARAMETER)
+        .ok_or_else(|| malformed("missing checksum"))?;
+    ensure!(
+        checksum.len() == 8 && checksum.bytes().all(|c| matches!(c, b'0'..=b'9' | b'a'..=b'f')),
+        MalformedSnafu {
+            reason: "checksum must be 8 lower case hex digits"
+        }
+    );
+    let found = u32::from_str_radix(checksum, 16).map_err(|_| malformed("invalid checksum"))?;
+    let expected = crc32(body.as_bytes());
+    ensure!(expected == found, ChecksumMismatchSnafu { expected, found });
+
+    let mut parts = body.splitn(3, ':');
+    let (uri_scheme, version, data) = match (parts.next(), parts.next(), parts.next()) {
+        (Some(s), Some(v), Some(d)) => (s, v, d),
+        _ => return Err(malformed("expected scheme:version:data")),
+    };
+    ensure!(uri_scheme.eq_ignore_ascii_case(scheme), SchemeMismatchSnafu {
+        expected: scheme,
+        found: uri_scheme
+    });
+    ensure!(
+        !version.is_empty() && version.bytes().all(|c| c.is_ascii_digit()),
+        InvalidVersionSnafu { version }
+    );
+    let version = version.parse().map_err(|_| UriError::InvalidVersion {
+        version: version.to_string(),
+    })?;
+    let data =
+        base64::decode(data, Config::URL_SAFE_NO_PAD).map_err(|e| UriError::InvalidData { reason: e.to_string() })?;
+    Ok(UriPayload { version, data })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn with_checksum(body: &str) -> String {
+        format!("{}?checksum={:08x}", body, crc32(body.as_bytes()))
+    }
+
+    #[test]
+    fn round_trip() {
+        for data in [&b""[..], b"\x00", b"\xfb\xff\xfe contact", &[0xaa; 300]] {
+            let uri = encode_uri("tari-contact", 7, data).unwrap();
+            assert_eq!(decode_uri("tari-contact", &uri).unwrap(), UriPayload {
+                version: 7,
+                data: data.to_vec()
+            });
+        }
+        let uri = encode_uri("tari", u32::MAX, b"x").unwrap();
+        assert_eq!(decode_uri("TARI", &uri).unwrap().version, u32::MAX);
+    }
+
+    #[test]
+    fn schemes() {
+        assert!(encode_uri("web+tari.v2", 1, b"").is_ok());
+        for scheme in ["", "1tari", "ta ri", "tari:", "tari?"] {
+            assert_eq!(
+                encode_uri(scheme, 1, b""),
+                Err(UriError::InvalidScheme {
+                    scheme: scheme.to_string()
+                })
+            );
+        }
+        let uri = encode_uri("tari", 1, b"").unwrap();
+        assert_eq!(
+            decode_uri("other", &uri),
+            Err(UriError::SchemeMismatch {
+                expected: "other".into(),
+                found: "tari".into()
+            })
+        );
+    }
+
+    #[test]
+    fn malformed() {
+        let uri = encode_uri("tari", 1, b"payment").unwrap();
+        assert!(matches!(
+            decode_uri("tari", "tari:1:cGF5bWVudA"),
+            Err(UriError::Malformed { .. })
+        ));
+        assert!(matches!(
+            decode_uri("tari", &uri.to_uppercase()),
+            Err(UriError::Malformed { .. })
+        ));
+        assert!(matches!(
+            decode_uri("tari", &format!("{}&x=1", uri)),
+            Err(UriError::Malformed { .. })
+        ));
+        assert!(matches!(
+            decode_uri("tari", &with_checksum("tari:1")),
+            Err(UriError::Malformed { .. })
+        ));
+        assert!(matches!(
+            decode_uri("tari", &with_checksum("tari:+1:cGF5bWVudA")),
+            Err(UriError::InvalidVersion { .. })
+        ));
+        assert!(matches!(
+            decode_uri("tari", &with_checksum("tari:4294967296:cGF5bWVudA")),
+            Err(UriError::InvalidVersion { .. })
+        ));
+        assert!(matches!(
+            decode_uri("tari", &with_checksum("tari:1:cGF5bWVudA==")),
+            Err(UriError::InvalidData { .. })
+        ));
+        assert!(matches!(
+            decode_uri("tari", &with_checksum("tari:1:cGF5b:WVudA")),
+            Err(UriError::InvalidData { .. })
+        ));
+    }
+
+    #[test]
+    fn checksum_mismatch() {
+        let uri = encode_uri("tari", 1, b"payment").unwrap();
+        let tampered = uri.replace("tari:1:", "tari:2:");
+        assert!(matches!(
+            decode_uri("tari", &tampered),
+            Err(UriError::ChecksumMismatch { .. })
+        ));
+    }
+}
@@ -0,0 +1,132 @@
+// Copyright 2026, The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+//! A [Validated] trait for checking the invariants of a value, such as a length or a range, in one place.
+//!
+//! Implementing [Validated] for a type lets it be decoded with
+//! [from_binary_validated](crate::message_format::MessageFormat::from_binary_validated) and the other `_validated`
+//! methods of `MessageFormat`, which reject values that decode successfully but do not pass [Validated::validate].
+//!
+//! ```edition2018
+//! # use tari_utilities::validate::{Validated, ValidationError};
+//! struct Fee {
+//!     amount: u64,
+//! }
+//!
+//! impl Validated for Fee {
+//!     fn validate(&self) -> Result<(), ValidationError> {
+//!         if self.amount > 1_000_000 {
+//!             return Err(ValidationError::invalid_field(
+//!                 "amount",
+//!                 "must not exceed 1000000",
+//!             ));
+//!         }
+//!         Ok(())
+//!     }
+//! }
+//!
+//! assert!(Fee { amount: 10 }.validate().is_ok());
+//! assert_eq!(
+//!     Fee { amount: 2_000_000 }
+//!         .validate()
+//!         .unwrap_err()
+//!         .to_string(),
+//!     "Invalid value for `amount': `must not exceed 1000000'"
+//! );
+//! ```
+
+use alloc::string::{String, ToString};
+
+use snafu::prelude::*;
+
+/// Errors for [Validated] trait.
+#[derive(Debug, Snafu, PartialEq, Eq)]
+pub enum ValidationError {
+    /// A field does not hold a valid value.
+    #[snafu(display("Invalid value for `{field}': `{reason}'"))]
+    InvalidField {
+        /// The name of the field
+        field: &'static str,
+        /// Why the value is invalid
+        reason: String,
+    },
+    /// An invariant that involves the value as a whole does not hold.
+    #[snafu(display("Invalid value: `{reason}'"))]
+    Invalid {
+        /// Why the value is invalid
+        reason: String,
+    },
+}
+
+impl ValidationError {
+    /// Create an [InvalidField](ValidationError::InvalidField) error.
+    pub fn invalid_field<R: ToString>(field: &'static str, reason: R) -> Self {
+        ValidationError::InvalidField {
+            field,
+            reason: reason.to_string(),
+        }
+    }
+
+    /// Create an [Invalid](ValidationError::Invalid) error.
+    pub fn invalid<R: ToString>(reason: R) -> Self {
+        ValidationError::Invalid {
+            reason: reason.to_string(),
+        }
+    }
+}
+
+/// Trait for types with invariants that must be checked after the type is constructed or decoded.
+pub trait Validated {
+    /// Check the invariants of the value.
+    fn validate(&self) -> Result<(), ValidationError>;
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    struct Range {
+        start: u32,
+        end: u32,
+    }
+
+    impl Validated for Range {
+        fn validate(&self) -> Result<(), ValidationError> {
+            ensure!(self.start <= self.end, InvalidSnafu {
+                reason: "start is after end"
+            });
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn validate() {
+        assert!(Range { start: 1, end: 2 }.validate().is_ok());
+        assert_eq!(
+            Range { start: 2, end: 1 }.validate(),
+            Err(ValidationError::invalid("start is after end"))
+        );
+        assert_eq!(
+            ValidationError::invalid_field("len", 5).to_string(),
+            "Invalid value for `len': `5'"
+        );
+    }
+}
@@ -0,0 +1,356 @@
+// Copyright 2026, The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+//! Variable-length integer (unsigned LEB128) encoding, as used by protobuf and many compact wire formats.
+//!
+//! Each byte carries seven bits of the value, least significant group first, with the high bit set on every byte but
+//! the last. Signed values are first mapped to unsigned values with zigzag encoding, so that small negative numbers
+//! stay short.
+//!
+//! ```edition2018
+//! # use tari_utilities::varint;
+//! let mut buf = Vec::new();
+//! varint::write_u64(300, &mut buf);
+//! assert_eq!(buf, vec![0xac, 0x02]);
+//! assert_eq!(varint::decode_u64(&buf), Ok((300, 2)));
+//!
+//! varint::write_i64(-2, &mut buf);
+//! assert_eq!(varint::decode_i64(&buf[2..]), Ok((-2, 1)));
+//! ```
+
+#[cfg(feature = "std")]
+use alloc::string::ToString;
+use alloc::{string::String, vec::Vec};
+#[cfg(feature = "std")]
+use std::io::{self, Read};
+
+use snafu::prelude::*;
+
+/// The maximum encoded length of a `u32`.
+pub const MAX_LEN_U32: usize = 5;
+/// The maximum encoded length of a `u64` or `i64`.
+pub const MAX_LEN_U64: usize = 10;
+
+/// Errors for varint encoding and decoding.
+#[derive(Debug, Snafu, PartialEq, Eq)]
+pub enum VarintError {
+    /// The input ended in the middle of a varint.
+    #[snafu(display("Varint is truncated"))]
+    Truncated {},
+    /// The varint is longer than the maximum length for its type.
+    #[snafu(display("Varint is longer than the maximum of {max} bytes"))]
+    TooLong {
+        /// The maximum encoded length for the type
+        max: usize,
+    },
+    /// The varint encodes a value that does not fit in the type.
+    #[snafu(display("Varint value overflows a {bits}-bit integer"))]
+    Overflow {
+        /// The width of the type
+        bits: u32,
+    },
+    /// The output buffer is too small for the encoded value.
+    #[snafu(display("Buffer of {len} bytes is too small, {needed} bytes are needed"))]
+    BufferTooSmall {
+        /// The length of the buffer
+        len: usize,
+        /// The number of bytes needed
+        needed: usize,
+    },
+    /// Reading from the underlying reader failed.
+    #[snafu(display("I/O error: `{reason}'"))]
+    Io {
+        /// The I/O error
+        reason: String,
+    },
+}
+
+/// Maps a signed integer to an unsigned one, so that values close to zero have short encodings: 0, -1, 1, -2, ... map
+/// to 0, 1, 2, 3, ...
+pub fn zigzag_encode(value: i64) -> u64 {
+    ((value << 1) ^ (value >> 63)) as u64
+}
+
+/// The inverse of [zigzag_encode].
+pub fn zigzag_decode(value: u64) -> i64 {
+    ((value >> 1) as i64) ^ -((value & 1) as i64)
+}
+
+/// Returns the encoded length of `value`.
+pub fn encoded_len(value: u64) -> usize {
+    let bits = 64 - (value | 1).leading_zeros() as usize;
+    bits.div_ceil(7)
+}
+
+/// Appends the encoding of `value` to `out`, returning the number of bytes written.
+pub fn write_u64(value: u64, out: &mut Vec<u8>) -> usize {
+    let mut buf = [0u8; MAX_LEN_U64];
+    // `encode_u64` only fails if the buffer is too short, and every u64 fits in `MAX_LEN_U64` bytes
+    let len = encode_u64(value, &mut buf).unwrap_or_else(|_| unreachable!("the buffer has the maximum length"));
+    out.extend_from_slice(&buf[..len]);
+    len
+}
+
+/// Appends the encoding of `value` to `out`, returning the number of bytes written.
+pub fn write_u32(value: u32, out: &mut Vec<u8>) -> usize {
+    write_u64(u64::from(value), out)
+}
+
+/// Appends the zigzag encoding of `value` to `out`, returning the number of bytes written.
+pub fn write_i64(value: i64, out: &mut Vec<u8>) -> usize {
+    write_u64(zigzag_encode(value), out)
+}
+
+/// Encodes `value` into the start of `buf`, returning the number of bytes written.
+pub fn encode_u64(mut value: u64, buf: &mut [u8]) -> Result<usize, VarintError> {
+    let needed = encoded_len(value);
+    ensure!(buf.len() >= needed, BufferTooSmallSnafu { len: buf.len(), needed });
+    for byte in buf.iter_mut().take(needed - 1) {
+        *byte = (value as u8 & 0x7f) | 0x80;
+        value >>= 7;
+    }
+    buf[needed - 1] = value as u8;
+    Ok(needed)
+}
+
+/// Encodes `value` into the start of `buf`, returning the number of bytes written.
+pub fn encode_u32(value: u32, buf: &mut [u8]) -> Result<usize, VarintError> {
+    encode_u64(u64::from(value), buf)
+}
+
+/// Encodes the zigzag encoding of `value` into the start of `buf`, returning the number of bytes written.
+pub fn encode_i64(value: i64, buf: &mut [u8]) -> Result<usize, VarintError> {
+    encode_u64(zigzag_encode(value), buf)
+}
+
+/// Decodes a `u64` from the start of `bytes`, returning the value and the number of bytes read.
+pub fn decode_u64(bytes: &[u8]) -> Result<(u64, usize), VarintError> {
+    let mut decoder = Decoder::new(64, MAX_LEN_U64);
+    for byte in bytes {
+        if let Some(value) = decoder.push(*byte)? {
+            return Ok((value, decoder.len));
+        }
+    }
+    TruncatedSnafu.fail()
+}
+
+/// Decodes a `u32` from the start of `bytes`, returning the value and the number of bytes read.
+pub fn decode_u32(bytes: &[u8]) -> Result<(u32, usize), VarintError> {
+    let mut decoder = Decoder::new(32, MAX_LEN_U32);
+    for byte in bytes {
+        if let Some(value) = decoder.push(*byte)? {
+            return Ok((value as u32, decoder.len));
+        }
+    }
+    TruncatedSnafu.fail()
+}
+
+/// Decodes a zigzag-encoded `i64` from the start of `bytes`, returning the value and the number of bytes read.
+pub fn decode_i64(bytes: &[u8]) -> Result<(i64, usize), VarintError> {
+    decode_u64(bytes).map(|(value, len)| (zigzag_decode(value), len))
+}
+
+/// Incremental decoder that enforces the maximum length and value range of the target type.
+struct Decoder {
+    bits: u32,
+    max_len: usize,
+    value: u64,
+    len: usize,
+}
+
+impl Decoder {
+    fn new(bits: u32, max_len: usize) -> Self {
+        Self {
+            bits,
+            max_len,
+            value: 0,
+            len: 0,
+        }
+    }
+
+    /// Adds a byte, returning the value once the final byte has been seen.
+    fn push(&mut self, byte: u8) -> Result<Option<u64>, VarintError> {
+        ensure!(self.len < self.max_len, TooLongSnafu { max: self.max_len });
+        let shift = 7 * self.len as u32;
+        let group = u64::from(byte & 0x7f);
+        // Bits of this group that would land beyond the width of the type
+        let remaining = self.bits.saturating_sub(shift);
+        ensure!(remaining >= 7 || group >> remaining == 0, OverflowSnafu {
+            bits: self.bits
+        });
+        self.value |= group << shift;
+        self.len += 1;
+        Ok((byte & 0x80 == 0).then_some(self.value))
+    }
+}
+
+/// Reads varints from a byte stream, one byte at a time, without reading past the end of each varint.
+#[cfg(feature = "std")]
+#[derive(Debug)]
+pub struct VarintReader<R> {
+    inner: R,
+}
+
+#[cfg(feature = "std")]
+impl<R: Read> VarintReader<R> {
+    /// Wraps a reader.
+    pub fn new(inner: R) -> Self {
+        Self { inner }
+    }
+
+    /// Reads a `u64`.
+    pub fn read_u64(&mut self) -> Result<u64, VarintError> {
+        self.read(Decoder::new(64, MAX_LEN_U64))
+    }
+
+    /// Reads a `u32`.
+    pub fn read_u32(&mut self) -> Result<u32, VarintError> {
+        self.read(Decoder::new(32, MAX_LEN_U32)).map(|value| value as u32)
+    }
+
+    /// Reads a zigzag-encoded `i64`.
+    pub fn read_i64(&mut self) -> Result<i64, VarintError> {
+        self.read_u64().map(zigzag_decode)
+    }
+
+    /// Returns a reference to the underlying reader.
+    pub fn get_ref(&self) -> &R {
+        &self.inner
+    }
+
+    /// Returns the underlying reader.
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+
+    fn read(&mut self, mut decoder: Decoder) -> Result<u64, VarintError> {
+        let mut byte = [0u8; 1];
+        loop {
+            match self.inner.read(&mut byte) {
+                Ok(0) => return TruncatedSnafu.fail(),
+                Ok(_) => {
+                    if let Some(value) = decoder.push(byte[0])? {
+                        return Ok(value);
+                    }
+                },
+                Err(e) if e.kind() == io::ErrorKind::Interrupted => {},
+                Err(e) => return IoSnafu { reason: e.to_string() }.fail(),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn known_encodings() {
+        let vectors: [(u64, &[u8]); 6] = [
+            (0, &[0x00]),
+            (1, &[0x01]),
+            (127, &[0x7f]),
+            (128, &[0x80, 0x01]),
+            (300, &[0xac, 0x02]),
+            (u64::MAX, &[0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x01]),
+        ];
+        for (value, encoded) in vectors {
+            let mut out = Vec::new();
+            assert_eq!(write_u64(value, &mut out), encoded.len());
+            assert_eq!(out, encoded);
+            assert_eq!(encoded_len(value), encoded.len());
+            assert_eq!(decode_u64(encoded), Ok((value, encoded.len())));
+        }
+    }
+
+    #[test]
+    fn zigzag() {
+        for (signed, unsigned) in [
+            (0i64, 0u64),
+            (-1, 1),
+            (1, 2),
+            (-2, 3),
+            (i64::MAX, u64::MAX - 1),
+            (i64::MIN, u64::MAX),
+        ] {
+            assert_eq!(zigzag_encode(signed), unsigned);
+            assert_eq!(zigzag_decode(unsigned), signed);
+        }
+        let mut buf = [0u8; MAX_LEN_U64];
+        let len = encode_i64(i64::MIN, &mut buf).unwrap();
+        assert_eq!(decode_i64(&buf[..len]), Ok((i64::MIN, 10)));
+    }
+
+    #[test]
+    fn u32_limits() {
+        let mut buf = [0u8; MAX_LEN_U32];
+        let len = encode_u32(u32::MAX, &mut buf).unwrap();
+        assert_eq!(len, 5);
+        assert_eq!(decode_u32(&buf), Ok((u32::MAX, 5)));
+        // 2^32 needs 33 bits
+        assert_eq!(
+            decode_u32(&[0x80, 0x80, 0x80, 0x80, 0x10]),
+            Err(VarintError::Overflow { bits: 32 })
+        );
+        assert_eq!(
+            decode_u32(&[0x80, 0x80, 0x80, 0x80, 0x80, 0x00]),
+            Err(VarintError::TooLong { max: 5 })
+        );
+        let mut out = Vec::new();
+        write_u32(7, &mut out);
+        assert_eq!(out, vec![7]);
+    }
+
+    #[test]
+    fn malformed() {
+        assert_eq!(decode_u64(&[]), Err(VarintError::Truncated {}));
+        assert_eq!(decode_u64(&[0x80, 0x80]), Err(VarintError::Truncated {}));
+        assert_eq!(decode_u64(&[0x80; 11]), Err(VarintError::TooLong { max: 10 }));
+        assert_eq!(
+            decode_u64(&[0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x02]),
+            Err(VarintError::Overflow { bits: 64 })
+        );
+        assert_eq!(
+            encode_u64(300, &mut [0u8; 1]),
+            Err(VarintError::BufferTooSmall { len: 1, needed: 2 })
+        );
+        // Trailing data is left for the caller
+        assert_eq!(decode_u64(&[0x01, 0xff]), Ok((1, 1)));
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn reader() {
+        let mut data = Vec::new();
+        write_u64(300, &mut data);
+        write_u32(u32::MAX, &mut data);
+        write_i64(-5, &mut data);
+        data.push(0x80);
+        let mut reader = VarintReader::new(&data[..]);
+        assert_eq!(reader.read_u64(), Ok(300));
+        assert_eq!(reader.read_u32(), Ok(u32::MAX));
+        assert_eq!(reader.read_i64(), Ok(-5));
+        assert_eq!(reader.get_ref().len(), 1);
+        assert_eq!(reader.read_u64(), Err(VarintError::Truncated {}));
+        assert!(reader.into_inner().is_empty());
+    }
+}
@@ -0,0 +1,175 @@
+// Copyright 2026, The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+//! WebAssembly bindings for the codecs and [MessageFormat], so that JavaScript callers use the same encoding rules as
+//! the Rust code.
+//!
+//! The codec functions are exported to JavaScript with camel case names such as `hexEncode` and `base64Decode`. Byte
+//! arrays cross the boundary as `Uint8Array`, and errors are thrown as strings. Decoders are strict, as their Rust
+//! counterparts are, and `base58MoneroEncode` matches [Base58::to_base58](crate::encoding::Base58::to_base58). Message
+//! types are specific to each application, so [to_json_value] and [from_json_value] are plain Rust helpers for use in
+//! an application's own `#[wasm_bindgen]` functions. The [JsValueFormat] methods convert messages directly to and from
+//! JavaScript values, which avoids serializing to a JSON string and parsing it again on the JavaScript side.
+
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::fmt::Display;
+
+use serde::{de::DeserializeOwned, Serialize};
+use wasm_bindgen::prelude::*;
+
+use crate::{
+    base58,
+    base64::{self, Config},
+    encoding::{Base58MoneroEncoding, DecodeMode, Encoding},
+    hex,
+    message_format::MessageFormat,
+};
+
+fn js_error<E: Display>(error: E) -> JsValue {
+    JsValue::from_str(&error.to_string())
+}
+
+/// Encode bytes as lower case hex.
+#[wasm_bindgen(js_name = hexEncode)]
+pub fn hex_encode(data: &[u8]) -> String {
+    let mut out = String::new();
+    hex::to_hex_into(data, &mut out);
+    out
+}
+
+/// Decode lower case hex, as [hex::from_hex] does.
+#[wasm_bindgen(js_name = hexDecode)]
+pub fn hex_decode(s: &str) -> Result<Vec<u8>, JsValue> {
+    hex::from_hex(s).map_err(js_error)
+}
+
+/// Decode hex, ignoring whitespace, case and any `0x` prefix.
+#[wasm_bindgen(js_name = hexDecodeLenient)]
+pub fn hex_decode_lenient(s: &str) -> Result<Vec<u8>, JsValue> {
+    hex::from_hex_with_mode(s, DecodeMode::Lenient).map_err(js_error)
+}
+
+/// Encode bytes as padded standard Base64, as used by [MessageFormat::to_base64].
+#[wasm_bindgen(js_name = base64Encode)]
+pub fn base64_encode(data: &[u8]) -> String {
+    base64::encode(data, Config::STANDARD)
+}
+
+/// Decode padded standard Base64.
+#[wasm_bindgen(js_name = base64Decode)]
+pub fn base64_decode(s: &str) -> Result<Vec<u8>, JsValue> {
+    base64::decode(s, Config::STANDARD).map_err(js_error)
+}
+
+/// Encode bytes as Monero block-based Base58, as [Base58::to_base58](crate::encoding::Base58::to_base58) does.
+#[wasm_bindgen(js_name = base58MoneroEncode)]
+pub fn base58_monero_encode(data: &[u8]) -> String {
+    Base58MoneroEncoding.encode(data)
+}
+
+/// Decode Monero block-based Base58.
+#[wasm_bindgen(js_name = base58MoneroDecode)]
+pub fn base58_monero_decode(s: &str) -> Result<Vec<u8>, JsValue> {
+    Base58MoneroEncoding.decode(s).map_err(js_error)
+}
+
+/// Encode bytes as Base58 with the Bitcoin alphabet.
+#[wasm_bindgen(js_name = base58BtcEncode)]
+pub fn base58_btc_encode(data: &[u8]) -> String {
+    base58::encode(data)
+}
+
+/// Decode Base58 with the Bitcoin alphabet.
+#[wasm_bindgen(js_name = base58BtcDecode)]
+pub fn base58_btc_decode(s: &str) -> Result<Vec<u8>, JsValue> {
+    base58::decode(s).map_err(js_error)
+}
+
+/// Convert a message to its JSON form as a JavaScript string.
+pub fn to_json_value<T: MessageFormat>(value: &T) -> Result<JsValue, JsValue> {
+    value.to_json().map(|json| JsValue::from_str(&json)).map_err(js_error)
+}
+
+/// Convert a JavaScript string holding the JSON form of a message back to the message.
+pub fn from_json_value<T: MessageFormat>(value: &JsValue) -> Result<T, JsValue> {
+    let json = value
+        .as_string()
+        .ok_or_else(|| JsValue::from_str("Expected a JSON string"))?;
+    T::from_json(&json).map_err(js_error)
+}
+
+/// Conversion of messages to and from JavaScript values, using `serde_wasm_bindgen`.
+///
+/// Structs become plain JavaScript objects, sequences become arrays and byte payloads serialized with
+/// [crate::serde::bytes] become `Uint8Array`s.
+pub trait JsValueFormat: Sized {
+    /// Convert to a JavaScript value.
+    fn to_js_value(&self) -> Result<JsValue, JsValue>;
+    /// Convert from a JavaScript value.
+    fn from_js_value(value: JsValue) -> Result<Self, JsValue>;
+}
+
+impl<T> JsValueFormat for T
+where T: DeserializeOwned + Serialize
+{
+    fn to_js_value(&self) -> Result<JsValue, JsValue> {
+        serde_wasm_bindgen::to_value(self).map_err(JsValue::from)
+    }
+
+    fn from_js_value(value: JsValue) -> Result<Self, JsValue> {
+        serde_wasm_bindgen::from_value(value).map_err(JsValue::from)
+    }
+}
+
+// Anything that creates a `JsValue` needs a JavaScript host, so only the successful codec paths are tested natively
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn codecs() {
+        let data = (0..=255u8).collect::<Vec<_>>();
+        assert_eq!(hex_decode(&hex_encode(&data)).unwrap(), data);
+        assert_eq!(hex_encode(&[0xca, 0xfe]), "cafe");
+        assert_eq!(base64_decode(&base64_encode(&data)).unwrap(), data);
+        assert_eq!(base64_encode(b"foob"), "Zm9vYg==");
+    }
+
+    #[test]
+    fn hex_modes() {
+        assert_eq!(hex_decode_lenient(" 0xCAFE ").unwrap(), vec![0xca, 0xfe]);
+    }
+
+    #[test]
+    fn base58() {
+        use crate::encoding::Base58;
+
+        let key = [7u8; 32];
+        assert_eq!(base58_monero_encode(&key), key.to_base58());
+        assert_eq!(base58_monero_decode(&key.to_base58()).unwrap(), key);
+        assert_eq!(base58_btc_encode(b"hello world"), "StV1DL6CwTryKyV");
+        assert_eq!(base58_btc_decode("StV1DL6CwTryKyV").unwrap(), b"hello world");
+    }
+}
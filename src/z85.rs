@@ -0,0 +1,149 @@
+// Copyright 2026, The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+//! [Z85](https://rfc.zeromq.org/spec/32/) encoding and decoding, the ZeroMQ flavour of Ascii85.
+//!
+//! Z85 encodes each 4 byte group as 5 printable characters, so the input to [encode] must be a multiple of 4 bytes
+//! long and the input to [decode] a multiple of 5 characters long. No padding scheme is defined.
+//!
+//! ```edition2018
+//! # use tari_utilities::z85;
+//! let data = [0x86, 0x4f, 0xd2, 0x6f, 0xb5, 0x59, 0xf7, 0x5b];
+//! assert_eq!(z85::encode(&data).unwrap(), "HelloWorld");
+//! assert_eq!(z85::decode("HelloWorld").unwrap(), data);
+//! ```
+
+use alloc::{string::String, vec::Vec};
+use core::convert::TryFrom;
+
+use snafu::prelude::*;
+
+/// The Z85 alphabet.
+pub const ALPHABET: &[u8; 85] =
+    b"0123456789abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ.-:+=^!/*?&<>()[]{}@%$#";
+
+/// Errors for Z85 encoding and decoding.
+#[derive(Debug, Snafu, PartialEq, Eq)]
+pub enum Z85Error {
+    /// The data to encode is not a multiple of 4 bytes long.
+    #[snafu(display("Z85 can only encode a multiple of 4 bytes, got {len}"))]
+    InvalidDataLength {
+        /// The length of the data
+        len: usize,
+    },
+    /// The string to decode is not a multiple of 5 characters long.
+    #[snafu(display("Z85 can only decode a multiple of 5 characters, got {len}"))]
+    InvalidEncodedLength {
+        /// The length of the string
+        len: usize,
+    },
+    /// The input contains a character that is not in the alphabet.
+    #[snafu(display("Invalid Z85 character `{character}' at position {index}"))]
+    InvalidCharacter {
+        /// The offending character
+        character: char,
+        /// The position of the character in the input
+        index: usize,
+    },
+    /// A group of 5 characters encodes a value that does not fit in 4 bytes.
+    #[snafu(display("Z85 group at position {index} overflows 32 bits"))]
+    Overflow {
+        /// The position of the first character of the group
+        index: usize,
+    },
+}
+
+/// Encode bytes as Z85. The data must be a multiple of 4 bytes long.
+pub fn encode(data: &[u8]) -> Result<String, Z85Error> {
+    ensure!(data.len().is_multiple_of(4), InvalidDataLengthSnafu { len: data.len() });
+    let mut s = String::with_capacity(data.len() / 4 * 5);
+    for chunk in data.chunks_exact(4) {
+        let mut value = u32::from_be_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]);
+        let mut group = [0u8; 5];
+        for c in group.iter_mut().rev() {
+            *c = ALPHABET[(value % 85) as usize];
+            value /= 85;
+        }
+        s.extend(group.iter().map(|c| char::from(*c)));
+    }
+    Ok(s)
+}
+
+/// Decode Z85. The string must be a multiple of 5 characters long.
+pub fn decode(s: &str) -> Result<Vec<u8>, Z85Error> {
+    let len = s.chars().count();
+    ensure!(len.is_multiple_of(5), InvalidEncodedLengthSnafu { len });
+    let chars = s.chars().collect::<Vec<_>>();
+    let mut out = Vec::with_capacity(len / 5 * 4);
+    for (group, chunk) in chars.chunks_exact(5).enumerate() {
+        let mut value = 0u64;
+        for (offset, character) in chunk.iter().enumerate() {
+            let index = group * 5 + offset;
+            let digit = ALPHABET
+                .iter()
+                .position(|a| char::from(*a) == *character)
+                .context(InvalidCharacterSnafu {
+                    character: *character,
+                    index,
+                })?;
+            value = value * 85 + digit as u64;
+        }
+        let value = u32::try_from(value).ok().context(OverflowSnafu { index: group * 5 })?;
+        out.extend_from_slice(&value.to_be_bytes());
+    }
+    Ok(out)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn spec_vector() {
+        let data = [0x86, 0x4f, 0xd2, 0x6f, 0xb5, 0x59, 0xf7, 0x5b];
+        assert_eq!(encode(&data).unwrap(), "HelloWorld");
+        assert_eq!(decode("HelloWorld").unwrap(), data);
+        assert_eq!(encode(&[]).unwrap(), "");
+        assert_eq!(decode("").unwrap(), Vec::<u8>::new());
+    }
+
+    #[test]
+    fn round_trip() {
+        let data = (0..=255u8).collect::<Vec<_>>();
+        assert_eq!(decode(&encode(&data).unwrap()).unwrap(), data);
+        assert_eq!(encode(&[0xff; 4]).unwrap(), "%nSc0");
+    }
+
+    #[test]
+    fn invalid_input() {
+        assert_eq!(encode(&[1, 2, 3]), Err(Z85Error::InvalidDataLength { len: 3 }));
+        assert_eq!(decode("Hello1"), Err(Z85Error::InvalidEncodedLength { len: 6 }));
+        assert_eq!(
+            decode("Hello Worl"),
+            Err(Z85Error::InvalidCharacter {
+                character: ' ',
+                index: 5
+            })
+        );
+        assert_eq!(decode("Hello%nSc1"), Err(Z85Error::Overflow { index: 5 }));
+    }
+}